@@ -1,4 +1,5 @@
-//! `toon` CLI — encode, decode, and analyze TOON files from the command line.
+//! `toon` CLI — encode, decode, and analyze TOON files from the command line, and
+//! expand recurrence rules with `truth-engine`.
 //!
 //! ## Usage
 //!
@@ -15,21 +16,43 @@
 //! # Encode with Google Calendar preset filter
 //! toon encode --filter-preset google -i calendar.json
 //!
+//! # Encode with field patterns listed in a file (one per line, `#` comments)
+//! toon encode --filter-file patterns.txt -i calendar.json
+//!
 //! # Decode TOON back to pretty-printed JSON
 //! toon decode -i data.toon
 //!
+//! # Rewrite a hand-edited TOON file in canonical layout
+//! toon fmt --in-place -i data.toon
+//!
+//! # Labeled records instead of tabular rows for arrays of objects
+//! toon encode --array-style records -i calendar.json
+//!
+//! # Tab-delimited arrays for comma-heavy data (decode with the same delimiter)
+//! toon encode --delimiter tab -i places.json | toon decode --delimiter tab
+//!
 //! # Show compression statistics
 //! toon stats -i data.json
 //!
+//! # Show the structure of a TOON document
+//! toon analyze -i data.toon
+//!
+//! # Expand an RRULE into instances (TOON table, or JSON with --json)
+//! toon rrule --rule "FREQ=WEEKLY;BYDAY=TU,TH" --dtstart 2026-02-17T14:00:00 \
+//!     --duration 60 --tz America/Los_Angeles --count 10
+//!
+//! # Machine-readable errors on stderr: {"error":"...","context":["..."]}
+//! toon decode --json-errors -i data.toon
+//!
 //! # Managed Cortex mode (stub)
 //! toon --managed-cortex --api-key YOUR_KEY
 //! ```
 
 use anyhow::{Context, Result};
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use std::io::{self, Read};
 use std::process;
-use toon_core::CalendarFilter;
+use toon_core::{ArrayStyle, CalendarFilter, DecodeOptions, Delimiter, EncodeOptions, ToonError};
 
 #[derive(Parser)]
 #[command(
@@ -48,15 +71,23 @@ struct Cli {
     /// API key for the managed Cortex service (requires --managed-cortex)
     #[arg(long, requires = "managed_cortex")]
     api_key: Option<String>,
+
+    /// On failure, print the error to stderr as a JSON object instead of text
+    #[arg(long, global = true)]
+    json_errors: bool,
 }
 
 #[derive(Subcommand)]
 enum Commands {
     /// Encode JSON to TOON format
     Encode {
-        /// Input file (reads from stdin if omitted)
+        /// Input file (reads from stdin if omitted). Repeat it, or list files
+        /// positionally, to encode several JSON documents as one TOON array.
         #[arg(short, long)]
-        input: Option<String>,
+        input: Vec<String>,
+        /// More input files, encoded together with any `--input` files
+        #[arg(value_name = "FILE")]
+        files: Vec<String>,
         /// Output file (writes to stdout if omitted)
         #[arg(short, long)]
         output: Option<String>,
@@ -66,6 +97,20 @@ enum Commands {
         /// Use a predefined filter preset (e.g., "google" for Google Calendar)
         #[arg(long)]
         filter_preset: Option<String>,
+        /// File of field patterns to strip, one per line (blank lines and lines
+        /// starting with `#` are ignored)
+        #[arg(long)]
+        filter_file: Option<String>,
+        /// Separator between array values and tabular cells
+        #[arg(long, value_enum, default_value_t = DelimiterArg::Comma)]
+        delimiter: DelimiterArg,
+        /// How to render arrays of uniform objects: compact `tabular` rows or
+        /// labeled `records` (one `key: value` per field, more tokens)
+        #[arg(long, value_enum, default_value_t = ArrayStyleArg::Tabular)]
+        array_style: ArrayStyleArg,
+        /// End the output with a newline (the TOON spec omits it)
+        #[arg(long)]
+        newline: bool,
     },
     /// Decode TOON back to JSON format
     Decode {
@@ -75,6 +120,26 @@ enum Commands {
         /// Output file (writes to stdout if omitted)
         #[arg(short, long)]
         output: Option<String>,
+        /// Separator the input was encoded with. TOON does not record it, so this
+        /// must match the `--delimiter` used for `encode`.
+        #[arg(long, value_enum, default_value_t = DelimiterArg::Comma)]
+        delimiter: DelimiterArg,
+    },
+    /// Rewrite a TOON document in canonical layout (indentation, number forms,
+    /// array syntax), keeping key order
+    Fmt {
+        /// Input file (reads from stdin if omitted)
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Output file (writes to stdout if omitted)
+        #[arg(short, long, conflicts_with = "in_place")]
+        output: Option<String>,
+        /// Overwrite the input file with the formatted document
+        #[arg(long, requires = "input")]
+        in_place: bool,
+        /// Separator the input was encoded with; the output keeps it
+        #[arg(long, value_enum, default_value_t = DelimiterArg::Comma)]
+        delimiter: DelimiterArg,
     },
     /// Show encoding statistics (token counts, compression ratio)
     Stats {
@@ -82,11 +147,98 @@ enum Commands {
         #[arg(short, long)]
         input: Option<String>,
     },
+    /// Show the structure of a TOON document (containers, array forms, depth)
+    Analyze {
+        /// Input TOON file (reads from stdin if omitted)
+        #[arg(short, long)]
+        input: Option<String>,
+    },
+    /// Expand an RRULE into concrete instances (UTC start/end)
+    Rrule {
+        /// RFC 5545 RRULE, e.g. "FREQ=WEEKLY;BYDAY=TU,TH"
+        #[arg(long)]
+        rule: String,
+        /// Local start of the first instance, e.g. 2026-02-17T14:00:00
+        #[arg(long)]
+        dtstart: String,
+        /// Length of each instance in minutes
+        #[arg(long, default_value_t = 60)]
+        duration: u32,
+        /// IANA timezone `--dtstart` and `--until` are local to
+        #[arg(long, default_value = "UTC")]
+        tz: String,
+        /// Maximum number of instances
+        #[arg(long)]
+        count: Option<u32>,
+        /// Local datetime after which no instances are produced
+        #[arg(long)]
+        until: Option<String>,
+        /// Print pretty JSON instead of TOON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// `--delimiter` values, mirroring [`toon_core::Delimiter`].
+#[derive(Clone, Copy, ValueEnum)]
+enum DelimiterArg {
+    Comma,
+    Tab,
+    Semicolon,
+    Pipe,
+}
+
+impl From<DelimiterArg> for Delimiter {
+    fn from(arg: DelimiterArg) -> Self {
+        match arg {
+            DelimiterArg::Comma => Delimiter::Comma,
+            DelimiterArg::Tab => Delimiter::Tab,
+            DelimiterArg::Semicolon => Delimiter::Semicolon,
+            DelimiterArg::Pipe => Delimiter::Pipe,
+        }
+    }
+}
+
+/// `--array-style` values, mirroring [`toon_core::ArrayStyle`].
+#[derive(Clone, Copy, ValueEnum)]
+enum ArrayStyleArg {
+    Tabular,
+    Records,
+}
+
+impl From<ArrayStyleArg> for ArrayStyle {
+    fn from(arg: ArrayStyleArg) -> Self {
+        match arg {
+            ArrayStyleArg::Tabular => ArrayStyle::Tabular,
+            ArrayStyleArg::Records => ArrayStyle::Records,
+        }
+    }
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
+    let json_errors = cli.json_errors;
+    let result = run(cli);
+    if json_errors {
+        if let Err(err) = &result {
+            eprintln!("{}", error_json(err));
+            process::exit(1);
+        }
+    }
+    result
+}
 
+/// Render an error for `--json-errors` as `{"error":"...","context":[...]}`.
+///
+/// `error` is the root cause; `context` lists the messages wrapped around it,
+/// outermost first (e.g. `"Failed to decode TOON to JSON"`).
+fn error_json(err: &anyhow::Error) -> serde_json::Value {
+    let mut messages: Vec<String> = err.chain().map(|cause| cause.to_string()).collect();
+    let error = messages.pop().unwrap_or_default();
+    serde_json::json!({ "error": error, "context": messages })
+}
+
+fn run(cli: Cli) -> Result<()> {
     // Handle --managed-cortex before subcommands
     if cli.managed_cortex {
         if cli.api_key.is_none() {
@@ -110,47 +262,151 @@ fn main() -> Result<()> {
 
     match command {
         Commands::Encode {
-            input,
+            mut input,
+            files,
             output,
             filter,
             filter_preset,
+            filter_file,
+            delimiter,
+            array_style,
+            newline,
         } => {
-            let json = read_input(input.as_deref())?;
+            input.extend(files);
+            let json = match input.as_slice() {
+                [] => read_input(None)?,
+                [path] => read_input(Some(path))?,
+                paths => collect_json_array(paths)?,
+            };
 
-            // Build the filter patterns from --filter and/or --filter-preset
-            let patterns = build_filter_patterns(filter.as_deref(), filter_preset.as_deref())?;
+            // Build the filter patterns from --filter, --filter-preset and/or --filter-file
+            let mut patterns = build_filter_patterns(filter.as_deref(), filter_preset.as_deref())?;
+            if let Some(path) = filter_file.as_deref() {
+                let text = std::fs::read_to_string(path)
+                    .with_context(|| format!("Failed to read filter file: {}", path))?;
+                patterns.extend(parse_filter_file(&text));
+            }
 
-            let toon = if patterns.is_empty() {
-                toon_core::encode(&json).context("Failed to encode JSON to TOON")?
+            let json = if patterns.is_empty() {
+                json
             } else {
                 let pattern_refs: Vec<&str> = patterns.iter().map(|s| s.as_str()).collect();
-                toon_core::filter_and_encode(&json, &pattern_refs)
-                    .context("Failed to filter and encode JSON to TOON")?
+                let value: serde_json::Value =
+                    serde_json::from_str(&json).context("Failed to parse JSON input")?;
+                serde_json::to_string(&toon_core::filter_fields(&value, &pattern_refs))?
+            };
+
+            let options = EncodeOptions {
+                delimiter: delimiter.into(),
+                array_style: array_style.into(),
+                trailing_newline: newline,
+                ..EncodeOptions::default()
             };
+            let toon = toon_core::encode_with_options(&json, &options)
+                .context("Failed to encode JSON to TOON")?;
 
             write_output(output.as_deref(), &toon)?;
         }
-        Commands::Decode { input, output } => {
+        Commands::Decode {
+            input,
+            output,
+            delimiter,
+        } => {
             let toon = read_input(input.as_deref())?;
-            let json = toon_core::decode(&toon).context("Failed to decode TOON to JSON")?;
+            let options = DecodeOptions {
+                delimiter: delimiter.into(),
+                ..DecodeOptions::default()
+            };
+            let json = toon_core::decode_with_options(&toon, &options)
+                .context("Failed to decode TOON to JSON")?;
             // Pretty-print the JSON output
             let value: serde_json::Value = serde_json::from_str(&json)?;
             let pretty = serde_json::to_string_pretty(&value)?;
             write_output(output.as_deref(), &pretty)?;
         }
+        Commands::Fmt {
+            input,
+            output,
+            in_place,
+            delimiter,
+        } => {
+            let toon = read_input(input.as_deref())?;
+            let decode_options = DecodeOptions {
+                delimiter: delimiter.into(),
+                ..DecodeOptions::default()
+            };
+            let json = toon_core::decode_with_options(&toon, &decode_options)
+                .context("Failed to decode TOON to JSON")?;
+            let encode_options = EncodeOptions {
+                delimiter: delimiter.into(),
+                ..EncodeOptions::default()
+            };
+            let formatted = toon_core::encode_with_options(&json, &encode_options)
+                .context("Failed to encode JSON to TOON")?;
+            // `--in-place` requires `--input` and conflicts with `--output`.
+            let target = if in_place { input } else { output };
+            write_output(target.as_deref(), &formatted)?;
+        }
         Commands::Stats { input } => {
             let json = read_input(input.as_deref())?;
-            let toon = toon_core::encode(&json).context("Failed to encode JSON to TOON")?;
-            let json_bytes = json.len();
-            let toon_bytes = toon.len();
-            let ratio = if json_bytes > 0 {
-                (1.0 - (toon_bytes as f64 / json_bytes as f64)) * 100.0
+            let stats =
+                toon_core::compression_stats(&json).context("Failed to encode JSON to TOON")?;
+            println!("JSON size:  {} bytes", stats.json_bytes);
+            println!("TOON size:  {} bytes", stats.toon_bytes);
+            println!("Reduction:  {:.1}%", stats.byte_reduction());
+            println!("JSON tokens: {}", stats.json_tokens);
+            println!("TOON tokens: {}", stats.toon_tokens);
+            println!("Token reduction: {:.1}%", stats.token_reduction());
+        }
+        Commands::Analyze { input } => {
+            let toon = read_input(input.as_deref())?;
+            let stats = toon_core::document_stats(&toon).context("Failed to analyze TOON")?;
+            println!("Objects:    {}", stats.objects);
+            println!(
+                "Arrays:     {} (inline {}, tabular {}, expanded {})",
+                stats.arrays(),
+                stats.inline_arrays,
+                stats.tabular_arrays,
+                stats.expanded_arrays
+            );
+            println!("Scalars:    {}", stats.scalars);
+            println!("Max depth:  {}", stats.max_depth);
+            println!(
+                "Quoted:     {} of {} strings ({:.1}%)",
+                stats.quoted_strings,
+                stats.quoted_strings + stats.unquoted_strings,
+                stats.quoted_ratio() * 100.0
+            );
+        }
+        Commands::Rrule {
+            rule,
+            dtstart,
+            duration,
+            tz,
+            count,
+            until,
+            json,
+        } => {
+            let events =
+                truth_engine::expand_rrule(&rule, &dtstart, duration, &tz, until.as_deref(), count)
+                    .context("Failed to expand RRULE")?;
+            let instances: Vec<serde_json::Value> = events
+                .iter()
+                .map(|e| {
+                    serde_json::json!({
+                        "start": e.start.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+                        "end": e.end.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+                    })
+                })
+                .collect();
+            // Wrapped in an object so the instances encode as an `instances[N]{start,end}:` table.
+            let value = serde_json::json!({ "instances": instances });
+            let out = if json {
+                serde_json::to_string_pretty(&value)?
             } else {
-                0.0
+                toon_core::encode(&value.to_string()).context("Failed to encode instances")?
             };
-            println!("JSON size:  {} bytes", json_bytes);
-            println!("TOON size:  {} bytes", toon_bytes);
-            println!("Reduction:  {:.1}%", ratio);
+            println!("{}", out);
         }
     }
 
@@ -194,19 +450,44 @@ fn build_filter_patterns(filter: Option<&str>, filter_preset: Option<&str>) -> R
     Ok(patterns)
 }
 
+/// Parse a `--filter-file`: one pattern per line, trimmed, skipping blank lines
+/// and `#` comments.
+fn parse_filter_file(text: &str) -> Vec<String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect()
+}
+
 fn read_input(path: Option<&str>) -> Result<String> {
-    match path {
+    // Read raw bytes so a non-UTF-8 file (e.g. Latin-1) is reported as such
+    // rather than as an opaque I/O error.
+    let bytes = match path {
         Some(path) => {
-            std::fs::read_to_string(path).with_context(|| format!("Failed to read file: {}", path))
+            std::fs::read(path).with_context(|| format!("Failed to read file: {}", path))?
         }
         None => {
-            let mut buf = String::new();
+            let mut buf = Vec::new();
             io::stdin()
-                .read_to_string(&mut buf)
+                .read_to_end(&mut buf)
                 .context("Failed to read from stdin")?;
-            Ok(buf)
+            buf
         }
-    }
+    };
+    String::from_utf8(bytes).map_err(|e| ToonError::from(e.utf8_error()).into())
+}
+
+/// Read each file as a JSON document and join them into one JSON array, in order.
+fn collect_json_array(paths: &[String]) -> Result<String> {
+    let items = paths
+        .iter()
+        .map(|path| {
+            serde_json::from_str(&read_input(Some(path))?)
+                .with_context(|| format!("Failed to parse JSON input: {}", path))
+        })
+        .collect::<Result<Vec<serde_json::Value>>>()?;
+    Ok(serde_json::to_string(&items)?)
 }
 
 fn write_output(path: Option<&str>, content: &str) -> Result<()> {
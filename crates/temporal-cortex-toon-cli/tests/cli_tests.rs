@@ -1,8 +1,8 @@
 //! Integration tests for the `toon` CLI binary.
 //!
 //! These tests use `assert_cmd` and `predicates` to exercise the encode, decode,
-//! and stats subcommands through the actual binary, including stdin/stdout piping,
-//! file I/O, error handling, and roundtrip correctness.
+//! fmt, stats, analyze, and rrule subcommands through the actual binary,
+//! including stdin/stdout piping, file I/O, error handling, and roundtrip correctness.
 
 // `Command::cargo_bin` was deprecated in assert_cmd 2.1.2 in favor of
 // `cargo::cargo_bin_cmd!`. Allow it until we migrate.
@@ -103,6 +103,89 @@ fn encode_invalid_json_fails() {
         );
 }
 
+#[test]
+fn encode_non_utf8_input_fails() {
+    // Latin-1 "café" — 0xE9 is not valid UTF-8 on its own.
+    Command::cargo_bin("toon")
+        .unwrap()
+        .arg("encode")
+        .write_stdin(b"{\"name\":\"caf\xe9\"}".to_vec())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "input is not valid UTF-8; TOON requires UTF-8",
+        ))
+        .stderr(predicate::str::contains("Failed to read").not());
+}
+
+#[test]
+fn encode_multiple_inputs_as_array() {
+    let first = "/tmp/toon-test-multi-1.json";
+    let second = "/tmp/toon-test-multi-2.json";
+    std::fs::write(first, r#"{"id":1,"title":"Standup","etag":"x"}"#).unwrap();
+    std::fs::write(second, r#"{"id":2,"title":"Review","etag":"y"}"#).unwrap();
+
+    // Repeated -i and positional paths are equivalent.
+    for args in [
+        vec!["encode", "-i", first, "-i", second],
+        vec!["encode", first, second],
+        vec!["encode", "-i", first, second],
+    ] {
+        let output = Command::cargo_bin("toon")
+            .unwrap()
+            .args(&args)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let toon = String::from_utf8(output.stdout).unwrap();
+        assert_eq!(toon, "[2]{id,title,etag}:\n  1,Standup,x\n  2,Review,y");
+
+        let decoded = Command::cargo_bin("toon")
+            .unwrap()
+            .arg("decode")
+            .write_stdin(toon)
+            .output()
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&decoded.stdout).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!([
+                {"id": 1, "title": "Standup", "etag": "x"},
+                {"id": 2, "title": "Review", "etag": "y"}
+            ])
+        );
+    }
+
+    // The filter applies to each element.
+    Command::cargo_bin("toon")
+        .unwrap()
+        .args(["encode", "--filter", "etag", first, second])
+        .assert()
+        .success()
+        .stdout("[2]{id,title}:\n  1,Standup\n  2,Review");
+
+    let _ = std::fs::remove_file(first);
+    let _ = std::fs::remove_file(second);
+}
+
+#[test]
+fn encode_multiple_inputs_names_the_invalid_file() {
+    let good = "/tmp/toon-test-multi-good.json";
+    let bad = "/tmp/toon-test-multi-bad.json";
+    std::fs::write(good, r#"{"id":1}"#).unwrap();
+    std::fs::write(bad, "{not json").unwrap();
+
+    Command::cargo_bin("toon")
+        .unwrap()
+        .args(["encode", good, bad])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(bad));
+
+    let _ = std::fs::remove_file(good);
+    let _ = std::fs::remove_file(bad);
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Decode subcommand
 // ─────────────────────────────────────────────────────────────────────────────
@@ -214,6 +297,40 @@ fn stats_output_format() {
         .stdout(predicate::str::contains("Reduction:"));
 }
 
+#[test]
+fn stats_reports_token_counts() {
+    Command::cargo_bin("toon")
+        .unwrap()
+        .arg("stats")
+        .write_stdin(r#"{"name":"Alice"}"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("JSON tokens: 9"))
+        .stdout(predicate::str::contains("TOON tokens: 3"))
+        .stdout(predicate::str::contains("Token reduction:"));
+}
+
+#[test]
+fn analyze_calendar_fixture() {
+    let toon = Command::cargo_bin("toon")
+        .unwrap()
+        .args(["encode", "-i", calendar_json_path()])
+        .output()
+        .unwrap()
+        .stdout;
+    // items → event → conferenceData → conferenceSolution → key, under the root
+    Command::cargo_bin("toon")
+        .unwrap()
+        .arg("analyze")
+        .write_stdin(toon)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Arrays:     11 (inline 1, tabular 5, expanded 5)",
+        ))
+        .stdout(predicate::str::contains("Max depth:  6"));
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Roundtrip
 // ─────────────────────────────────────────────────────────────────────────────
@@ -438,6 +555,382 @@ fn encode_filter_empty_pattern_preserves_all() {
     );
 }
 
+/// Helper: path to the patterns.txt fixture (comments, blank lines, indentation).
+fn patterns_path() -> &'static str {
+    concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/patterns.txt")
+}
+
+#[test]
+fn encode_with_filter_file_strips_listed_fields_and_ignores_comments() {
+    let input = r#"{"summary":"Sync","etag":"abc","kind":"event","attendees":[{"email":"a@x.com","responseStatus":"accepted"}]}"#;
+
+    let output = Command::cargo_bin("toon")
+        .unwrap()
+        .args(["encode", "--filter-file", patterns_path()])
+        .write_stdin(input)
+        .output()
+        .expect("encode with --filter-file should succeed");
+
+    assert!(
+        output.status.success(),
+        "encode with --filter-file must succeed"
+    );
+    let toon = String::from_utf8(output.stdout).expect("output should be UTF-8");
+
+    assert!(!toon.contains("etag"), "should strip etag");
+    assert!(!toon.contains("kind"), "should strip kind");
+    assert!(
+        !toon.contains("responseStatus"),
+        "should strip the trimmed nested pattern"
+    );
+    assert!(toon.contains("email"), "should keep attendee email");
+    // `#summary` is a comment, not a pattern.
+    assert!(toon.contains("summary: Sync"), "should keep summary");
+}
+
+#[test]
+fn encode_filter_file_merges_with_filter_and_preset() {
+    let input = r#"{"summary":"Sync","etag":"abc","kind":"event","htmlLink":"https://x","location":"Room 1","colorId":"5"}"#;
+
+    let output = Command::cargo_bin("toon")
+        .unwrap()
+        .args([
+            "encode",
+            "--filter",
+            "location",
+            "--filter-preset",
+            "google",
+            "--filter-file",
+            patterns_path(),
+        ])
+        .write_stdin(input)
+        .output()
+        .expect("encode with combined filters should succeed");
+
+    assert!(output.status.success());
+    let toon = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(toon, "summary: Sync\ncolorId: \"5\"");
+}
+
+#[test]
+fn encode_missing_filter_file_fails() {
+    Command::cargo_bin("toon")
+        .unwrap()
+        .args(["encode", "--filter-file", "/nonexistent/patterns.txt"])
+        .write_stdin("{}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to read filter file"));
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// --delimiter flag on encode/decode subcommands
+// ─────────────────────────────────────────────────────────────────────────────
+
+const DELIMITED_INPUT: &str = r#"{"places":[{"id":1,"addr":"1 Main St, Springfield"},{"id":2,"addr":"9 Elm Rd"}],"tags":["a,b","c"]}"#;
+
+#[test]
+fn encode_decode_with_tab_delimiter_roundtrips() {
+    let encode_output = Command::cargo_bin("toon")
+        .unwrap()
+        .args(["encode", "--delimiter", "tab"])
+        .write_stdin(DELIMITED_INPUT)
+        .output()
+        .expect("encode with --delimiter tab should succeed");
+    assert!(encode_output.status.success());
+    let toon = String::from_utf8(encode_output.stdout).unwrap();
+    assert!(
+        toon.contains("  1\t1 Main St, Springfield"),
+        "cells should be tab-separated and commas left unquoted:\n{toon}"
+    );
+
+    let decode_output = Command::cargo_bin("toon")
+        .unwrap()
+        .args(["decode", "--delimiter", "tab"])
+        .write_stdin(toon)
+        .output()
+        .expect("decode with --delimiter tab should succeed");
+    assert!(decode_output.status.success());
+
+    let original: serde_json::Value = serde_json::from_str(DELIMITED_INPUT).unwrap();
+    let roundtripped: serde_json::Value =
+        serde_json::from_slice(&decode_output.stdout).expect("decode output is valid JSON");
+    assert_eq!(original, roundtripped);
+}
+
+#[test]
+fn decode_with_mismatched_delimiter_is_wrong_but_does_not_crash() {
+    // Documents the footgun: TOON does not record its delimiter, so decoding
+    // tab-delimited output as comma-delimited silently misreads the cells.
+    let encode_output = Command::cargo_bin("toon")
+        .unwrap()
+        .args(["encode", "--delimiter", "tab"])
+        .write_stdin(DELIMITED_INPUT)
+        .output()
+        .unwrap();
+    let toon = String::from_utf8(encode_output.stdout).unwrap();
+
+    let decode_output = Command::cargo_bin("toon")
+        .unwrap()
+        .arg("decode")
+        .write_stdin(toon)
+        .output()
+        .expect("decode should run");
+    assert!(decode_output.status.success(), "mismatch must not crash");
+
+    let original: serde_json::Value = serde_json::from_str(DELIMITED_INPUT).unwrap();
+    let decoded: serde_json::Value =
+        serde_json::from_slice(&decode_output.stdout).expect("output is still valid JSON");
+    assert_ne!(original, decoded);
+}
+
+#[test]
+fn unknown_delimiter_is_rejected() {
+    Command::cargo_bin("toon")
+        .unwrap()
+        .args(["encode", "--delimiter", "colon"])
+        .write_stdin("{}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value"));
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// --array-style flag on encode
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Rough LLM token estimate: each alphanumeric run and each punctuation
+/// character counts as one token.
+fn approx_tokens(text: &str) -> usize {
+    let mut tokens = 0;
+    let mut in_word = false;
+    for ch in text.chars() {
+        if ch.is_alphanumeric() {
+            if !in_word {
+                tokens += 1;
+            }
+            in_word = true;
+        } else {
+            in_word = false;
+            if !ch.is_whitespace() {
+                tokens += 1;
+            }
+        }
+    }
+    tokens
+}
+
+/// Encode with the given `--array-style`, reading from `input` args or `stdin`.
+fn encode_with_style(style: &str, input: &[&str], stdin: &str) -> String {
+    let output = Command::cargo_bin("toon")
+        .unwrap()
+        .args(["encode", "--array-style", style])
+        .args(input)
+        .write_stdin(stdin)
+        .output()
+        .expect("encode should run");
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// Decode TOON through the CLI.
+fn decode_to_value(toon: &str) -> serde_json::Value {
+    let output = Command::cargo_bin("toon")
+        .unwrap()
+        .arg("decode")
+        .write_stdin(toon)
+        .output()
+        .unwrap();
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn records_style_token_cost_on_calendar_fixture() {
+    let input = ["-i", calendar_json_path()];
+    let tabular = encode_with_style("tabular", &input, "");
+    let records = encode_with_style("records", &input, "");
+
+    assert!(tabular.contains("entryPoints[1]{entryPointType,uri,label}:"));
+    assert!(!records.contains("{entryPointType,uri,label}"));
+    assert!(records.contains("entryPoints[1]:\n"));
+    assert!(records.contains("- entryPointType: video"));
+    assert_eq!(decode_to_value(&tabular), decode_to_value(&records));
+
+    // The fixture's uniform arrays have one element each, so labeling the
+    // fields costs about as much as the tabular header it replaces.
+    let (tabular_tokens, records_tokens) = (approx_tokens(&tabular), approx_tokens(&records));
+    assert!(
+        records_tokens.abs_diff(tabular_tokens) * 100 < tabular_tokens,
+        "records {records_tokens} vs tabular {tabular_tokens}"
+    );
+}
+
+#[test]
+fn records_style_costs_more_tokens_as_rows_grow() {
+    let attendees: Vec<serde_json::Value> = (0..10)
+        .map(|i| serde_json::json!({"email": format!("user{i}@company.com"), "responseStatus": "accepted"}))
+        .collect();
+    let json = serde_json::json!({ "attendees": attendees }).to_string();
+    let tabular = encode_with_style("tabular", &[], &json);
+    let records = encode_with_style("records", &[], &json);
+
+    // Each record repeats both keys and their colons.
+    let (tabular_tokens, records_tokens) = (approx_tokens(&tabular), approx_tokens(&records));
+    assert!(
+        records_tokens >= tabular_tokens + 3 * 10,
+        "records {records_tokens} vs tabular {tabular_tokens}"
+    );
+    assert_eq!(decode_to_value(&tabular), decode_to_value(&records));
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Rrule subcommand
+// ─────────────────────────────────────────────────────────────────────────────
+
+const TUE_THU_ARGS: [&str; 11] = [
+    "rrule",
+    "--rule",
+    "FREQ=WEEKLY;BYDAY=TU,TH",
+    "--dtstart",
+    "2026-02-17T14:00:00",
+    "--duration",
+    "60",
+    "--tz",
+    "America/Los_Angeles",
+    "--count",
+    "10",
+];
+
+#[test]
+fn rrule_prints_instances_as_toon_table() {
+    let output = Command::cargo_bin("toon")
+        .unwrap()
+        .args(TUE_THU_ARGS)
+        .output()
+        .expect("rrule should run");
+    assert!(output.status.success());
+    let toon = String::from_utf8(output.stdout).unwrap();
+    let rows: Vec<&str> = toon.lines().collect();
+
+    assert_eq!(rows[0], "instances[10]{start,end}:");
+    assert_eq!(rows.len(), 11);
+    // Tue Feb 17 2026, 14:00 PST = 22:00 UTC.
+    assert_eq!(rows[1], "  2026-02-17T22:00:00Z,2026-02-17T23:00:00Z");
+    // The 10th instance is Thu Mar 19, after the DST switch (14:00 PDT = 21:00 UTC).
+    assert_eq!(rows[10], "  2026-03-19T21:00:00Z,2026-03-19T22:00:00Z");
+}
+
+#[test]
+fn rrule_json_matches_decoded_toon() {
+    let json_output = Command::cargo_bin("toon")
+        .unwrap()
+        .args(TUE_THU_ARGS)
+        .arg("--json")
+        .output()
+        .expect("rrule --json should run");
+    assert!(json_output.status.success());
+    let json: serde_json::Value =
+        serde_json::from_slice(&json_output.stdout).expect("output is valid JSON");
+    let instances = json["instances"].as_array().unwrap();
+    assert_eq!(instances.len(), 10);
+    assert_eq!(instances[0]["start"], "2026-02-17T22:00:00Z");
+    assert_eq!(instances[9]["end"], "2026-03-19T22:00:00Z");
+
+    let toon_output = Command::cargo_bin("toon")
+        .unwrap()
+        .args(TUE_THU_ARGS)
+        .output()
+        .unwrap();
+    let decoded = decode_via_cli(&toon_output.stdout);
+    assert_eq!(json, decoded);
+}
+
+/// Decode TOON bytes through the CLI's own `decode` subcommand.
+fn decode_via_cli(toon: &[u8]) -> serde_json::Value {
+    let output = Command::cargo_bin("toon")
+        .unwrap()
+        .arg("decode")
+        .write_stdin(toon)
+        .output()
+        .unwrap();
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn rrule_invalid_rule_fails() {
+    Command::cargo_bin("toon")
+        .unwrap()
+        .args([
+            "rrule",
+            "--rule",
+            "FREQ=SOMETIMES",
+            "--dtstart",
+            "2026-02-17T14:00:00",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to expand RRULE"));
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// --newline flag on encode
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn encode_newline_flag_appends_one_newline() {
+    Command::cargo_bin("toon")
+        .unwrap()
+        .arg("encode")
+        .write_stdin(r#"{"name":"Alice"}"#)
+        .assert()
+        .success()
+        .stdout("name: Alice");
+
+    Command::cargo_bin("toon")
+        .unwrap()
+        .args(["encode", "--newline"])
+        .write_stdin(r#"{"name":"Alice"}"#)
+        .assert()
+        .success()
+        .stdout("name: Alice\n");
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// --json-errors flag
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn decode_invalid_input_with_json_errors_prints_json_to_stderr() {
+    let output = Command::cargo_bin("toon")
+        .unwrap()
+        .args(["decode", "--json-errors"])
+        .write_stdin("tags[2]: \"a,b")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let stderr: serde_json::Value =
+        serde_json::from_slice(&output.stderr).expect("stderr should be valid JSON");
+    assert!(stderr["error"].is_string());
+    assert_eq!(
+        stderr["context"],
+        serde_json::json!(["Failed to decode TOON to JSON"])
+    );
+}
+
+#[test]
+fn decode_invalid_input_without_json_errors_prints_text() {
+    Command::cargo_bin("toon")
+        .unwrap()
+        .arg("decode")
+        .write_stdin("tags[2]: \"a,b")
+        .assert()
+        .failure()
+        .stderr(predicate::str::starts_with(
+            "Error: Failed to decode TOON to JSON",
+        ));
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // --managed-cortex flag (stub)
 // ─────────────────────────────────────────────────────────────────────────────
@@ -468,3 +961,83 @@ fn managed_cortex_with_api_key_shows_not_available() {
             "Managed Cortex mode is not yet available",
         ));
 }
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Fmt subcommand
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Valid TOON with irregular spacing, number forms, row indentation and quoting.
+const MESSY_TOON: &str = "user:\n  name:   Ada\n  score: 1.50\n  tags[2]:  a , b\n\
+empty[0]:\nitems[2]{id,n}:\n      1,x\n      2,y\nlist[2]:\n    - 1e3\n    - -0\nq: \"plain\"";
+
+const CANONICAL_TOON: &str = "user:\n  name: Ada\n  score: 1.5\n  tags[2]: a,b\n\
+empty[0]:\nitems[2]{id,n}:\n  1,x\n  2,y\nlist[2]: 1000,0\nq: plain";
+
+fn fmt_stdin(toon: &str) -> String {
+    let output = Command::cargo_bin("toon")
+        .unwrap()
+        .arg("fmt")
+        .write_stdin(toon)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn fmt_normalizes_messy_document() {
+    assert_eq!(fmt_stdin(MESSY_TOON), CANONICAL_TOON);
+}
+
+#[test]
+fn fmt_is_idempotent() {
+    let once = fmt_stdin(MESSY_TOON);
+    let twice = fmt_stdin(&once);
+    assert_eq!(once, twice);
+}
+
+#[test]
+fn fmt_in_place_rewrites_input_file() {
+    let path = "/tmp/toon-test-fmt-in-place.toon";
+    std::fs::write(path, MESSY_TOON).unwrap();
+
+    for _ in 0..2 {
+        Command::cargo_bin("toon")
+            .unwrap()
+            .args(["fmt", "--in-place", "-i", path])
+            .assert()
+            .success()
+            .stdout("");
+        assert_eq!(std::fs::read_to_string(path).unwrap(), CANONICAL_TOON);
+    }
+
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn fmt_in_place_requires_input_file() {
+    Command::cargo_bin("toon")
+        .unwrap()
+        .args(["fmt", "--in-place"])
+        .write_stdin(MESSY_TOON)
+        .assert()
+        .failure();
+}
+
+#[test]
+fn fmt_invalid_toon_fails_and_leaves_file_untouched() {
+    let path = "/tmp/toon-test-fmt-invalid.toon";
+    std::fs::write(path, "tags[2]: \"a,b").unwrap();
+
+    Command::cargo_bin("toon")
+        .unwrap()
+        .args(["fmt", "--in-place", "-i", path])
+        .assert()
+        .failure()
+        .stderr(predicate::str::starts_with(
+            "Error: Failed to decode TOON to JSON",
+        ));
+    assert_eq!(std::fs::read_to_string(path).unwrap(), "tags[2]: \"a,b");
+
+    let _ = std::fs::remove_file(path);
+}
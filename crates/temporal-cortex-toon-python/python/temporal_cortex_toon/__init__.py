@@ -10,33 +10,57 @@
 import os
 
 from temporal_cortex_toon._native import (
+    bucket_by_day,
+    conflicts_with_recurrence,
     decode,
     encode,
     expand_rrule,
     filter_and_encode,
+    find_all_free_across,
+    find_common_free,
     find_first_free_across,
+    find_slot_across_timezones,
+    next_free_slots,
+    parse_ics,
+    utilization,
     convert_timezone,
     compute_duration,
     adjust_timestamp,
     resolve_relative,
     resolve_relative_with_options,
+    parse_relative_window,
+    rrule_free_busy,
+    shift_events,
+    validate_rrule,
 )
 from temporal_cortex_toon._native import (
     merge_availability as _native_merge_availability,
 )
 
 __all__ = [
+    "bucket_by_day",
+    "conflicts_with_recurrence",
     "decode",
     "encode",
     "expand_rrule",
     "filter_and_encode",
+    "find_all_free_across",
+    "find_common_free",
     "find_first_free_across",
+    "find_slot_across_timezones",
     "merge_availability",
+    "next_free_slots",
+    "parse_ics",
+    "utilization",
     "convert_timezone",
     "compute_duration",
     "adjust_timestamp",
     "resolve_relative",
     "resolve_relative_with_options",
+    "parse_relative_window",
+    "rrule_free_busy",
+    "shift_events",
+    "validate_rrule",
 ]
 
 _hint_shown = False
@@ -77,15 +77,18 @@ fn filter_and_encode(json: &str, patterns: Vec<String>) -> PyResult<String> {
 ///     duration_minutes: Duration of each event instance in minutes.
 ///     timezone: IANA timezone identifier (e.g., "America/Los_Angeles").
 ///     until: Optional end boundary for expansion (local datetime string).
-///     max_count: Optional maximum number of instances to generate.
+///     max_count: Optional maximum number of instances to generate. If the
+///         RRULE also has COUNT, the smaller of the two wins.
+///     duration_mode: "fixed" (default) adds the duration in absolute time;
+///         "wallclock" adds it in local time, so ends keep their local time across DST.
 ///
 /// Returns:
 ///     A JSON string containing an array of event objects with `start` and `end` fields.
 ///
 /// Raises:
-///     ValueError: If the RRULE or timezone is invalid.
+///     ValueError: If the RRULE, timezone, or duration_mode is invalid.
 #[pyfunction]
-#[pyo3(signature = (rrule, dtstart, duration_minutes, timezone, until=None, max_count=None))]
+#[pyo3(signature = (rrule, dtstart, duration_minutes, timezone, until=None, max_count=None, duration_mode="fixed"))]
 fn expand_rrule(
     rrule: &str,
     dtstart: &str,
@@ -93,14 +96,31 @@ fn expand_rrule(
     timezone: &str,
     until: Option<&str>,
     max_count: Option<u32>,
+    duration_mode: &str,
 ) -> PyResult<String> {
-    let events = truth_engine::expand_rrule(
+    use truth_engine::{DurationMode, ExpandOptions};
+
+    let duration_mode = match duration_mode {
+        "fixed" => DurationMode::Fixed,
+        "wallclock" => DurationMode::WallClock,
+        other => {
+            return Err(PyValueError::new_err(format!(
+                "Invalid duration_mode '{}': expected fixed or wallclock",
+                other
+            )))
+        }
+    };
+    let events = truth_engine::expand_rrule_with_options(
         rrule,
         dtstart,
         duration_minutes as u32,
         timezone,
         until,
         max_count,
+        &ExpandOptions {
+            duration_mode,
+            ..ExpandOptions::default()
+        },
     )
     .map_err(|e| PyValueError::new_err(e.to_string()))?;
 
@@ -118,89 +138,267 @@ fn expand_rrule(
     serde_json::to_string(&json_events).map_err(|e| PyValueError::new_err(e.to_string()))
 }
 
+/// Check that an RRULE is valid without expanding it.
+///
+/// Needs no DTSTART or timezone, so it suits validating a rule as it is typed.
+///
+/// Args:
+///     rrule: RFC 5545 RRULE string (e.g., "FREQ=WEEKLY;BYDAY=TU,TH").
+///
+/// Raises:
+///     ValueError: If the RRULE is invalid, with a message naming the problem.
+#[pyfunction]
+fn validate_rrule(rrule: &str) -> PyResult<()> {
+    truth_engine::validate_rrule(rrule).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// Expand an RRULE over a window and compute the free time around it.
+///
+/// Args:
+///     rrule: RFC 5545 RRULE string (e.g., "FREQ=WEEKLY;BYDAY=TU,TH").
+///     dtstart: Local datetime string (e.g., "2026-02-17T14:00:00").
+///     duration_minutes: Duration of each instance in minutes.
+///     timezone: IANA timezone (e.g., "America/Los_Angeles").
+///     window_start: Start of the window (ISO 8601 datetime string).
+///     window_end: End of the window (ISO 8601 datetime string).
+///
+/// Returns:
+///     A JSON string with `{busy, free}`: `busy` is the array of `{start, end}`
+///         instances overlapping the window, `free` the `{start, end,
+///         duration_minutes}` gaps between them within the window.
+///
+/// Raises:
+///     ValueError: If the RRULE, timezone, or a datetime is invalid, or
+///         `duration_minutes` is negative.
+#[pyfunction]
+fn rrule_free_busy(
+    rrule: &str,
+    dtstart: &str,
+    duration_minutes: i64,
+    timezone: &str,
+    window_start: &str,
+    window_end: &str,
+) -> PyResult<String> {
+    let spec = truth_engine::RRuleSpec {
+        rrule: rrule.to_string(),
+        dtstart: dtstart.to_string(),
+        duration_minutes: occurrence_minutes(duration_minutes)?,
+        timezone: timezone.to_string(),
+    };
+    let (busy, free) =
+        truth_engine::rrule_free_busy(&spec, parse_dt(window_start)?, parse_dt(window_end)?)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+    let busy: Vec<serde_json::Value> = busy
+        .iter()
+        .map(|evt| {
+            serde_json::json!({
+                "start": evt.start.to_rfc3339(),
+                "end": evt.end.to_rfc3339(),
+            })
+        })
+        .collect();
+    let free: Vec<serde_json::Value> = free
+        .iter()
+        .map(|slot| {
+            serde_json::json!({
+                "start": slot.start.to_rfc3339(),
+                "end": slot.end.to_rfc3339(),
+                "duration_minutes": slot.duration_minutes,
+            })
+        })
+        .collect();
+
+    serde_json::to_string(&serde_json::json!({ "busy": busy, "free": free }))
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// Find the occurrences of a recurring event that overlap one proposed event.
+///
+/// Only the occurrences around the proposed time are collected, however far it
+/// lies from `dtstart`.
+///
+/// Args:
+///     proposed_json: A single `{start, end}` object with ISO 8601 strings.
+///     rrule: RFC 5545 RRULE string (e.g., "FREQ=WEEKLY;BYDAY=MO").
+///     dtstart: Local datetime string (e.g., "2026-03-02T09:00:00").
+///     duration_minutes: Duration of each occurrence in minutes.
+///     timezone: IANA timezone (e.g., "America/Los_Angeles").
+///
+/// Returns:
+///     A JSON string containing an array of conflict objects, each with
+///     `event_a` (the proposed event), `event_b` (the occurrence),
+///     `overlap_start`, `overlap_end`, `overlap_minutes` and `severity`.
+///
+/// Raises:
+///     ValueError: If the JSON input, RRULE, timezone, or a datetime is invalid,
+///         or `duration_minutes` is negative.
+#[pyfunction]
+fn conflicts_with_recurrence(
+    proposed_json: &str,
+    rrule: &str,
+    dtstart: &str,
+    duration_minutes: i64,
+    timezone: &str,
+) -> PyResult<String> {
+    let input: EventInput = serde_json::from_str(proposed_json)
+        .map_err(|e| PyValueError::new_err(format!("Invalid event JSON: {}", e)))?;
+    let proposed = checked_event(
+        parse_dt(&input.start)?,
+        parse_dt(&input.end)?,
+        &input.start,
+        &input.end,
+    )?;
+    let spec = truth_engine::RRuleSpec {
+        rrule: rrule.to_string(),
+        dtstart: dtstart.to_string(),
+        duration_minutes: occurrence_minutes(duration_minutes)?,
+        timezone: timezone.to_string(),
+    };
+    let conflicts = truth_engine::conflicts_with_recurrence(&proposed, &spec)
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+    let range = |e: &truth_engine::ExpandedEvent| {
+        serde_json::json!({
+            "start": e.start.to_rfc3339(),
+            "end": e.end.to_rfc3339(),
+        })
+    };
+    let conflicts: Vec<serde_json::Value> = conflicts
+        .iter()
+        .map(|c| {
+            serde_json::json!({
+                "event_a": range(&c.event_a),
+                "event_b": range(&c.event_b),
+                "overlap_start": c.overlap_start.to_rfc3339(),
+                "overlap_end": c.overlap_end.to_rfc3339(),
+                "overlap_minutes": c.overlap_minutes,
+                "severity": c.severity.as_str(),
+            })
+        })
+        .collect();
+
+    serde_json::to_string(&conflicts)
+        .map_err(|e| PyValueError::new_err(format!("Serialization error: {}", e)))
+}
+
+/// Move every event by `by_minutes` (negative moves earlier), keeping durations.
+///
+/// The shift is in absolute time, so instances keep their spacing across DST.
+///
+/// Args:
+///     events_json: JSON array of `{start, end}` objects with ISO 8601 strings.
+///     by_minutes: Minutes to move each event by.
+///
+/// Returns:
+///     A JSON string containing an array of `{start, end}` objects with RFC 3339 strings.
+///
+/// Raises:
+///     ValueError: If the JSON input or a datetime is invalid, or `by_minutes` is
+///         out of range.
+#[pyfunction]
+fn shift_events(events_json: &str, by_minutes: i64) -> PyResult<String> {
+    let inputs: Vec<EventInput> = serde_json::from_str(events_json)
+        .map_err(|e| PyValueError::new_err(format!("Invalid events JSON: {}", e)))?;
+    let events = to_events(&inputs)?;
+    let by = chrono::TimeDelta::try_minutes(by_minutes).ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "Invalid shift: {} minutes is out of range",
+            by_minutes
+        ))
+    })?;
+
+    let shifted: Vec<serde_json::Value> = truth_engine::shift_events(&events, by)
+        .iter()
+        .map(|evt| {
+            serde_json::json!({
+                "start": evt.start.to_rfc3339(),
+                "end": evt.end.to_rfc3339(),
+            })
+        })
+        .collect();
+
+    serde_json::to_string(&shifted)
+        .map_err(|e| PyValueError::new_err(format!("Serialization error: {}", e)))
+}
+
 /// Merge N event streams into unified availability within a time window.
 ///
 /// Args:
 ///     streams_json: JSON array of stream objects, each with `stream_id` (str) and
-///         `events` (array of `{start, end}` objects with ISO 8601 strings).
+///         `events` (array of `{start, end}` objects with ISO 8601 strings), and
+///         optionally `display_name` and `color` (str), echoed on busy blocks.
 ///     window_start: Start of the time window (ISO 8601 datetime string).
 ///     window_end: End of the time window (ISO 8601 datetime string).
 ///     opaque: If True, hide source counts in busy blocks (privacy mode). Default: True.
+///     privacy: Optional privacy level overriding `opaque`: `"full"`, `"opaque"`, or
+///         `"free_only"` (free slots only, with `busy` left empty).
 ///
 /// Returns:
-///     A JSON string with `{busy, free, window_start, window_end, privacy}`.
+///     A JSON string with `{busy, free, window_start, window_end, privacy}`, where
+///         `privacy` is `"full"`, `"opaque"` or `"free_only"`. Under
+///         `"full"` privacy each busy block lists its contributing streams in
+///         `sources` as `{stream_id, display_name, color}`.
 ///
 /// Raises:
-///     ValueError: If the JSON input is malformed or datetimes are invalid.
+///     ValueError: If the JSON input is malformed, datetimes are invalid, or
+///         `privacy` is not a known level.
 #[pyfunction]
-#[pyo3(signature = (streams_json, window_start, window_end, opaque=true))]
+#[pyo3(signature = (streams_json, window_start, window_end, opaque=true, privacy=None))]
 fn merge_availability(
     streams_json: &str,
     window_start: &str,
     window_end: &str,
     opaque: bool,
+    privacy: Option<&str>,
 ) -> PyResult<String> {
-    use chrono::{DateTime, NaiveDateTime, Utc};
-    use truth_engine::availability::{EventStream, PrivacyLevel};
-    use truth_engine::expander::ExpandedEvent;
-
-    #[derive(serde::Deserialize)]
-    struct StreamInput {
-        stream_id: String,
-        events: Vec<EventInput>,
-    }
-    #[derive(serde::Deserialize)]
-    struct EventInput {
-        start: String,
-        end: String,
-    }
-
-    fn parse_dt(s: &str) -> PyResult<DateTime<Utc>> {
-        if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
-            return Ok(dt.with_timezone(&Utc));
-        }
-        NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S")
-            .map(|ndt| ndt.and_utc())
-            .map_err(|e| PyValueError::new_err(format!("Invalid datetime '{}': {}", s, e)))
-    }
-
-    let inputs: Vec<StreamInput> = serde_json::from_str(streams_json)
-        .map_err(|e| PyValueError::new_err(format!("Invalid streams JSON: {}", e)))?;
+    use truth_engine::availability::PrivacyLevel;
 
+    let streams = parse_streams(streams_json)?;
     let ws = parse_dt(window_start)?;
     let we = parse_dt(window_end)?;
 
-    let privacy = if opaque {
-        PrivacyLevel::Opaque
-    } else {
-        PrivacyLevel::Full
+    let privacy = match privacy {
+        Some(name) => parse_privacy(name)?,
+        None if opaque => PrivacyLevel::Opaque,
+        None => PrivacyLevel::Full,
     };
 
-    let streams: Vec<EventStream> = inputs
-        .into_iter()
-        .map(|si| {
-            let events: PyResult<Vec<ExpandedEvent>> = si
-                .events
-                .into_iter()
-                .map(|ei| {
-                    let start = parse_dt(&ei.start)?;
-                    let end = parse_dt(&ei.end)?;
-                    Ok(ExpandedEvent { start, end })
-                })
-                .collect();
-            Ok(EventStream {
-                stream_id: si.stream_id,
-                events: events?,
-            })
-        })
-        .collect::<PyResult<Vec<_>>>()?;
-
     let result = truth_engine::merge_availability(&streams, ws, we, privacy);
 
-    serde_json::to_string(&result)
+    let mut value = serde_json::to_value(&result)
+        .map_err(|e| PyValueError::new_err(format!("Serialization error: {}", e)))?;
+    value["privacy"] = privacy_name(result.privacy).into();
+    serde_json::to_string(&value)
         .map_err(|e| PyValueError::new_err(format!("Serialization error: {}", e)))
 }
 
+/// Parse a privacy level name: `"full"`, `"opaque"` or `"free_only"`.
+fn parse_privacy(name: &str) -> PyResult<truth_engine::PrivacyLevel> {
+    use truth_engine::PrivacyLevel;
+
+    match name {
+        "full" => Ok(PrivacyLevel::Full),
+        "opaque" => Ok(PrivacyLevel::Opaque),
+        "free_only" => Ok(PrivacyLevel::FreeOnly),
+        other => Err(PyValueError::new_err(format!(
+            "Invalid privacy '{}': expected full, opaque, or free_only",
+            other
+        ))),
+    }
+}
+
+/// The name of a privacy level, as accepted by [`parse_privacy`].
+fn privacy_name(privacy: truth_engine::PrivacyLevel) -> &'static str {
+    use truth_engine::PrivacyLevel;
+
+    match privacy {
+        PrivacyLevel::Full => "full",
+        PrivacyLevel::Opaque => "opaque",
+        PrivacyLevel::FreeOnly => "free_only",
+    }
+}
+
 /// Find the first free slot of at least `min_duration_minutes` across N merged
 /// event streams.
 ///
@@ -222,64 +420,293 @@ fn find_first_free_across(
     window_end: &str,
     min_duration_minutes: i64,
 ) -> PyResult<String> {
-    use chrono::{DateTime, NaiveDateTime, Utc};
-    use truth_engine::availability::EventStream;
-    use truth_engine::expander::ExpandedEvent;
+    let streams = parse_streams(streams_json)?;
+    let ws = parse_dt(window_start)?;
+    let we = parse_dt(window_end)?;
 
-    #[derive(serde::Deserialize)]
-    struct StreamInput {
-        stream_id: String,
-        events: Vec<EventInput>,
-    }
-    #[derive(serde::Deserialize)]
-    struct EventInput {
-        start: String,
-        end: String,
-    }
+    let slot = truth_engine::find_first_free_across(&streams, ws, we, min_duration_minutes);
 
-    fn parse_dt(s: &str) -> PyResult<DateTime<Utc>> {
-        if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
-            return Ok(dt.with_timezone(&Utc));
-        }
-        NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S")
-            .map(|ndt| ndt.and_utc())
-            .map_err(|e| PyValueError::new_err(format!("Invalid datetime '{}': {}", s, e)))
+    match slot {
+        Some(s) => serde_json::to_string(&s)
+            .map_err(|e| PyValueError::new_err(format!("Serialization error: {}", e))),
+        None => Ok("null".to_string()),
     }
+}
 
-    let inputs: Vec<StreamInput> = serde_json::from_str(streams_json)
-        .map_err(|e| PyValueError::new_err(format!("Invalid streams JSON: {}", e)))?;
+/// Find every free slot of at least `min_duration_minutes` across N merged
+/// event streams.
+///
+/// Args:
+///     streams_json: JSON array of stream objects (same format as merge_availability).
+///     window_start: Start of the search window (ISO 8601 datetime string).
+///     window_end: End of the search window (ISO 8601 datetime string).
+///     min_duration_minutes: Minimum free slot duration in minutes.
+///
+/// Returns:
+///     A JSON string containing an array of `{start, end, duration_minutes}` objects,
+///     sorted by start.
+///
+/// Raises:
+///     ValueError: If the JSON input is malformed or datetimes are invalid.
+#[pyfunction]
+fn find_all_free_across(
+    streams_json: &str,
+    window_start: &str,
+    window_end: &str,
+    min_duration_minutes: i64,
+) -> PyResult<String> {
+    let streams = parse_streams(streams_json)?;
+    let ws = parse_dt(window_start)?;
+    let we = parse_dt(window_end)?;
+
+    let slots = truth_engine::find_all_free_across(&streams, ws, we, min_duration_minutes);
+
+    serde_json::to_string(&slots)
+        .map_err(|e| PyValueError::new_err(format!("Serialization error: {}", e)))
+}
+
+/// Find the next `n` free slots of at least `min_duration_minutes` across N
+/// merged event streams, starting at or after `after`.
+///
+/// Args:
+///     streams_json: JSON array of stream objects (same format as merge_availability).
+///     after: Reference time; a slot under way then counts from it (ISO 8601
+///         datetime string).
+///     window_end: End of the search window (ISO 8601 datetime string).
+///     min_duration_minutes: Minimum free slot duration in minutes.
+///     n: Maximum number of slots to return.
+///
+/// Returns:
+///     A JSON string containing an array of up to `n`
+///     `{start, end, duration_minutes}` objects, sorted by start.
+///
+/// Raises:
+///     ValueError: If the JSON input is malformed or datetimes are invalid.
+#[pyfunction]
+fn next_free_slots(
+    streams_json: &str,
+    after: &str,
+    window_end: &str,
+    min_duration_minutes: i64,
+    n: usize,
+) -> PyResult<String> {
+    let streams = parse_streams(streams_json)?;
+    let after = parse_dt(after)?;
+    let we = parse_dt(window_end)?;
+
+    let slots = truth_engine::next_free_slots(&streams, after, we, min_duration_minutes, n);
 
+    serde_json::to_string(&slots)
+        .map_err(|e| PyValueError::new_err(format!("Serialization error: {}", e)))
+}
+
+/// Compute the fraction of working time within a window that is busy.
+///
+/// Args:
+///     streams_json: JSON array of stream objects (same format as merge_availability).
+///     timezone: IANA timezone the working hours are local to.
+///     work_start_hour: Local hour the working day starts (0-23).
+///     work_end_hour: Local hour the working day ends (1-24).
+///     window_start: Start of the window (ISO 8601 datetime string).
+///     window_end: End of the window (ISO 8601 datetime string).
+///
+/// Returns:
+///     Busy working time divided by total working time, from 0.0 to 1.0. Busy
+///     time outside working hours is not counted; 0.0 if there is no working time.
+///
+/// Raises:
+///     ValueError: If the JSON input is malformed, or a datetime or timezone is invalid.
+#[pyfunction]
+fn utilization(
+    streams_json: &str,
+    timezone: &str,
+    work_start_hour: u32,
+    work_end_hour: u32,
+    window_start: &str,
+    window_end: &str,
+) -> PyResult<f64> {
+    use truth_engine::availability::WorkingHours;
+
+    let streams = parse_streams(streams_json)?;
     let ws = parse_dt(window_start)?;
     let we = parse_dt(window_end)?;
+    let tz: chrono_tz::Tz = timezone
+        .parse()
+        .map_err(|_| PyValueError::new_err(format!("Invalid timezone: {}", timezone)))?;
+
+    let hours = WorkingHours {
+        timezone: tz,
+        start_hour: work_start_hour,
+        end_hour: work_end_hour,
+    };
+    Ok(truth_engine::utilization(&streams, &hours, ws, we))
+}
 
-    let streams: Vec<EventStream> = inputs
+/// Find time slots free for every participant, each with their own window.
+///
+/// Args:
+///     participants_json: JSON array of participant objects, e.g.
+///         `[{"streams": [{"stream_id": "work", "events": [{"start": "...", "end": "..."}]}],
+///           "window_start": "...", "window_end": "..."}]`.
+///     min_duration_minutes: Minimum free slot duration in minutes.
+///
+/// Returns:
+///     A JSON string containing an array of `{start, end, duration_minutes}` objects.
+///
+/// Raises:
+///     ValueError: If the JSON input is malformed or datetimes are invalid.
+#[pyfunction]
+fn find_common_free(participants_json: &str, min_duration_minutes: i64) -> PyResult<String> {
+    use truth_engine::availability::Participant;
+
+    #[derive(serde::Deserialize)]
+    struct ParticipantInput {
+        streams: Vec<StreamInput>,
+        window_start: String,
+        window_end: String,
+    }
+
+    let inputs: Vec<ParticipantInput> = serde_json::from_str(participants_json)
+        .map_err(|e| PyValueError::new_err(format!("Invalid participants JSON: {}", e)))?;
+
+    let participants: Vec<Participant> = inputs
         .into_iter()
-        .map(|si| {
-            let events: PyResult<Vec<ExpandedEvent>> = si
-                .events
-                .into_iter()
-                .map(|ei| {
-                    let start = parse_dt(&ei.start)?;
-                    let end = parse_dt(&ei.end)?;
-                    Ok(ExpandedEvent { start, end })
-                })
-                .collect();
-            Ok(EventStream {
-                stream_id: si.stream_id,
-                events: events?,
+        .map(|pi| {
+            let streams = to_streams(pi.streams)?;
+            Ok(Participant {
+                streams,
+                window_start: parse_dt(&pi.window_start)?,
+                window_end: parse_dt(&pi.window_end)?,
             })
         })
         .collect::<PyResult<Vec<_>>>()?;
 
-    let slot = truth_engine::find_first_free_across(&streams, ws, we, min_duration_minutes);
+    let slots = truth_engine::find_common_free(&participants, min_duration_minutes);
+    serde_json::to_string(&slots)
+        .map_err(|e| PyValueError::new_err(format!("Serialization error: {}", e)))
+}
 
-    match slot {
-        Some(s) => serde_json::to_string(&s)
-            .map_err(|e| PyValueError::new_err(format!("Serialization error: {}", e))),
+/// Find the earliest meeting inside every attendee's local working hours.
+///
+/// Args:
+///     attendees_json: JSON array of attendee objects, e.g.
+///         `[{"streams": [...], "timezone": "Europe/London",
+///           "work_start_hour": 9, "work_end_hour": 17}]`.
+///     duration_minutes: Meeting length in minutes.
+///     window_start: Start of the search window (ISO 8601 datetime string).
+///     window_end: End of the search window (ISO 8601 datetime string).
+///
+/// Returns:
+///     A JSON string with `{start, end}` or `"null"` if no slot fits.
+///
+/// Raises:
+///     ValueError: If the JSON input is malformed, or a datetime or timezone is invalid.
+#[pyfunction]
+fn find_slot_across_timezones(
+    attendees_json: &str,
+    duration_minutes: i64,
+    window_start: &str,
+    window_end: &str,
+) -> PyResult<String> {
+    use truth_engine::availability::TzParticipant;
+
+    #[derive(serde::Deserialize)]
+    struct AttendeeInput {
+        streams: Vec<StreamInput>,
+        timezone: String,
+        work_start_hour: u32,
+        work_end_hour: u32,
+    }
+
+    let inputs: Vec<AttendeeInput> = serde_json::from_str(attendees_json)
+        .map_err(|e| PyValueError::new_err(format!("Invalid attendees JSON: {}", e)))?;
+
+    let attendees: Vec<TzParticipant> = inputs
+        .into_iter()
+        .map(|ai| {
+            let streams = to_streams(ai.streams)?;
+            let timezone: chrono_tz::Tz = ai
+                .timezone
+                .parse()
+                .map_err(|_| PyValueError::new_err(format!("Invalid timezone: {}", ai.timezone)))?;
+            Ok(TzParticipant {
+                streams,
+                timezone,
+                work_start_hour: ai.work_start_hour,
+                work_end_hour: ai.work_end_hour,
+            })
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let ws = parse_dt(window_start)?;
+    let we = parse_dt(window_end)?;
+
+    match truth_engine::find_slot_across_timezones(&attendees, duration_minutes, ws, we) {
+        Some(evt) => serde_json::to_string(&serde_json::json!({
+            "start": evt.start.to_rfc3339(),
+            "end": evt.end.to_rfc3339(),
+        }))
+        .map_err(|e| PyValueError::new_err(format!("Serialization error: {}", e))),
         None => Ok("null".to_string()),
     }
 }
 
+/// Split a merged availability into per-day buckets in a local timezone.
+///
+/// Args:
+///     availability_json: JSON object returned by `merge_availability`.
+///     timezone: IANA timezone name used to determine local day boundaries.
+///
+/// Returns:
+///     A JSON string containing an array of `{date, busy, free}` objects, with
+///     blocks clipped to each local day.
+///
+/// Raises:
+///     ValueError: If the JSON input is malformed or the timezone is invalid.
+#[pyfunction]
+fn bucket_by_day(availability_json: &str, timezone: &str) -> PyResult<String> {
+    let invalid =
+        |e: serde_json::Error| PyValueError::new_err(format!("Invalid availability JSON: {}", e));
+    let mut value: serde_json::Value = serde_json::from_str(availability_json).map_err(invalid)?;
+    // merge_availability names the privacy level in snake case; map it back.
+    if let Some(name) = value.get("privacy").and_then(|p| p.as_str()) {
+        if let Ok(privacy) = parse_privacy(name) {
+            value["privacy"] = serde_json::to_value(privacy).map_err(invalid)?;
+        }
+    }
+    let availability: truth_engine::UnifiedAvailability =
+        serde_json::from_value(value).map_err(invalid)?;
+
+    let tz: chrono_tz::Tz = timezone
+        .parse()
+        .map_err(|_| PyValueError::new_err(format!("Invalid timezone: {}", timezone)))?;
+
+    let days = truth_engine::bucket_by_day(&availability, tz);
+    serde_json::to_string(&days)
+        .map_err(|e| PyValueError::new_err(format!("Serialization error: {}", e)))
+}
+
+/// Parse the VEVENTs of an iCalendar (`.ics`) document.
+///
+/// Args:
+///     ics: iCalendar text containing one or more VEVENT components.
+///
+/// Returns:
+///     A JSON string containing an array of
+///     `{summary, dtstart, timezone, duration_minutes, rrule, exdates}` objects.
+///     `rrule`, `dtstart`, `duration_minutes`, and `timezone` can be passed
+///     straight to `expand_rrule`.
+///
+/// Raises:
+///     ValueError: If a VEVENT is unterminated or lacks DTSTART, or a TZID or
+///         date-time value is invalid.
+#[pyfunction]
+fn parse_ics(ics: &str) -> PyResult<String> {
+    let events = truth_engine::parse_ics(ics).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    serde_json::to_string(&events)
+        .map_err(|e| PyValueError::new_err(format!("Serialization error: {}", e)))
+}
+
 /// Convert a datetime to a different timezone representation.
 ///
 /// Args:
@@ -415,6 +842,126 @@ fn resolve_relative_with_options(
     serde_json::to_string(&result).map_err(|e| PyValueError::new_err(e.to_string()))
 }
 
+/// Resolve a named window spec to a `{start, end}` range.
+///
+/// Args:
+///     spec: One of "today", "tomorrow", "this_week", "next_week", "this_month",
+///         "next_month", or "next_N_days" (e.g., "next_7_days").
+///     now: ISO 8601 datetime string (the "now" reference point); a naive
+///         datetime is taken as UTC.
+///     timezone: IANA timezone whose local calendar defines day, week (Monday
+///         start) and month boundaries.
+///
+/// Returns:
+///     A JSON string with `{start, end}` as RFC 3339 UTC strings; `end` is exclusive.
+///
+/// Raises:
+///     ValueError: If the spec is unknown or the datetime or timezone is invalid.
+#[pyfunction]
+fn parse_relative_window(spec: &str, now: &str, timezone: &str) -> PyResult<String> {
+    let now_dt = parse_dt(now)?;
+    let tz: chrono_tz::Tz = timezone
+        .parse()
+        .map_err(|_| PyValueError::new_err(format!("Invalid timezone: {}", timezone)))?;
+
+    let (start, end) = truth_engine::temporal::parse_relative_window(spec, now_dt, tz)
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    serde_json::to_string(&serde_json::json!({
+        "start": start.to_rfc3339(),
+        "end": end.to_rfc3339(),
+    }))
+    .map_err(|e| PyValueError::new_err(format!("Serialization error: {}", e)))
+}
+
+/// One event stream as passed in from Python: `stream_id`, `events` as
+/// `{start, end}` objects, and optional `display_name` and `color`.
+#[derive(serde::Deserialize)]
+struct StreamInput {
+    stream_id: String,
+    events: Vec<EventInput>,
+    #[serde(default)]
+    display_name: Option<String>,
+    #[serde(default)]
+    color: Option<String>,
+}
+
+/// One `{start, end}` event as passed in from Python.
+#[derive(serde::Deserialize)]
+struct EventInput {
+    start: String,
+    end: String,
+}
+
+/// Parse an RFC 3339 datetime, or a naive `%Y-%m-%dT%H:%M:%S` one taken as UTC.
+fn parse_dt(s: &str) -> PyResult<chrono::DateTime<chrono::Utc>> {
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(s) {
+        return Ok(dt.with_timezone(&chrono::Utc));
+    }
+    chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S")
+        .map(|ndt| ndt.and_utc())
+        .map_err(|e| PyValueError::new_err(format!("Invalid datetime '{}': {}", s, e)))
+}
+
+/// Parse `{start, end}` inputs into events, rejecting reversed ones.
+fn to_events(inputs: &[EventInput]) -> PyResult<Vec<truth_engine::ExpandedEvent>> {
+    inputs
+        .iter()
+        .map(|ei| checked_event(parse_dt(&ei.start)?, parse_dt(&ei.end)?, &ei.start, &ei.end))
+        .collect()
+}
+
+/// Convert deserialized stream inputs into `EventStream`s.
+fn to_streams(inputs: Vec<StreamInput>) -> PyResult<Vec<truth_engine::EventStream>> {
+    inputs
+        .into_iter()
+        .map(|si| {
+            Ok(truth_engine::EventStream {
+                events: to_events(&si.events)?,
+                stream_id: si.stream_id,
+                display_name: si.display_name,
+                color: si.color,
+            })
+        })
+        .collect()
+}
+
+/// Parse a JSON array of streams (the `streams_json` argument of the
+/// availability functions) into `EventStream`s.
+fn parse_streams(streams_json: &str) -> PyResult<Vec<truth_engine::EventStream>> {
+    let inputs: Vec<StreamInput> = serde_json::from_str(streams_json)
+        .map_err(|e| PyValueError::new_err(format!("Invalid streams JSON: {}", e)))?;
+    to_streams(inputs)
+}
+
+/// Check a recurring event's `duration_minutes` argument, which must fit a `u32`.
+fn occurrence_minutes(minutes: i64) -> PyResult<u32> {
+    u32::try_from(minutes).map_err(|_| {
+        PyValueError::new_err(format!(
+            "Invalid duration: {} minutes is out of range",
+            minutes
+        ))
+    })
+}
+
+/// Build an `ExpandedEvent`, rejecting events whose end is before their start.
+///
+/// A reversed event would otherwise produce negative overlaps and nonsense free
+/// slots downstream. `raw_start` and `raw_end` are echoed in the error message.
+fn checked_event(
+    start: chrono::DateTime<chrono::Utc>,
+    end: chrono::DateTime<chrono::Utc>,
+    raw_start: &str,
+    raw_end: &str,
+) -> PyResult<truth_engine::ExpandedEvent> {
+    if end < start {
+        return Err(PyValueError::new_err(format!(
+            "Invalid event: end '{}' is before start '{}'",
+            raw_end, raw_start
+        )));
+    }
+    Ok(truth_engine::ExpandedEvent { start, end })
+}
+
 /// The native extension module, exposed as `temporal_cortex_toon._native`.
 /// The public Python API is in `python/temporal_cortex_toon/__init__.py`.
 #[pymodule]
@@ -423,12 +970,24 @@ fn _native(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(decode, m)?)?;
     m.add_function(wrap_pyfunction!(filter_and_encode, m)?)?;
     m.add_function(wrap_pyfunction!(expand_rrule, m)?)?;
+    m.add_function(wrap_pyfunction!(rrule_free_busy, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_rrule, m)?)?;
+    m.add_function(wrap_pyfunction!(conflicts_with_recurrence, m)?)?;
+    m.add_function(wrap_pyfunction!(shift_events, m)?)?;
     m.add_function(wrap_pyfunction!(merge_availability, m)?)?;
     m.add_function(wrap_pyfunction!(find_first_free_across, m)?)?;
+    m.add_function(wrap_pyfunction!(find_all_free_across, m)?)?;
+    m.add_function(wrap_pyfunction!(next_free_slots, m)?)?;
+    m.add_function(wrap_pyfunction!(find_common_free, m)?)?;
+    m.add_function(wrap_pyfunction!(find_slot_across_timezones, m)?)?;
+    m.add_function(wrap_pyfunction!(utilization, m)?)?;
+    m.add_function(wrap_pyfunction!(bucket_by_day, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_ics, m)?)?;
     m.add_function(wrap_pyfunction!(convert_timezone, m)?)?;
     m.add_function(wrap_pyfunction!(compute_duration, m)?)?;
     m.add_function(wrap_pyfunction!(adjust_timestamp, m)?)?;
     m.add_function(wrap_pyfunction!(resolve_relative, m)?)?;
     m.add_function(wrap_pyfunction!(resolve_relative_with_options, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_relative_window, m)?)?;
     Ok(())
 }
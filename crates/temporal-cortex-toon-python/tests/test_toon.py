@@ -11,8 +11,9 @@
 import pytest
 
 from temporal_cortex_toon import (
-    decode, encode, expand_rrule, filter_and_encode,
-    convert_timezone, compute_duration, adjust_timestamp, resolve_relative,
+    bucket_by_day, decode, encode, expand_rrule, filter_and_encode, find_common_free, parse_ics,
+    convert_timezone, compute_duration, adjust_timestamp, resolve_relative, parse_relative_window,
+    rrule_free_busy, validate_rrule, conflicts_with_recurrence, shift_events,
 )
 import temporal_cortex_toon
 
@@ -221,6 +222,11 @@ def test_expand_invalid_rrule_raises(self):
         with pytest.raises(ValueError):
             expand_rrule("", "2026-02-17T14:00:00", 60, "UTC", None, None)
 
+    @pytest.mark.parametrize("rrule", ["", "   "])
+    def test_expand_blank_rrule_reports_empty_rule(self, rrule):
+        with pytest.raises(ValueError, match="Invalid RRULE: empty RRULE string"):
+            expand_rrule(rrule, "2026-02-17T14:00:00", 60, "UTC")
+
     def test_expand_invalid_timezone_raises(self):
         with pytest.raises(ValueError):
             expand_rrule(
@@ -250,6 +256,158 @@ def test_expand_weekly(self):
         delta = (end - start).total_seconds()
         assert delta == 45 * 60
 
+    def _spring_forward_day(self, **kwargs):
+        # 2026-03-08 is the US spring-forward day: 23 hours long in New York.
+        result = expand_rrule(
+            "FREQ=DAILY;COUNT=1",
+            "2026-03-08T00:00:00",
+            1440,
+            "America/New_York",
+            **kwargs,
+        )
+        return json.loads(result)[0]
+
+    def test_expand_fixed_duration_across_dst(self):
+        event = self._spring_forward_day()
+        assert event == self._spring_forward_day(duration_mode="fixed")
+        # 24 absolute hours later: 01:00 EDT on Mar 9
+        assert event["end"] == "2026-03-09T05:00:00+00:00"
+
+    def test_expand_wallclock_duration_across_dst(self):
+        event = self._spring_forward_day(duration_mode="wallclock")
+        # Next local midnight: 00:00 EDT on Mar 9
+        assert event["start"] == "2026-03-08T05:00:00+00:00"
+        assert event["end"] == "2026-03-09T04:00:00+00:00"
+
+    def test_expand_invalid_duration_mode_raises(self):
+        with pytest.raises(ValueError, match="Invalid duration_mode 'local'"):
+            self._spring_forward_day(duration_mode="local")
+
+
+# ---------------------------------------------------------------------------
+# rrule_free_busy
+# ---------------------------------------------------------------------------
+
+
+class TestValidateRrule:
+    """Tests for checking an RRULE without expanding it."""
+
+    def test_valid_rule_passes(self):
+        assert validate_rrule("FREQ=WEEKLY;BYDAY=TU,TH") is None
+
+    def test_missing_freq_raises(self):
+        with pytest.raises(ValueError, match="FREQ"):
+            validate_rrule("BYDAY=TU;COUNT=3")
+
+    def test_unknown_weekday_raises(self):
+        with pytest.raises(ValueError, match="`XX` is not a valid weekday"):
+            validate_rrule("FREQ=WEEKLY;BYDAY=XX")
+
+
+class TestRruleFreeBusy:
+    """Tests for expanding a rule and computing free time in one call."""
+
+    def test_weekly_meeting_over_two_weeks(self):
+        result = json.loads(rrule_free_busy(
+            "FREQ=WEEKLY;BYDAY=MO,WE",
+            "2026-03-02T10:00:00",
+            60,
+            "UTC",
+            "2026-03-09T00:00:00",
+            "2026-03-23T00:00:00",
+        ))
+        assert [e["start"] for e in result["busy"]] == [
+            "2026-03-09T10:00:00+00:00",
+            "2026-03-11T10:00:00+00:00",
+            "2026-03-16T10:00:00+00:00",
+            "2026-03-18T10:00:00+00:00",
+        ]
+        assert len(result["free"]) == 5
+        assert result["free"][1] == {
+            "start": "2026-03-09T11:00:00+00:00",
+            "end": "2026-03-11T10:00:00+00:00",
+            "duration_minutes": 47 * 60,
+        }
+
+    def test_invalid_rule_raises(self):
+        with pytest.raises(ValueError):
+            rrule_free_busy(
+                "FREQ=SOMETIMES", "2026-03-02T10:00:00", 60, "UTC",
+                "2026-03-09T00:00:00", "2026-03-23T00:00:00",
+            )
+
+    def test_negative_duration_raises(self):
+        with pytest.raises(ValueError, match="duration"):
+            rrule_free_busy(
+                "FREQ=DAILY", "2026-03-02T10:00:00", -60, "UTC",
+                "2026-03-09T00:00:00", "2026-03-23T00:00:00",
+            )
+
+
+# ---------------------------------------------------------------------------
+# conflicts_with_recurrence
+# ---------------------------------------------------------------------------
+
+
+class TestConflictsWithRecurrence:
+    """Tests for checking one proposed event against a recurring series."""
+
+    def test_meeting_clips_one_occurrence(self):
+        proposed = json.dumps({"start": "2026-03-16T09:15:00Z", "end": "2026-03-16T10:00:00Z"})
+        result = json.loads(conflicts_with_recurrence(
+            proposed, "FREQ=WEEKLY;BYDAY=MO", "2026-03-02T09:00:00", 30, "UTC",
+        ))
+        assert len(result) == 1
+        assert result[0]["event_b"]["start"] == "2026-03-16T09:00:00+00:00"
+        assert result[0]["overlap_minutes"] == 15
+        assert result[0]["severity"] == "partial"
+
+    def test_meeting_years_after_dtstart_still_conflicts(self):
+        proposed = json.dumps({"start": "2026-03-16T09:15:00Z", "end": "2026-03-16T10:00:00Z"})
+        result = json.loads(conflicts_with_recurrence(
+            proposed, "FREQ=HOURLY", "2018-01-01T00:00:00", 30, "UTC",
+        ))
+        assert [c["event_b"]["start"] for c in result] == ["2026-03-16T09:00:00+00:00"]
+
+    def test_invalid_rule_raises(self):
+        proposed = json.dumps({"start": "2026-03-16T09:00:00Z", "end": "2026-03-16T10:00:00Z"})
+        with pytest.raises(ValueError):
+            conflicts_with_recurrence(proposed, "FREQ=SOMETIMES", "2026-03-02T09:00:00", 30, "UTC")
+
+    def test_negative_duration_raises(self):
+        proposed = json.dumps({"start": "2026-03-16T09:00:00Z", "end": "2026-03-16T10:00:00Z"})
+        with pytest.raises(ValueError, match="duration"):
+            conflicts_with_recurrence(proposed, "FREQ=DAILY", "2026-03-02T09:00:00", -30, "UTC")
+
+
+# ---------------------------------------------------------------------------
+# shift_events
+# ---------------------------------------------------------------------------
+
+
+class TestShiftEvents:
+    """Tests for moving events by a fixed number of minutes."""
+
+    def test_shift_keeps_durations(self):
+        events = json.dumps([
+            {"start": "2026-03-16T09:00:00Z", "end": "2026-03-16T09:30:00Z"},
+            {"start": "2026-03-17T23:45:00Z", "end": "2026-03-18T00:45:00Z"},
+        ])
+        result = json.loads(shift_events(events, 30))
+        assert result == [
+            {"start": "2026-03-16T09:30:00+00:00", "end": "2026-03-16T10:00:00+00:00"},
+            {"start": "2026-03-18T00:15:00+00:00", "end": "2026-03-18T01:15:00+00:00"},
+        ]
+
+    def test_negative_shift_moves_earlier(self):
+        events = json.dumps([{"start": "2026-03-16T09:00:00Z", "end": "2026-03-16T10:00:00Z"}])
+        result = json.loads(shift_events(events, -90))
+        assert result[0]["start"] == "2026-03-16T07:30:00+00:00"
+
+    def test_invalid_json_raises(self):
+        with pytest.raises(ValueError):
+            shift_events("not json", 30)
+
 
 # ---------------------------------------------------------------------------
 # merge_availability hint
@@ -328,6 +486,330 @@ def test_hint_suppressed_by_env_var(self, caplog):
             os.environ.pop("TEMPORAL_CORTEX_QUIET", None)
 
 
+# ---------------------------------------------------------------------------
+# Event validation
+# ---------------------------------------------------------------------------
+
+
+class TestReversedEvents:
+    """Events whose end precedes their start are rejected at the boundary."""
+
+    REVERSED = {"start": "2026-03-16T10:00:00Z", "end": "2026-03-16T09:00:00Z"}
+
+    def test_merge_availability_rejects_reversed_event(self):
+        streams = json.dumps([{"stream_id": "a", "events": [self.REVERSED]}])
+        with pytest.raises(ValueError, match="end '2026-03-16T09:00:00Z' is before start '2026-03-16T10:00:00Z'"):
+            temporal_cortex_toon.merge_availability(
+                streams, "2026-03-16T08:00:00Z", "2026-03-16T17:00:00Z",
+            )
+
+    def test_find_first_free_across_rejects_reversed_event(self):
+        streams = json.dumps([{"stream_id": "a", "events": [self.REVERSED]}])
+        with pytest.raises(ValueError, match="is before start"):
+            temporal_cortex_toon.find_first_free_across(
+                streams, "2026-03-16T08:00:00Z", "2026-03-16T17:00:00Z", 30,
+            )
+
+    def test_zero_length_event_is_accepted(self):
+        instant = {"start": "2026-03-16T10:00:00Z", "end": "2026-03-16T10:00:00Z"}
+        streams = json.dumps([{"stream_id": "a", "events": [instant]}])
+        result = json.loads(temporal_cortex_toon.merge_availability(
+            streams, "2026-03-16T08:00:00Z", "2026-03-16T17:00:00Z",
+        ))
+        assert result["free"]
+
+
+# ---------------------------------------------------------------------------
+# merge_availability privacy
+# ---------------------------------------------------------------------------
+
+
+class TestMergeAvailabilityPrivacy:
+    """Tests for the privacy level passed to merge_availability."""
+
+    STREAMS = json.dumps([
+        {"stream_id": "work", "events": [
+            {"start": "2026-03-16T09:00:00Z", "end": "2026-03-16T10:00:00Z"},
+        ]},
+    ])
+
+    def test_free_only_omits_busy_blocks(self):
+        full = json.loads(temporal_cortex_toon.merge_availability(
+            self.STREAMS, "2026-03-16T08:00:00Z", "2026-03-16T17:00:00Z", False,
+        ))
+        free_only = json.loads(temporal_cortex_toon.merge_availability(
+            self.STREAMS, "2026-03-16T08:00:00Z", "2026-03-16T17:00:00Z", privacy="free_only",
+        ))
+        assert free_only["busy"] == []
+        assert free_only["free"] == full["free"]
+        assert free_only["privacy"] == "free_only"
+        assert full["privacy"] == "full"
+
+    def test_unknown_privacy_is_rejected(self):
+        with pytest.raises(ValueError, match="Invalid privacy 'secret'"):
+            temporal_cortex_toon.merge_availability(
+                self.STREAMS, "2026-03-16T08:00:00Z", "2026-03-16T17:00:00Z", privacy="secret",
+            )
+
+
+class TestMergeAvailabilityEmptyStreams:
+    """merge_availability with no streams leaves the whole window free."""
+
+    def test_no_streams_frees_whole_window(self):
+        result = json.loads(temporal_cortex_toon.merge_availability(
+            "[]", "2026-03-16T08:00:00Z", "2026-03-16T17:00:00Z",
+        ))
+        assert result["busy"] == []
+        assert result["free"] == [{
+            "start": "2026-03-16T08:00:00Z",
+            "end": "2026-03-16T17:00:00Z",
+            "duration_minutes": 540,
+        }]
+
+    def test_empty_string_is_invalid_json(self):
+        with pytest.raises(ValueError, match="Invalid streams JSON"):
+            temporal_cortex_toon.merge_availability(
+                "", "2026-03-16T08:00:00Z", "2026-03-16T17:00:00Z",
+            )
+
+
+# ---------------------------------------------------------------------------
+# find_all_free_across
+# ---------------------------------------------------------------------------
+
+
+class TestFindAllFreeAcross:
+    """Tests for listing every qualifying free slot across streams."""
+
+    def test_returns_every_qualifying_slot_in_order(self):
+        streams = json.dumps([
+            {"stream_id": "work", "events": [
+                {"start": "2026-03-16T09:00:00Z", "end": "2026-03-16T10:00:00Z"},
+                {"start": "2026-03-16T12:00:00Z", "end": "2026-03-16T13:00:00Z"},
+            ]},
+            {"stream_id": "personal", "events": [
+                {"start": "2026-03-16T13:15:00Z", "end": "2026-03-16T15:00:00Z"},
+            ]},
+        ])
+        slots = json.loads(temporal_cortex_toon.find_all_free_across(
+            streams, "2026-03-16T08:00:00Z", "2026-03-16T17:00:00Z", 30,
+        ))
+        assert [s["duration_minutes"] for s in slots] == [60, 120, 120]
+        assert slots[1]["start"] == "2026-03-16T10:00:00Z"
+
+
+# ---------------------------------------------------------------------------
+# next_free_slots
+# ---------------------------------------------------------------------------
+
+
+class TestNextFreeSlots:
+    """Tests for listing the next few free slots after a reference time."""
+
+    def test_returns_only_the_requested_number(self):
+        streams = json.dumps([
+            {"stream_id": "work", "events": [
+                {"start": "2026-03-16T09:00:00Z", "end": "2026-03-16T10:00:00Z"},
+                {"start": "2026-03-16T11:00:00Z", "end": "2026-03-16T12:00:00Z"},
+                {"start": "2026-03-16T13:00:00Z", "end": "2026-03-16T14:00:00Z"},
+                {"start": "2026-03-16T15:00:00Z", "end": "2026-03-16T16:00:00Z"},
+            ]},
+        ])
+        slots = json.loads(temporal_cortex_toon.next_free_slots(
+            streams, "2026-03-16T08:00:00Z", "2026-03-16T18:00:00Z", 30, 3,
+        ))
+        assert [s["start"] for s in slots] == [
+            "2026-03-16T08:00:00Z",
+            "2026-03-16T10:00:00Z",
+            "2026-03-16T12:00:00Z",
+        ]
+
+
+# ---------------------------------------------------------------------------
+# find_common_free
+# ---------------------------------------------------------------------------
+
+
+class TestFindCommonFree:
+    """Tests for finding free time shared by all participants."""
+
+    def test_partial_overlap(self):
+        participants = json.dumps([
+            {
+                "streams": [{"stream_id": "alice", "events": [
+                    {"start": "2026-03-16T12:00:00Z", "end": "2026-03-16T13:00:00Z"},
+                ]}],
+                "window_start": "2026-03-16T09:00:00Z",
+                "window_end": "2026-03-16T17:00:00Z",
+            },
+            {
+                "streams": [],
+                "window_start": "2026-03-16T11:00:00Z",
+                "window_end": "2026-03-16T19:00:00Z",
+            },
+        ])
+        slots = json.loads(find_common_free(participants, 30))
+        assert [s["duration_minutes"] for s in slots] == [60, 240]
+        assert slots[0]["start"] == "2026-03-16T11:00:00Z"
+
+    def test_invalid_json_raises(self):
+        with pytest.raises(ValueError):
+            find_common_free("not json", 30)
+
+
+# ---------------------------------------------------------------------------
+# find_slot_across_timezones
+# ---------------------------------------------------------------------------
+
+
+class TestFindSlotAcrossTimezones:
+    """Tests for meeting slots inside each attendee's local working hours."""
+
+    ATTENDEES = [
+        {"streams": [], "timezone": "America/Los_Angeles",
+         "work_start_hour": 8, "work_end_hour": 17},
+        {"streams": [{"stream_id": "london", "events": [
+            {"start": "2026-03-02T16:00:00Z", "end": "2026-03-02T16:30:00Z"},
+        ]}], "timezone": "Europe/London", "work_start_hour": 9, "work_end_hour": 18},
+    ]
+
+    def test_finds_slot_in_working_hours_overlap(self):
+        slot = json.loads(temporal_cortex_toon.find_slot_across_timezones(
+            json.dumps(self.ATTENDEES), 60, "2026-03-02T00:00:00Z", "2026-03-03T00:00:00Z",
+        ))
+        assert slot["start"].startswith("2026-03-02T16:30:00")
+        assert slot["end"].startswith("2026-03-02T17:30:00")
+
+    def test_returns_null_when_overlap_too_short(self):
+        result = temporal_cortex_toon.find_slot_across_timezones(
+            json.dumps(self.ATTENDEES), 150, "2026-03-02T00:00:00Z", "2026-03-03T00:00:00Z",
+        )
+        assert json.loads(result) is None
+
+    def test_invalid_timezone_raises(self):
+        attendees = [{"streams": [], "timezone": "Invalid/Zone",
+                      "work_start_hour": 9, "work_end_hour": 17}]
+        with pytest.raises(ValueError):
+            temporal_cortex_toon.find_slot_across_timezones(
+                json.dumps(attendees), 30, "2026-03-02T00:00:00Z", "2026-03-03T00:00:00Z",
+            )
+
+
+# ---------------------------------------------------------------------------
+# utilization
+# ---------------------------------------------------------------------------
+
+
+class TestUtilization:
+    """Tests for the busy fraction of working hours."""
+
+    # 09:00-17:00 New York (EDT, UTC-4) on Monday 2026-03-16
+    WINDOW = ("2026-03-16T04:00:00Z", "2026-03-17T04:00:00Z")
+
+    def _streams(self, start, end):
+        return json.dumps([{"stream_id": "work", "events": [{"start": start, "end": end}]}])
+
+    def test_half_booked_day(self):
+        streams = self._streams("2026-03-16T13:00:00Z", "2026-03-16T17:00:00Z")
+        ratio = temporal_cortex_toon.utilization(streams, "America/New_York", 9, 17, *self.WINDOW)
+        assert ratio == pytest.approx(0.5)
+
+    def test_busy_time_outside_working_hours_is_ignored(self):
+        streams = self._streams("2026-03-16T11:00:00Z", "2026-03-17T01:00:00Z")
+        ratio = temporal_cortex_toon.utilization(streams, "America/New_York", 9, 17, *self.WINDOW)
+        assert ratio == 1.0
+
+
+# ---------------------------------------------------------------------------
+# bucket_by_day
+# ---------------------------------------------------------------------------
+
+
+class TestBucketByDay:
+    """Tests for splitting merged availability into local days."""
+
+    def test_block_crossing_midnight_split(self):
+        streams = json.dumps([
+            {"stream_id": "a", "events": [
+                # 22:00-02:00 New York time (EST, UTC-5)
+                {"start": "2026-02-18T03:00:00Z", "end": "2026-02-18T07:00:00Z"},
+            ]},
+        ])
+        merged = temporal_cortex_toon.merge_availability(
+            streams, "2026-02-17T05:00:00Z", "2026-02-19T05:00:00Z",
+        )
+        days = json.loads(bucket_by_day(merged, "America/New_York"))
+        assert [d["date"] for d in days] == ["2026-02-17", "2026-02-18"]
+        assert days[0]["busy"][0]["end"] == "2026-02-18T05:00:00Z"
+        assert days[1]["busy"][0]["start"] == "2026-02-18T05:00:00Z"
+
+    def test_accepts_every_privacy_level(self):
+        for privacy in ["full", "opaque", "free_only"]:
+            merged = temporal_cortex_toon.merge_availability(
+                "[]", "2026-02-17T05:00:00Z", "2026-02-18T05:00:00Z", privacy=privacy,
+            )
+            days = json.loads(bucket_by_day(merged, "America/New_York"))
+            assert [d["date"] for d in days] == ["2026-02-17"]
+
+    def test_bucket_invalid_timezone_raises(self):
+        merged = temporal_cortex_toon.merge_availability(
+            "[]", "2026-02-17T00:00:00Z", "2026-02-18T00:00:00Z",
+        )
+        with pytest.raises(ValueError):
+            bucket_by_day(merged, "Invalid/Zone")
+
+
+# ---------------------------------------------------------------------------
+# parse_ics
+# ---------------------------------------------------------------------------
+
+ICS_TWO_EVENTS = "\r\n".join([
+    "BEGIN:VCALENDAR",
+    "BEGIN:VEVENT",
+    "SUMMARY:Standup",
+    "DTSTART;TZID=America/New_York:20260303T090000",
+    "DTEND;TZID=America/New_York:20260303T093000",
+    "RRULE:FREQ=WEEKLY;BYDAY=TU;COUNT=3",
+    "END:VEVENT",
+    "BEGIN:VEVENT",
+    "SUMMARY:Review",
+    "DTSTART:20260305T180000Z",
+    "DURATION:PT1H",
+    "END:VEVENT",
+    "END:VCALENDAR",
+])
+
+
+class TestParseIcs:
+    """Tests for parsing iCalendar VEVENTs."""
+
+    def test_parses_each_vevent(self):
+        events = json.loads(parse_ics(ICS_TWO_EVENTS))
+        assert [e["summary"] for e in events] == ["Standup", "Review"]
+        assert events[0]["timezone"] == "America/New_York"
+        assert events[0]["duration_minutes"] == 30
+        assert events[1]["rrule"] is None
+        assert events[1]["duration_minutes"] == 60
+
+    def test_recurring_event_expands(self):
+        standup = json.loads(parse_ics(ICS_TWO_EVENTS))[0]
+        instances = json.loads(expand_rrule(
+            standup["rrule"],
+            standup["dtstart"],
+            standup["duration_minutes"],
+            standup["timezone"],
+            None,
+            None,
+        ))
+        assert len(instances) == 3
+        assert instances[0]["start"].startswith("2026-03-03T14:00:00")
+        assert instances[1]["start"].startswith("2026-03-10T13:00:00")
+
+    def test_unterminated_vevent_raises(self):
+        with pytest.raises(ValueError):
+            parse_ics("BEGIN:VEVENT\nDTSTART:20260305T180000Z\n")
+
+
 # ---------------------------------------------------------------------------
 # convert_timezone
 # ---------------------------------------------------------------------------
@@ -406,3 +888,26 @@ def test_resolve_next_tuesday_at_2pm(self):
     def test_resolve_unparseable_raises(self):
         with pytest.raises(ValueError):
             resolve_relative("2026-02-18T14:30:00+00:00", "gobbledygook", "UTC")
+
+
+# ---------------------------------------------------------------------------
+# parse_relative_window
+# ---------------------------------------------------------------------------
+
+
+class TestParseRelativeWindow:
+    """Tests for named window construction."""
+
+    def test_today_is_local_day(self):
+        # 03:00 UTC on Feb 19 is still Feb 18 in New York
+        window = json.loads(parse_relative_window("today", "2026-02-19T03:00:00+00:00", "America/New_York"))
+        assert window["start"] == "2026-02-18T05:00:00+00:00"
+        assert window["end"] == "2026-02-19T05:00:00+00:00"
+
+    def test_naive_now_is_utc(self):
+        window = json.loads(parse_relative_window("today", "2026-02-19T03:00:00", "America/New_York"))
+        assert window["start"] == "2026-02-18T05:00:00+00:00"
+
+    def test_unknown_spec_raises(self):
+        with pytest.raises(ValueError):
+            parse_relative_window("someday", "2026-02-18T14:30:00+00:00", "UTC")
@@ -1,6 +1,6 @@
 //! WASM bindings for temporal-cortex-toon.
 //!
-//! Exposes `encode` and `decode` as `#[wasm_bindgen]` functions that can be
+//! Exposes `encode`, `decode`, `roundtrip` and `validateToon` as `#[wasm_bindgen]` functions that can be
 //! called from JavaScript/TypeScript. Built with `wasm-bindgen-cli` (not
 //! wasm-pack, which was archived in July 2025).
 //!
@@ -34,3 +34,23 @@ pub fn encode(json: &str) -> std::result::Result<String, JsValue> {
 pub fn decode(toon: &str) -> std::result::Result<String, JsValue> {
     toon_core::decode(toon).map_err(|e| JsValue::from_str(&e.to_string()))
 }
+
+/// Check whether a JSON string survives an encode → decode round trip.
+///
+/// Returns `true` if the decoded value matches the input (numbers compared by
+/// value, so `1.0` and `1` match), `false` otherwise. Throws a JS error if the
+/// input is not valid JSON.
+#[wasm_bindgen]
+pub fn roundtrip(json: &str) -> std::result::Result<bool, JsValue> {
+    toon_core::roundtrips(json).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// Check that a TOON string decodes.
+///
+/// Returns `true`, or throws a JS error carrying the parse error.
+#[wasm_bindgen(js_name = "validateToon")]
+pub fn validate_toon(toon: &str) -> std::result::Result<bool, JsValue> {
+    toon_core::validate(toon)
+        .map(|()| true)
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
@@ -1,8 +1,86 @@
-use criterion::{criterion_group, criterion_main, Criterion};
+//! Encode/decode throughput baselines.
+//!
+//! Run with `cargo bench -p temporal-cortex-toon`.
 
-fn bench_encode(_c: &mut Criterion) {
-    // Will be implemented after encoder is working
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::hint::black_box;
+use toon_core::{decode, encode};
+
+/// A Google Calendar events response: nested objects, tabular attendees,
+/// inline arrays and quoted strings.
+const CALENDAR_JSON: &str = include_str!("fixtures/calendar.json");
+
+/// Rows in the generated tabular document.
+const TABULAR_ROWS: usize = 10_000;
+
+/// A root tabular array of `TABULAR_ROWS` uniform event rows, as TOON.
+fn large_tabular_toon() -> String {
+    let rows: Vec<serde_json::Value> = (0..TABULAR_ROWS)
+        .map(|i| {
+            serde_json::json!({
+                "id": i,
+                "summary": format!("Meeting {i}"),
+                "start": format!("2026-03-{:02}T09:00:00Z", i % 28 + 1),
+                "duration": 30 + (i % 4) * 15,
+                "confirmed": i % 3 != 0,
+            })
+        })
+        .collect();
+    encode(&serde_json::Value::Array(rows).to_string()).unwrap()
+}
+
+/// Columns and rows in the generated wide tabular document.
+const WIDE_COLUMNS: usize = 1_000;
+const WIDE_ROWS: usize = 1_000;
+
+/// A `WIDE_COLUMNS` × `WIDE_ROWS` table written directly as TOON, every other
+/// cell quoted (with the delimiter inside) so both cell paths are exercised.
+fn wide_tabular_toon() -> String {
+    let fields: Vec<String> = (0..WIDE_COLUMNS).map(|c| format!("f{c}")).collect();
+    let mut toon = format!("rows[{WIDE_ROWS}]{{{}}}:", fields.join(","));
+    for r in 0..WIDE_ROWS {
+        toon.push_str("\n  ");
+        for c in 0..WIDE_COLUMNS {
+            if c > 0 {
+                toon.push(',');
+            }
+            if c % 2 == 0 {
+                toon.push_str(&format!("v{r}"));
+            } else {
+                toon.push_str(&format!("\"a,{c}\""));
+            }
+        }
+    }
+    toon
+}
+
+fn bench_encode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("encode");
+    group.throughput(Throughput::Bytes(CALENDAR_JSON.len() as u64));
+    group.bench_function("calendar_fixture", |b| {
+        b.iter(|| encode(black_box(CALENDAR_JSON)).unwrap())
+    });
+    group.finish();
+}
+
+fn bench_decode(c: &mut Criterion) {
+    let toon = large_tabular_toon();
+    let mut group = c.benchmark_group("decode");
+    group.throughput(Throughput::Bytes(toon.len() as u64));
+    group.bench_function("tabular_10k_rows", |b| {
+        b.iter(|| decode(black_box(&toon)).unwrap())
+    });
+    group.finish();
+
+    let wide = wide_tabular_toon();
+    let mut group = c.benchmark_group("decode_wide");
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(wide.len() as u64));
+    group.bench_function("tabular_1000x1000", |b| {
+        b.iter(|| decode(black_box(&wide)).unwrap())
+    });
+    group.finish();
 }
 
-criterion_group!(benches, bench_encode);
+criterion_group!(benches, bench_encode, bench_decode);
 criterion_main!(benches);
@@ -20,7 +20,10 @@
 //!   rather than assuming `base_indent + 2`, supporting flexible nesting depths.
 
 use crate::error::{Result, ToonError};
+use crate::types::{ColumnType, Delimiter, KeywordCase};
 use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::io::BufRead;
 
 /// Decode a TOON string back into JSON format.
 ///
@@ -28,37 +31,638 @@
 /// The output is minified (no pretty-printing) — use `serde_json::to_string_pretty`
 /// on the result if human-readable JSON is needed.
 pub fn decode(toon: &str) -> Result<String> {
-    let value = parse_toon(toon)?;
+    decode_with_options(toon, &DecodeOptions::default())
+}
+
+/// Options controlling how strictly [`decode_with_options`] interprets its input.
+///
+/// The default is lenient and matches [`decode`].
+#[derive(Debug, Clone, Default)]
+pub struct DecodeOptions {
+    /// Reject tabular rows whose cell count differs from the header's field count.
+    ///
+    /// When `false`, short rows are padded with `null` and extra cells are ignored,
+    /// which can hide a ragged table emitted by an LLM.
+    pub strict_tabular: bool,
+    /// Separator between inline array elements and tabular cells. Must match the
+    /// delimiter the document was encoded with; TOON does not record it.
+    pub delimiter: Delimiter,
+    /// Decode an empty unquoted cell (`1,,3`) as `""` instead of `null`.
+    ///
+    /// By default an empty cell is a missing value: the encoder writes empty
+    /// strings as `""` and missing tabular fields as empty cells, so `null` is
+    /// what round-trips. Set this for hand-written or LLM-emitted tables that use
+    /// bare empty cells for empty text. Quoted `""` is always an empty string,
+    /// and the `null` token and padding for short rows are always `null`.
+    pub empty_cells_as_strings: bool,
+    /// Reject escape sequences in quoted strings other than `\\`, `\"`, `\n`,
+    /// `\r`, `\t` and `\/`.
+    ///
+    /// When `false`, an unknown escape such as `\q` is kept literally (backslash
+    /// included), which can hide a generator that emits invalid escapes.
+    pub strict_escapes: bool,
+    /// Reject arrays whose element count differs from the `[N]` in their header.
+    ///
+    /// The encoder always writes the true count, so with this set the count is
+    /// authoritative end to end: an LLM that drops or invents rows, or a count
+    /// edited by hand, fails to decode instead of yielding a shorter or longer
+    /// array. When `false`, the count is informational and the elements present
+    /// are decoded as found.
+    pub assert_counts: bool,
+    /// Reject a field with no value (`user:`) whose next line is indented past
+    /// it but by less than the two spaces its children need.
+    ///
+    /// Such a line was meant as a child, but the lenient default reads `user` as
+    /// an empty object and skips the line, so `user:\n id: 1` silently loses
+    /// `id`. The encoder never writes it, so encoder output always passes.
+    /// Children not indented at all (`user:\nid: 1`) cannot be told apart from
+    /// an empty object followed by a sibling, which the encoder writes the same
+    /// way, and are read as siblings either way: `{"user":{},"id":1}`.
+    pub strict_nesting: bool,
+    /// Reject lines that are not part of any value, reporting the first one.
+    ///
+    /// When `false`, a line indented deeper than the field before it can hold
+    /// (`a: 1\n  stray`) is skipped, and a line with no `key:` (`garbage line`)
+    /// becomes a `null` field named after the whole line. Both usually mean the
+    /// document was truncated or corrupted, so the lenient result silently
+    /// differs from what was written.
+    pub require_full_consume: bool,
+    /// Spelling of boolean and null keywords besides the spec's lowercase ones.
+    ///
+    /// Must match the [`EncodeOptions::keyword_case`](crate::EncodeOptions::keyword_case)
+    /// the document was written with: under the default, `TRUE` is the string
+    /// "TRUE", while under [`KeywordCase::Upper`] it is the boolean `true`.
+    /// Lowercase `true`, `false` and `null` are keywords in every case.
+    pub keyword_case: KeywordCase,
+}
+
+/// Check that a TOON string decodes, without producing the JSON.
+///
+/// Returns the same error [`decode`] would for malformed input.
+///
+/// # Example
+/// ```
+/// use toon_core::validate;
+///
+/// assert!(validate("name: Ada\ntags[2]: a,b").is_ok());
+/// assert!(validate("tags[2]: \"a,b").is_err());
+/// ```
+pub fn validate(toon: &str) -> Result<()> {
+    decode_value(toon, &DecodeOptions::default()).map(drop)
+}
+
+/// Decode a TOON string back into JSON format with the given options.
+///
+/// # Example
+/// ```
+/// use toon_core::decoder::{decode_with_options, DecodeOptions};
+///
+/// let ragged = "rows[1]{a,b}:\n  1";
+/// let strict = DecodeOptions {
+///     strict_tabular: true,
+///     ..DecodeOptions::default()
+/// };
+/// assert!(decode_with_options(ragged, &strict).is_err());
+/// ```
+pub fn decode_with_options(toon: &str, options: &DecodeOptions) -> Result<String> {
+    let value = decode_value(toon, options)?;
     Ok(serde_json::to_string(&value)?)
 }
 
+/// Decode a TOON string into JSON with object keys sorted at every level.
+///
+/// [`decode`] keeps keys in source order; this canonical form instead gives
+/// byte-identical output for documents that differ only in key order, which is
+/// what diffing and content-hashing snapshots need. Array order is preserved.
+///
+/// # Example
+/// ```
+/// use toon_core::decode_sorted;
+///
+/// let a = decode_sorted("name: Ada\nid: 1").unwrap();
+/// let b = decode_sorted("id: 1\nname: Ada").unwrap();
+/// assert_eq!(a, b);
+/// assert_eq!(a, r#"{"id":1,"name":"Ada"}"#);
+/// ```
+pub fn decode_sorted(toon: &str) -> Result<String> {
+    let mut value = decode_value(toon, &DecodeOptions::default())?;
+    sort_keys(&mut value);
+    Ok(serde_json::to_string(&value)?)
+}
+
+/// Decode a document that is a single tabular array into its header field names
+/// and rows of raw string cells, without type inference.
+///
+/// The document is either a root table (`[N]{f1,f2}:`) or one key holding a
+/// table (`key[N]{f1,f2}:`). Quoted cells are unescaped; unquoted cells are
+/// returned trimmed, as written, so `42`, `true` and `null` stay the strings
+/// they were and an empty cell is `""`. This suits columnar ingestion, where
+/// the caller casts each column itself. Type hints in a typed header are
+/// dropped from the field names and not applied. Rows are split on
+/// `delimiter`, which must match the one the table was encoded with.
+///
+/// # Errors
+/// Returns [`ToonError::ToonParse`] if the document is anything other than a
+/// single tabular array, or a row's cell count differs from the header's.
+///
+/// # Example
+/// ```
+/// use toon_core::{decode_tabular_raw, Delimiter};
+///
+/// let toon = "[2]{id,name}:\n  007,Ada\n  8,\"Grace\"";
+/// let (fields, rows) = decode_tabular_raw(toon, Delimiter::Comma).unwrap();
+/// assert_eq!(fields, ["id", "name"]);
+/// assert_eq!(rows, [["007", "Ada"], ["8", "Grace"]]);
+/// ```
+pub fn decode_tabular_raw(
+    toon: &str,
+    delimiter: Delimiter,
+) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+    let not_tabular = |line: usize| ToonError::ToonParse {
+        line,
+        message: "Document is not a single tabular array".to_string(),
+    };
+    let mut lines = toon
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let (header_idx, header_line) = lines.next().ok_or_else(|| not_tabular(1))?;
+    let header_indent = count_indent(header_line);
+    let fields = header_line
+        .trim()
+        .split_once('[')
+        .filter(|(key, _)| !key.contains([':', ' ']))
+        .and_then(|_| parse_array_header(header_line))
+        .and_then(|header| header.fields)
+        .ok_or_else(|| not_tabular(header_idx + 1))?;
+
+    let mut rows = Vec::new();
+    for (i, line) in lines {
+        if count_indent(line) <= header_indent {
+            return Err(not_tabular(i + 1));
+        }
+        let cells = split_cells(line.trim(), delimiter.as_char()).map_err(at_line(i))?;
+        if cells.len() != fields.len() {
+            return Err(ToonError::ToonParse {
+                line: i + 1,
+                message: format!(
+                    "Tabular row has {} cells, expected {} (fields: {})",
+                    cells.len(),
+                    fields.len(),
+                    fields.join(",")
+                ),
+            });
+        }
+        rows.push(
+            cells
+                .into_iter()
+                .map(|cell| match cell {
+                    Cell::Quoted(s) => s,
+                    Cell::Bare(token) => token.trim().to_string(),
+                })
+                .collect(),
+        );
+    }
+    Ok((fields, rows))
+}
+
+/// Recursively sort the keys of every object in `value`.
+pub(crate) fn sort_keys(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.sort_keys();
+            map.values_mut().for_each(sort_keys);
+        }
+        Value::Array(items) => items.iter_mut().for_each(sort_keys),
+        _ => {}
+    }
+}
+
+/// Decode a TOON string into a `serde_json::Value` without re-serializing.
+pub(crate) fn decode_value(toon: &str, options: &DecodeOptions) -> Result<Value> {
+    if options.strict_escapes {
+        check_escapes(toon, options.delimiter.as_char())?;
+    }
+    let mut ctx = DecodeCtx {
+        options: options.clone(),
+        ..DecodeCtx::default()
+    };
+    parse_toon(toon, &mut ctx)
+}
+
+/// Decode TOON read line by line from `reader` into a `serde_json::Value`.
+///
+/// Root arrays — a tabular `[N]{f1,f2}:` block or an expanded `[N]:` list of
+/// `- ` items, the usual shapes of large exports — are decoded one row or item
+/// at a time, so the document never has to be held in memory as a single
+/// string. Any other document is read in full and decoded as by [`decode`]; the
+/// result is the same either way.
+///
+/// # Example
+/// ```
+/// use toon_core::decoder::decode_from_reader;
+///
+/// let toon = "[2]{id,name}:\n  1,Ada\n  2,Grace\n";
+/// let value = decode_from_reader(toon.as_bytes()).unwrap();
+/// assert_eq!(value[1]["name"], "Grace");
+/// ```
+pub fn decode_from_reader<R: BufRead>(reader: R) -> Result<Value> {
+    decode_from_reader_with_options(reader, &DecodeOptions::default())
+}
+
+/// Decode TOON read line by line from `reader` with the given options.
+///
+/// Streams the same root arrays as [`decode_from_reader`], and the result is
+/// the same as [`decode_with_options`] on the whole document.
+///
+/// # Example
+/// ```
+/// use toon_core::decoder::{decode_from_reader_with_options, DecodeOptions};
+/// use toon_core::Delimiter;
+///
+/// let options = DecodeOptions {
+///     delimiter: Delimiter::Pipe,
+///     ..DecodeOptions::default()
+/// };
+/// let toon = "[2]{id,name}:\n  1|Ada, Countess\n  2|Grace\n";
+/// let value = decode_from_reader_with_options(toon.as_bytes(), &options).unwrap();
+/// assert_eq!(value[0]["name"], "Ada, Countess");
+/// ```
+pub fn decode_from_reader_with_options<R: BufRead>(
+    reader: R,
+    options: &DecodeOptions,
+) -> Result<Value> {
+    let mut ctx = DecodeCtx {
+        options: options.clone(),
+        ..DecodeCtx::default()
+    };
+    let strict_escapes = options.strict_escapes;
+    let delimiter = options.delimiter.as_char();
+    // Escapes are checked line by line, exactly as `check_escapes` does for a
+    // whole document.
+    let mut lines = utf8_lines(reader).enumerate().map(move |(line_idx, line)| {
+        let line = line?;
+        if strict_escapes {
+            check_escapes(&line, delimiter).map_err(|err| match err {
+                ToonError::ToonParse { message, .. } => ToonError::ToonParse {
+                    line: line_idx + 1,
+                    message,
+                },
+                other => other,
+            })?;
+        }
+        Ok(line)
+    });
+    let first = match lines.next() {
+        Some(line) => line?,
+        None => return Ok(Value::Object(Map::new())),
+    };
+
+    if first.starts_with('[') {
+        if let Some(header) = parse_array_header(&first) {
+            let streamed = if header.len == 0 {
+                None
+            } else if let Some(fields) = &header.fields {
+                Some(stream_tabular_rows(
+                    lines.by_ref(),
+                    fields,
+                    &header.column_types,
+                    &mut ctx,
+                )?)
+            } else if header.inline_values.is_none() {
+                Some(stream_list_items(lines.by_ref(), &mut ctx)?)
+            } else {
+                None
+            };
+            if let Some(value) = streamed {
+                check_array_count(&header, &value, 0, &ctx)?;
+                return Ok(value);
+            }
+        }
+    }
+
+    let mut toon = first;
+    for line in lines {
+        toon.push('\n');
+        toon.push_str(&line?);
+    }
+    parse_toon(&toon, &mut ctx)
+}
+
+/// The lines of `reader`, split as by [`BufRead::lines`] but reporting invalid
+/// UTF-8 as [`ToonError::InvalidUtf8`] rather than as an I/O error.
+fn utf8_lines<R: BufRead>(mut reader: R) -> impl Iterator<Item = Result<String>> {
+    let mut buf = Vec::new();
+    std::iter::from_fn(move || {
+        buf.clear();
+        match reader.read_until(b'\n', &mut buf) {
+            Ok(0) => None,
+            Ok(_) => {
+                if buf.ends_with(b"\n") {
+                    buf.pop();
+                    if buf.ends_with(b"\r") {
+                        buf.pop();
+                    }
+                }
+                Some(
+                    std::str::from_utf8(&buf)
+                        .map(str::to_owned)
+                        .map_err(ToonError::from),
+                )
+            }
+            Err(e) => Some(Err(e.into())),
+        }
+    })
+}
+
+/// Decode the rows of a root tabular array as they are read. Follows the
+/// tabular branch of `parse_array_body` at base indent 0: the table ends at the
+/// second unindented line.
+fn stream_tabular_rows(
+    lines: impl Iterator<Item = Result<String>>,
+    fields: &[String],
+    column_types: &[Option<ColumnType>],
+    ctx: &mut DecodeCtx,
+) -> Result<Value> {
+    let mut rows = Vec::new();
+    for (offset, line) in lines.enumerate() {
+        let line = line?;
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if count_indent(&line) == 0 && offset > 0 {
+            reject_unconsumed(offset + 1, ctx)?;
+            break;
+        }
+        rows.push(parse_tabular_row(
+            trimmed,
+            fields,
+            column_types,
+            offset + 1,
+            ctx,
+        )?);
+    }
+    Ok(Value::Array(rows))
+}
+
+/// Decode the `- ` items of a root expanded list as they are read, buffering only
+/// the lines of the current item. Item boundaries follow `parse_list_items`.
+fn stream_list_items(
+    lines: impl Iterator<Item = Result<String>>,
+    ctx: &mut DecodeCtx,
+) -> Result<Value> {
+    let mut items = Vec::new();
+    let mut item_indent = None;
+    let mut item_lines: Vec<String> = Vec::new();
+    let mut item_start = 0;
+
+    for (offset, line) in lines.enumerate() {
+        let line = line?;
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            if !item_lines.is_empty() {
+                item_lines.push(line);
+            }
+            continue;
+        }
+        let indent = count_indent(&line);
+        // Same detection as `parse_array_body`: the first line's indent if it is
+        // an item, otherwise the default of 2.
+        let expected =
+            *item_indent.get_or_insert(if trimmed.starts_with("- ") { indent } else { 2 });
+        if indent > expected {
+            // Continuation of the current item (or stray lines before the first).
+            if !item_lines.is_empty() {
+                item_lines.push(line);
+            }
+            continue;
+        }
+        if indent < expected || !trimmed.starts_with("- ") {
+            reject_unconsumed(offset + 1, ctx)?;
+            break;
+        }
+        parse_buffered_item(&mut item_lines, item_start, expected, ctx, &mut items)?;
+        item_start = offset + 1;
+        item_lines.push(line);
+    }
+    if let Some(expected) = item_indent {
+        parse_buffered_item(&mut item_lines, item_start, expected, ctx, &mut items)?;
+    }
+    Ok(Value::Array(items))
+}
+
+/// Parse the buffered lines of one list item, whose first line is at 0-based
+/// index `item_start` of the document, appending it to `items`.
+fn parse_buffered_item(
+    item_lines: &mut Vec<String>,
+    item_start: usize,
+    item_indent: usize,
+    ctx: &mut DecodeCtx,
+    items: &mut Vec<Value>,
+) -> Result<()> {
+    if item_lines.is_empty() {
+        return Ok(());
+    }
+    let refs: Vec<&str> = item_lines.iter().map(String::as_str).collect();
+    let parsed = parse_list_items(&refs, 0, item_indent, ctx).map_err(|err| match err {
+        ToonError::ToonParse { line, message } => ToonError::ToonParse {
+            line: line + item_start,
+            message,
+        },
+        other => other,
+    })?;
+    if let Value::Array(parsed) = parsed {
+        items.extend(parsed);
+    }
+    item_lines.clear();
+    Ok(())
+}
+
+/// Maps JSON pointer paths (RFC 6901, e.g. `/items/0/summary`) to the 1-based,
+/// inclusive `(line_start, line_end)` range of TOON source each value came from.
+///
+/// The root value is keyed by the empty pointer `""`. Tabular cells and inline
+/// array elements share the line of their row/header.
+pub type SpanMap = BTreeMap<String, (usize, usize)>;
+
+/// Decode a TOON string into a JSON value plus the source line span of every node.
+///
+/// Intended for editors and linters that need to map decoded values back to the
+/// TOON lines they came from (e.g., for inline error highlighting). Decoding rules
+/// are identical to [`decode`].
+///
+/// # Example
+/// ```
+/// use toon_core::decoder::decode_with_spans;
+///
+/// let (value, spans) = decode_with_spans("user:\n  name: Ada\n  id: 1").unwrap();
+/// assert_eq!(value["user"]["name"], "Ada");
+/// assert_eq!(spans["/user"], (1, 3));
+/// assert_eq!(spans["/user/id"], (3, 3));
+/// ```
+pub fn decode_with_spans(toon: &str) -> Result<(Value, SpanMap)> {
+    let mut ctx = DecodeCtx {
+        spans: Some(SpanMap::new()),
+        ..DecodeCtx::default()
+    };
+    let value = parse_toon(toon, &mut ctx)?;
+    Ok((value, ctx.spans.unwrap_or_default()))
+}
+
+/// Deepest container nesting the decoder will follow. Matches `serde_json`'s
+/// recursion limit, so any document [`crate::encode`] can produce still decodes.
+const MAX_DEPTH: usize = 128;
+
+/// Per-decode state threaded through the parser.
+///
+/// Span recording is opt-in: with `spans` unset, path tracking is skipped so
+/// plain [`decode`] pays nothing for it.
+#[derive(Default)]
+struct DecodeCtx {
+    /// Caller-supplied decoding options.
+    options: DecodeOptions,
+    /// JSON pointer of the node currently being parsed.
+    path: String,
+    /// Collected spans, populated only by [`decode_with_spans`].
+    spans: Option<SpanMap>,
+    /// Number of nested containers currently being parsed.
+    depth: usize,
+    /// Array forms and string quoting seen so far, populated only by
+    /// [`decode_with_forms`].
+    forms: Option<FormCounts>,
+}
+
+/// How a decoded document was written: array encoding forms and quoted strings.
+///
+/// These are properties of the TOON source that the decoded value no longer
+/// carries, collected for [`crate::stats::document_stats`].
+#[derive(Debug, Default)]
+pub(crate) struct FormCounts {
+    /// Arrays written on their header line (`[N]: a,b`), including empty ones.
+    pub(crate) inline_arrays: usize,
+    /// Arrays written as a header with fields and one row per line.
+    pub(crate) tabular_arrays: usize,
+    /// Arrays written as `- ` items.
+    pub(crate) expanded_arrays: usize,
+    /// String values written in double quotes.
+    pub(crate) quoted_strings: usize,
+}
+
+/// Decode a TOON string, also reporting the [`FormCounts`] of its source.
+pub(crate) fn decode_with_forms(toon: &str) -> Result<(Value, FormCounts)> {
+    let mut ctx = DecodeCtx {
+        forms: Some(FormCounts::default()),
+        ..DecodeCtx::default()
+    };
+    let value = parse_toon(toon, &mut ctx)?;
+    Ok((value, ctx.forms.unwrap_or_default()))
+}
+
+impl DecodeCtx {
+    /// Descend into an object member. Returns the path length to restore via `leave`.
+    fn enter_key(&mut self, key: &str) -> usize {
+        let len = self.path.len();
+        if self.spans.is_some() {
+            self.path.push('/');
+            self.path
+                .push_str(&key.replace('~', "~0").replace('/', "~1"));
+        }
+        len
+    }
+
+    /// Descend into an array element. Returns the path length to restore via `leave`.
+    fn enter_index(&mut self, index: usize) -> usize {
+        let len = self.path.len();
+        if self.spans.is_some() {
+            self.path.push('/');
+            self.path.push_str(&index.to_string());
+        }
+        len
+    }
+
+    fn leave(&mut self, len: usize) {
+        self.path.truncate(len);
+    }
+
+    /// Update the form counts, if they are being collected.
+    fn count_form(&mut self, update: impl FnOnce(&mut FormCounts)) {
+        if let Some(forms) = self.forms.as_mut() {
+            update(forms);
+        }
+    }
+
+    /// Count `value` as a quoted string if it is a string written in quotes.
+    fn note_scalar(&mut self, quoted: bool, value: &Value) {
+        if quoted && value.is_string() {
+            self.count_form(|forms| forms.quoted_strings += 1);
+        }
+    }
+
+    /// Parse a nested container one level deeper, failing instead of recursing
+    /// past [`MAX_DEPTH`] so hostile input cannot overflow the stack.
+    fn nested<T>(
+        &mut self,
+        line_idx: usize,
+        parse: impl FnOnce(&mut Self) -> Result<T>,
+    ) -> Result<T> {
+        if self.depth >= MAX_DEPTH {
+            return Err(ToonError::ToonParse {
+                line: line_idx + 1,
+                message: format!("Nesting exceeds {} levels", MAX_DEPTH),
+            });
+        }
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    /// Record the current node as spanning line indices `start..end` (0-based,
+    /// exclusive end), ignoring trailing blank lines.
+    fn record(&mut self, lines: &[&str], start: usize, end: usize) {
+        if let Some(spans) = self.spans.as_mut() {
+            // Every node covers at least its own line, even at end of input.
+            let mut last = end.min(lines.len()).max(start + 1) - 1;
+            while last > start && lines.get(last).is_some_and(|l| l.trim().is_empty()) {
+                last -= 1;
+            }
+            spans.insert(self.path.clone(), (start + 1, last + 1));
+        }
+    }
+}
+
 /// Main entry point: classify the TOON input as root array, root primitive, or object.
-fn parse_toon(toon: &str) -> Result<Value> {
+fn parse_toon(toon: &str, ctx: &mut DecodeCtx) -> Result<Value> {
     let toon = toon.trim_end_matches('\n');
 
     if toon.is_empty() {
         return Ok(Value::Object(Map::new()));
     }
 
+    let lines: Vec<&str> = toon.lines().collect();
+    ctx.record(&lines, 0, lines.len());
+
     // Check for root array: starts with [N]:
     if toon.starts_with('[') {
-        if let Some(val) = try_parse_root_array(toon)? {
+        if let Some(val) = try_parse_root_array(toon, ctx)? {
             return Ok(val);
         }
     }
 
     // Check for root primitive (single line, no colon structure)
-    let lines: Vec<&str> = toon.lines().collect();
     if lines.len() == 1 && !line_has_key_colon(lines[0]) {
-        return parse_primitive_value(lines[0].trim());
+        let value = parse_primitive_value(lines[0].trim(), ctx)?;
+        ctx.note_scalar(is_quoted_token(lines[0]), &value);
+        return Ok(value);
     }
 
     // Object: key-value pairs
-    parse_object_from_lines(&lines, 0, 0, lines.len())
+    parse_object_from_lines(&lines, 0, 0, lines.len(), ctx)
 }
 
 /// Try parsing as root array: [N]: ... or [N]:\n...
-fn try_parse_root_array(toon: &str) -> Result<Option<Value>> {
+fn try_parse_root_array(toon: &str, ctx: &mut DecodeCtx) -> Result<Option<Value>> {
     let lines: Vec<&str> = toon.lines().collect();
     if lines.is_empty() {
         return Ok(None);
@@ -67,7 +671,17 @@ fn try_parse_root_array(toon: &str) -> Result<Option<Value>> {
 
     // Match [N]{fields}: or [N]: or [N]:
     if let Some(header) = parse_array_header(first_line) {
-        let arr = parse_array_body(&header, &lines, 0, 0)?;
+        let arr = parse_array_body(&header, &lines, 0, 0, ctx)?;
+        // As for an array field: an empty or inline array is its header line
+        // alone, and anything after the body is not part of the document.
+        let end = if header.len == 0 || header.inline_values.is_some() {
+            1
+        } else {
+            skip_array_body(&lines, 1, 0)
+        };
+        if let Some(stray) = (end..lines.len()).find(|&i| !lines[i].trim().is_empty()) {
+            reject_unconsumed(stray, ctx)?;
+        }
         return Ok(Some(arr));
     }
     Ok(None)
@@ -80,8 +694,8 @@ fn line_has_key_colon(line: &str) -> bool {
     if trimmed.starts_with('"') {
         // Find the closing quote (handling escapes)
         if let Some(end) = find_closing_quote(trimmed, 1) {
-            // After closing quote, should be ':'
-            return end + 1 < trimmed.len() && trimmed.as_bytes()[end + 1] == b':';
+            // After closing quote, should be ':' or an array header `[N]`
+            return trimmed[end + 1..].starts_with([':', '[']);
         }
         return false;
     }
@@ -102,17 +716,20 @@ fn line_has_key_colon(line: &str) -> bool {
 
 /// Parsed metadata from an array header line like `key[3]{a,b}: ` or `key[2]: v1,v2`.
 ///
-/// - `len`: declared element count (used for validation, not currently enforced)
+/// - `len`: declared element count (enforced under [`DecodeOptions::assert_counts`])
 /// - `fields`: tabular column names if present (`{f1,f2}` syntax)
+/// - `column_types`: per-field type hints from a typed header (`{id:int,name:str}`),
+///   `None` for fields without one
 /// - `inline_values`: the raw value string if inline (`[N]: v1,v2` — text after `: `)
-struct ArrayHeader {
-    len: usize,
-    fields: Option<Vec<String>>,
-    inline_values: Option<String>,
+pub(crate) struct ArrayHeader {
+    pub(crate) len: usize,
+    pub(crate) fields: Option<Vec<String>>,
+    column_types: Vec<Option<ColumnType>>,
+    pub(crate) inline_values: Option<String>,
 }
 
 /// Parse array header from a line like `[N]: v1,v2` or `[N]{f1,f2}:` or `[N]:`
-fn parse_array_header(line: &str) -> Option<ArrayHeader> {
+pub(crate) fn parse_array_header(line: &str) -> Option<ArrayHeader> {
     let trimmed = line.trim();
     let bracket_start = trimmed.find('[')?;
     let bracket_end = trimmed[bracket_start..].find(']')? + bracket_start;
@@ -122,66 +739,153 @@ fn parse_array_header(line: &str) -> Option<ArrayHeader> {
     let after_bracket = &trimmed[bracket_end + 1..];
 
     // Check for tabular: {f1,f2}:
-    if after_bracket.starts_with('{') {
-        let brace_end = after_bracket.find('}')?;
-        let fields_str = &after_bracket[1..brace_end];
-        let fields: Vec<String> = fields_str.split(',').map(|s| s.to_string()).collect();
-        let after_brace = &after_bracket[brace_end + 1..];
+    if let Some(fields_str) = after_bracket.strip_prefix('{') {
+        let (raw_fields, after_brace) = split_header_fields(fields_str)?;
+        let (fields, column_types): (Vec<String>, Vec<Option<ColumnType>>) =
+            raw_fields.into_iter().map(parse_header_field).unzip();
         if after_brace.starts_with(':') {
             return Some(ArrayHeader {
                 len,
                 fields: Some(fields),
+                column_types,
                 inline_values: None,
             });
         }
         return None;
     }
 
-    // Check for inline: `: v1,v2` (space after colon with values on same line)
-    if let Some(values) = after_bracket.strip_prefix(": ") {
-        return Some(ArrayHeader {
-            len,
-            fields: None,
-            inline_values: Some(values.to_string()),
-        });
+    // Inline: `: v1,v2`. The encoder always writes the space, but hand-written
+    // `:v1,v2` is accepted too. A bare `:` is expanded or empty.
+    let rest = after_bracket.strip_prefix(':')?;
+    let inline_values =
+        (!rest.is_empty()).then(|| rest.strip_prefix(' ').unwrap_or(rest).to_string());
+    Some(ArrayHeader {
+        len,
+        fields: None,
+        column_types: Vec::new(),
+        inline_values,
+    })
+}
+
+/// Split the inside of a tabular header (`f1,f2}: ...`) into its raw fields and
+/// the text after the closing `}`. Commas and braces inside quoted field names
+/// do not count. Returns `None` if the header is unterminated.
+fn split_header_fields(s: &str) -> Option<(Vec<&str>, &str)> {
+    let mut fields = Vec::new();
+    let mut field_start = 0;
+    let mut in_quotes = false;
+    let mut escaped = false;
+    for (i, ch) in s.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match ch {
+            '\\' if in_quotes => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => {
+                fields.push(&s[field_start..i]);
+                field_start = i + 1;
+            }
+            '}' if !in_quotes => {
+                fields.push(&s[field_start..i]);
+                return Some((fields, &s[i + 1..]));
+            }
+            _ => {}
+        }
     }
+    None
+}
 
-    // Expanded/empty: `:`
-    if after_bracket.starts_with(':') {
-        return Some(ArrayHeader {
-            len,
-            fields: None,
-            inline_values: None,
-        });
+/// Split a tabular header field into its name and optional type hint
+/// (`id:int` → `("id", Some(Int))`). A suffix that is not a known type is part
+/// of the name. Quoted names (`"a,b":int`) are unescaped.
+fn parse_header_field(field: &str) -> (String, Option<ColumnType>) {
+    if field.starts_with('"') {
+        if let Some(end) = find_closing_quote(field, 1) {
+            let name = unescape_string(&field[1..end]);
+            let ty = field[end + 1..]
+                .strip_prefix(':')
+                .and_then(ColumnType::from_annotation);
+            return (name, ty);
+        }
     }
+    match field.rsplit_once(':') {
+        Some((name, annotation)) => match ColumnType::from_annotation(annotation) {
+            Some(ty) => (name.to_string(), Some(ty)),
+            None => (field.to_string(), None),
+        },
+        None => (field.to_string(), None),
+    }
+}
 
-    None
+/// Parse the body of an array given its header and surrounding lines, checking
+/// the element count against the header under [`DecodeOptions::assert_counts`].
+fn parse_array_body(
+    header: &ArrayHeader,
+    lines: &[&str],
+    line_idx: usize,
+    base_indent: usize,
+    ctx: &mut DecodeCtx,
+) -> Result<Value> {
+    let value = parse_array_elements(header, lines, line_idx, base_indent, ctx)?;
+    check_array_count(header, &value, line_idx, ctx)?;
+    Ok(value)
+}
+
+/// Under [`DecodeOptions::assert_counts`], fail if the decoded array's length
+/// differs from the `[N]` in its header on line `line_idx`.
+fn check_array_count(
+    header: &ArrayHeader,
+    value: &Value,
+    line_idx: usize,
+    ctx: &DecodeCtx,
+) -> Result<()> {
+    if ctx.options.assert_counts {
+        let found = value.as_array().map_or(0, Vec::len);
+        if found != header.len {
+            return Err(ToonError::ToonParse {
+                line: line_idx + 1,
+                message: format!("Array declares {} elements but has {}", header.len, found),
+            });
+        }
+    }
+    Ok(())
 }
 
-/// Parse the body of an array given its header and surrounding lines.
+/// Parse the elements of an array given its header and surrounding lines.
 ///
 /// Dispatches to inline parsing, tabular row parsing, or expanded list parsing
 /// based on the header type. For expanded lists, auto-detects the indent of the
 /// first "- " marker rather than assuming a fixed offset.
-fn parse_array_body(
+fn parse_array_elements(
     header: &ArrayHeader,
     lines: &[&str],
     line_idx: usize,
     base_indent: usize,
+    ctx: &mut DecodeCtx,
 ) -> Result<Value> {
     // Empty array
     if header.len == 0 {
+        ctx.count_form(|forms| forms.inline_arrays += 1);
         return Ok(Value::Array(vec![]));
     }
 
     // Inline values
     if let Some(ref inline) = header.inline_values {
-        let values = parse_inline_values(inline)?;
+        ctx.count_form(|forms| forms.inline_arrays += 1);
+        let values = parse_inline_values(inline, header.len, ctx).map_err(at_line(line_idx))?;
+        for i in 0..values.len() {
+            let saved = ctx.enter_index(i);
+            ctx.record(lines, line_idx, line_idx + 1);
+            ctx.leave(saved);
+        }
         return Ok(Value::Array(values));
     }
 
     // Tabular
     if let Some(ref fields) = header.fields {
+        ctx.count_form(|forms| forms.tabular_arrays += 1);
         let mut rows = Vec::new();
         for (i, line) in lines.iter().enumerate().skip(line_idx + 1) {
             let trimmed = line.trim();
@@ -193,13 +897,22 @@ fn parse_array_body(
             if indent <= base_indent && i > line_idx + 1 {
                 break;
             }
-            let obj = parse_tabular_row(trimmed, fields)?;
+            let obj = parse_tabular_row(trimmed, fields, &header.column_types, i, ctx)?;
+            let saved = ctx.enter_index(rows.len());
+            ctx.record(lines, i, i + 1);
+            for field in fields {
+                let saved_field = ctx.enter_key(field);
+                ctx.record(lines, i, i + 1);
+                ctx.leave(saved_field);
+            }
+            ctx.leave(saved);
             rows.push(obj);
         }
         return Ok(Value::Array(rows));
     }
 
     // Expanded list (- items)
+    ctx.count_form(|forms| forms.expanded_arrays += 1);
     // Auto-detect the indent of the first "- " line
     let mut detected_indent = base_indent + 2;
     for line in &lines[line_idx + 1..] {
@@ -213,64 +926,265 @@ fn parse_array_body(
         }
         break;
     }
-    parse_list_items(lines, line_idx + 1, detected_indent)
+    parse_list_items(lines, line_idx + 1, detected_indent, ctx)
 }
 
-/// Parse comma-separated inline values like `1,Alice,true`.
+/// Attach the 1-based line number to a parse error from a helper that only sees
+/// a fragment of the line (and so reports `line: 0`).
+pub(crate) fn at_line(line_idx: usize) -> impl FnOnce(ToonError) -> ToonError {
+    move |err| match err {
+        ToonError::ToonParse { line: 0, message } => ToonError::ToonParse {
+            line: line_idx + 1,
+            message,
+        },
+        other => other,
+    }
+}
+
+/// One delimiter-separated cell, before type inference.
+pub(crate) enum Cell<'a> {
+    /// A quoted value, already unescaped.
+    Quoted(String),
+    /// An unquoted token, untrimmed. Empty (or all-space) for a missing value.
+    Bare(&'a str),
+}
+
+impl Cell<'_> {
+    /// Infer the cell's JSON type the way untyped TOON does. An empty cell is
+    /// `null`, or `""` with `empty_as_string`.
+    fn into_value(self, empty_as_string: bool, keywords: KeywordCase) -> Value {
+        match self {
+            Cell::Quoted(s) => Value::String(s),
+            Cell::Bare(token) if token.trim().is_empty() => empty_cell(empty_as_string),
+            Cell::Bare(token) => parse_primitive_token(token, keywords),
+        }
+    }
+}
+
+/// The value of an empty unquoted cell (see [`DecodeOptions::empty_cells_as_strings`]).
+fn empty_cell(as_string: bool) -> Value {
+    if as_string {
+        Value::String(String::new())
+    } else {
+        Value::Null
+    }
+}
+
+/// Parse delimiter-separated inline values like `1,Alice,true`.
 /// Handles quoted values with escape sequences (e.g., `"hello, world",42,true`).
-fn parse_inline_values(s: &str) -> Result<Vec<Value>> {
-    let mut values = Vec::new();
+///
+/// An empty unquoted cell (`1,,3`) decodes to `null`; the encoder always quotes
+/// empty strings, so an empty cell can only mean a missing value. Keeping it as a
+/// cell preserves the column positions that follow it.
+/// [`DecodeOptions::empty_cells_as_strings`] reads it as `""` instead.
+///
+/// A trailing delimiter that would add one element beyond the declared `len`
+/// (`items[2]: 1,2,`) is ignored rather than read as a phantom empty element;
+/// see [`drop_trailing_empty_cell`].
+fn parse_inline_values(s: &str, len: usize, ctx: &mut DecodeCtx) -> Result<Vec<Value>> {
+    let empty_as_string = ctx.options.empty_cells_as_strings;
+    let mut cells = split_cells(s, ctx.options.delimiter.as_char())?;
+    drop_trailing_empty_cell(&mut cells, len);
+    Ok(cells
+        .into_iter()
+        .map(|cell| {
+            let quoted = matches!(cell, Cell::Quoted(_));
+            let value = cell.into_value(empty_as_string, ctx.options.keyword_case);
+            ctx.note_scalar(quoted, &value);
+            value
+        })
+        .collect())
+}
+
+/// Drop the last cell when it is empty and one past `expected` cells: a stray
+/// trailing delimiter in hand-written or LLM-emitted TOON (`1,2,`). The encoder
+/// never writes one, and when the count already matches, a trailing empty cell
+/// is a genuine missing value and is kept.
+fn drop_trailing_empty_cell(cells: &mut Vec<Cell<'_>>, expected: usize) {
+    if cells.len() == expected + 1
+        && matches!(cells.last(), Some(Cell::Bare(token)) if token.trim().is_empty())
+    {
+        cells.pop();
+    }
+}
+
+/// Split a delimiter-separated line into raw cells, honouring quotes.
+///
+/// A delimiter inside a value must be quoted (`"a,b"`), as the encoder always
+/// does; backslash escapes only work inside quotes. An unquoted cell ending in
+/// a backslash right before a delimiter (`a\,b`) is an error rather than two
+/// cells, since it almost certainly meant an escaped delimiter.
+pub(crate) fn split_cells(s: &str, delimiter: char) -> Result<Vec<Cell<'_>>> {
+    let mut cells = Vec::new();
     let mut i = 0;
     let bytes = s.as_bytes();
 
     while i < bytes.len() {
-        if bytes[i] == b'"' {
+        let mut consumed_delimiter = false;
+        // Padding before a quoted cell (e.g., aligned tabular columns) is not content.
+        let value_start = i + (s[i..].len() - s[i..].trim_start_matches(' ').len());
+        if value_start < bytes.len() && bytes[value_start] == b'"' {
             // Quoted value
-            let end = find_closing_quote(s, i + 1).ok_or_else(|| ToonError::ToonParse {
-                line: 0,
-                message: "Unterminated quoted string in inline array".to_string(),
-            })?;
-            let inner = &s[i + 1..end];
-            let unescaped = unescape_string(inner);
-            values.push(Value::String(unescaped));
+            let end =
+                find_closing_quote(s, value_start + 1).ok_or_else(|| ToonError::ToonParse {
+                    line: 0,
+                    message: "Unterminated quoted string in inline array".to_string(),
+                })?;
+            let inner = &s[value_start + 1..end];
+            cells.push(Cell::Quoted(unescape_string(inner)));
             i = end + 1;
-            // Skip comma
-            if i < bytes.len() && bytes[i] == b',' {
+            while i < bytes.len() && bytes[i] == b' ' {
                 i += 1;
             }
+            // Skip delimiter
+            if s[i..].starts_with(delimiter) {
+                i += delimiter.len_utf8();
+                consumed_delimiter = true;
+            }
         } else {
-            // Unquoted value — find next comma
-            let end = s[i..].find(',').map(|p| p + i).unwrap_or(s.len());
-            let token = &s[i..end];
-            values.push(parse_primitive_token(token));
+            // Unquoted value — find next delimiter
+            let end = s[i..].find(delimiter).map(|p| p + i).unwrap_or(s.len());
+            if end < s.len() && s[i..end].ends_with('\\') {
+                return Err(ToonError::ToonParse {
+                    line: 0,
+                    message: format!(
+                        "Escaped delimiter '\\{}' in unquoted value; quote the value instead",
+                        delimiter
+                    ),
+                });
+            }
+            cells.push(Cell::Bare(&s[i..end]));
             i = end;
-            if i < bytes.len() && bytes[i] == b',' {
-                i += 1;
+            if i < bytes.len() {
+                i += delimiter.len_utf8();
+                consumed_delimiter = true;
             }
         }
+        // A delimiter at the very end leaves one more (empty) cell.
+        if consumed_delimiter && i >= bytes.len() {
+            cells.push(Cell::Bare(""));
+        }
     }
 
-    Ok(values)
+    Ok(cells)
 }
 
-/// Parse a tabular row: comma-separated values mapped to field names
-fn parse_tabular_row(row: &str, fields: &[String]) -> Result<Value> {
-    let values = parse_inline_values(row)?;
+/// Parse a tabular row: delimiter-separated values mapped to field names.
+///
+/// Cells map to fields by position. An empty cell is a missing value and becomes
+/// `null`, the same as an explicit `null` token, unless
+/// [`DecodeOptions::empty_cells_as_strings`] is set.
+///
+/// A field with a type hint from a typed header is coerced to that type instead
+/// of inferred (see [`coerce_cell`]).
+///
+/// A row whose cell count differs from the header's field count is an error under
+/// [`DecodeOptions::strict_tabular`], including a stray trailing delimiter
+/// (`1,2,` for two fields). Otherwise the row is handled leniently: missing
+/// trailing cells become `null` and extra cells beyond the last field, such as
+/// the empty one after a stray trailing delimiter, are dropped.
+fn parse_tabular_row(
+    row: &str,
+    fields: &[String],
+    column_types: &[Option<ColumnType>],
+    line_idx: usize,
+    ctx: &mut DecodeCtx,
+) -> Result<Value> {
+    let cells = split_cells(row, ctx.options.delimiter.as_char()).map_err(at_line(line_idx))?;
+    if ctx.options.strict_tabular && cells.len() != fields.len() {
+        return Err(ToonError::ToonParse {
+            line: line_idx + 1,
+            message: format!(
+                "Tabular row has {} cells, expected {} (fields: {})",
+                cells.len(),
+                fields.len(),
+                fields.join(",")
+            ),
+        });
+    }
+    let empty_as_string = ctx.options.empty_cells_as_strings;
+    let mut cells = cells.into_iter();
     let mut map = Map::new();
     for (i, field) in fields.iter().enumerate() {
-        let val = values.get(i).cloned().unwrap_or(Value::Null);
+        let cell = cells.next();
+        let quoted = matches!(cell, Some(Cell::Quoted(_)));
+        let val = match (cell, column_types.get(i).copied().flatten()) {
+            (None, _) => Value::Null,
+            (Some(cell), None) => cell.into_value(empty_as_string, ctx.options.keyword_case),
+            (Some(cell), Some(ty)) => coerce_cell(
+                cell,
+                ty,
+                empty_as_string,
+                ctx.options.keyword_case,
+            )
+            .ok_or_else(|| ToonError::ToonParse {
+                line: line_idx + 1,
+                message: format!("Cell in column '{}' is not a valid {}", field, ty.as_str()),
+            })?,
+        };
+        ctx.note_scalar(quoted, &val);
         map.insert(field.clone(), val);
     }
     Ok(Value::Object(map))
 }
 
+/// Convert a cell to its column's declared type, quoted or not, so `"42"` in an
+/// `int` column is the number 42 and a bare `42` in a `str` column is the string
+/// "42". A bare `null` is `null` in any column, and so is an empty cell except
+/// in a `str` column with `empty_as_string`. Returns `None` if the text cannot be
+/// read as the declared type.
+fn coerce_cell(
+    cell: Cell<'_>,
+    ty: ColumnType,
+    empty_as_string: bool,
+    keywords: KeywordCase,
+) -> Option<Value> {
+    let text = match &cell {
+        Cell::Quoted(s) => s.as_str(),
+        Cell::Bare(token) => {
+            let token = token.trim();
+            if token.is_empty() {
+                return Some(empty_cell(empty_as_string && ty == ColumnType::Str));
+            }
+            if keywords.keyword(token) == Some(Value::Null) {
+                return Some(Value::Null);
+            }
+            token
+        }
+    };
+    match ty {
+        ColumnType::Str => Some(Value::String(text.to_string())),
+        ColumnType::Bool => match keywords.keyword(text) {
+            Some(Value::Bool(b)) => Some(Value::Bool(b)),
+            _ => None,
+        },
+        ColumnType::Int => text
+            .parse::<i64>()
+            .map(Value::from)
+            .or_else(|_| text.parse::<u64>().map(Value::from))
+            .ok(),
+        // Whole numbers stay integers, as the encoder writes 2.0 as `2`.
+        ColumnType::Float => text.parse::<i64>().map(Value::from).ok().or_else(|| {
+            text.parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+                .map(Value::Number)
+        }),
+    }
+}
+
 /// Parse expanded list items starting from a given line index.
 ///
 /// `item_indent` is the character offset where "- " markers appear. Items at this
 /// indent are collected; lines deeper than `item_indent` belong to the current item;
 /// lines shallower terminate the list. Lines at `item_indent` without "- " also
 /// terminate (they're sibling fields, not list items).
-fn parse_list_items(lines: &[&str], start_line: usize, item_indent: usize) -> Result<Value> {
+fn parse_list_items(
+    lines: &[&str],
+    start_line: usize,
+    item_indent: usize,
+    ctx: &mut DecodeCtx,
+) -> Result<Value> {
     let mut items = Vec::new();
     let mut i = start_line;
 
@@ -289,8 +1203,10 @@ fn parse_list_items(lines: &[&str], start_line: usize, item_indent: usize) -> Re
             break;
         }
 
-        // Skip lines that are deeper (continuation of previous item)
+        // Skip lines that are deeper (continuation of previous item) that the
+        // item itself did not consume
         if indent > item_indent {
+            reject_unconsumed(i, ctx)?;
             i += 1;
             continue;
         }
@@ -301,27 +1217,39 @@ fn parse_list_items(lines: &[&str], start_line: usize, item_indent: usize) -> Re
         }
 
         let content = &trimmed[2..]; // After "- "
+        let saved = ctx.enter_index(items.len());
 
         // Check if the list item is an array
         if content.starts_with('[') {
             if let Some(header) = parse_array_header(content) {
-                let arr = parse_array_body(&header, lines, i, indent + 2)?;
+                let arr = ctx.nested(i, |ctx| {
+                    parse_array_body(&header, lines, i, indent + 2, ctx)
+                })?;
                 items.push(arr);
-                i = skip_nested_lines(lines, i + 1, indent + 2);
+                let next_i = skip_nested_lines(lines, i + 1, indent + 2);
+                ctx.record(lines, i, next_i);
+                ctx.leave(saved);
+                i = next_i;
                 continue;
             }
         }
 
         // Check if the list item is an object (has key: pattern)
         if item_content_is_object(content) {
-            let (obj, next_i) = parse_list_item_object(lines, i, indent + 2, content)?;
+            let (obj, next_i) = parse_list_item_object(lines, i, indent + 2, content, ctx)?;
             items.push(obj);
+            ctx.record(lines, i, next_i);
+            ctx.leave(saved);
             i = next_i;
             continue;
         }
 
         // Primitive value
-        items.push(parse_primitive_value(content)?);
+        let value = parse_primitive_value(content, ctx)?;
+        ctx.note_scalar(is_quoted_token(content), &value);
+        items.push(value);
+        ctx.record(lines, i, i + 1);
+        ctx.leave(saved);
         i += 1;
     }
 
@@ -334,7 +1262,7 @@ fn item_content_is_object(content: &str) -> bool {
     // Check if content starts with a key: pattern
     if content.starts_with('"') {
         if let Some(end) = find_closing_quote(content, 1) {
-            return end + 1 < content.len() && content.as_bytes()[end + 1] == b':';
+            return content[end + 1..].starts_with([':', '[']);
         }
         return false;
     }
@@ -360,6 +1288,7 @@ fn parse_list_item_object(
     start_line: usize,
     hyphen_content_indent: usize,
     first_field_content: &str,
+    ctx: &mut DecodeCtx,
 ) -> Result<(Value, usize)> {
     let mut map = Map::new();
 
@@ -370,6 +1299,7 @@ fn parse_list_item_object(
         lines,
         start_line,
         hyphen_content_indent,
+        ctx,
     )?;
 
     let sibling_indent = hyphen_content_indent;
@@ -394,7 +1324,7 @@ fn parse_list_item_object(
             break;
         }
 
-        i = parse_key_value_into_map(trimmed, &mut map, lines, i, indent)?;
+        i = parse_key_value_into_map(trimmed, &mut map, lines, i, indent, ctx)?;
     }
 
     Ok((Value::Object(map), i))
@@ -490,9 +1420,28 @@ fn parse_key_value_into_map(
     lines: &[&str],
     line_idx: usize,
     base_indent: usize,
+    ctx: &mut DecodeCtx,
 ) -> Result<usize> {
-    let (key, rest) = parse_key_from_content(content)?;
+    let (key, rest) = parse_key_from_content(content).map_err(at_line(line_idx))?;
+    let saved = ctx.enter_key(&key);
+    let next = parse_field_into_map(key, &rest, map, lines, line_idx, base_indent, ctx)?;
+    ctx.record(lines, line_idx, next);
+    ctx.leave(saved);
+    Ok(next)
+}
 
+/// Parse the value part of a field (everything after the key) and insert it
+/// into `map` under `key`. Returns the next line index, as for
+/// [`parse_key_value_into_map`].
+fn parse_field_into_map(
+    key: String,
+    rest: &str,
+    map: &mut Map<String, Value>,
+    lines: &[&str],
+    line_idx: usize,
+    base_indent: usize,
+    ctx: &mut DecodeCtx,
+) -> Result<usize> {
     // Check for array field: key[N]...
     if rest.starts_with('[') {
         // Build a synthetic line "x[N]..." so parse_array_header can parse it
@@ -500,7 +1449,9 @@ fn parse_key_value_into_map(
         if let Some(header) = parse_array_header(&arr_line) {
             let is_empty = header.len == 0;
             let is_inline = header.inline_values.is_some();
-            let arr = parse_array_body(&header, lines, line_idx, base_indent)?;
+            let arr = ctx.nested(line_idx, |ctx| {
+                parse_array_body(&header, lines, line_idx, base_indent, ctx)
+            })?;
             map.insert(key, arr);
             // For empty or inline arrays, no body lines to skip
             if is_empty || is_inline {
@@ -521,18 +1472,46 @@ fn parse_key_value_into_map(
             if next_indent >= child_indent && !lines[line_idx + 1].trim().is_empty() {
                 // Nested object
                 let end = find_block_end(lines, line_idx + 1, child_indent);
-                let obj = parse_object_from_lines(lines, child_indent, line_idx + 1, end)?;
+                let obj = ctx.nested(line_idx, |ctx| {
+                    parse_object_from_lines(lines, child_indent, line_idx + 1, end, ctx)
+                })?;
                 map.insert(key, obj);
                 return Ok(end);
             }
         }
-        // Empty object
+        // Empty object. A following line indented past the key but short of its
+        // children's indent was meant as a child; the lenient path skips it.
+        if ctx.options.strict_nesting {
+            let next = lines[line_idx + 1..]
+                .iter()
+                .enumerate()
+                .find(|(_, l)| !l.trim().is_empty());
+            if let Some((offset, next)) = next {
+                let indent = count_indent(next);
+                if indent > base_indent && indent < child_indent {
+                    return Err(ToonError::ToonParse {
+                        line: line_idx + 2 + offset,
+                        message: format!(
+                            "Field '{}' has no value and the next line is under-indented: {} spaces, its children need {}",
+                            key, indent, child_indent
+                        ),
+                    });
+                }
+            }
+        }
         map.insert(key, Value::Object(Map::new()));
     } else if let Some(value_str) = rest.strip_prefix(": ") {
-        let value = parse_primitive_value(value_str)?;
+        let value = parse_primitive_value(value_str, ctx)?;
+        ctx.note_scalar(is_quoted_token(value_str), &value);
         map.insert(key, value);
     } else {
         // Shouldn't happen with well-formed TOON
+        if ctx.options.require_full_consume {
+            return Err(ToonError::ToonParse {
+                line: line_idx + 1,
+                message: format!("Line '{}' is not a key-value field", key),
+            });
+        }
         map.insert(key, Value::Null);
     }
 
@@ -576,6 +1555,7 @@ fn parse_object_from_lines(
     expected_indent: usize,
     start: usize,
     end: usize,
+    ctx: &mut DecodeCtx,
 ) -> Result<Value> {
     let mut map = Map::new();
     let mut i = start;
@@ -595,12 +1575,13 @@ fn parse_object_from_lines(
         }
         if indent > expected_indent {
             // This is a child line of a previous key — skip
+            reject_unconsumed(i, ctx)?;
             i += 1;
             continue;
         }
 
         // At our indent level — parse as key-value
-        i = parse_key_value_into_map(trimmed, &mut map, lines, i, indent)?;
+        i = parse_key_value_into_map(trimmed, &mut map, lines, i, indent, ctx)?;
         // Skip any nested content that parse_key_value_into_map didn't consume
         while i < end {
             let next_line = lines[i];
@@ -613,6 +1594,7 @@ fn parse_object_from_lines(
             if next_indent <= expected_indent {
                 break;
             }
+            reject_unconsumed(i, ctx)?;
             i += 1;
         }
     }
@@ -620,6 +1602,18 @@ fn parse_object_from_lines(
     Ok(Value::Object(map))
 }
 
+/// Fail on a line the parser is about to skip, under
+/// [`DecodeOptions::require_full_consume`].
+fn reject_unconsumed(line_idx: usize, ctx: &DecodeCtx) -> Result<()> {
+    if ctx.options.require_full_consume {
+        return Err(ToonError::ToonParse {
+            line: line_idx + 1,
+            message: "Line is not part of any value".to_string(),
+        });
+    }
+    Ok(())
+}
+
 /// Find the end of a block at the given indent level
 fn find_block_end(lines: &[&str], start: usize, min_indent: usize) -> usize {
     let mut i = start;
@@ -640,8 +1634,8 @@ fn find_block_end(lines: &[&str], start: usize, min_indent: usize) -> usize {
 }
 
 /// Parse a primitive value from a string token
-fn parse_primitive_value(s: &str) -> Result<Value> {
-    Ok(parse_primitive_token(s))
+fn parse_primitive_value(s: &str, ctx: &DecodeCtx) -> Result<Value> {
+    Ok(parse_primitive_token(s, ctx.options.keyword_case))
 }
 
 /// Parse an unquoted or quoted token into a JSON Value.
@@ -649,26 +1643,19 @@ fn parse_primitive_value(s: &str) -> Result<Value> {
 /// Type inference order: quoted string → null → bool → integer → float → unquoted string.
 /// This mirrors the encoder's quoting rules: strings that look like numbers/bools are
 /// quoted by the encoder, so unquoted tokens can be safely interpreted as their types.
-fn parse_primitive_token(s: &str) -> Value {
+/// `null` and the booleans are recognized in lowercase and in `keywords`' case.
+pub(crate) fn parse_primitive_token(s: &str, keywords: KeywordCase) -> Value {
     let s = s.trim();
 
     // Quoted string
-    if s.starts_with('"') && s.ends_with('"') && s.len() >= 2 {
+    if is_quoted_token(s) {
         let inner = &s[1..s.len() - 1];
         return Value::String(unescape_string(inner));
     }
 
-    // null
-    if s == "null" {
-        return Value::Null;
-    }
-
-    // bool
-    if s == "true" {
-        return Value::Bool(true);
-    }
-    if s == "false" {
-        return Value::Bool(false);
+    // null and bool
+    if let Some(value) = keywords.keyword(s) {
+        return value;
     }
 
     // Try integer
@@ -687,28 +1674,80 @@ fn parse_primitive_token(s: &str) -> Value {
     Value::String(s.to_string())
 }
 
+/// Whether `s` is a quoted token, as `parse_primitive_token` decides.
+fn is_quoted_token(s: &str) -> bool {
+    let s = s.trim();
+    s.starts_with('"') && s.ends_with('"') && s.len() >= 2
+}
+
 /// Count leading spaces in a line (each 2 spaces = 1 indent level)
-fn count_indent(line: &str) -> usize {
-    line.len() - line.trim_start().len()
+///
+/// Only ASCII spaces and tabs indent. Other Unicode whitespace (a no-break or
+/// ideographic space) is content: counting its UTF-8 bytes as indentation put
+/// such lines at an odd depth, where they were silently dropped.
+pub(crate) fn count_indent(line: &str) -> usize {
+    line.len() - line.trim_start_matches([' ', '\t']).len()
 }
 
-/// Find the position of the closing quote, handling escape sequences
-fn find_closing_quote(s: &str, start: usize) -> Option<usize> {
-    let bytes = s.as_bytes();
-    let mut i = start;
-    while i < bytes.len() {
-        if bytes[i] == b'\\' {
-            i += 2; // Skip escaped character
-        } else if bytes[i] == b'"' {
-            return Some(i);
-        } else {
-            i += 1;
+/// Find the byte position of the closing quote, handling escape sequences.
+///
+/// Returns `None` if there is no closing quote or `start` is not a char boundary
+/// inside `s`, so the result is always safe to slice at.
+pub(crate) fn find_closing_quote(s: &str, start: usize) -> Option<usize> {
+    let mut escaped = false;
+    for (i, c) in s.get(start..)?.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == '"' {
+            return Some(start + i);
         }
     }
     None
 }
 
-/// Unescape a TOON string (handle \\, \", \n, \r, \t)
+/// Characters that may follow a backslash in a quoted string.
+const KNOWN_ESCAPES: [char; 6] = ['n', 'r', 't', '\\', '"', '/'];
+
+/// Fail on the first unknown escape sequence inside a quoted string, for
+/// [`DecodeOptions::strict_escapes`].
+///
+/// A `"` opens a quoted string only where a quoted token can begin — at the
+/// start of a line or after `:`, `-`, `{`, `,` or the delimiter — so quotes
+/// inside unquoted text are left alone, as the parser leaves them.
+fn check_escapes(toon: &str, delimiter: char) -> Result<()> {
+    for (line_idx, line) in toon.lines().enumerate() {
+        let mut in_quotes = false;
+        let mut token_start = true;
+        let mut chars = line.chars();
+        while let Some(c) = chars.next() {
+            if in_quotes {
+                match c {
+                    '"' => in_quotes = false,
+                    '\\' => match chars.next() {
+                        Some(next) if KNOWN_ESCAPES.contains(&next) => {}
+                        next => {
+                            let escape: String = next.into_iter().collect();
+                            return Err(ToonError::ToonParse {
+                                line: line_idx + 1,
+                                message: format!("Unknown escape sequence '\\{}'", escape),
+                            });
+                        }
+                    },
+                    _ => {}
+                }
+            } else if c == '"' && token_start {
+                in_quotes = true;
+            } else if c != ' ' {
+                token_start = matches!(c, ':' | '-' | '{' | ',') || c == delimiter;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Unescape a TOON string (handle \\, \", \n, \r, \t, and JSON's \/)
 fn unescape_string(s: &str) -> String {
     let mut out = String::with_capacity(s.len());
     let mut chars = s.chars();
@@ -720,6 +1759,8 @@ fn unescape_string(s: &str) -> String {
                 Some('t') => out.push('\t'),
                 Some('\\') => out.push('\\'),
                 Some('"') => out.push('"'),
+                // JSON's optional `\/`, kept by some producers.
+                Some('/') => out.push('/'),
                 Some(other) => {
                     out.push('\\');
                     out.push(other);
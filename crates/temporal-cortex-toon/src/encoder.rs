@@ -22,6 +22,7 @@
 //! ```
 
 use crate::error::Result;
+use crate::types::{ArrayStyle, ColumnType, Delimiter, KeywordCase, ToonValue};
 use serde_json::Value;
 
 /// Encode a JSON string into TOON v3.0 format.
@@ -29,38 +30,247 @@
 /// Parses the input as JSON, then walks the value tree to produce a compact TOON
 /// representation. Returns an error if the input is not valid JSON.
 pub fn encode(json: &str) -> Result<String> {
+    encode_with_options(json, &EncodeOptions::default())
+}
+
+/// Encode a JSON string into TOON with the given rendering options.
+///
+/// # Example
+/// ```
+/// use toon_core::encoder::{encode_with_options, EncodeOptions};
+/// use toon_core::types::Delimiter;
+///
+/// let opts = EncodeOptions {
+///     delimiter: Delimiter::Pipe,
+///     ..EncodeOptions::default()
+/// };
+/// let toon = encode_with_options(r#"{"tags":["a,b","c"]}"#, &opts).unwrap();
+/// assert_eq!(toon, "tags[2]: a,b|c");
+/// ```
+pub fn encode_with_options(json: &str, opts: &EncodeOptions) -> Result<String> {
+    let value: Value = serde_json::from_str(json)?;
+    Ok(encode_value(&value, opts))
+}
+
+/// Report whether a JSON string survives an encode → decode round trip.
+///
+/// The decoded value is compared with the original structurally, with numbers
+/// compared by value: TOON normalizes `1.0` to `1` and `-0` to `0`, so those
+/// count as surviving. Returns an error if the input is not valid JSON.
+///
+/// # Example
+/// ```
+/// use toon_core::roundtrips;
+///
+/// assert!(roundtrips(r#"{"ids":[1,2.0,3]}"#).unwrap());
+/// ```
+pub fn roundtrips(json: &str) -> Result<bool> {
     let value: Value = serde_json::from_str(json)?;
+    let toon = encode_value(&value, &EncodeOptions::default());
+    let decoded = crate::decoder::decode_value(&toon, &crate::decoder::DecodeOptions::default())?;
+    Ok(same_value(&value, &decoded))
+}
+
+/// Encode a JSON string into a canonical TOON form, for use as a cache or
+/// content-hash key.
+///
+/// Inputs that are semantically identical produce byte-identical output: object
+/// keys are sorted at every level (array order is preserved), numbers are
+/// written normalized (`1.0` → `1`, `-0` → `0`), and the layout is fixed
+/// regardless of defaults callers may tune elsewhere: comma delimiter, tabular
+/// arrays with sorted columns, and the same `key[0]:` form for every empty
+/// array. Returns an error if the input is not valid JSON.
+///
+/// # Example
+/// ```
+/// use toon_core::canonicalize;
+///
+/// let a = canonicalize(r#"{"name":"Ada","score":1.0}"#).unwrap();
+/// let b = canonicalize(r#"{"score":1,"name":"Ada"}"#).unwrap();
+/// assert_eq!(a, b);
+/// assert_eq!(a, "name: Ada\nscore: 1");
+/// ```
+pub fn canonicalize(json: &str) -> Result<String> {
+    let value: Value = serde_json::from_str(json)?;
+    let opts = EncodeOptions {
+        sort_object_keys: true,
+        sort_tabular_columns: true,
+        ..EncodeOptions::default()
+    };
+    Ok(encode_value(&value, &opts))
+}
+
+/// Structural equality with numbers compared as `f64`.
+fn same_value(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) => x.as_f64() == y.as_f64(),
+        (Value::Array(xs), Value::Array(ys)) => {
+            xs.len() == ys.len() && xs.iter().zip(ys).all(|(x, y)| same_value(x, y))
+        }
+        (Value::Object(xs), Value::Object(ys)) => {
+            xs.len() == ys.len()
+                && xs
+                    .iter()
+                    .all(|(k, x)| ys.get(k).is_some_and(|y| same_value(x, y)))
+        }
+        _ => a == b,
+    }
+}
+
+/// Render a [`ToonValue`] as human-readable TOON with aligned tabular columns.
+///
+/// Tabular row cells are padded so each column starts at the same offset, which
+/// makes wide tables easier to review by eye. The output spends extra tokens on
+/// padding and is not byte-identical to [`encode`], but it decodes to the same
+/// value: the decoder trims the whitespace around each cell.
+///
+/// # Example
+/// ```
+/// use toon_core::encode_toon_value_pretty;
+/// use toon_core::types::ToonValue;
+///
+/// let row = |id: i64, name: &str| {
+///     ToonValue::Object(vec![
+///         ("id".to_string(), ToonValue::Integer(id)),
+///         ("name".to_string(), ToonValue::String(name.to_string())),
+///     ])
+/// };
+/// let value = ToonValue::Object(vec![(
+///     "users".to_string(),
+///     ToonValue::Array(vec![row(1, "Alice"), row(100, "Bob")]),
+/// )]);
+/// assert_eq!(
+///     encode_toon_value_pretty(&value),
+///     "users[2]{id,name}:\n  1,  Alice\n  100,Bob"
+/// );
+/// ```
+pub fn encode_toon_value_pretty(value: &ToonValue) -> String {
+    let opts = EncodeOptions {
+        align_tabular_columns: true,
+        ..EncodeOptions::default()
+    };
+    encode_value(&Value::from(value), &opts)
+}
+
+/// Rendering options for [`encode_with_options`], threaded through every encoder
+/// function. The default matches [`encode`].
+#[derive(Debug, Clone, Default)]
+pub struct EncodeOptions {
+    /// Pad tabular cells so columns line up visually (pretty mode).
+    pub align_tabular_columns: bool,
+    /// Separator between inline array elements and tabular cells. Decoding must
+    /// use the same delimiter (see [`DecodeOptions`](crate::DecodeOptions)).
+    pub delimiter: Delimiter,
+    /// Annotate tabular headers with each column's type, e.g.
+    /// `items[2]{id:int,name:str,active:bool}:`, so strings in a `str` column
+    /// need no quotes just for looking like numbers or booleans. This is a
+    /// superset of TOON v3.0 that only this decoder understands; off by default.
+    pub typed_columns: bool,
+    /// Render uniform object arrays as tabular blocks (default) or as labeled
+    /// `- key: value` records. Both decode to the same JSON.
+    pub array_style: ArrayStyle,
+    /// End the output with a single `\n`, as POSIX text files do. The TOON spec
+    /// omits it, so this is off by default; the decoder accepts either form.
+    pub trailing_newline: bool,
+    /// Order tabular columns by field name instead of the first row's key order,
+    /// so equal data encodes byte-identically whatever its key order (e.g. for
+    /// content-addressed caching). Decoded rows then have their keys sorted too.
+    /// Object fields outside tables keep their order.
+    pub sort_tabular_columns: bool,
+    /// Sort the keys of every object lexicographically before emitting, so the
+    /// output no longer depends on the source's key order (e.g. JSON serialized
+    /// from a `HashMap`). Field order then differs from the JSON source, and
+    /// tabular columns come out sorted as well.
+    pub sort_object_keys: bool,
+    /// Widest tabular header to emit. A uniform object array with more columns
+    /// than this is written as labeled `- key: value` records instead, as under
+    /// [`ArrayStyle::Records`], trading compactness for lines that stay readable.
+    /// Both forms decode to the same JSON. `None` (the default) means no limit.
+    pub max_header_columns: Option<usize>,
+    /// Spelling of boolean and null values, e.g. `TRUE` under
+    /// [`KeywordCase::Upper`]. Anything other than the default `Lower` must be
+    /// decoded with the same [`DecodeOptions::keyword_case`](crate::DecodeOptions::keyword_case),
+    /// or the keywords come back as strings.
+    pub keyword_case: KeywordCase,
+    /// Leave out object fields whose value is `null`, so `{"a":1,"b":null}`
+    /// encodes as `a: 1`. This is lossy — decoding does not bring the field
+    /// back — so it is off by default. Rows of a tabular array keep their null
+    /// cells, since every row must fill every column.
+    pub omit_nulls: bool,
+}
+
+/// Encode an already-parsed JSON value with the given options.
+pub(crate) fn encode_value(value: &Value, opts: &EncodeOptions) -> String {
+    if opts.sort_object_keys || opts.omit_nulls {
+        let mut prepared = value.clone();
+        if opts.sort_object_keys {
+            crate::decoder::sort_keys(&mut prepared);
+        }
+        if opts.omit_nulls {
+            drop_null_fields(&mut prepared, opts);
+        }
+        let opts = EncodeOptions {
+            sort_object_keys: false,
+            omit_nulls: false,
+            ..opts.clone()
+        };
+        return encode_value(&prepared, &opts);
+    }
     let mut out = String::new();
-    encode_root(&value, &mut out);
-    Ok(out)
+    encode_root(value, opts, &mut out);
+    if opts.trailing_newline {
+        out.push('\n');
+    }
+    out
+}
+
+/// Remove `null`-valued fields from every object in `value`, except in the rows
+/// of arrays that encode as tables (see [`EncodeOptions::omit_nulls`]).
+fn drop_null_fields(value: &mut Value, opts: &EncodeOptions) {
+    match value {
+        Value::Object(map) => {
+            map.retain(|_, v| !v.is_null());
+            map.values_mut().for_each(|v| drop_null_fields(v, opts));
+        }
+        Value::Array(items) if tabular_fields(items, opts).is_none() => {
+            items.iter_mut().for_each(|v| drop_null_fields(v, opts));
+        }
+        _ => {}
+    }
 }
 
 /// Top-level dispatch: objects emit fields, arrays emit root array syntax,
 /// primitives emit a bare value.
-fn encode_root(value: &Value, out: &mut String) {
+fn encode_root(value: &Value, opts: &EncodeOptions, out: &mut String) {
     match value {
         Value::Object(map) => {
-            encode_object_fields(map, 0, out);
+            encode_object_fields(map, 0, opts, out);
         }
         Value::Array(arr) => {
-            encode_root_array(arr, out);
+            encode_root_array(arr, opts, out);
         }
         _ => {
-            encode_primitive_value(value, QuoteContext::Document, out);
+            encode_primitive_value(value, QuoteContext::Document, opts.keyword_case, out);
         }
     }
 }
 
-/// Encode a root-level array. Primitive arrays use inline syntax `[N]: v1,v2`;
-/// mixed/complex arrays use expanded list syntax `[N]:\n  - item`.
-fn encode_root_array(arr: &[Value], out: &mut String) {
+/// Encode a root-level array. An empty array is `[0]:`, as for a field; uniform
+/// object arrays use tabular syntax `[N]{f1,f2}:\n  v1,v2`; primitive arrays use
+/// inline syntax `[N]: v1,v2`; mixed/complex arrays use expanded list syntax
+/// `[N]:\n  - item`.
+fn encode_root_array(arr: &[Value], opts: &EncodeOptions, out: &mut String) {
     let len = arr.len();
-    if all_primitives(arr) {
+    if arr.is_empty() {
+        out.push_str("[0]:");
+    } else if let Some(fields) = tabular_fields(arr, opts) {
+        encode_tabular_array(arr, &fields, 0, opts, out);
+    } else if all_primitives(arr) {
         out.push_str(&format!("[{}]: ", len));
-        encode_inline_values(arr, out);
+        encode_inline_values(arr, opts, out);
     } else {
         out.push_str(&format!("[{}]:", len));
-        encode_list_items(arr, 0, out);
+        encode_list_items(arr, 0, opts, out);
     }
 }
 
@@ -69,7 +279,12 @@ fn encode_root_array(arr: &[Value], out: &mut String) {
 ///
 /// Relies on `serde_json::Map` with `preserve_order` feature to maintain
 /// the original JSON insertion order (IndexMap, not BTreeMap).
-fn encode_object_fields(map: &serde_json::Map<String, Value>, depth: usize, out: &mut String) {
+fn encode_object_fields(
+    map: &serde_json::Map<String, Value>,
+    depth: usize,
+    opts: &EncodeOptions,
+    out: &mut String,
+) {
     let indent = make_indent(depth);
     let mut first = true;
     for (key, value) in map {
@@ -79,7 +294,7 @@ fn encode_object_fields(map: &serde_json::Map<String, Value>, depth: usize, out:
         first = false;
         out.push_str(&indent);
         out.push_str(&encode_key(key));
-        encode_field_value(key, value, depth, out);
+        encode_field_value(key, value, depth, opts, out);
     }
 }
 
@@ -88,7 +303,13 @@ fn encode_object_fields(map: &serde_json::Map<String, Value>, depth: usize, out:
 /// - Non-empty objects → `key:\n  child_key: child_val`
 /// - Arrays → delegated to `encode_array_field` (inline/tabular/expanded)
 /// - Primitives → `key: value`
-fn encode_field_value(_key: &str, value: &Value, depth: usize, out: &mut String) {
+fn encode_field_value(
+    _key: &str,
+    value: &Value,
+    depth: usize,
+    opts: &EncodeOptions,
+    out: &mut String,
+) {
     match value {
         Value::Object(map) if map.is_empty() => {
             out.push(':');
@@ -96,14 +317,14 @@ fn encode_field_value(_key: &str, value: &Value, depth: usize, out: &mut String)
         Value::Object(map) => {
             out.push(':');
             out.push('\n');
-            encode_object_fields(map, depth + 1, out);
+            encode_object_fields(map, depth + 1, opts, out);
         }
         Value::Array(arr) => {
-            encode_array_field(arr, depth, out);
+            encode_array_field(arr, depth, opts, out);
         }
         _ => {
             out.push_str(": ");
-            encode_primitive_value(value, QuoteContext::Document, out);
+            encode_primitive_value(value, QuoteContext::Document, opts.keyword_case, out);
         }
     }
 }
@@ -112,10 +333,12 @@ fn encode_field_value(_key: &str, value: &Value, depth: usize, out: &mut String)
 ///
 /// 1. **Empty**: `key[0]:`
 /// 2. **Tabular**: all elements are objects with identical primitive-only keys →
-///    `key[N]{f1,f2}:\n  v1,v2\n  v3,v4`
+///    `key[N]{f1,f2}:\n  v1,v2\n  v3,v4` (unless `opts.array_style` is
+///    [`ArrayStyle::Records`] or the header would exceed
+///    `opts.max_header_columns`, which fall through to the expanded list)
 /// 3. **Inline**: all elements are primitives → `key[N]: v1,v2,v3`
 /// 4. **Expanded list**: mixed content → `key[N]:\n  - item1\n  - item2`
-fn encode_array_field(arr: &[Value], depth: usize, out: &mut String) {
+fn encode_array_field(arr: &[Value], depth: usize, opts: &EncodeOptions, out: &mut String) {
     let len = arr.len();
 
     if arr.is_empty() {
@@ -124,51 +347,133 @@ fn encode_array_field(arr: &[Value], depth: usize, out: &mut String) {
     }
 
     // Tabular: uniform object arrays (greatest compression for repetitive data)
-    if let Some(fields) = detect_tabular(arr) {
-        out.push_str(&format!("[{}]{{{}}}:", len, fields.join(",")));
-        encode_tabular_rows(arr, &fields, depth, out);
+    if let Some(fields) = tabular_fields(arr, opts) {
+        encode_tabular_array(arr, &fields, depth, opts, out);
         return;
     }
 
     // Inline: all-primitive arrays on a single line
     if all_primitives(arr) {
         out.push_str(&format!("[{}]: ", len));
-        encode_inline_values(arr, out);
+        encode_inline_values(arr, opts, out);
         return;
     }
 
     // Expanded: complex/mixed arrays with "- " list markers
     out.push_str(&format!("[{}]:", len));
-    encode_list_items(arr, depth, out);
+    encode_list_items(arr, depth, opts, out);
+}
+
+/// Emit a tabular header `[N]{f1,f2}:` (with type annotations under
+/// `typed_columns`) followed by one row per element.
+fn encode_tabular_array(
+    arr: &[Value],
+    fields: &[String],
+    depth: usize,
+    opts: &EncodeOptions,
+    out: &mut String,
+) {
+    let column_types: Vec<Option<ColumnType>> = if opts.typed_columns {
+        fields.iter().map(|f| infer_column_type(arr, f)).collect()
+    } else {
+        vec![None; fields.len()]
+    };
+    let header: Vec<String> = fields
+        .iter()
+        .zip(&column_types)
+        .map(|(field, ty)| match ty {
+            Some(ty) => format!("{}:{}", encode_key(field), ty.as_str()),
+            None => encode_key(field),
+        })
+        .collect();
+    out.push_str(&format!("[{}]{{{}}}:", arr.len(), header.join(",")));
+    encode_tabular_rows(arr, fields, &column_types, depth, opts, out);
 }
 
-/// Emit comma-separated primitive values on a single line: `v1,v2,v3`
-/// Quoting uses `InlineArray` context (comma is the active delimiter, not colon).
-fn encode_inline_values(arr: &[Value], out: &mut String) {
+/// Emit delimiter-separated primitive values on a single line: `v1,v2,v3`
+/// Quoting uses `InlineArray` context (the delimiter is active, not colon).
+fn encode_inline_values(arr: &[Value], opts: &EncodeOptions, out: &mut String) {
     for (i, val) in arr.iter().enumerate() {
         if i > 0 {
-            out.push(',');
+            out.push(opts.delimiter.as_char());
         }
-        encode_primitive_value(val, QuoteContext::InlineArray, out);
+        encode_primitive_value(
+            val,
+            QuoteContext::InlineArray(opts.delimiter),
+            opts.keyword_case,
+            out,
+        );
     }
 }
 
-/// Emit tabular rows: each object's values as a comma-separated line, no keys repeated.
-/// Quoting uses `TabularCell` context (comma triggers quoting, not colon).
-fn encode_tabular_rows(arr: &[Value], fields: &[String], depth: usize, out: &mut String) {
+/// Emit tabular rows: each object's values as a delimiter-separated line, no keys
+/// repeated. Quoting uses `TabularCell` context (the delimiter triggers quoting, not colon).
+///
+/// Cells are emitted in header order. An explicit `null` renders as the `null`
+/// token; a field missing from an object renders as an empty cell so later cells
+/// keep their column, and decodes back to `null` ("missing → null"). Today
+/// `detect_tabular` only accepts objects with identical keys, so the empty-cell
+/// case is a guard rather than a reachable path.
+///
+/// Cells in a `str`-typed column use `TypedStrCell` quoting.
+///
+/// With `align_tabular_columns`, every cell except the last in a row is followed by
+/// enough spaces that the next column starts at the same offset on every row.
+fn encode_tabular_rows(
+    arr: &[Value],
+    fields: &[String],
+    column_types: &[Option<ColumnType>],
+    depth: usize,
+    opts: &EncodeOptions,
+    out: &mut String,
+) {
     let row_indent = make_indent(depth + 1);
-    for obj_val in arr {
+    let rows: Vec<Vec<String>> = arr
+        .iter()
+        .map(|obj_val| {
+            fields
+                .iter()
+                .zip(column_types)
+                .map(|(field, ty)| {
+                    let ctx = match ty {
+                        Some(ColumnType::Str) => QuoteContext::TypedStrCell(opts.delimiter),
+                        _ => QuoteContext::TabularCell(opts.delimiter),
+                    };
+                    let mut cell = String::new();
+                    if let Some(val) = obj_val.as_object().and_then(|map| map.get(field)) {
+                        encode_primitive_value(val, ctx, opts.keyword_case, &mut cell);
+                    }
+                    cell
+                })
+                .collect()
+        })
+        .collect();
+
+    let widths: Vec<usize> = if opts.align_tabular_columns {
+        (0..fields.len())
+            .map(|i| {
+                rows.iter()
+                    .map(|row| row[i].chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    for row in &rows {
         out.push('\n');
         out.push_str(&row_indent);
-        if let Value::Object(map) = obj_val {
-            for (i, field) in fields.iter().enumerate() {
-                if i > 0 {
-                    out.push(',');
-                }
-                if let Some(val) = map.get(field) {
-                    encode_primitive_value(val, QuoteContext::TabularCell, out);
+        for (i, cell) in row.iter().enumerate() {
+            if i > 0 {
+                out.push(opts.delimiter.as_char());
+                if let Some(width) = widths.get(i - 1) {
+                    let pad = width - row[i - 1].chars().count();
+                    out.extend(std::iter::repeat_n(' ', pad));
                 }
             }
+            out.push_str(cell);
         }
     }
 }
@@ -176,8 +481,8 @@ fn encode_tabular_rows(arr: &[Value], fields: &[String], depth: usize, out: &mut
 /// Emit expanded list items with "- " markers. Each item can be:
 /// - A primitive value: `- hello`
 /// - An object: `- key1: val1\n    key2: val2` (first field on hyphen line)
-/// - A nested array: `- [N]: v1,v2`
-fn encode_list_items(arr: &[Value], depth: usize, out: &mut String) {
+/// - A nested array: `- [N]: v1,v2`, or `- [0]:` when empty
+fn encode_list_items(arr: &[Value], depth: usize, opts: &EncodeOptions, out: &mut String) {
     let item_indent = make_indent(depth + 1);
     for item in arr {
         out.push('\n');
@@ -191,30 +496,32 @@ fn encode_list_items(arr: &[Value], depth: usize, out: &mut String) {
                     if first {
                         first = false;
                         out.push_str(&encode_key(key));
-                        encode_list_item_field_value(value, depth + 1, out);
+                        encode_list_item_field_value(value, depth + 1, opts, out);
                     } else {
                         out.push('\n');
                         // Sibling fields at same depth as "- " content
                         out.push_str(&make_indent(depth + 1));
                         out.push_str("  ");
                         out.push_str(&encode_key(key));
-                        encode_list_item_field_value(value, depth + 1, out);
+                        encode_list_item_field_value(value, depth + 1, opts, out);
                     }
                 }
             }
             Value::Array(inner_arr) => {
                 // Nested array as list item
                 let len = inner_arr.len();
-                if all_primitives(inner_arr) {
+                if inner_arr.is_empty() {
+                    out.push_str("[0]:");
+                } else if all_primitives(inner_arr) {
                     out.push_str(&format!("[{}]: ", len));
-                    encode_inline_values(inner_arr, out);
+                    encode_inline_values(inner_arr, opts, out);
                 } else {
                     out.push_str(&format!("[{}]:", len));
-                    encode_list_items(inner_arr, depth + 1, out);
+                    encode_list_items(inner_arr, depth + 1, opts, out);
                 }
             }
             _ => {
-                encode_primitive_value(item, QuoteContext::Document, out);
+                encode_primitive_value(item, QuoteContext::Document, opts.keyword_case, out);
             }
         }
     }
@@ -223,7 +530,12 @@ fn encode_list_items(arr: &[Value], depth: usize, out: &mut String) {
 /// Encode a field value within a list item object. Differs from `encode_field_value`
 /// because nested objects inside list items use an extra indent level to account
 /// for the "- " prefix offset.
-fn encode_list_item_field_value(value: &Value, depth: usize, out: &mut String) {
+fn encode_list_item_field_value(
+    value: &Value,
+    depth: usize,
+    opts: &EncodeOptions,
+    out: &mut String,
+) {
     match value {
         Value::Object(map) if map.is_empty() => {
             out.push(':');
@@ -241,40 +553,52 @@ fn encode_list_item_field_value(value: &Value, depth: usize, out: &mut String) {
                 first = false;
                 out.push_str(&nested_indent);
                 out.push_str(&encode_key(key));
-                encode_field_value(key, val, depth + 2, out);
+                encode_field_value(key, val, depth + 2, opts, out);
             }
         }
         Value::Array(arr) => {
-            encode_array_field(arr, depth, out);
+            // The field sits one level past `depth` (after the "- " offset), so its
+            // rows or items nest under it from there.
+            encode_array_field(arr, depth + 1, opts, out);
         }
         _ => {
             out.push_str(": ");
-            encode_primitive_value(value, QuoteContext::Document, out);
+            encode_primitive_value(value, QuoteContext::Document, opts.keyword_case, out);
         }
     }
 }
 
 /// Context for quoting decisions per TOON v3.0 delimiter scoping rules.
-#[derive(Clone, Copy, PartialEq)]
-enum QuoteContext {
+///
+/// Passed to [`would_quote`] to ask how a string is written in a given position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteContext {
     /// Object field value or bare root primitive — colon triggers quoting
     Document,
-    /// Inline primitive array value — comma (active delimiter) triggers quoting
-    InlineArray,
-    /// Tabular row cell — comma (active delimiter) triggers quoting, NOT colon
-    TabularCell,
+    /// Inline primitive array value — the active delimiter triggers quoting
+    InlineArray(Delimiter),
+    /// Tabular row cell — the active delimiter triggers quoting, NOT colon
+    TabularCell(Delimiter),
+    /// Cell of a `str`-typed tabular column — as `TabularCell`, but strings that
+    /// look like numbers or booleans stay bare since the header says they are strings
+    TypedStrCell(Delimiter),
 }
 
 /// Emit a primitive JSON value (null, bool, number, string) in TOON format.
 /// String quoting depends on the `QuoteContext` — different delimiters are
 /// "active" in different positions (see TOON v3.0 spec, delimiter scoping).
-fn encode_primitive_value(value: &Value, ctx: QuoteContext, out: &mut String) {
+fn encode_primitive_value(
+    value: &Value,
+    ctx: QuoteContext,
+    keywords: KeywordCase,
+    out: &mut String,
+) {
     match value {
-        Value::Null => out.push_str("null"),
-        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Null => out.push_str(keywords.null()),
+        Value::Bool(b) => out.push_str(keywords.bool(*b)),
         Value::Number(n) => out.push_str(&format_number(n)),
-        Value::String(s) => encode_string_value(s, ctx, out),
-        _ => out.push_str("null"), // arrays/objects in primitive context
+        Value::String(s) => encode_string_value(s, ctx, keywords, out),
+        _ => out.push_str(keywords.null()), // arrays/objects in primitive context
     }
 }
 
@@ -317,8 +641,13 @@ fn format_number(n: &serde_json::Number) -> String {
 
 /// Emit a string value, quoting and escaping only when necessary.
 /// Unquoted strings save 2 tokens (the quotes) per value — significant at scale.
-fn encode_string_value(s: &str, ctx: QuoteContext, out: &mut String) {
-    if needs_quoting(s, ctx) {
+pub(crate) fn encode_string_value(
+    s: &str,
+    ctx: QuoteContext,
+    keywords: KeywordCase,
+    out: &mut String,
+) {
+    if needs_quoting(s, ctx, keywords) {
         out.push('"');
         for ch in s.chars() {
             match ch {
@@ -336,19 +665,43 @@ fn encode_string_value(s: &str, ctx: QuoteContext, out: &mut String) {
     }
 }
 
+/// Whether the encoder writes the string `s` in double quotes in `context`.
+///
+/// Exposes the encoder's own quoting rules (see [`QuoteContext`]) so tooling such
+/// as syntax highlighters can tell how a value will be written without encoding
+/// a document. `keywords` is the [`EncodeOptions::keyword_case`] in effect, since
+/// a string spelled like a keyword in that case is quoted too.
+///
+/// # Example
+/// ```
+/// use toon_core::{would_quote, Delimiter, KeywordCase, QuoteContext};
+///
+/// let lower = KeywordCase::Lower;
+/// assert!(!would_quote("Ada", QuoteContext::Document, lower));
+/// assert!(would_quote("true", QuoteContext::Document, lower));
+/// assert!(would_quote("TRUE", QuoteContext::Document, KeywordCase::Upper));
+/// assert!(would_quote("a:b", QuoteContext::Document, lower));
+/// assert!(!would_quote("a:b", QuoteContext::InlineArray(Delimiter::Comma), lower));
+/// assert!(would_quote("a,b", QuoteContext::TabularCell(Delimiter::Comma), lower));
+/// ```
+pub fn would_quote(s: &str, context: QuoteContext, keywords: KeywordCase) -> bool {
+    needs_quoting(s, context, keywords)
+}
+
 /// Determine if a string value must be quoted to preserve TOON roundtrip fidelity.
 ///
 /// A string MUST be quoted if it:
 /// - Is empty
 /// - Has leading/trailing whitespace
-/// - Looks like a boolean (`true`/`false`) or `null`
+/// - Looks like a boolean (`true`/`false`) or `null`, lowercase or in the
+///   configured [`KeywordCase`]
 /// - Looks numeric (would be decoded as a number instead of string)
 /// - Contains backslash, double quote, brackets, braces, or control chars
 /// - Starts with `-` (ambiguous with list item marker)
 /// - Contains the ACTIVE delimiter for the current context:
 ///   - Document context: colon (`:`)
-///   - InlineArray/TabularCell context: comma (`,`)
-fn needs_quoting(s: &str, ctx: QuoteContext) -> bool {
+///   - InlineArray/TabularCell context: the configured delimiter (`,` by default)
+fn needs_quoting(s: &str, ctx: QuoteContext, keywords: KeywordCase) -> bool {
     // Empty string
     if s.is_empty() {
         return true;
@@ -357,12 +710,16 @@ fn needs_quoting(s: &str, ctx: QuoteContext) -> bool {
     if s != s.trim() {
         return true;
     }
-    // Looks like bool or null
-    if s == "true" || s == "false" || s == "null" {
-        return true;
+    let typed_str = matches!(ctx, QuoteContext::TypedStrCell(_));
+    match keywords.keyword(s) {
+        // Looks like null (a bare `null` is null even in a typed column)
+        Some(Value::Null) => return true,
+        // Looks like bool
+        Some(_) if !typed_str => return true,
+        _ => {}
     }
     // Looks like a number (including leading-zero forms like "05")
-    if looks_numeric(s) {
+    if !typed_str && looks_numeric(s) {
         return true;
     }
     // Contains backslash or double quote
@@ -389,9 +746,11 @@ fn needs_quoting(s: &str, ctx: QuoteContext) -> bool {
                 return true;
             }
         }
-        QuoteContext::InlineArray | QuoteContext::TabularCell => {
+        QuoteContext::InlineArray(delimiter)
+        | QuoteContext::TabularCell(delimiter)
+        | QuoteContext::TypedStrCell(delimiter) => {
             // Active delimiter (comma by default) triggers quoting
-            if s.contains(',') {
+            if s.contains(delimiter.as_char()) {
                 return true;
             }
         }
@@ -400,14 +759,19 @@ fn needs_quoting(s: &str, ctx: QuoteContext) -> bool {
 }
 
 /// Check if a string looks like a number (and thus must be quoted to preserve type info).
-/// Matches integers, floats, and leading-zero forms like "05" or "0001".
+/// Matches integers, floats, and leading-zero forms like "05" or "0001", with an
+/// optional `-` or `+` sign — the decoder reads a bare `+7` as the number 7.
 fn looks_numeric(s: &str) -> bool {
     // Matches numeric patterns: integers, floats, leading-zero forms
     if s.is_empty() {
         return false;
     }
     let bytes = s.as_bytes();
-    let start = if bytes[0] == b'-' { 1 } else { 0 };
+    let start = if matches!(bytes[0], b'-' | b'+') {
+        1
+    } else {
+        0
+    };
     if start >= bytes.len() {
         return false;
     }
@@ -473,8 +837,32 @@ fn is_valid_unquoted_key(key: &str) -> bool {
     chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
 }
 
+/// The tabular columns for `arr`, or `None` if it should not be rendered as a
+/// table under `opts`.
+fn tabular_fields(arr: &[Value], opts: &EncodeOptions) -> Option<Vec<String>> {
+    if opts.array_style != ArrayStyle::Tabular {
+        return None;
+    }
+    let mut fields = detect_tabular(arr)?;
+    if opts
+        .max_header_columns
+        .is_some_and(|max| fields.len() > max)
+    {
+        return None;
+    }
+    if opts.sort_tabular_columns {
+        fields.sort_unstable();
+    }
+    Some(fields)
+}
+
 /// Detect if an array is tabular: all elements are objects with identical key sets,
 /// all values are primitives (no nested arrays/objects).
+///
+/// Key order does not have to match. The returned fields, and so the column order
+/// of the header and every row, follow the first element's key order; later rows
+/// look their cells up by name. Decoding therefore yields every row with the first
+/// element's key order.
 fn detect_tabular(arr: &[Value]) -> Option<Vec<String>> {
     if arr.is_empty() {
         return None;
@@ -507,6 +895,31 @@ fn detect_tabular(arr: &[Value]) -> Option<Vec<String>> {
     Some(fields)
 }
 
+/// Infer a tabular column's type from its non-null values: all integers → `int`,
+/// integers and fractional numbers → `float`, all strings → `str`, all booleans →
+/// `bool`. Mixed or all-null columns get no annotation.
+fn infer_column_type(arr: &[Value], field: &str) -> Option<ColumnType> {
+    let mut inferred = None;
+    for value in arr.iter().filter_map(|obj| obj.get(field)) {
+        let ty = match value {
+            Value::Null => continue,
+            Value::Bool(_) => ColumnType::Bool,
+            Value::String(_) => ColumnType::Str,
+            Value::Number(n) if n.is_f64() => ColumnType::Float,
+            Value::Number(_) => ColumnType::Int,
+            _ => return None,
+        };
+        inferred = match (inferred, ty) {
+            (None, ty) => Some(ty),
+            (Some(prev), ty) if prev == ty => Some(prev),
+            (Some(ColumnType::Int), ColumnType::Float)
+            | (Some(ColumnType::Float), ColumnType::Int) => Some(ColumnType::Float),
+            _ => return None,
+        };
+    }
+    inferred
+}
+
 /// Check if all array elements are primitives (not objects or arrays).
 fn all_primitives(arr: &[Value]) -> bool {
     arr.iter().all(|v| !v.is_object() && !v.is_array())
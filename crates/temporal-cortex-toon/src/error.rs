@@ -3,20 +3,59 @@
 use thiserror::Error;
 
 /// Errors that can occur during TOON encoding or decoding.
+///
+/// The `Display` output is what the WASM, Python, and CLI layers surface to
+/// users, so its format is stable: every message starts with `toon: ` followed
+/// by a fixed per-variant prefix.
+///
+/// | Variant      | `code()`         | `Display`                                   |
+/// |--------------|------------------|---------------------------------------------|
+/// | `JsonParse`  | `"invalid_json"` | `toon: invalid JSON: <message>`             |
+/// | `ToonParse`  | `"parse_error"`  | `toon: parse error at line N: <message>`    |
+/// | `Encode`     | `"encode_error"` | `toon: encode error: <message>`             |
+/// | `Io`         | `"io_error"`     | `toon: I/O error: <message>`                |
+/// | `InvalidUtf8`| `"invalid_utf8"` | `toon: input is not valid UTF-8; TOON requires UTF-8 (<message>)` |
+///
+/// The text after the prefix is diagnostic and may change; match on
+/// [`ToonError::code`] rather than on the message. New variants may be added,
+/// so matches on `ToonError` need a wildcard arm.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum ToonError {
     /// The input string was not valid JSON (encoding path).
-    #[error("JSON parse error: {0}")]
+    #[error("toon: invalid JSON: {0}")]
     JsonParse(#[from] serde_json::Error),
 
     /// The input string was not valid TOON (decoding path).
     /// Includes the 1-based line number where the error was detected.
-    #[error("TOON parse error at line {line}: {message}")]
+    #[error("toon: parse error at line {line}: {message}")]
     ToonParse { line: usize, message: String },
 
     /// A structural error during encoding (e.g., unsupported value type).
-    #[error("Encoding error: {0}")]
+    #[error("toon: encode error: {0}")]
     Encode(String),
+
+    /// Reading TOON from a reader failed (e.g., [`crate::decoder::decode_from_reader`]).
+    #[error("toon: I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Raw input bytes were not valid UTF-8. TOON text is always UTF-8, so
+    /// Latin-1 and other encodings must be converted before decoding.
+    #[error("toon: input is not valid UTF-8; TOON requires UTF-8 ({0})")]
+    InvalidUtf8(#[from] std::str::Utf8Error),
+}
+
+impl ToonError {
+    /// A machine-readable code identifying the variant, stable across releases.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ToonError::JsonParse(_) => "invalid_json",
+            ToonError::ToonParse { .. } => "parse_error",
+            ToonError::Encode(_) => "encode_error",
+            ToonError::Io(_) => "io_error",
+            ToonError::InvalidUtf8(_) => "invalid_utf8",
+        }
+    }
 }
 
 /// Convenience alias used throughout temporal-cortex-toon.
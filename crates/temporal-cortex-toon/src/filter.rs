@@ -11,14 +11,30 @@
 //! - `"*.etag"` -- wildcard: strip "etag" at any depth
 //! - `"attendees.*.responseStatus"` -- strip "responseStatus" inside each
 //!   array element of "attendees"
+//! - `"ext_*"` / `"*_internal"` -- segment glob: strip top-level keys starting
+//!   with "ext_" / ending in "_internal"
+//!
+//! A segment that is exactly `*` matches any key at any depth. A segment that
+//! merely *contains* `*` is a glob over one key name: it matches only at its
+//! own position, exactly like a literal segment, and `*` inside it stands for
+//! any run of characters (possibly empty). So `"ext_*"` strips `ext_id` and
+//! `ext_source` at the top level but not `external` (no underscore), and
+//! `"*.ext_*"` strips them at any depth.
+//!
+//! [`redact_fields`] takes the same patterns but replaces matching values
+//! instead of removing their keys, so the document keeps its shape.
+//!
+//! [`filter_elements`] filters by content instead: it drops the elements of an
+//! array whose field matches an [`ElementPredicate`], e.g. cancelled events.
 
 use crate::error::Result;
 use serde_json::{Map, Value};
 
 /// A parsed filter pattern, split on dots for efficient matching.
 ///
-/// Each segment is either a literal field name or the wildcard `*`.
-/// For example, `"items.*.etag"` becomes `["items", "*", "etag"]`.
+/// Each segment is a literal field name, the wildcard `*`, or a key glob
+/// such as `ext_*`. For example, `"items.*.etag"` becomes
+/// `["items", "*", "etag"]`.
 #[derive(Debug, Clone)]
 struct Pattern<'a> {
     segments: Vec<&'a str>,
@@ -45,6 +61,7 @@ fn parse(pattern: &'a str) -> Self {
 /// - `"parent.child"` -- remove `child` inside `parent`
 /// - `"*.field"` -- remove `field` at any nesting depth
 /// - `"arr.*.field"` -- remove `field` inside each element of array `arr`
+/// - `"prefix_*"` -- remove every top-level key starting with `prefix_`
 ///
 /// # Examples
 ///
@@ -61,7 +78,31 @@ pub fn filter_fields(value: &Value, patterns: &[&str]) -> Value {
         return value.clone();
     }
     let parsed: Vec<Pattern<'_>> = patterns.iter().map(|p| Pattern::parse(p)).collect();
-    apply_filter(value, &parsed)
+    apply_filter(value, &parsed, None)
+}
+
+/// Replace the values of fields matching the given patterns with `replacement`.
+///
+/// Uses the same pattern syntax as [`filter_fields`], but a matching key is
+/// kept and only its value is replaced. Because every object keeps the same
+/// keys, a uniform array of objects still encodes as a TOON table.
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// use toon_core::redact_fields;
+///
+/// let value = json!({"name": "Alice", "email": "alice@example.com"});
+/// let redacted = redact_fields(&value, &["email"], &json!("[redacted]"));
+/// assert_eq!(redacted, json!({"name": "Alice", "email": "[redacted]"}));
+/// ```
+pub fn redact_fields(value: &Value, patterns: &[&str], replacement: &Value) -> Value {
+    if patterns.is_empty() {
+        return value.clone();
+    }
+    let parsed: Vec<Pattern<'_>> = patterns.iter().map(|p| Pattern::parse(p)).collect();
+    apply_filter(value, &parsed, Some(replacement))
 }
 
 /// Internal recursive filter engine.
@@ -79,18 +120,25 @@ pub fn filter_fields(value: &Value, patterns: &[&str]) -> Value {
 ///
 /// Arrays are transparent to pattern matching: all patterns pass through
 /// to each array element unchanged.
-fn apply_filter(value: &Value, patterns: &[Pattern<'_>]) -> Value {
+///
+/// A matched key is removed, or kept with its value set to `replacement`
+/// when one is given.
+fn apply_filter(value: &Value, patterns: &[Pattern<'_>], replacement: Option<&Value>) -> Value {
     match value {
-        Value::Object(map) => filter_object(map, patterns),
-        Value::Array(arr) => filter_array(arr, patterns),
+        Value::Object(map) => filter_object(map, patterns, replacement),
+        Value::Array(arr) => filter_array(arr, patterns, replacement),
         // Primitives (string, number, bool, null) are returned as-is.
         other => other.clone(),
     }
 }
 
-/// Filter an object map by removing keys that match terminal patterns,
-/// and recursing into children with narrowed patterns.
-fn filter_object(map: &Map<String, Value>, patterns: &[Pattern<'_>]) -> Value {
+/// Filter an object map by removing (or redacting) keys that match terminal
+/// patterns, and recursing into children with narrowed patterns.
+fn filter_object(
+    map: &Map<String, Value>,
+    patterns: &[Pattern<'_>],
+    replacement: Option<&Value>,
+) -> Value {
     let mut result = Map::new();
 
     for (key, child) in map {
@@ -117,14 +165,14 @@ fn filter_object(map: &Map<String, Value>, patterns: &[Pattern<'_>]) -> Value {
                 }
                 // The wildcard consumed one level. Check if the remaining
                 // pattern's first segment matches this key as a terminal.
-                if rest.len() == 1 && rest[0] == key {
+                if rest.len() == 1 && segment_matches(rest[0], key) {
                     // e.g. pattern `*.etag` and key is `etag` -- remove it.
                     remove = true;
                     break;
                 }
                 // Otherwise, narrow the rest as a child pattern if the next
                 // segment matches this key or is another wildcard.
-                if rest[0] == key || rest[0] == "*" {
+                if segment_matches(rest[0], key) {
                     // Descend with segments after the matched key.
                     child_patterns.push(Pattern {
                         segments: rest[1..].to_vec(),
@@ -133,8 +181,8 @@ fn filter_object(map: &Map<String, Value>, patterns: &[Pattern<'_>]) -> Value {
                 // Always propagate the full wildcard pattern into children
                 // so it can match at deeper levels too.
                 child_patterns.push(pattern.clone());
-            } else if first == key {
-                // Literal match on the first segment.
+            } else if segment_matches(first, key) {
+                // Literal (or key glob) match on the first segment.
                 if rest.is_empty() {
                     // Terminal match: `"etag"` matches key "etag" -- remove.
                     remove = true;
@@ -150,6 +198,9 @@ fn filter_object(map: &Map<String, Value>, patterns: &[Pattern<'_>]) -> Value {
         }
 
         if remove {
+            if let Some(replacement) = replacement {
+                result.insert(key.clone(), replacement.clone());
+            }
             continue;
         }
 
@@ -157,26 +208,157 @@ fn filter_object(map: &Map<String, Value>, patterns: &[Pattern<'_>]) -> Value {
         if child_patterns.is_empty() {
             result.insert(key.clone(), child.clone());
         } else {
-            result.insert(key.clone(), apply_filter(child, &child_patterns));
+            result.insert(
+                key.clone(),
+                apply_filter(child, &child_patterns, replacement),
+            );
         }
     }
 
     Value::Object(result)
 }
 
+/// Whether a single pattern segment matches `key`.
+///
+/// A segment without `*` must equal the key. Otherwise each `*` matches any
+/// run of characters, so `ext_*` matches `ext_id` and `*_internal` matches
+/// `user_internal`; the bare `*` matches every key.
+fn segment_matches(segment: &str, key: &str) -> bool {
+    let Some((prefix, rest)) = segment.split_once('*') else {
+        return segment == key;
+    };
+    let Some(mut remaining) = key.strip_prefix(prefix) else {
+        return false;
+    };
+    let mut parts: Vec<&str> = rest.split('*').collect();
+    let suffix = parts.pop().unwrap_or_default();
+    // Middle parts match greedily left to right; the suffix must end the key.
+    for part in parts {
+        match remaining.find(part) {
+            Some(idx) => remaining = &remaining[idx + part.len()..],
+            None => return false,
+        }
+    }
+    remaining.ends_with(suffix)
+}
+
 /// Filter array elements by passing all patterns through to each element.
 ///
 /// Arrays are "transparent" to pattern matching -- they don't consume
 /// any pattern segments. This means `"items.etag"` works correctly when
 /// `items` is an array: the pattern descends into each array element.
-fn filter_array(arr: &[Value], patterns: &[Pattern<'_>]) -> Value {
+fn filter_array(arr: &[Value], patterns: &[Pattern<'_>], replacement: Option<&Value>) -> Value {
     Value::Array(
         arr.iter()
-            .map(|elem| apply_filter(elem, patterns))
+            .map(|elem| apply_filter(elem, patterns, replacement))
             .collect(),
     )
 }
 
+/// A condition on one field of an array element, for [`filter_elements`].
+///
+/// The field is a dot-separated path inside the element (`"status"`,
+/// `"organizer.email"`). Elements that are not objects, or lack the field,
+/// never match.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ElementPredicate {
+    /// The field's value equals the given value.
+    Equals(String, Value),
+    /// The field is a string containing the given string, or an array
+    /// containing an element equal to the given value.
+    Contains(String, Value),
+}
+
+impl ElementPredicate {
+    /// Whether `element` satisfies this predicate.
+    fn matches(&self, element: &Value) -> bool {
+        let (path, expected) = match self {
+            ElementPredicate::Equals(path, expected)
+            | ElementPredicate::Contains(path, expected) => (path, expected),
+        };
+        let Some(field) = path
+            .split('.')
+            .try_fold(element, |node, key| node.as_object()?.get(key))
+        else {
+            return false;
+        };
+        match self {
+            ElementPredicate::Equals(..) => field == expected,
+            ElementPredicate::Contains(..) => match (field, expected) {
+                (Value::String(text), Value::String(needle)) => text.contains(needle.as_str()),
+                (Value::Array(items), _) => items.contains(expected),
+                _ => false,
+            },
+        }
+    }
+}
+
+/// Drop the elements of the array at `array_path` that match `predicate`.
+///
+/// `array_path` is a dot-separated path of object keys (`"items"`,
+/// `"calendar.events"`); an empty path means `value` itself is the array.
+/// Arrays along the way are transparent, as in [`filter_fields`], so the
+/// elements are filtered in every array the path reaches. If the path leads to
+/// no array, `value` is returned unchanged.
+///
+/// Unlike [`filter_fields`], which strips keys, this removes whole elements by
+/// their content — e.g. cancelled or declined events, which otherwise cost a
+/// full row each.
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// use toon_core::{filter_elements, ElementPredicate};
+///
+/// let value = json!({"items": [
+///     {"summary": "Standup", "status": "confirmed"},
+///     {"summary": "Retro", "status": "cancelled"},
+/// ]});
+/// let cancelled = ElementPredicate::Equals("status".into(), json!("cancelled"));
+/// let filtered = filter_elements(&value, "items", &cancelled);
+/// assert_eq!(filtered, json!({"items": [{"summary": "Standup", "status": "confirmed"}]}));
+/// ```
+pub fn filter_elements(value: &Value, array_path: &str, predicate: &ElementPredicate) -> Value {
+    let segments: Vec<&str> = if array_path.is_empty() {
+        Vec::new()
+    } else {
+        array_path.split('.').collect()
+    };
+    drop_matching_elements(value, &segments, predicate)
+}
+
+/// Follow `segments` down from `value`, then drop matching elements of the
+/// array reached.
+fn drop_matching_elements(value: &Value, segments: &[&str], predicate: &ElementPredicate) -> Value {
+    match (value, segments.split_first()) {
+        (Value::Array(arr), None) => Value::Array(
+            arr.iter()
+                .filter(|elem| !predicate.matches(elem))
+                .cloned()
+                .collect(),
+        ),
+        (Value::Array(arr), Some(_)) => Value::Array(
+            arr.iter()
+                .map(|elem| drop_matching_elements(elem, segments, predicate))
+                .collect(),
+        ),
+        (Value::Object(map), Some((first, rest))) => Value::Object(
+            map.iter()
+                .map(|(key, child)| {
+                    let child = if key == first {
+                        drop_matching_elements(child, rest, predicate)
+                    } else {
+                        child.clone()
+                    };
+                    (key.clone(), child)
+                })
+                .collect(),
+        ),
+        _ => value.clone(),
+    }
+}
+
 /// Filter JSON fields by pattern, then encode the result to TOON.
 ///
 /// This is a convenience function combining [`filter_fields`] with
@@ -203,6 +385,31 @@ pub fn filter_and_encode(json: &str, patterns: &[&str]) -> Result<String> {
     crate::encoder::encode(&filtered_json)
 }
 
+/// Redact JSON fields by pattern, then encode the result to TOON.
+///
+/// The [`redact_fields`] counterpart of [`filter_and_encode`].
+///
+/// # Errors
+///
+/// Returns an error if the input is not valid JSON or if TOON encoding fails.
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// use toon_core::redact_and_encode;
+///
+/// let json = r#"{"name":"Alice","email":"alice@example.com"}"#;
+/// let toon = redact_and_encode(json, &["email"], &json!("[redacted]")).unwrap();
+/// assert_eq!(toon, "name: Alice\nemail: \"[redacted]\"");
+/// ```
+pub fn redact_and_encode(json: &str, patterns: &[&str], replacement: &Value) -> Result<String> {
+    let value: Value = serde_json::from_str(json)?;
+    let redacted = redact_fields(&value, patterns, replacement);
+    let redacted_json = serde_json::to_string(&redacted)?;
+    crate::encoder::encode(&redacted_json)
+}
+
 /// Predefined filter sets for common calendar APIs.
 pub struct CalendarFilter;
 
@@ -24,19 +24,41 @@
 //!
 //! ## Modules
 //!
-//! - [`encoder`] — JSON string → TOON string
-//! - [`decoder`] — TOON string → JSON string
-//! - [`filter`] — Semantic filtering + TOON encode (`filter_and_encode`, `CalendarFilter`)
+//! - [`encoder`] — JSON string → TOON string (plus an aligned pretty renderer for [`types::ToonValue`] and a round-trip check)
+//! - [`decoder`] — TOON string or reader → JSON (optionally with per-node source line spans or canonical sorted keys), or a single table → raw string cells
+//! - [`filter`] — Semantic filtering and redaction + TOON encode (`filter_and_encode`, `redact_and_encode`, `filter_elements`, `CalendarFilter`)
+//! - [`merge`] — RFC 7386 merge patch of one TOON document into another (`merge_documents`)
+//! - [`transcode`] — Re-delimit inline arrays and tabular rows without a decode/encode round trip
+//! - [`stats`] — JSON vs TOON size and token statistics with a pluggable [`TokenCounter`], and structural document statistics
 //! - [`error`] — Error types for parse/encode failures
-//! - [`types`] — `ToonValue` AST (reserved for future direct-manipulation use)
+//! - [`types`] — `ToonValue` AST for direct manipulation, convertible to/from `serde_json::Value`
 
 pub mod decoder;
 pub mod encoder;
 pub mod error;
 pub mod filter;
+pub mod merge;
+pub mod stats;
+pub mod transcode;
 pub mod types;
 
-pub use decoder::decode;
-pub use encoder::encode;
+pub use decoder::{
+    decode, decode_from_reader, decode_from_reader_with_options, decode_sorted, decode_tabular_raw,
+    decode_with_options, decode_with_spans, validate, DecodeOptions, SpanMap,
+};
+pub use encoder::{
+    canonicalize, encode, encode_toon_value_pretty, encode_with_options, roundtrips, would_quote,
+    EncodeOptions, QuoteContext,
+};
 pub use error::ToonError;
-pub use filter::{filter_and_encode, filter_fields, CalendarFilter};
+pub use filter::{
+    filter_and_encode, filter_elements, filter_fields, redact_and_encode, redact_fields,
+    CalendarFilter, ElementPredicate,
+};
+pub use merge::merge_documents;
+pub use stats::{
+    compression_stats, compression_stats_with_counter, document_stats, CompressionStats,
+    DocumentStats, TokenCounter, WhitespaceTokenCounter,
+};
+pub use transcode::transcode_delimiter;
+pub use types::{ArrayStyle, Delimiter, KeywordCase};
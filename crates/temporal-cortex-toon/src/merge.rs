@@ -0,0 +1,64 @@
+//! Value-level merging of TOON documents.
+//!
+//! Applies an RFC 7386 JSON Merge Patch to a base document, so callers holding
+//! partial updates as TOON never need to round-trip through JSON themselves.
+
+use serde_json::{Map, Value};
+
+use crate::decoder::{decode_value, DecodeOptions};
+use crate::encoder::{encode_value, EncodeOptions};
+use crate::error::Result;
+
+/// Merge a patch TOON document into a base TOON document.
+///
+/// Both inputs are decoded and combined using RFC 7386 JSON Merge Patch
+/// semantics, then re-encoded to TOON:
+///
+/// - a `null` in the patch deletes the key from the base
+/// - objects merge recursively
+/// - arrays and primitives replace the base value wholesale
+/// - a non-object patch replaces the whole base document
+///
+/// # Errors
+///
+/// Returns an error if either document fails to decode.
+///
+/// # Example
+/// ```
+/// use toon_core::merge_documents;
+///
+/// let base = "summary: Standup\nstatus: tentative";
+/// let patch = "status: confirmed";
+/// assert_eq!(
+///     merge_documents(base, patch).unwrap(),
+///     "summary: Standup\nstatus: confirmed"
+/// );
+/// ```
+pub fn merge_documents(base: &str, patch: &str) -> Result<String> {
+    let options = DecodeOptions::default();
+    let mut target = decode_value(base, &options)?;
+    let patch = decode_value(patch, &options)?;
+    merge_patch(&mut target, patch);
+    Ok(encode_value(&target, &EncodeOptions::default()))
+}
+
+/// Apply `patch` to `target` in place (RFC 7386, section 2).
+fn merge_patch(target: &mut Value, patch: Value) {
+    let Value::Object(patch_map) = patch else {
+        *target = patch;
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    if let Value::Object(target_map) = target {
+        for (key, value) in patch_map {
+            if value.is_null() {
+                // `shift_remove` keeps the remaining keys in document order.
+                target_map.shift_remove(&key);
+            } else {
+                merge_patch(target_map.entry(key).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
@@ -0,0 +1,221 @@
+//! Statistics about TOON documents.
+//!
+//! [`compression_stats`] compares a JSON document with its TOON encoding. Token
+//! counts come from a pluggable [`TokenCounter`], so callers can calibrate
+//! against a real tokenizer (cl100k, o200k, ...) from their own crate without
+//! `toon-core` depending on one. [`WhitespaceTokenCounter`] is the built-in
+//! heuristic.
+//!
+//! [`document_stats`] describes the structure of a TOON document — how many
+//! containers and scalars it holds and how each array is written — to show
+//! where its size comes from.
+
+use serde_json::Value;
+
+use crate::decoder::decode_with_forms;
+use crate::encoder::encode;
+use crate::error::Result;
+
+/// Counts the tokens in a piece of text.
+pub trait TokenCounter {
+    /// Number of tokens in `s`.
+    fn count(&self, s: &str) -> usize;
+}
+
+/// Heuristic token counter: every whitespace-separated word is one token, and
+/// each structural character (`{ } [ ] : , "`) is a token of its own.
+///
+/// Not tied to any real tokenizer, but it charges JSON for its braces and
+/// quotes the way BPE tokenizers do, so JSON/TOON comparisons are meaningful.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WhitespaceTokenCounter;
+
+impl TokenCounter for WhitespaceTokenCounter {
+    fn count(&self, s: &str) -> usize {
+        let mut tokens = 0;
+        let mut in_word = false;
+        for c in s.chars() {
+            if matches!(c, '{' | '}' | '[' | ']' | ':' | ',' | '"') {
+                tokens += 1;
+                in_word = false;
+            } else if c.is_whitespace() {
+                in_word = false;
+            } else if !in_word {
+                tokens += 1;
+                in_word = true;
+            }
+        }
+        tokens
+    }
+}
+
+/// Sizes of a JSON document and its TOON encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompressionStats {
+    /// Length of the JSON input in bytes.
+    pub json_bytes: usize,
+    /// Length of the TOON encoding in bytes.
+    pub toon_bytes: usize,
+    /// Tokens in the JSON input, per the counter used.
+    pub json_tokens: usize,
+    /// Tokens in the TOON encoding, per the counter used.
+    pub toon_tokens: usize,
+}
+
+impl CompressionStats {
+    /// Percentage of bytes saved by TOON (`0.0` for empty input).
+    pub fn byte_reduction(&self) -> f64 {
+        reduction(self.json_bytes, self.toon_bytes)
+    }
+
+    /// Percentage of tokens saved by TOON (`0.0` for empty input).
+    pub fn token_reduction(&self) -> f64 {
+        reduction(self.json_tokens, self.toon_tokens)
+    }
+}
+
+fn reduction(before: usize, after: usize) -> f64 {
+    if before > 0 {
+        (1.0 - (after as f64 / before as f64)) * 100.0
+    } else {
+        0.0
+    }
+}
+
+/// Encode `json` to TOON and measure both, counting tokens with
+/// [`WhitespaceTokenCounter`].
+///
+/// # Errors
+///
+/// Returns an error if `json` is not valid JSON.
+///
+/// # Example
+/// ```
+/// use toon_core::compression_stats;
+///
+/// let stats = compression_stats(r#"{"name":"Alice","age":30}"#).unwrap();
+/// assert_eq!(stats.toon_bytes, "name: Alice\nage: 30".len());
+/// assert!(stats.toon_tokens < stats.json_tokens);
+/// ```
+pub fn compression_stats(json: &str) -> Result<CompressionStats> {
+    compression_stats_with_counter(json, &WhitespaceTokenCounter)
+}
+
+/// Identical to [`compression_stats`] but counts tokens with `counter`.
+///
+/// # Errors
+///
+/// Returns an error if `json` is not valid JSON.
+pub fn compression_stats_with_counter<C: TokenCounter + ?Sized>(
+    json: &str,
+    counter: &C,
+) -> Result<CompressionStats> {
+    let toon = encode(json)?;
+    Ok(CompressionStats {
+        json_bytes: json.len(),
+        toon_bytes: toon.len(),
+        json_tokens: counter.count(json),
+        toon_tokens: counter.count(&toon),
+    })
+}
+
+/// Structural statistics of a TOON document, from [`document_stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DocumentStats {
+    /// Objects, including the root and each tabular row.
+    pub objects: usize,
+    /// Arrays written inline on their header line (`tags[2]: a,b`), including
+    /// empty arrays.
+    pub inline_arrays: usize,
+    /// Arrays written as a table (`items[2]{id,name}:` plus one row per line).
+    pub tabular_arrays: usize,
+    /// Arrays written as `- ` list items.
+    pub expanded_arrays: usize,
+    /// Scalar values: strings, numbers, booleans and nulls.
+    pub scalars: usize,
+    /// Deepest container nesting; `0` for a scalar document, `1` for a flat object.
+    pub max_depth: usize,
+    /// String values written in double quotes.
+    pub quoted_strings: usize,
+    /// String values written bare.
+    pub unquoted_strings: usize,
+}
+
+impl DocumentStats {
+    /// Total number of arrays, in any form.
+    pub fn arrays(&self) -> usize {
+        self.inline_arrays + self.tabular_arrays + self.expanded_arrays
+    }
+
+    /// Fraction of string values that are quoted (`0.0` if there are none).
+    pub fn quoted_ratio(&self) -> f64 {
+        let strings = self.quoted_strings + self.unquoted_strings;
+        if strings > 0 {
+            self.quoted_strings as f64 / strings as f64
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Decode `toon` and report its [`DocumentStats`].
+///
+/// # Errors
+///
+/// Returns an error if `toon` fails to decode.
+///
+/// # Example
+/// ```
+/// use toon_core::document_stats;
+///
+/// let stats = document_stats("team: core\nusers[2]{id,name}:\n  1,Ada\n  2,\"true\"").unwrap();
+/// assert_eq!(stats.tabular_arrays, 1);
+/// assert_eq!(stats.objects, 3);
+/// assert_eq!(stats.max_depth, 3);
+/// assert_eq!((stats.quoted_strings, stats.unquoted_strings), (1, 2));
+/// ```
+pub fn document_stats(toon: &str) -> Result<DocumentStats> {
+    let (value, forms) = decode_with_forms(toon)?;
+    let mut stats = DocumentStats {
+        inline_arrays: forms.inline_arrays,
+        tabular_arrays: forms.tabular_arrays,
+        expanded_arrays: forms.expanded_arrays,
+        quoted_strings: forms.quoted_strings,
+        ..DocumentStats::default()
+    };
+    let mut strings = 0;
+    stats.max_depth = walk(&value, &mut stats, &mut strings);
+    // A repeated key can drop a quoted string that was already counted.
+    stats.unquoted_strings = strings.saturating_sub(stats.quoted_strings);
+    Ok(stats)
+}
+
+/// Count the objects, scalars and strings under `value`, returning its depth.
+fn walk(value: &Value, stats: &mut DocumentStats, strings: &mut usize) -> usize {
+    match value {
+        Value::Object(map) => {
+            stats.objects += 1;
+            1 + map
+                .values()
+                .map(|v| walk(v, stats, strings))
+                .max()
+                .unwrap_or(0)
+        }
+        Value::Array(items) => {
+            1 + items
+                .iter()
+                .map(|v| walk(v, stats, strings))
+                .max()
+                .unwrap_or(0)
+        }
+        Value::String(_) => {
+            stats.scalars += 1;
+            *strings += 1;
+            0
+        }
+        _ => {
+            stats.scalars += 1;
+            0
+        }
+    }
+}
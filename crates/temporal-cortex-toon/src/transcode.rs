@@ -0,0 +1,165 @@
+//! Re-delimit a TOON document without decoding it.
+//!
+//! [`transcode_delimiter`] rewrites the inline arrays and tabular rows of a
+//! document written with one [`Delimiter`] so it reads the same with another.
+//! Every other byte is kept as is, so unlike a decode → encode round trip it
+//! does not reorder keys, renormalize numbers or re-indent the document.
+
+use crate::decoder::{
+    at_line, count_indent, find_closing_quote, parse_array_header, parse_primitive_token,
+    split_cells, ArrayHeader, Cell,
+};
+use crate::encoder::{encode_string_value, QuoteContext};
+use crate::error::Result;
+use crate::types::{Delimiter, KeywordCase};
+use serde_json::Value;
+
+/// Rewrite `toon`, written with the `from` delimiter, to use `to`.
+///
+/// Only inline array values and tabular rows change. Cells are re-quoted for
+/// the new delimiter: a bare cell that contains `to` gains quotes, and a quoted
+/// cell that only needed them for `from` loses them. All other cells, including
+/// numbers, are copied verbatim. Tabular header field lists always use commas
+/// and are left alone.
+///
+/// `keywords` must be the [`KeywordCase`] the document was written with, so a
+/// quoted `"TRUE"` written under [`KeywordCase::Upper`] keeps its quotes.
+///
+/// # Errors
+///
+/// Returns [`ToonError::ToonParse`] if an inline array or tabular row has an
+/// unterminated quoted cell.
+///
+/// # Example
+/// ```
+/// use toon_core::{transcode_delimiter, Delimiter, KeywordCase};
+///
+/// let toon = "places[2]{id,addr}:\n  1,\"1 Main St, Springfield\"\n  2,9 Elm Rd";
+/// let tabbed =
+///     transcode_delimiter(toon, Delimiter::Comma, Delimiter::Tab, KeywordCase::Lower).unwrap();
+/// assert_eq!(tabbed, "places[2]{id,addr}:\n  1\t1 Main St, Springfield\n  2\t9 Elm Rd");
+/// ```
+pub fn transcode_delimiter(
+    toon: &str,
+    from: Delimiter,
+    to: Delimiter,
+    keywords: KeywordCase,
+) -> Result<String> {
+    if from == to {
+        return Ok(toon.to_string());
+    }
+
+    let mut out = String::with_capacity(toon.len());
+    // Indent of the header of the tabular block being read, and the line it is on.
+    let mut table: Option<(usize, usize)> = None;
+
+    for (i, line) in toon.split('\n').enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        let trimmed = line.trim();
+        let indent = count_indent(line);
+
+        // Rows continue until a line at or above the header's indent, except
+        // that the line right after the header is always a row (as in the decoder).
+        if let Some((base_indent, header_line)) = table {
+            if trimmed.is_empty() {
+                out.push_str(line);
+                continue;
+            }
+            if indent > base_indent || i == header_line + 1 {
+                out.push_str(&line[..indent]);
+                redelimit(
+                    trimmed,
+                    from,
+                    to,
+                    QuoteContext::TabularCell(to),
+                    keywords,
+                    &mut out,
+                )
+                .map_err(at_line(i))?;
+                continue;
+            }
+            table = None;
+        }
+
+        let Some((header, content_indent)) = array_header(trimmed, indent) else {
+            out.push_str(line);
+            continue;
+        };
+        if header.len == 0 {
+            out.push_str(line);
+        } else if let Some(values) = &header.inline_values {
+            // The values are the tail of the trimmed line.
+            let values_start = indent + trimmed.len() - values.len();
+            out.push_str(&line[..values_start]);
+            redelimit(
+                values,
+                from,
+                to,
+                QuoteContext::InlineArray(to),
+                keywords,
+                &mut out,
+            )
+            .map_err(at_line(i))?;
+            out.push_str(&line[indent + trimmed.len()..]);
+        } else {
+            if header.fields.is_some() {
+                table = Some((content_indent, i));
+            }
+            out.push_str(line);
+        }
+    }
+
+    Ok(out)
+}
+
+/// The array header on a line, if it has one, with the indent its body is
+/// measured from. Recognizes the same forms as the decoder: a root `[N]...`,
+/// a field `key[N]...`, and either one after a `- ` list marker (whose content
+/// sits two columns further in).
+fn array_header(trimmed: &str, indent: usize) -> Option<(ArrayHeader, usize)> {
+    let (content, content_indent) = match trimmed.strip_prefix("- ") {
+        Some(content) => (content, indent + 2),
+        None => (trimmed, indent),
+    };
+    let rest = if content.starts_with('[') {
+        content
+    } else if content.starts_with('"') {
+        &content[find_closing_quote(content, 1)? + 1..]
+    } else {
+        let end = content.find([':', '['])?;
+        &content[end..]
+    };
+    if !rest.starts_with('[') {
+        return None;
+    }
+    Some((parse_array_header(rest)?, content_indent))
+}
+
+/// Split `cells` on `from` and write them joined by `to`, re-quoting as needed.
+fn redelimit(
+    cells: &str,
+    from: Delimiter,
+    to: Delimiter,
+    ctx: QuoteContext,
+    keywords: KeywordCase,
+    out: &mut String,
+) -> Result<()> {
+    for (i, cell) in split_cells(cells, from.as_char())?.into_iter().enumerate() {
+        if i > 0 {
+            out.push(to.as_char());
+        }
+        match cell {
+            Cell::Quoted(s) => encode_string_value(&s, ctx, keywords, out),
+            Cell::Bare(token) if token.contains(to.as_char()) => {
+                match parse_primitive_token(token, keywords) {
+                    Value::String(s) => encode_string_value(&s, ctx, keywords, out),
+                    _ => out.push_str(token),
+                }
+            }
+            Cell::Bare(token) => out.push_str(token),
+        }
+    }
+    Ok(())
+}
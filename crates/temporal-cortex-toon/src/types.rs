@@ -1,9 +1,18 @@
-//! TOON value types for direct AST manipulation (reserved for future use).
+//! TOON value types for direct AST manipulation.
 //!
-//! Currently, encoding and decoding go through `serde_json::Value` as the
-//! intermediate representation. This module defines a TOON-native AST that
-//! could be used for direct manipulation without the JSON roundtrip, e.g.,
-//! for semantic filtering or streaming transformations.
+//! Encoding and decoding go through `serde_json::Value` as the intermediate
+//! representation. This module defines a TOON-native AST that can be used for
+//! direct manipulation without the JSON roundtrip, e.g., for semantic filtering,
+//! streaming transformations, or pretty rendering via
+//! [`encode_toon_value_pretty`](crate::encode_toon_value_pretty).
+//! Conversions to and from `serde_json::Value` are provided via `From`, and
+//! [`ToonValue::walk_mut`] rewrites a tree in place.
+//!
+//! [`Delimiter`] selects the separator used between array values, and
+//! [`KeywordCase`] the spelling of `true`/`false`/`null`, by the encoder and
+//! decoder options.
+
+use serde_json::{Map, Number, Value};
 
 /// Represents a TOON document value. Mirrors JSON types but separates integers
 /// from floats (TOON preserves the distinction) and uses `Vec<(String, ToonValue)>`
@@ -19,3 +28,242 @@ pub enum ToonValue {
     /// Key-value pairs in insertion order.
     Object(Vec<(String, ToonValue)>),
 }
+
+impl ToonValue {
+    /// Visit every node post-order — children before their parent — letting `f`
+    /// modify each one in place.
+    ///
+    /// Because a parent is visited after its children, `f` sees (and may
+    /// replace) an object or array whose contents are already transformed.
+    ///
+    /// # Example
+    /// ```
+    /// use toon_core::types::ToonValue;
+    ///
+    /// // Strip null-valued fields at every level.
+    /// let mut value = ToonValue::Object(vec![
+    ///     ("name".to_string(), ToonValue::String("Ada".to_string())),
+    ///     ("email".to_string(), ToonValue::Null),
+    ///     ("meta".to_string(), ToonValue::Object(vec![("tz".to_string(), ToonValue::Null)])),
+    /// ]);
+    /// value.walk_mut(|node| {
+    ///     if let ToonValue::Object(fields) = node {
+    ///         fields.retain(|(_, v)| *v != ToonValue::Null);
+    ///     }
+    /// });
+    /// assert_eq!(
+    ///     value,
+    ///     ToonValue::Object(vec![
+    ///         ("name".to_string(), ToonValue::String("Ada".to_string())),
+    ///         ("meta".to_string(), ToonValue::Object(vec![])),
+    ///     ])
+    /// );
+    /// ```
+    pub fn walk_mut<F: FnMut(&mut ToonValue)>(&mut self, mut f: F) {
+        self.walk_mut_inner(&mut f);
+    }
+
+    fn walk_mut_inner<F: FnMut(&mut ToonValue)>(&mut self, f: &mut F) {
+        match self {
+            ToonValue::Array(items) => {
+                for item in items {
+                    item.walk_mut_inner(f);
+                }
+            }
+            ToonValue::Object(fields) => {
+                for (_, val) in fields {
+                    val.walk_mut_inner(f);
+                }
+            }
+            _ => {}
+        }
+        f(self);
+    }
+}
+
+impl From<&ToonValue> for Value {
+    /// Non-finite floats (NaN, ±infinity) have no JSON form and become `null`,
+    /// matching how the encoder renders them.
+    fn from(value: &ToonValue) -> Self {
+        match value {
+            ToonValue::Null => Value::Null,
+            ToonValue::Bool(b) => Value::Bool(*b),
+            ToonValue::Integer(i) => Value::Number((*i).into()),
+            ToonValue::Float(f) => Number::from_f64(*f).map_or(Value::Null, Value::Number),
+            ToonValue::String(s) => Value::String(s.clone()),
+            ToonValue::Array(items) => Value::Array(items.iter().map(Value::from).collect()),
+            ToonValue::Object(fields) => {
+                let mut map = Map::new();
+                for (key, val) in fields {
+                    map.insert(key.clone(), Value::from(val));
+                }
+                Value::Object(map)
+            }
+        }
+    }
+}
+
+impl From<&Value> for ToonValue {
+    /// Numbers representable as `i64` become `Integer`; everything else
+    /// (fractions, `u64` beyond `i64::MAX`) becomes `Float`.
+    fn from(value: &Value) -> Self {
+        match value {
+            Value::Null => ToonValue::Null,
+            Value::Bool(b) => ToonValue::Bool(*b),
+            Value::Number(n) => match n.as_i64() {
+                Some(i) => ToonValue::Integer(i),
+                None => ToonValue::Float(n.as_f64().unwrap_or(f64::NAN)),
+            },
+            Value::String(s) => ToonValue::String(s.clone()),
+            Value::Array(items) => ToonValue::Array(items.iter().map(ToonValue::from).collect()),
+            Value::Object(map) => ToonValue::Object(
+                map.iter()
+                    .map(|(key, val)| (key.clone(), ToonValue::from(val)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Separator between inline array elements and tabular row cells.
+///
+/// TOON documents do not record which delimiter they were written with, so a
+/// document must be decoded with the same delimiter it was encoded with.
+/// Tabular header field lists (`{f1,f2}`) always use commas.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Delimiter {
+    /// `,` — the TOON default.
+    #[default]
+    Comma,
+    /// `\t` — useful when values are comma-heavy (e.g., addresses).
+    Tab,
+    /// `;`
+    Semicolon,
+    /// `|`
+    Pipe,
+}
+
+impl Delimiter {
+    /// The delimiter character.
+    pub fn as_char(self) -> char {
+        match self {
+            Delimiter::Comma => ',',
+            Delimiter::Tab => '\t',
+            Delimiter::Semicolon => ';',
+            Delimiter::Pipe => '|',
+        }
+    }
+}
+
+/// How [`encode_with_options`](crate::encode_with_options) renders arrays of
+/// uniform objects.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ArrayStyle {
+    /// Header plus positional rows: `items[2]{id,name}:\n  1,Ada\n  2,Grace`.
+    /// The most compact form — keys are written once.
+    #[default]
+    Tabular,
+    /// Expanded list items with every field labeled:
+    /// `items[2]:\n  - id: 1\n    name: Ada\n  - id: 2\n    name: Grace`.
+    /// Repeats every key (and its colon) on every element, so the extra cost
+    /// grows with rows × fields; single-element arrays cost about the same as
+    /// tabular. Some prompts are answered more reliably when every value sits
+    /// next to its field name.
+    Records,
+}
+
+/// Spelling of the `true`, `false` and `null` keywords.
+///
+/// TOON v3.0 spells them in lowercase, and that is the default. The other cases
+/// are for consumers that expect Python-style (`True`) or SQL-style (`TRUE`)
+/// literals. Only booleans and nulls are affected; a string value that matches a
+/// keyword in the configured case is quoted so it stays a string.
+///
+/// A document must be decoded with the case it was encoded with: under the
+/// default, `TRUE` is the string "TRUE". The lowercase forms are keywords in
+/// every case.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KeywordCase {
+    /// `true`, `false`, `null` — the TOON spelling.
+    #[default]
+    Lower,
+    /// `TRUE`, `FALSE`, `NULL`.
+    Upper,
+    /// `True`, `False`, `Null`.
+    Title,
+}
+
+impl KeywordCase {
+    /// How `null` is written.
+    pub(crate) fn null(self) -> &'static str {
+        match self {
+            KeywordCase::Lower => "null",
+            KeywordCase::Upper => "NULL",
+            KeywordCase::Title => "Null",
+        }
+    }
+
+    /// How a boolean is written.
+    pub(crate) fn bool(self, b: bool) -> &'static str {
+        match (self, b) {
+            (KeywordCase::Lower, true) => "true",
+            (KeywordCase::Lower, false) => "false",
+            (KeywordCase::Upper, true) => "TRUE",
+            (KeywordCase::Upper, false) => "FALSE",
+            (KeywordCase::Title, true) => "True",
+            (KeywordCase::Title, false) => "False",
+        }
+    }
+
+    /// The value `token` stands for if it is a keyword in this case (or in
+    /// lowercase), otherwise `None`.
+    pub(crate) fn keyword(self, token: &str) -> Option<Value> {
+        [KeywordCase::Lower, self].into_iter().find_map(|case| {
+            if token == case.null() {
+                Some(Value::Null)
+            } else if token == case.bool(true) {
+                Some(Value::Bool(true))
+            } else if token == case.bool(false) {
+                Some(Value::Bool(false))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// Declared type of a tabular column in a typed header (`{id:int,name:str}`).
+///
+/// Typed headers are a superset of TOON v3.0, emitted only when
+/// [`EncodeOptions::typed_columns`](crate::EncodeOptions::typed_columns) is set.
+/// The decoder always honours them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ColumnType {
+    Int,
+    Float,
+    Str,
+    Bool,
+}
+
+impl ColumnType {
+    /// The annotation written after the field name.
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            ColumnType::Int => "int",
+            ColumnType::Float => "float",
+            ColumnType::Str => "str",
+            ColumnType::Bool => "bool",
+        }
+    }
+
+    /// Parse an annotation; unknown names are not type hints.
+    pub(crate) fn from_annotation(s: &str) -> Option<Self> {
+        match s {
+            "int" => Some(ColumnType::Int),
+            "float" => Some(ColumnType::Float),
+            "str" => Some(ColumnType::Str),
+            "bool" => Some(ColumnType::Bool),
+            _ => None,
+        }
+    }
+}
@@ -235,6 +235,29 @@ fn decode_inline_array_with_quoted_value() {
     assert_json_eq(&json, r#"{"items":["a,b","c"]}"#);
 }
 
+#[test]
+fn decode_inline_array_without_space_after_colon() {
+    // Hand-written `key[N]:v1,v2` decodes the same as the encoder's `key[N]: v1,v2`.
+    let spaced = decode("items[2]: 1,2").unwrap();
+    let unspaced = decode("items[2]:1,2").unwrap();
+    assert_json_eq(&unspaced, r#"{"items":[1,2]}"#);
+    assert_eq!(unspaced, spaced);
+
+    assert_json_eq(&decode("[2]:a,b").unwrap(), r#"["a","b"]"#);
+    assert_json_eq(
+        &decode("tags[2]:\"x,y\",z\nn: 1").unwrap(),
+        r#"{"tags":["x,y","z"],"n":1}"#,
+    );
+}
+
+#[test]
+fn decode_escaped_forward_slash_as_json_does() {
+    let json = decode(r#"path: "path\/to""#).unwrap();
+    assert_json_eq(&json, r#"{"path":"path/to"}"#);
+    let json = decode("rows[1]{\"a\\/b\"}:\n  \"x\\/y\"").unwrap();
+    assert_json_eq(&json, r#"{"rows":[{"a/b":"x/y"}]}"#);
+}
+
 #[test]
 fn decode_empty_array() {
     let toon = "items[0]:";
@@ -305,6 +328,111 @@ fn decode_tabular_with_null() {
     assert_json_eq(&json, r#"{"rows":[{"a":1,"b":null},{"a":null,"b":2}]}"#);
 }
 
+#[test]
+fn decode_tabular_empty_cell_is_null_in_position() {
+    // Missing → null: an empty cell keeps later cells in their own columns.
+    let toon = "rows[3]{a,b,c}:\n  1,,3\n  ,5,6\n  7,8,";
+    let json = decode(toon).unwrap();
+    assert_json_eq(
+        &json,
+        r#"{"rows":[{"a":1,"b":null,"c":3},{"a":null,"b":5,"c":6},{"a":7,"b":8,"c":null}]}"#,
+    );
+}
+
+#[test]
+fn decode_tabular_trailing_empty_cell_counts_under_strict() {
+    use toon_core::{decode_with_options, DecodeOptions};
+
+    let opts = DecodeOptions {
+        strict_tabular: true,
+        ..DecodeOptions::default()
+    };
+    let json = decode_with_options("rows[1]{a,b}:\n  1,", &opts).unwrap();
+    assert_json_eq(&json, r#"{"rows":[{"a":1,"b":null}]}"#);
+}
+
+#[test]
+fn decode_inline_empty_cell_is_null() {
+    let json = decode("vals[3]: a,,\"\"").unwrap();
+    assert_json_eq(&json, r#"{"vals":["a",null,""]}"#);
+}
+
+#[test]
+fn decode_inline_trailing_delimiter_adds_no_phantom_element() {
+    let json = decode("items[2]: 1,2,").unwrap();
+    assert_json_eq(&json, r#"{"items":[1,2]}"#);
+
+    let json = decode("tags[2]: \"a\",\"b\",").unwrap();
+    assert_json_eq(&json, r#"{"tags":["a","b"]}"#);
+
+    // When the count already matches, a trailing empty cell is a real missing value.
+    let json = decode("items[3]: 1,2,").unwrap();
+    assert_json_eq(&json, r#"{"items":[1,2,null]}"#);
+}
+
+#[test]
+fn decode_tabular_trailing_delimiter_adds_no_phantom_field() {
+    let json = decode("rows[2]{a,b}:\n  1,2,\n  3,4").unwrap();
+    assert_json_eq(&json, r#"{"rows":[{"a":1,"b":2},{"a":3,"b":4}]}"#);
+}
+
+#[test]
+fn decode_tabular_trailing_delimiter_errors_under_strict() {
+    use toon_core::{decode_with_options, DecodeOptions, ToonError};
+
+    let opts = DecodeOptions {
+        strict_tabular: true,
+        ..DecodeOptions::default()
+    };
+    let err = decode_with_options("rows[2]{a,b}:\n  1,2,\n  3,4", &opts).unwrap_err();
+    assert!(
+        matches!(err, ToonError::ToonParse { line: 2, ref message } if message.contains("3 cells, expected 2")),
+        "got {err:?}"
+    );
+}
+
+/// Bare-empty, quoted-empty and `null` cells, in a table and an inline array.
+const EMPTY_CELL_MATRIX: &str = "rows[1]{bare,quoted,token}:\n  ,\"\",null\nvals[3]: ,\"\",null";
+
+#[test]
+fn decode_empty_cell_matrix_default() {
+    let json = decode(EMPTY_CELL_MATRIX).unwrap();
+    assert_json_eq(
+        &json,
+        r#"{"rows":[{"bare":null,"quoted":"","token":null}],"vals":[null,"",null]}"#,
+    );
+}
+
+#[test]
+fn decode_empty_cell_matrix_as_strings() {
+    use toon_core::{decode_with_options, DecodeOptions};
+
+    let opts = DecodeOptions {
+        empty_cells_as_strings: true,
+        ..DecodeOptions::default()
+    };
+    let json = decode_with_options(EMPTY_CELL_MATRIX, &opts).unwrap();
+    assert_json_eq(
+        &json,
+        r#"{"rows":[{"bare":"","quoted":"","token":null}],"vals":["","",null]}"#,
+    );
+    // Padding for a short row is still a missing value.
+    let json = decode_with_options("rows[1]{a,b}:\n  1", &opts).unwrap();
+    assert_json_eq(&json, r#"{"rows":[{"a":1,"b":null}]}"#);
+}
+
+#[test]
+fn decode_empty_cell_as_string_only_in_str_columns() {
+    use toon_core::{decode_with_options, DecodeOptions};
+
+    let opts = DecodeOptions {
+        empty_cells_as_strings: true,
+        ..DecodeOptions::default()
+    };
+    let json = decode_with_options("rows[1]{n:int,s:str}:\n  ,", &opts).unwrap();
+    assert_json_eq(&json, r#"{"rows":[{"n":null,"s":""}]}"#);
+}
+
 // ============================================================================
 // Mixed / Expanded Arrays (List Items)
 // ============================================================================
@@ -355,6 +483,47 @@ fn decode_list_item_with_array_field() {
     );
 }
 
+#[test]
+fn decode_list_item_with_array_as_first_field() {
+    let toon = "items[1]:\n  - tags[2]: a,b";
+    let json = decode(toon).unwrap();
+    assert_json_eq(&json, r#"{"items":[{"tags":["a","b"]}]}"#);
+}
+
+#[test]
+fn decode_list_item_with_array_first_then_sibling_fields() {
+    let toon = "items[2]:\n  - tags[2]: a,b\n    name: x\n  - tags[0]:\n    name: y";
+    let json = decode(toon).unwrap();
+    assert_json_eq(
+        &json,
+        r#"{"items":[{"tags":["a","b"],"name":"x"},{"tags":[],"name":"y"}]}"#,
+    );
+}
+
+#[test]
+fn decode_list_item_with_multiline_tabular_first_field() {
+    // Rows sit two spaces deeper than the item's sibling fields.
+    let toon = "items[2]:\n  - rows[2]{a,b}:\n      1,2\n      3,4\n    name: x\n  - tags[2]: a,b";
+    let json = decode(toon).unwrap();
+    assert_json_eq(
+        &json,
+        r#"{"items":[{"rows":[{"a":1,"b":2},{"a":3,"b":4}],"name":"x"},{"tags":["a","b"]}]}"#,
+    );
+}
+
+#[test]
+fn list_item_with_leading_array_field_roundtrips() {
+    let value = serde_json::json!({
+        "items": [
+            {"rows": [{"a": 1, "b": 2}, {"a": 3, "b": 4}], "name": "x"},
+            {"tags": ["a", "b"], "name": "y"}
+        ]
+    });
+    let toon = toon_core::encode(&value.to_string()).unwrap();
+    let json = decode(&toon).unwrap();
+    assert_json_eq(&json, &value.to_string());
+}
+
 // ============================================================================
 // String Value Type Inference
 // ============================================================================
@@ -528,3 +697,874 @@ fn decode_non_uniform_objects_in_array() {
     let json = decode(toon).unwrap();
     assert_json_eq(&json, r#"{"items":[{"a":1},{"b":2}]}"#);
 }
+
+// ============================================================================
+// Source Spans (decode_with_spans)
+// ============================================================================
+
+#[test]
+fn decode_with_spans_maps_paths_to_lines() {
+    use toon_core::decoder::decode_with_spans;
+
+    let toon = "\
+calendar:
+  id: primary
+  owner:
+    name: Ada
+items[2]:
+  - summary: Standup
+    attendees[2]: ada,bob
+  - summary: Review
+    room: B
+rows[2]{id,title}:
+  1,Alpha
+  2,Beta";
+    let (value, spans) = decode_with_spans(toon).unwrap();
+
+    // Same value as plain decode.
+    let plain: serde_json::Value = serde_json::from_str(&decode(toon).unwrap()).unwrap();
+    assert_eq!(value, plain);
+
+    assert_eq!(spans[""], (1, 12));
+    assert_eq!(spans["/calendar"], (1, 4));
+    assert_eq!(spans["/calendar/id"], (2, 2));
+    assert_eq!(spans["/calendar/owner/name"], (4, 4));
+    assert_eq!(spans["/items"], (5, 9));
+    assert_eq!(spans["/items/0"], (6, 7));
+    assert_eq!(spans["/items/0/summary"], (6, 6));
+    assert_eq!(spans["/items/0/attendees/1"], (7, 7));
+    assert_eq!(spans["/items/1/room"], (9, 9));
+    assert_eq!(spans["/rows"], (10, 12));
+    assert_eq!(spans["/rows/1"], (12, 12));
+    assert_eq!(spans["/rows/1/title"], (12, 12));
+}
+
+#[test]
+fn decode_with_spans_escapes_pointer_segments() {
+    use toon_core::decoder::decode_with_spans;
+
+    let (_, spans) = decode_with_spans("\"a/b\": 1\n\"c~d\": 2").unwrap();
+    assert_eq!(spans["/a~1b"], (1, 1));
+    assert_eq!(spans["/c~0d"], (2, 2));
+}
+
+// ============================================================================
+// Ragged Tabular Rows (DecodeOptions::strict_tabular)
+// ============================================================================
+
+const SHORT_ROW: &str = "rows[2]{id,name,active}:\n  1,Alice,true\n  2,Bob";
+const LONG_ROW: &str = "rows[2]{id,name}:\n  1,Alice\n  2,Bob,extra";
+
+#[test]
+fn decode_lenient_pads_short_tabular_row_with_null() {
+    let json = decode(SHORT_ROW).unwrap();
+    assert_json_eq(
+        &json,
+        r#"{"rows":[{"id":1,"name":"Alice","active":true},{"id":2,"name":"Bob","active":null}]}"#,
+    );
+}
+
+#[test]
+fn decode_lenient_drops_extra_tabular_cells() {
+    let json = decode(LONG_ROW).unwrap();
+    assert_json_eq(
+        &json,
+        r#"{"rows":[{"id":1,"name":"Alice"},{"id":2,"name":"Bob"}]}"#,
+    );
+}
+
+#[test]
+fn decode_strict_rejects_short_tabular_row() {
+    use toon_core::{decode_with_options, DecodeOptions, ToonError};
+
+    let opts = DecodeOptions {
+        strict_tabular: true,
+        ..DecodeOptions::default()
+    };
+    match decode_with_options(SHORT_ROW, &opts) {
+        Err(ToonError::ToonParse { line, message }) => {
+            assert_eq!(line, 3);
+            assert!(message.contains("2 cells, expected 3"), "got: {message}");
+        }
+        other => panic!("expected ToonParse error, got {other:?}"),
+    }
+}
+
+#[test]
+fn decode_strict_rejects_long_tabular_row() {
+    use toon_core::{decode_with_options, DecodeOptions, ToonError};
+
+    let opts = DecodeOptions {
+        strict_tabular: true,
+        ..DecodeOptions::default()
+    };
+    match decode_with_options(LONG_ROW, &opts) {
+        Err(ToonError::ToonParse { line, message }) => {
+            assert_eq!(line, 3);
+            assert!(message.contains("3 cells, expected 2"), "got: {message}");
+        }
+        other => panic!("expected ToonParse error, got {other:?}"),
+    }
+}
+
+#[test]
+fn decode_strict_accepts_well_formed_table() {
+    use toon_core::{decode_with_options, DecodeOptions};
+
+    let opts = DecodeOptions {
+        strict_tabular: true,
+        ..DecodeOptions::default()
+    };
+    let toon = "rows[2]{id,name}:\n  1,Alice\n  2,Bob";
+    assert_eq!(
+        decode_with_options(toon, &opts).unwrap(),
+        decode(toon).unwrap()
+    );
+}
+
+// ============================================================================
+// Unknown Escapes (DecodeOptions::strict_escapes)
+// ============================================================================
+
+fn strict_escapes() -> toon_core::DecodeOptions {
+    toon_core::DecodeOptions {
+        strict_escapes: true,
+        ..toon_core::DecodeOptions::default()
+    }
+}
+
+#[test]
+fn decode_lenient_keeps_unknown_escape_literally() {
+    let json = decode(r#"s: "a\qb""#).unwrap();
+    assert_json_eq(&json, r#"{"s":"a\\qb"}"#);
+}
+
+#[test]
+fn decode_strict_escapes_rejects_unknown_escape() {
+    use toon_core::{decode_with_options, ToonError};
+
+    match decode_with_options("id: 1\ns: \"a\\qb\"", &strict_escapes()) {
+        Err(ToonError::ToonParse { line, message }) => {
+            assert_eq!(line, 2);
+            assert!(message.contains(r"'\q'"), "got: {message}");
+        }
+        other => panic!("expected ToonParse error, got {other:?}"),
+    }
+    // Cells, keys and header fields are checked too.
+    for toon in [
+        "rows[1]{a,b}:\n  1,\"x\\y\"",
+        "\"k\\e\": 1",
+        "rows[1]{\"a\\b\"}:\n  1",
+    ] {
+        assert!(
+            decode_with_options(toon, &strict_escapes()).is_err(),
+            "should reject: {toon}"
+        );
+    }
+}
+
+#[test]
+fn decode_strict_escapes_accepts_known_escapes_and_unquoted_text() {
+    use toon_core::decode_with_options;
+
+    let toon = r#"s: "tab\there \"q\" back\\slash\r\n"
+path: C:\dir
+note: say "hi\q""#;
+    assert_eq!(
+        decode_with_options(toon, &strict_escapes()).unwrap(),
+        decode(toon).unwrap()
+    );
+}
+
+#[test]
+fn decode_rejects_escaped_delimiter_in_unquoted_cell() {
+    use toon_core::ToonError;
+
+    for toon in ["tags[2]: a\\,b,c", "rows[1]{x,y}:\n  a\\,b"] {
+        let err = decode(toon).unwrap_err();
+        assert!(
+            matches!(err, ToonError::ToonParse { ref message, .. } if message.contains("Escaped delimiter")),
+            "{toon}: got {err:?}"
+        );
+    }
+    // Quoting is the supported way to put a delimiter in a value.
+    assert_json_eq(
+        &decode("tags[2]: \"a,b\",c").unwrap(),
+        r#"{"tags":["a,b","c"]}"#,
+    );
+    // A backslash not followed by a delimiter is ordinary text.
+    assert_json_eq(
+        &decode("tags[2]: a\\b,c").unwrap(),
+        r#"{"tags":["a\\b","c"]}"#,
+    );
+}
+
+#[test]
+fn decode_unicode_keys_hand_written() {
+    assert_json_eq(
+        &decode("\"café\":\n  \"日本語\"[2]: 東京,大阪\n  \"🎉\": true").unwrap(),
+        r#"{"café":{"日本語":["東京","大阪"],"🎉":true}}"#,
+    );
+    assert_json_eq(
+        &decode("\"出席者\"[1]{\"名前\",\"🎂\"}:\n  Ada,12-10").unwrap(),
+        r#"{"出席者":[{"名前":"Ada","🎂":"12-10"}]}"#,
+    );
+    // Byte-index helpers must not split a multibyte char in a malformed key.
+    assert!(decode("\"é").is_ok());
+    assert!(decode("x[1]{\"é}:\n  1").is_ok());
+}
+
+#[test]
+fn decode_unicode_whitespace_is_not_indentation() {
+    // A leading ideographic or no-break space is not an indent: the line stays
+    // at its real depth instead of being dropped as misindented.
+    assert_json_eq(&decode("\u{3000}x: 1").unwrap(), r#"{"x":1}"#);
+    assert_json_eq(
+        &decode("a:\n  b: 1\n\u{a0}c: 2").unwrap(),
+        r#"{"a":{"b":1},"c":2}"#,
+    );
+}
+
+// ============================================================================
+// Untrusted Input
+// ============================================================================
+
+/// `depth` nested arrays under `x`, written as expanded lists.
+fn nested_lists(depth: usize) -> String {
+    let mut toon = String::from("x[1]:\n");
+    for level in 0..depth {
+        toon.push_str(&" ".repeat(2 + level * 2));
+        toon.push_str("- [1]:\n");
+    }
+    toon.push_str(&" ".repeat(2 + depth * 2));
+    toon.push_str("- 1");
+    toon
+}
+
+#[test]
+fn decode_rejects_excessive_nesting_instead_of_overflowing() {
+    use toon_core::ToonError;
+
+    match decode(&nested_lists(1000)) {
+        Err(ToonError::ToonParse { message, .. }) => {
+            assert!(message.contains("Nesting exceeds"), "got: {message}");
+        }
+        other => panic!("expected ToonParse error, got {other:?}"),
+    }
+}
+
+#[test]
+fn decode_accepts_nesting_within_limit() {
+    let decoded: serde_json::Value =
+        serde_json::from_str(&decode(&nested_lists(100)).unwrap()).unwrap();
+    let mut inner = &decoded["x"];
+    for _ in 0..100 {
+        inner = &inner[0];
+    }
+    assert_eq!(inner[0], 1);
+}
+
+#[test]
+fn decode_multibyte_after_quoted_key_does_not_panic() {
+    assert!(decode("\"k\"\u{00e9}").is_ok());
+    assert!(decode("- \"\\\u{4f60}\"").is_ok());
+    assert!(decode("a[1]: \"\\\u{1f600}").is_err());
+}
+
+// ============================================================================
+// Typed tabular columns
+// ============================================================================
+
+#[test]
+fn decode_typed_header_strips_annotations() {
+    let toon = "items[2]{id:int,name:str,active:bool}:\n  1,Alice,true\n  2,Bob,false";
+    assert_json_eq(
+        &decode(toon).unwrap(),
+        r#"{"items":[{"id":1,"name":"Alice","active":true},{"id":2,"name":"Bob","active":false}]}"#,
+    );
+}
+
+#[test]
+fn decode_typed_column_forces_declared_type() {
+    // Unquoted 42 and true in a str column are strings; quoted "7" in an int
+    // column and "2.5" in a float column are numbers.
+    let toon = "rows[1]{label:str,flag:str,n:int,x:float}:\n  42,true,\"7\",\"2.5\"";
+    assert_json_eq(
+        &decode(toon).unwrap(),
+        r#"{"rows":[{"label":"42","flag":"true","n":7,"x":2.5}]}"#,
+    );
+}
+
+#[test]
+fn decode_typed_column_keeps_nulls() {
+    let toon = "rows[2]{n:int,s:str}:\n  null,\n  1,\"null\"";
+    assert_json_eq(
+        &decode(toon).unwrap(),
+        r#"{"rows":[{"n":null,"s":null},{"n":1,"s":"null"}]}"#,
+    );
+}
+
+#[test]
+fn decode_typed_column_rejects_mismatched_cell() {
+    use toon_core::ToonError;
+
+    match decode("rows[2]{n:int}:\n  1\n  abc") {
+        Err(ToonError::ToonParse { line, message }) => {
+            assert_eq!(line, 3);
+            assert!(message.contains("column 'n'"), "got: {message}");
+        }
+        other => panic!("expected ToonParse error, got {other:?}"),
+    }
+}
+
+// ============================================================================
+// Canonical key order (decode_sorted)
+// ============================================================================
+
+#[test]
+fn decode_sorted_is_independent_of_source_key_order() {
+    use toon_core::decode_sorted;
+
+    let a = "name: Standup\nmeta:\n  zone: UTC\n  at: 9\nattendees[2]{role,email}:\n  host,a@x.io\n  guest,b@x.io";
+    let b = "attendees[2]{email,role}:\n  a@x.io,host\n  b@x.io,guest\nmeta:\n  at: 9\n  zone: UTC\nname: Standup";
+    assert_ne!(decode(a).unwrap(), decode(b).unwrap());
+    assert_eq!(decode_sorted(a).unwrap(), decode_sorted(b).unwrap());
+    assert_eq!(
+        decode_sorted(a).unwrap(),
+        r#"{"attendees":[{"email":"a@x.io","role":"host"},{"email":"b@x.io","role":"guest"}],"meta":{"at":9,"zone":"UTC"},"name":"Standup"}"#
+    );
+}
+
+#[test]
+fn decode_sorted_keeps_array_order() {
+    assert_eq!(
+        toon_core::decode_sorted("tags[3]: c,a,b").unwrap(),
+        r#"{"tags":["c","a","b"]}"#
+    );
+}
+
+// ============================================================================
+// Reader decoding (decode_from_reader)
+// ============================================================================
+
+/// A root array of `n` event objects, some with nested arrays and multi-line values.
+fn large_event_array(n: usize) -> String {
+    let rows: Vec<serde_json::Value> = (0..n)
+        .map(|i| {
+            serde_json::json!({
+                "id": i,
+                "title": format!("Event, #{i}"),
+                "busy": i % 3 == 0,
+                "tags": if i % 5 == 0 { serde_json::json!(["a", "b"]) } else { serde_json::json!([]) },
+                "meta": { "score": i as f64 / 4.0, "note": null },
+            })
+        })
+        .collect();
+    serde_json::Value::Array(rows).to_string()
+}
+
+#[test]
+fn decode_from_reader_matches_in_memory_for_large_list() {
+    use toon_core::{decode_from_reader, encode};
+
+    let toon = encode(&large_event_array(5_000)).unwrap();
+    assert!(toon.starts_with("[5000]:\n  - "));
+
+    let from_reader = decode_from_reader(std::io::Cursor::new(toon.as_bytes())).unwrap();
+    let in_memory: serde_json::Value = serde_json::from_str(&decode(&toon).unwrap()).unwrap();
+    assert_eq!(from_reader, in_memory);
+}
+
+#[test]
+fn decode_from_reader_matches_in_memory_for_large_table() {
+    use toon_core::decode_from_reader;
+
+    let mut toon = String::from("[10000]{id:int,title,busy,score}:\n");
+    for i in 0..10_000 {
+        let score = if i % 7 == 0 {
+            "null".to_string()
+        } else {
+            (i as f64 / 4.0).to_string()
+        };
+        toon.push_str(&format!("  {i},\"Event, #{i}\",{},{score}\n", i % 3 == 0));
+    }
+
+    let from_reader = decode_from_reader(std::io::Cursor::new(toon.as_bytes())).unwrap();
+    let in_memory: serde_json::Value = serde_json::from_str(&decode(&toon).unwrap()).unwrap();
+    assert_eq!(from_reader.as_array().unwrap().len(), 10_000);
+    assert_eq!(from_reader, in_memory);
+}
+
+#[test]
+fn decode_from_reader_falls_back_for_other_documents() {
+    use toon_core::decode_from_reader;
+
+    let toon = "name: Standup\nattendees[2]{email,role}:\n  a@x.io,host\n  b@x.io,guest\n";
+    let expected: serde_json::Value = serde_json::from_str(&decode(toon).unwrap()).unwrap();
+    assert_eq!(decode_from_reader(toon.as_bytes()).unwrap(), expected);
+    assert_eq!(
+        decode_from_reader("[3]: 1,2,3\r\n".as_bytes()).unwrap(),
+        serde_json::json!([1, 2, 3])
+    );
+    assert_eq!(
+        decode_from_reader("".as_bytes()).unwrap(),
+        serde_json::json!({})
+    );
+}
+
+#[test]
+fn decode_from_reader_reports_invalid_utf8_as_such() {
+    use toon_core::{decode_from_reader, ToonError};
+
+    // Latin-1 "café" in the fallback path and in a streamed table row.
+    for bytes in [
+        &b"name: caf\xe9\n"[..],
+        &b"[2]{id,name}:\n  1,Ada\n  2,caf\xe9\n"[..],
+    ] {
+        let err = decode_from_reader(bytes).unwrap_err();
+        assert!(matches!(err, ToonError::InvalidUtf8(_)), "got {err:?}");
+    }
+}
+
+#[test]
+fn decode_from_reader_reports_document_line_numbers() {
+    use toon_core::{decode_from_reader, ToonError};
+
+    let err = decode_from_reader("[2]{id:int}:\n  1\n  x\n".as_bytes()).unwrap_err();
+    assert!(
+        matches!(err, ToonError::ToonParse { line: 3, .. }),
+        "got {err}"
+    );
+
+    let err = decode_from_reader("[2]:\n  - a: 1\n  - b[1]: \"x\n".as_bytes()).unwrap_err();
+    assert!(
+        matches!(err, ToonError::ToonParse { line: 3, .. }),
+        "got {err}"
+    );
+}
+
+#[test]
+fn decode_from_reader_with_options_applies_them_while_streaming() {
+    use toon_core::{decode_from_reader_with_options, DecodeOptions, Delimiter, KeywordCase};
+
+    let piped = DecodeOptions {
+        delimiter: Delimiter::Pipe,
+        keyword_case: KeywordCase::Upper,
+        ..DecodeOptions::default()
+    };
+    let table = "[2]{id,name,busy}:\n  1|Ada, Countess|TRUE\n  2|Grace|FALSE\n";
+    assert_eq!(
+        decode_from_reader_with_options(table.as_bytes(), &piped).unwrap(),
+        serde_json::json!([
+            {"id": 1, "name": "Ada, Countess", "busy": true},
+            {"id": 2, "name": "Grace", "busy": false},
+        ])
+    );
+
+    let list = "[2]:\n  - tags[2]: a|b\n  - NULL\n";
+    assert_eq!(
+        decode_from_reader_with_options(list.as_bytes(), &piped).unwrap(),
+        serde_json::json!([{"tags": ["a", "b"]}, null])
+    );
+}
+
+#[test]
+fn decode_from_reader_with_options_rejects_what_decode_with_options_does() {
+    use toon_core::{
+        decode_from_reader_with_options, decode_with_options, DecodeOptions, ToonError,
+    };
+
+    let cases = [
+        (
+            "[2]{a,b}:\n  1,2\n  3\n",
+            DecodeOptions {
+                strict_tabular: true,
+                ..DecodeOptions::default()
+            },
+            3,
+        ),
+        (
+            "[3]{a}:\n  1\n  2\n",
+            DecodeOptions {
+                assert_counts: true,
+                ..DecodeOptions::default()
+            },
+            1,
+        ),
+        (
+            "[1]:\n  - a\n  - b\n",
+            DecodeOptions {
+                assert_counts: true,
+                ..DecodeOptions::default()
+            },
+            1,
+        ),
+        (
+            "[1]{a}:\n  \"x\\q\"\n",
+            DecodeOptions {
+                strict_escapes: true,
+                ..DecodeOptions::default()
+            },
+            2,
+        ),
+        (
+            "[1]:\n  - a\nstray\n",
+            DecodeOptions {
+                require_full_consume: true,
+                ..DecodeOptions::default()
+            },
+            3,
+        ),
+    ];
+    for (toon, options, line) in cases {
+        assert!(decode_with_options(toon, &options).is_err(), "{toon:?}");
+        let err = decode_from_reader_with_options(toon.as_bytes(), &options).unwrap_err();
+        assert!(
+            matches!(err, ToonError::ToonParse { line: l, .. } if l == line),
+            "{toon:?}: got {err}"
+        );
+    }
+}
+
+// ============================================================================
+// Authoritative array counts (DecodeOptions::assert_counts)
+// ============================================================================
+
+fn assert_counts() -> toon_core::DecodeOptions {
+    toon_core::DecodeOptions {
+        assert_counts: true,
+        ..toon_core::DecodeOptions::default()
+    }
+}
+
+#[test]
+fn encoded_counts_pass_strict_count_validation() {
+    let json = r#"{"tags":["a","b","c"],"rows":[{"id":1,"ok":true},{"id":2,"ok":false}],"mixed":[1,{"x":2},[3]],"none":[]}"#;
+    let toon = toon_core::encode(json).unwrap();
+    let decoded = toon_core::decode_with_options(&toon, &assert_counts()).unwrap();
+    assert_json_eq(&decoded, json);
+}
+
+#[test]
+fn corrupted_counts_fail_strict_count_validation() {
+    use toon_core::{decode_with_options, ToonError};
+
+    let json =
+        r#"{"name":"x","tags":["a","b","c"],"rows":[{"id":1},{"id":2}],"mixed":[1,{"x":2}]}"#;
+    let toon = toon_core::encode(json).unwrap();
+    for (from, to, line) in [
+        ("tags[3]", "tags[4]", 2),
+        ("rows[2]", "rows[3]", 3),
+        ("mixed[2]", "mixed[1]", 6),
+    ] {
+        let corrupted = toon.replace(from, to);
+        // Lenient decoding takes the elements as found.
+        assert!(toon_core::decode(&corrupted).is_ok());
+        let err = decode_with_options(&corrupted, &assert_counts()).unwrap_err();
+        assert!(
+            matches!(err, ToonError::ToonParse { line: l, ref message } if l == line && message.contains("declares")),
+            "{to}: got {err:?}"
+        );
+    }
+}
+
+// ============================================================================
+// Un-indented children (DecodeOptions::strict_nesting)
+// ============================================================================
+
+#[test]
+fn unindented_children_decode_as_siblings_by_default() {
+    let toon = "user:\nid: 1\nname: Ada";
+    assert_json_eq(&decode(toon).unwrap(), r#"{"user":{},"id":1,"name":"Ada"}"#);
+}
+
+#[test]
+fn underindented_children_are_skipped_by_default() {
+    assert_json_eq(
+        &decode("user:\n id: 1\nname: Ada").unwrap(),
+        r#"{"user":{},"name":"Ada"}"#,
+    );
+}
+
+#[test]
+fn strict_nesting_rejects_underindented_children() {
+    use toon_core::{decode_with_options, DecodeOptions, ToonError};
+
+    let strict = DecodeOptions {
+        strict_nesting: true,
+        ..DecodeOptions::default()
+    };
+    for (toon, line) in [
+        ("user:\n id: 1\nname: Ada", 2),
+        ("team:\n  lead:\n   id: 7", 3),
+        ("user:\n\n id: 1", 3),
+    ] {
+        let err = decode_with_options(toon, &strict).unwrap_err();
+        assert!(
+            matches!(err, ToonError::ToonParse { line: l, ref message } if l == line && message.contains("under-indented")),
+            "{toon}: got {err:?}"
+        );
+    }
+    // Properly indented children and a trailing empty object are fine.
+    assert_json_eq(
+        &decode_with_options("user:\n  id: 1\nmeta:", &strict).unwrap(),
+        r#"{"user":{"id":1},"meta":{}}"#,
+    );
+    // Un-indented children read as siblings, like the encoder's empty objects.
+    assert_json_eq(
+        &decode_with_options("user:\nid: 1", &strict).unwrap(),
+        r#"{"user":{},"id":1}"#,
+    );
+}
+
+#[test]
+fn strict_nesting_accepts_encoder_output_with_empty_objects() {
+    use toon_core::{decode_with_options, encode, DecodeOptions};
+
+    let strict = DecodeOptions {
+        strict_nesting: true,
+        ..DecodeOptions::default()
+    };
+    for json in [
+        r#"{"config":{},"name":"x"}"#,
+        r#"{"a":{"b":{},"c":{"d":{}},"e":1},"f":{}}"#,
+        r#"{"items":[{"meta":{},"id":1},{"id":2}]}"#,
+    ] {
+        let toon = encode(json).unwrap();
+        assert_json_eq(&decode_with_options(&toon, &strict).unwrap(), json);
+    }
+}
+
+// ============================================================================
+// Unconsumed lines (DecodeOptions::require_full_consume)
+// ============================================================================
+
+#[test]
+fn stray_lines_are_ignored_by_default() {
+    assert_json_eq(
+        &decode("a: 1\nb: 2\ngarbage line").unwrap(),
+        r#"{"a":1,"b":2,"garbage line":null}"#,
+    );
+    assert_json_eq(
+        &decode("a: 1\n  garbage line\nb: 2").unwrap(),
+        r#"{"a":1,"b":2}"#,
+    );
+}
+
+#[test]
+fn require_full_consume_rejects_stray_lines() {
+    use toon_core::{decode_with_options, DecodeOptions, ToonError};
+
+    let strict = DecodeOptions {
+        require_full_consume: true,
+        ..DecodeOptions::default()
+    };
+    for (toon, line) in [
+        ("a: 1\nb: 2\ngarbage line", 3),
+        ("a: 1\n  garbage line\nb: 2", 2),
+        ("user:\n  id: 1\n      deeper\n  name: Ada", 3),
+        ("tags[2]: a,b\n  stray\n  more", 2),
+    ] {
+        let err = decode_with_options(toon, &strict).unwrap_err();
+        assert!(
+            matches!(err, ToonError::ToonParse { line: l, .. } if l == line),
+            "{toon}: got {err:?}"
+        );
+    }
+    // Every line of a well-formed document is consumed.
+    assert_json_eq(
+        &decode_with_options(
+            "user:\n  id: 1\n  tags[2]: a,b\nrows[2]{x,y}:\n  1,2\n  3,4\nitems[2]:\n  - a: 1\n    b: 2\n  - 3\n\n",
+            &strict,
+        )
+        .unwrap(),
+        r#"{"user":{"id":1,"tags":["a","b"]},"rows":[{"x":1,"y":2},{"x":3,"y":4}],"items":[{"a":1,"b":2},3]}"#,
+    );
+}
+
+#[test]
+fn require_full_consume_rejects_stray_lines_after_root_arrays_and_in_list_items() {
+    use toon_core::{decode_with_options, DecodeOptions, ToonError};
+
+    let strict = DecodeOptions {
+        require_full_consume: true,
+        ..DecodeOptions::default()
+    };
+    for (toon, line) in [
+        ("[2]: 1,2\ngarbage line", 2),
+        ("[2]{x,y}:\n  1,2\n  3,4\nextra: 1", 4),
+        ("[2]:\n  - 1\n  - 2\n\nextra", 5),
+        ("[0]:\nextra: 1", 2),
+        ("items[1]:\n  - a: 1\n      stray\n    b: 2", 3),
+        ("[1]:\n  - a: 1\n    b: 2\n      stray", 4),
+        ("[2]:\n  - 1\n    stray\n  - 2", 3),
+    ] {
+        let err = decode_with_options(toon, &strict).unwrap_err();
+        assert!(
+            matches!(err, ToonError::ToonParse { line: l, .. } if l == line),
+            "{toon}: got {err:?}"
+        );
+    }
+    // Well-formed root arrays, including nested bodies, are fully consumed.
+    for (toon, json) in [
+        ("[2]: 1,2\n", "[1,2]"),
+        (
+            "[2]{x,y}:\n  1,2\n  3,4",
+            r#"[{"x":1,"y":2},{"x":3,"y":4}]"#,
+        ),
+        (
+            "[2]:\n  - a: 1\n    tags[2]: x,y\n    user:\n      id: 2\n  - [1]: 3",
+            r#"[{"a":1,"tags":["x","y"],"user":{"id":2}},[3]]"#,
+        ),
+    ] {
+        assert_json_eq(&decode_with_options(toon, &strict).unwrap(), json);
+    }
+}
+
+// ============================================================================
+// Large tables
+// ============================================================================
+
+/// A `cols` × `rows` tabular document, alternating bare and quoted cells.
+fn wide_table(cols: usize, rows: usize) -> String {
+    let fields: Vec<String> = (0..cols).map(|c| format!("f{c}")).collect();
+    let mut toon = format!("rows[{rows}]{{{}}}:", fields.join(","));
+    for r in 0..rows {
+        toon.push_str("\n  ");
+        let cells: Vec<String> = (0..cols)
+            .map(|c| {
+                if c % 2 == 0 {
+                    format!("v{r}")
+                } else {
+                    format!("\"a,{c}\"")
+                }
+            })
+            .collect();
+        toon.push_str(&cells.join(","));
+    }
+    toon
+}
+
+/// Fastest of five decodes of `toon`, to damp scheduler noise.
+fn fastest_decode(toon: &str) -> std::time::Duration {
+    (0..5)
+        .map(|_| {
+            let start = std::time::Instant::now();
+            decode(toon).unwrap();
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+#[test]
+fn large_table_decodes_every_cell() {
+    let toon = wide_table(300, 300);
+    let value: serde_json::Value = serde_json::from_str(&decode(&toon).unwrap()).unwrap();
+    let rows = value["rows"].as_array().unwrap();
+    assert_eq!(rows.len(), 300);
+    assert_eq!(rows[299]["f298"], "v299");
+    assert_eq!(rows[299]["f299"], "a,299");
+}
+
+#[test]
+fn table_decode_time_grows_linearly() {
+    // Sixteen times the rows at a fixed width. Linear decoding takes about 16x
+    // as long; a pass that is quadratic in the rows would take about 256x. The
+    // 100x bound is loose enough to hold while other tests run in parallel.
+    let small = fastest_decode(&wide_table(50, 50));
+    let large = fastest_decode(&wide_table(50, 800));
+    assert!(
+        large < small * 100,
+        "16x the rows took {:.1}x as long ({small:?} -> {large:?})",
+        large.as_secs_f64() / small.as_secs_f64()
+    );
+
+    // One very long row: sixteen times the cells on a single line, which a
+    // pass quadratic in the cells would likewise turn into about 256x.
+    let small = fastest_decode(&wide_table(2_000, 1));
+    let large = fastest_decode(&wide_table(32_000, 1));
+    assert!(
+        large < small * 100,
+        "16x the row width took {:.1}x as long ({small:?} -> {large:?})",
+        large.as_secs_f64() / small.as_secs_f64()
+    );
+}
+
+// ============================================================================
+// Raw tabular cells
+// ============================================================================
+
+const CALENDAR_TABLE: &str = r#"{"items":[{"id":"1001","status":"confirmed","summary":"Q1 Strategy Sync","start":"2026-02-17T10:00:00-08:00","sequence":3},{"id":"evt_9f8e","status":"cancelled","summary":"Vendor, Negotiation","start":"2026-02-18T13:00:00-08:00","sequence":0}]}"#;
+
+#[test]
+fn decode_tabular_raw_returns_header_and_string_cells() {
+    let toon = toon_core::encode(CALENDAR_TABLE).unwrap();
+    let (fields, rows) = toon_core::decode_tabular_raw(&toon, toon_core::Delimiter::Comma).unwrap();
+    assert_eq!(fields, ["id", "status", "summary", "start", "sequence"]);
+    assert_eq!(rows.len(), 2);
+    // A quoted numeric-looking id and a bare number both come back as written.
+    assert_eq!(rows[0][0], "1001");
+    assert_eq!(rows[0][4], "3");
+    assert_eq!(rows[1][1], "cancelled");
+    assert_eq!(rows[1][2], "Vendor, Negotiation");
+    assert_eq!(rows[1][3], "2026-02-18T13:00:00-08:00");
+}
+
+#[test]
+fn decode_tabular_raw_splits_on_the_given_delimiter() {
+    let opts = toon_core::EncodeOptions {
+        delimiter: toon_core::Delimiter::Tab,
+        ..toon_core::EncodeOptions::default()
+    };
+    let toon = toon_core::encode_with_options(CALENDAR_TABLE, &opts).unwrap();
+    let (fields, rows) = toon_core::decode_tabular_raw(&toon, toon_core::Delimiter::Tab).unwrap();
+    assert_eq!(fields, ["id", "status", "summary", "start", "sequence"]);
+    assert_eq!(rows[1][2], "Vendor, Negotiation");
+    assert_eq!(rows[1][4], "0");
+
+    // Split on commas instead, the tab-separated rows are one ragged cell.
+    assert!(toon_core::decode_tabular_raw(&toon, toon_core::Delimiter::Comma).is_err());
+}
+
+#[test]
+fn decode_tabular_raw_skips_type_inference() {
+    let toon = "[3]{a:int,b,c}:\n  007,true,\n  1.50,null,\"x\"\n  -0,1e3,\"\"";
+    let (fields, rows) = toon_core::decode_tabular_raw(toon, toon_core::Delimiter::Comma).unwrap();
+    assert_eq!(fields, ["a", "b", "c"]);
+    assert_eq!(
+        rows,
+        [
+            ["007", "true", ""],
+            ["1.50", "null", "x"],
+            ["-0", "1e3", ""]
+        ]
+    );
+}
+
+#[test]
+fn decode_tabular_raw_accepts_empty_table() {
+    let (fields, rows) =
+        toon_core::decode_tabular_raw("rows[0]{a,b}:", toon_core::Delimiter::Comma).unwrap();
+    assert_eq!(fields, ["a", "b"]);
+    assert!(rows.is_empty());
+}
+
+#[test]
+fn decode_tabular_raw_rejects_other_documents() {
+    for toon in [
+        "",
+        "name: Ada",
+        "tags[2]: a,b",
+        "items[1]{a}:\n  1\nname: Ada",
+        "meta:\n  rows[1]{a}:\n    1",
+    ] {
+        assert!(
+            toon_core::decode_tabular_raw(toon, toon_core::Delimiter::Comma).is_err(),
+            "accepted {toon:?}"
+        );
+    }
+    let err = toon_core::decode_tabular_raw("[2]{a,b}:\n  1,2\n  3", toon_core::Delimiter::Comma)
+        .unwrap_err();
+    assert!(err.to_string().contains("line 3"), "{err}");
+}
@@ -4,7 +4,7 @@
 /// is implemented. All tests should FAIL initially (encoder returns todo!()).
 ///
 /// Spec reference: TOON v3.0 (2025-11-24) — github.com/toon-format/spec
-use toon_core::encode;
+use toon_core::{encode, EncodeOptions};
 
 // ============================================================================
 // Primitives
@@ -384,6 +384,65 @@ fn encode_tabular_single_row() {
     assert_eq!(toon, expected);
 }
 
+#[test]
+fn encode_tabular_explicit_null_keeps_column() {
+    let json = r#"{"rows":[{"a":1,"b":null,"c":3},{"a":null,"b":5,"c":6}]}"#;
+    let toon = encode(json).unwrap();
+    assert_eq!(toon, "rows[2]{a,b,c}:\n  1,null,3\n  null,5,6");
+    assert_eq!(toon_core::decode(&toon).unwrap(), json);
+}
+
+#[test]
+fn encode_objects_with_missing_keys_are_not_tabular() {
+    // A row lacking a field would misalign the columns, so the array falls back
+    // to expanded list form instead of a table.
+    let json = r#"{"rows":[{"a":1,"b":2},{"a":3}]}"#;
+    let toon = encode(json).unwrap();
+    assert_eq!(toon, "rows[2]:\n  - a: 1\n    b: 2\n  - a: 3");
+    assert_eq!(toon_core::decode(&toon).unwrap(), json);
+}
+
+#[test]
+fn encode_tabular_reordered_keys_follow_first_object() {
+    // Same key set, different insertion order: still tabular, and every row's
+    // cells are emitted in the first object's column order, looked up by name.
+    let json =
+        r#"{"rows":[{"id":1,"name":"Alice","role":"admin"},{"role":"guest","id":2,"name":"Bob"}]}"#;
+    let toon = encode(json).unwrap();
+    assert_eq!(
+        toon,
+        "rows[2]{id,name,role}:\n  1,Alice,admin\n  2,Bob,guest"
+    );
+    // Decoded rows take the header's key order; values stay with their keys.
+    assert_eq!(
+        toon_core::decode(&toon).unwrap(),
+        r#"{"rows":[{"id":1,"name":"Alice","role":"admin"},{"id":2,"name":"Bob","role":"guest"}]}"#
+    );
+}
+
+#[test]
+fn encode_tabular_field_of_list_item_indents_rows_under_header() {
+    // The header sits at the list item's field indent, so its rows go one level deeper.
+    let json = r#"{"items":[{"id":1,"rows":[{"a":1,"b":2},{"a":3,"b":4}]},"x"]}"#;
+    let toon = encode(json).unwrap();
+    assert_eq!(
+        toon,
+        "items[2]:\n  - id: 1\n    rows[2]{a,b}:\n      1,2\n      3,4\n  - x"
+    );
+    assert_eq!(toon_core::decode(&toon).unwrap(), json);
+}
+
+#[test]
+fn encode_expanded_field_of_list_item_indents_items_under_header() {
+    let json = r#"{"items":[{"id":1,"list":[{"a":1},{"b":2}]},{"id":2}]}"#;
+    let toon = encode(json).unwrap();
+    assert_eq!(
+        toon,
+        "items[2]:\n  - id: 1\n    list[2]:\n      - a: 1\n      - b: 2\n  - id: 2"
+    );
+    assert_eq!(toon_core::decode(&toon).unwrap(), json);
+}
+
 // ============================================================================
 // Mixed / Non-Uniform Arrays (Expanded List)
 // ============================================================================
@@ -405,6 +464,43 @@ fn encode_array_of_non_uniform_objects() {
     assert_eq!(toon, expected);
 }
 
+#[test]
+fn encode_objects_with_stray_primitive_as_list() {
+    // One non-object element disqualifies tabular form
+    let json = r#"{"items":[{"a":1},{"a":2},"oops"]}"#;
+    let toon = encode(json).unwrap();
+    assert_eq!(toon, "items[3]:\n  - a: 1\n  - a: 2\n  - oops");
+}
+
+#[test]
+fn encode_objects_with_missing_key_as_list() {
+    // The second object lacks `b`, so the field sets differ -> list form
+    let json = r#"{"items":[{"a":1,"b":2},{"a":3}]}"#;
+    let toon = encode(json).unwrap();
+    assert_eq!(toon, "items[2]:\n  - a: 1\n    b: 2\n  - a: 3");
+}
+
+#[test]
+fn encode_root_tabular_array() {
+    let json = r#"[{"id":1,"name":"Alice"},{"id":2,"name":"Bob"}]"#;
+    let toon = encode(json).unwrap();
+    assert_eq!(toon, "[2]{id,name}:\n  1,Alice\n  2,Bob");
+}
+
+#[test]
+fn encode_root_tabular_array_honours_records_style() {
+    let json = r#"[{"id":1,"name":"Alice"},{"id":2,"name":"Bob"}]"#;
+    let opts = toon_core::EncodeOptions {
+        array_style: toon_core::ArrayStyle::Records,
+        ..toon_core::EncodeOptions::default()
+    };
+    let toon = toon_core::encode_with_options(json, &opts).unwrap();
+    assert_eq!(
+        toon,
+        "[2]:\n  - id: 1\n    name: Alice\n  - id: 2\n    name: Bob"
+    );
+}
+
 #[test]
 fn encode_root_mixed_array() {
     let json = r#"[1,"hello",true]"#;
@@ -524,3 +620,674 @@ fn encode_tabular_datetime_no_extra_quotes() {
     let expected = "events[1]{time,name}:\n  10:30:00,meeting";
     assert_eq!(toon, expected);
 }
+
+// ============================================================================
+// Pretty rendering of ToonValue (aligned tabular columns)
+// ============================================================================
+
+fn pretty_fixture() -> toon_core::types::ToonValue {
+    use toon_core::types::ToonValue;
+    let row = |id: i64, name: &str, note: &str| {
+        ToonValue::Object(vec![
+            ("id".to_string(), ToonValue::Integer(id)),
+            ("name".to_string(), ToonValue::String(name.to_string())),
+            ("note".to_string(), ToonValue::String(note.to_string())),
+        ])
+    };
+    ToonValue::Object(vec![(
+        "users".to_string(),
+        ToonValue::Array(vec![
+            row(1, "Alice", "hello, world"),
+            row(100, "Bo", "ok"),
+            row(7, "Christopher", "a \"quote\""),
+        ]),
+    )])
+}
+
+#[test]
+fn encode_toon_value_pretty_aligns_columns() {
+    let toon = toon_core::encode_toon_value_pretty(&pretty_fixture());
+    let rows: Vec<&str> = toon.lines().skip(1).collect();
+    assert_eq!(rows.len(), 3);
+    // Each column after the first starts at the same character offset on every row.
+    let name_col = rows[0].find("Alice").unwrap();
+    assert_eq!(rows[1].find("Bo").unwrap(), name_col);
+    assert_eq!(rows[2].find("Christopher").unwrap(), name_col);
+    let note_col = rows[0].find('"').unwrap();
+    assert_eq!(rows[1].find("ok").unwrap(), note_col);
+    assert_eq!(rows[2].find('"').unwrap(), note_col);
+    // No trailing whitespace on any line.
+    assert!(toon.lines().all(|line| !line.ends_with(' ')));
+}
+
+#[test]
+fn encode_toon_value_pretty_decodes_to_original() {
+    let value = pretty_fixture();
+    let toon = toon_core::encode_toon_value_pretty(&value);
+    let json = toon_core::decode(&toon).unwrap();
+    let decoded: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(toon_core::types::ToonValue::from(&decoded), value);
+}
+
+// ============================================================================
+// Alternative delimiters
+// ============================================================================
+
+/// Encode `json` with `opts`, panicking on error.
+fn encode_opts(json: &str, opts: EncodeOptions) -> String {
+    toon_core::encode_with_options(json, &opts).unwrap()
+}
+
+#[test]
+fn encode_tab_delimited_tabular_rows() {
+    let json =
+        r#"{"places":[{"id":1,"addr":"1 Main St, Springfield"},{"id":2,"addr":"9 Elm Rd"}]}"#;
+    let toon = encode_opts(
+        json,
+        EncodeOptions {
+            delimiter: toon_core::Delimiter::Tab,
+            ..EncodeOptions::default()
+        },
+    );
+    // Commas are no longer active, so the address stays unquoted.
+    assert_eq!(
+        toon,
+        "places[2]{id,addr}:\n  1\t1 Main St, Springfield\n  2\t9 Elm Rd"
+    );
+}
+
+#[test]
+fn encode_pipe_delimiter_quotes_pipes_not_commas() {
+    let toon = encode_opts(
+        r#"{"tags":["a,b","c|d","e"]}"#,
+        EncodeOptions {
+            delimiter: toon_core::Delimiter::Pipe,
+            ..EncodeOptions::default()
+        },
+    );
+    assert_eq!(toon, r#"tags[3]: a,b|"c|d"|e"#);
+}
+
+#[test]
+fn encode_delimited_roundtrips_with_matching_decode() {
+    let json = r#"{"rows":[{"a":"x;y","b":"p,q"},{"a":"z","b":"r"}],"list":["1;2",3]}"#;
+    for delimiter in [
+        toon_core::Delimiter::Comma,
+        toon_core::Delimiter::Tab,
+        toon_core::Delimiter::Semicolon,
+        toon_core::Delimiter::Pipe,
+    ] {
+        let toon = encode_opts(
+            json,
+            EncodeOptions {
+                delimiter,
+                ..EncodeOptions::default()
+            },
+        );
+        let opts = toon_core::DecodeOptions {
+            delimiter,
+            ..toon_core::DecodeOptions::default()
+        };
+        let decoded = toon_core::decode_with_options(&toon, &opts).unwrap();
+        assert_eq!(decoded, json, "delimiter {delimiter:?}, TOON:\n{toon}");
+    }
+}
+
+// ============================================================================
+// Typed tabular columns (EncodeOptions::typed_columns)
+// ============================================================================
+
+#[test]
+fn encode_typed_columns_annotates_header() {
+    let json = r#"{"items":[{"id":1,"name":"Alice","active":true,"score":9.5},{"id":2,"name":"Bob","active":false,"score":7}]}"#;
+    assert_eq!(
+        encode_opts(
+            json,
+            EncodeOptions {
+                typed_columns: true,
+                ..EncodeOptions::default()
+            }
+        ),
+        "items[2]{id:int,name:str,active:bool,score:float}:\n  1,Alice,true,9.5\n  2,Bob,false,7"
+    );
+}
+
+#[test]
+fn encode_typed_str_column_leaves_lookalikes_unquoted() {
+    let json = r#"{"codes":[{"code":"42"},{"code":"true"},{"code":"null"}]}"#;
+    // "42" and "true" need no quotes once the column is typed; "null" still does.
+    assert_eq!(
+        encode_opts(
+            json,
+            EncodeOptions {
+                typed_columns: true,
+                ..EncodeOptions::default()
+            }
+        ),
+        "codes[3]{code:str}:\n  42\n  true\n  \"null\""
+    );
+}
+
+#[test]
+fn encode_typed_columns_skip_mixed_and_null_columns() {
+    let json = r#"{"rows":[{"a":1,"b":null},{"a":"x","b":null}]}"#;
+    assert_eq!(
+        encode_opts(
+            json,
+            EncodeOptions {
+                typed_columns: true,
+                ..EncodeOptions::default()
+            }
+        ),
+        "rows[2]{a,b}:\n  1,null\n  x,null"
+    );
+}
+
+#[test]
+fn encode_typed_columns_off_by_default() {
+    let json = r#"{"items":[{"id":1,"name":"42"}]}"#;
+    assert_eq!(encode(json).unwrap(), "items[1]{id,name}:\n  1,\"42\"");
+}
+
+// ============================================================================
+// Records array style (EncodeOptions::array_style)
+// ============================================================================
+
+#[test]
+fn encode_records_labels_every_field() {
+    let json = r#"{"users":[{"id":1,"name":"Ada"},{"id":2,"name":"Grace"}]}"#;
+    assert_eq!(
+        encode_opts(
+            json,
+            EncodeOptions {
+                array_style: toon_core::ArrayStyle::Records,
+                ..EncodeOptions::default()
+            }
+        ),
+        "users[2]:\n  - id: 1\n    name: Ada\n  - id: 2\n    name: Grace"
+    );
+    assert_eq!(
+        encode(json).unwrap(),
+        "users[2]{id,name}:\n  1,Ada\n  2,Grace"
+    );
+}
+
+#[test]
+fn encode_records_leaves_primitive_arrays_inline() {
+    assert_eq!(
+        encode_opts(
+            r#"{"tags":["a","b"]}"#,
+            EncodeOptions {
+                array_style: toon_core::ArrayStyle::Records,
+                ..EncodeOptions::default()
+            }
+        ),
+        "tags[2]: a,b"
+    );
+}
+
+#[test]
+fn encode_records_roundtrips() {
+    let json = r#"{"events":[{"id":"e1","start":"09:00","busy":true,"note":null},{"id":"e2","start":"10:30","busy":false,"note":"a, b"}],"meta":{"rows":[{"k":1},{"k":2}]}}"#;
+    let decoded = toon_core::decode(&encode_opts(
+        json,
+        EncodeOptions {
+            array_style: toon_core::ArrayStyle::Records,
+            ..EncodeOptions::default()
+        },
+    ))
+    .unwrap();
+    let original: serde_json::Value = serde_json::from_str(json).unwrap();
+    let roundtripped: serde_json::Value = serde_json::from_str(&decoded).unwrap();
+    assert_eq!(original, roundtripped);
+}
+
+// ============================================================================
+// Trailing newline (EncodeOptions::trailing_newline)
+// ============================================================================
+
+#[test]
+fn encode_omits_trailing_newline_by_default() {
+    let toon = encode(r#"{"name":"Alice","tags":["a","b"]}"#).unwrap();
+    assert!(!toon.ends_with('\n'), "got: {toon:?}");
+}
+
+#[test]
+fn encode_trailing_newline_appends_exactly_one() {
+    let json = r#"{"users":[{"id":1,"name":"Ada"},{"id":2,"name":"Grace"}]}"#;
+    let opts = toon_core::EncodeOptions {
+        trailing_newline: true,
+        ..toon_core::EncodeOptions::default()
+    };
+    let toon = toon_core::encode_with_options(json, &opts).unwrap();
+    assert_eq!(toon, format!("{}\n", encode(json).unwrap()));
+    assert!(!toon.ends_with("\n\n"));
+    assert_eq!(toon_core::decode(&toon).unwrap(), json);
+}
+
+// ============================================================================
+// Sorted tabular columns (EncodeOptions::sort_tabular_columns)
+// ============================================================================
+
+#[test]
+fn encode_sorted_columns_is_independent_of_key_order() {
+    let a = r#"{"events":[{"start":"09:00","id":1,"title":"Standup"},{"title":"Review","id":2,"start":"14:00"}]}"#;
+    let b = r#"{"events":[{"title":"Standup","start":"09:00","id":1},{"id":2,"start":"14:00","title":"Review"}]}"#;
+
+    let toon = encode_opts(
+        a,
+        EncodeOptions {
+            sort_tabular_columns: true,
+            ..EncodeOptions::default()
+        },
+    );
+    assert_eq!(
+        toon,
+        encode_opts(
+            b,
+            EncodeOptions {
+                sort_tabular_columns: true,
+                ..EncodeOptions::default()
+            }
+        )
+    );
+    assert_eq!(
+        toon,
+        "events[2]{id,start,title}:\n  1,\"09:00\",Standup\n  2,14:00,Review"
+    );
+    // Without the option the first row's key order wins.
+    assert_ne!(toon_core::encode(a).unwrap(), toon_core::encode(b).unwrap());
+
+    let decoded: serde_json::Value =
+        serde_json::from_str(&toon_core::decode(&toon).unwrap()).unwrap();
+    let original: serde_json::Value = serde_json::from_str(a).unwrap();
+    assert_eq!(decoded, original);
+}
+
+#[test]
+fn encode_sorted_columns_applies_to_root_tables_only_in_tabular_style() {
+    let json = r#"[{"b":1,"a":2}]"#;
+    assert_eq!(
+        encode_opts(
+            json,
+            EncodeOptions {
+                sort_tabular_columns: true,
+                ..EncodeOptions::default()
+            }
+        ),
+        "[1]{a,b}:\n  2,1"
+    );
+
+    let records = toon_core::EncodeOptions {
+        sort_tabular_columns: true,
+        array_style: toon_core::ArrayStyle::Records,
+        ..toon_core::EncodeOptions::default()
+    };
+    // Records are object fields, which keep their order.
+    assert_eq!(
+        toon_core::encode_with_options(json, &records).unwrap(),
+        toon_core::encode_with_options(
+            json,
+            &toon_core::EncodeOptions {
+                array_style: toon_core::ArrayStyle::Records,
+                ..toon_core::EncodeOptions::default()
+            }
+        )
+        .unwrap()
+    );
+}
+
+// ============================================================================
+// Sorted object keys (EncodeOptions::sort_object_keys)
+// ============================================================================
+
+#[test]
+fn encode_sorted_keys_at_every_level() {
+    let json = r#"{"zone":"UTC","user":{"name":"Ada","id":1},"items":[{"qty":2,"sku":"A1"}],"log":[{"msg":"hi","at":1},3]}"#;
+    assert_eq!(
+        encode_opts(json, EncodeOptions { sort_object_keys: true, ..EncodeOptions::default() }),
+        "items[1]{qty,sku}:\n  2,A1\nlog[2]:\n  - at: 1\n    msg: hi\n  - 3\nuser:\n  id: 1\n  name: Ada\nzone: UTC"
+    );
+}
+
+#[test]
+fn encode_sorted_keys_is_independent_of_input_order() {
+    let a = r#"{"b":{"y":1,"x":2},"a":[{"k":1,"j":2}],"c":true}"#;
+    let b = r#"{"c":true,"a":[{"j":2,"k":1}],"b":{"x":2,"y":1}}"#;
+    assert_eq!(
+        encode_opts(
+            a,
+            EncodeOptions {
+                sort_object_keys: true,
+                ..EncodeOptions::default()
+            }
+        ),
+        encode_opts(
+            b,
+            EncodeOptions {
+                sort_object_keys: true,
+                ..EncodeOptions::default()
+            }
+        )
+    );
+    assert_ne!(toon_core::encode(a).unwrap(), toon_core::encode(b).unwrap());
+
+    // Only key order changes: the document still decodes to the same value.
+    let decoded: serde_json::Value = serde_json::from_str(
+        &toon_core::decode(&encode_opts(
+            a,
+            EncodeOptions {
+                sort_object_keys: true,
+                ..EncodeOptions::default()
+            },
+        ))
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        decoded,
+        serde_json::from_str::<serde_json::Value>(a).unwrap()
+    );
+}
+
+// ============================================================================
+// Header width limit (EncodeOptions::max_header_columns)
+// ============================================================================
+
+#[test]
+fn encode_wide_table_over_column_limit_falls_back_to_records() {
+    let row = |n: i64| {
+        let fields: Vec<String> = (0..10)
+            .map(|c| format!(r#""c{c}":{}"#, n * 10 + c))
+            .collect();
+        format!("{{{}}}", fields.join(","))
+    };
+    let json = format!(r#"{{"items":[{},{}]}}"#, row(0), row(1));
+
+    let toon = encode_opts(
+        &json,
+        EncodeOptions {
+            max_header_columns: Some(5),
+            ..EncodeOptions::default()
+        },
+    );
+    assert!(
+        toon.starts_with("items[2]:\n  - c0: 0\n    c1: 1\n"),
+        "{toon}"
+    );
+    assert!(!toon.contains('{'), "{toon}");
+    // The default is still a single tabular header.
+    assert!(toon_core::encode(&json)
+        .unwrap()
+        .starts_with("items[2]{c0,c1,"));
+
+    let decoded: serde_json::Value =
+        serde_json::from_str(&toon_core::decode(&toon).unwrap()).unwrap();
+    assert_eq!(
+        decoded,
+        serde_json::from_str::<serde_json::Value>(&json).unwrap()
+    );
+}
+
+#[test]
+fn encode_table_within_column_limit_stays_tabular() {
+    let json = r#"[{"a":1,"b":2},{"a":3,"b":4}]"#;
+    assert_eq!(
+        encode_opts(
+            json,
+            EncodeOptions {
+                max_header_columns: Some(2),
+                ..EncodeOptions::default()
+            }
+        ),
+        "[2]{a,b}:\n  1,2\n  3,4"
+    );
+    assert_eq!(
+        encode_opts(
+            json,
+            EncodeOptions {
+                max_header_columns: Some(1),
+                ..EncodeOptions::default()
+            }
+        ),
+        "[2]:\n  - a: 1\n    b: 2\n  - a: 3\n    b: 4"
+    );
+}
+
+// ============================================================================
+// Omitting nulls (EncodeOptions::omit_nulls)
+// ============================================================================
+
+#[test]
+fn omit_nulls_drops_null_fields() {
+    let json = r#"{"a":1,"b":null,"c":{"d":null,"e":"x"},"tags":[null,"y"]}"#;
+    // Array elements are not fields and stay.
+    assert_eq!(
+        encode_opts(
+            json,
+            EncodeOptions {
+                omit_nulls: true,
+                ..EncodeOptions::default()
+            }
+        ),
+        "a: 1\nc:\n  e: x\ntags[2]: null,y"
+    );
+    assert_eq!(
+        toon_core::decode(&encode_opts(
+            json,
+            EncodeOptions {
+                omit_nulls: true,
+                ..EncodeOptions::default()
+            }
+        ))
+        .unwrap(),
+        r#"{"a":1,"c":{"e":"x"},"tags":[null,"y"]}"#
+    );
+    // Off by default.
+    assert_eq!(
+        toon_core::encode(json).unwrap(),
+        "a: 1\nb: null\nc:\n  d: null\n  e: x\ntags[2]: null,y"
+    );
+}
+
+#[test]
+fn omit_nulls_keeps_null_cells_in_tables() {
+    let json = r#"{"rows":[{"id":1,"note":null},{"id":2,"note":"ok"}]}"#;
+    assert_eq!(
+        encode_opts(
+            json,
+            EncodeOptions {
+                omit_nulls: true,
+                ..EncodeOptions::default()
+            }
+        ),
+        "rows[2]{id,note}:\n  1,null\n  2,ok"
+    );
+    assert_eq!(
+        encode_opts(
+            r#"[{"id":1,"note":null}]"#,
+            EncodeOptions {
+                omit_nulls: true,
+                ..EncodeOptions::default()
+            }
+        ),
+        "[1]{id,note}:\n  1,null"
+    );
+}
+
+#[test]
+fn omit_nulls_applies_inside_list_items() {
+    // Objects with nested values are not tabular, so their null fields go.
+    let json = r#"{"events":[{"id":1,"end":null,"who":{"name":"Ada","email":null}}]}"#;
+    assert_eq!(
+        encode_opts(
+            json,
+            EncodeOptions {
+                omit_nulls: true,
+                ..EncodeOptions::default()
+            }
+        ),
+        "events[1]:\n  - id: 1\n    who:\n      name: Ada"
+    );
+}
+
+// ============================================================================
+// Canonical form (canonicalize)
+// ============================================================================
+
+#[test]
+fn canonicalize_equivalent_inputs_to_identical_toon() {
+    let a = r#"{
+        "calendar": "work",
+        "events": [
+            {"id": 1, "title": "Standup", "duration": 15.0},
+            {"title": "Review", "duration": 60, "id": 2}
+        ],
+        "meta": {"offset": -0, "tags": []}
+    }"#;
+    let b = r#"{
+        "meta": {"tags": [], "offset": 0},
+        "events": [
+            {"duration": 15, "id": 1.0, "title": "Standup"},
+            {"id": 2, "duration": 60.0, "title": "Review"}
+        ],
+        "calendar": "work"
+    }"#;
+
+    let canonical = toon_core::canonicalize(a).unwrap();
+    assert_eq!(canonical, toon_core::canonicalize(b).unwrap());
+    assert_eq!(
+        canonical,
+        "calendar: work\nevents[2]{duration,id,title}:\n  15,1,Standup\n  60,2,Review\nmeta:\n  offset: 0\n  tags[0]:"
+    );
+}
+
+#[test]
+fn canonicalize_keeps_array_order_and_rejects_invalid_json() {
+    assert_ne!(
+        toon_core::canonicalize("[1,2]").unwrap(),
+        toon_core::canonicalize("[2,1]").unwrap()
+    );
+    assert!(toon_core::canonicalize("{not json").is_err());
+}
+
+// ============================================================================
+// Quoting queries (would_quote)
+// ============================================================================
+
+/// Strings covering every quoting rule, plus ones that stay bare.
+const QUOTING_SAMPLES: &[&str] = &[
+    "Ada",
+    "hello world",
+    "",
+    " padded",
+    "trailing ",
+    "null",
+    "true",
+    "false",
+    "True",
+    "TRUE",
+    "NULL",
+    "False",
+    "42",
+    "-3.5",
+    "05",
+    "1e3",
+    "-dash",
+    "a:b",
+    "a,b",
+    "a\tb",
+    "a|b",
+    "a;b",
+    "say \"hi\"",
+    "back\\slash",
+    "[x]",
+    "{y}",
+    "line\nbreak",
+    "café",
+    "2026-03-16T09:00:00Z",
+];
+
+/// Whether `cell`, as written by the encoder, is quoted.
+fn written_quoted(cell: &str) -> bool {
+    cell.starts_with('"')
+}
+
+#[test]
+fn would_quote_matches_encoder_output_in_every_context() {
+    use toon_core::{
+        encode_with_options, would_quote, Delimiter, EncodeOptions, KeywordCase, QuoteContext,
+    };
+
+    for (&s, keywords) in QUOTING_SAMPLES
+        .iter()
+        .flat_map(|s| [KeywordCase::Lower, KeywordCase::Upper, KeywordCase::Title].map(|k| (s, k)))
+    {
+        let string = serde_json::to_string(s).unwrap();
+
+        // Document: an object field value.
+        let options = EncodeOptions {
+            keyword_case: keywords,
+            ..EncodeOptions::default()
+        };
+        let toon = encode_with_options(&format!(r#"{{"k":{string}}}"#), &options).unwrap();
+        let value = toon.strip_prefix("k: ").unwrap();
+        assert_eq!(
+            would_quote(s, QuoteContext::Document, keywords),
+            written_quoted(value),
+            "{s:?} as field value ({keywords:?}): {toon}"
+        );
+
+        for delimiter in [
+            Delimiter::Comma,
+            Delimiter::Tab,
+            Delimiter::Semicolon,
+            Delimiter::Pipe,
+        ] {
+            let options = EncodeOptions {
+                delimiter,
+                ..options
+            };
+
+            // InlineArray: the single element of a primitive array.
+            let toon = encode_with_options(&format!(r#"{{"k":[{string}]}}"#), &options).unwrap();
+            let value = toon.strip_prefix("k[1]: ").unwrap();
+            assert_eq!(
+                would_quote(s, QuoteContext::InlineArray(delimiter), keywords),
+                written_quoted(value),
+                "{s:?} in inline array ({delimiter:?}): {toon}"
+            );
+
+            // TabularCell: the single cell of a one-column table.
+            let toon = encode_with_options(
+                &format!(r#"{{"rows":[{{"a":{string}}},{{"a":"x"}}]}}"#),
+                &options,
+            )
+            .unwrap();
+            let cell = toon.lines().nth(1).unwrap().trim_start();
+            assert_eq!(
+                would_quote(s, QuoteContext::TabularCell(delimiter), keywords),
+                written_quoted(cell),
+                "{s:?} in tabular cell ({delimiter:?}): {toon}"
+            );
+
+            // TypedStrCell: the same table with typed columns.
+            let typed = EncodeOptions {
+                typed_columns: true,
+                ..options
+            };
+            let toon = encode_with_options(
+                &format!(r#"{{"rows":[{{"a":{string}}},{{"a":"x"}}]}}"#),
+                &typed,
+            )
+            .unwrap();
+            assert!(toon.starts_with("rows[2]{a:str}:"), "{toon}");
+            let cell = toon.lines().nth(1).unwrap().trim_start();
+            assert_eq!(
+                would_quote(s, QuoteContext::TypedStrCell(delimiter), keywords),
+                written_quoted(cell),
+                "{s:?} in str column ({delimiter:?}): {toon}"
+            );
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Tests for the stable `Display` format and codes of `ToonError`.
+
+use toon_core::{decode, decode_from_reader, encode, ToonError};
+
+// ============================================================================
+// JsonParse
+// ============================================================================
+
+#[test]
+fn invalid_json_display_and_code() {
+    let err = encode("{not json").unwrap_err();
+    assert!(matches!(err, ToonError::JsonParse(_)));
+    assert_eq!(err.code(), "invalid_json");
+    assert!(
+        err.to_string().starts_with("toon: invalid JSON: "),
+        "got: {}",
+        err
+    );
+}
+
+// ============================================================================
+// ToonParse
+// ============================================================================
+
+#[test]
+fn parse_error_display_and_code() {
+    let err = decode("name: Alice\ntags[1]: \"oops").unwrap_err();
+    assert!(matches!(err, ToonError::ToonParse { line: 2, .. }));
+    assert_eq!(err.code(), "parse_error");
+    assert!(
+        err.to_string().starts_with("toon: parse error at line 2: "),
+        "got: {}",
+        err
+    );
+}
+
+#[test]
+fn parse_error_display_includes_message() {
+    let err = ToonError::ToonParse {
+        line: 7,
+        message: "unterminated string".to_string(),
+    };
+    assert_eq!(
+        err.to_string(),
+        "toon: parse error at line 7: unterminated string"
+    );
+}
+
+// ============================================================================
+// Encode
+// ============================================================================
+
+#[test]
+fn encode_error_display_and_code() {
+    let err = ToonError::Encode("unsupported value".to_string());
+    assert_eq!(err.code(), "encode_error");
+    assert_eq!(err.to_string(), "toon: encode error: unsupported value");
+}
+
+// ============================================================================
+// Io
+// ============================================================================
+
+#[test]
+fn io_error_display_and_code() {
+    struct FailingReader;
+    impl std::io::Read for FailingReader {
+        fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("disk unplugged"))
+        }
+    }
+
+    let err = decode_from_reader(std::io::BufReader::new(FailingReader)).unwrap_err();
+    assert!(matches!(err, ToonError::Io(_)));
+    assert_eq!(err.code(), "io_error");
+    assert!(
+        err.to_string().starts_with("toon: I/O error: "),
+        "got: {}",
+        err
+    );
+}
+
+// ============================================================================
+// InvalidUtf8
+// ============================================================================
+
+#[test]
+fn invalid_utf8_display_and_code() {
+    let bytes: &[u8] = b"name: caf\xe9";
+    let err = decode_from_reader(bytes).unwrap_err();
+    assert!(matches!(err, ToonError::InvalidUtf8(_)));
+    assert_eq!(err.code(), "invalid_utf8");
+    assert!(
+        err.to_string()
+            .starts_with("toon: input is not valid UTF-8; TOON requires UTF-8 ("),
+        "got: {}",
+        err
+    );
+}
@@ -5,7 +5,10 @@
 ///
 /// The filter module strips unnecessary fields from JSON before TOON encoding,
 /// reducing token consumption for LLM processing of calendar data.
-use toon_core::{encode, filter_and_encode, filter_fields, CalendarFilter};
+use toon_core::{
+    decode, encode, filter_and_encode, filter_elements, filter_fields, redact_and_encode,
+    redact_fields, CalendarFilter, ElementPredicate,
+};
 
 // ============================================================================
 // Helper: Realistic Google Calendar-like JSON fixtures
@@ -190,6 +193,59 @@ fn filter_wildcard_strips_etag_from_calendar_at_all_levels() {
     }
 }
 
+#[test]
+fn filter_prefix_glob_strips_matching_keys_only() {
+    let value = serde_json::json!({
+        "ext_id": "123",
+        "ext_source": "crm",
+        "external": true,
+        "name": "Alice",
+    });
+    let filtered = filter_fields(&value, &["ext_*"]);
+    assert_eq!(
+        filtered,
+        serde_json::json!({"external": true, "name": "Alice"})
+    );
+}
+
+#[test]
+fn filter_suffix_glob_strips_matching_keys_only() {
+    let value = serde_json::json!({
+        "id_internal": 1,
+        "_internal": 2,
+        "internal": 3,
+        "internals": 4,
+    });
+    let filtered = filter_fields(&value, &["*_internal"]);
+    assert_eq!(filtered, serde_json::json!({"internal": 3, "internals": 4}));
+}
+
+#[test]
+fn filter_key_glob_matches_only_at_its_own_level() {
+    let value = serde_json::json!({
+        "ext_id": "top",
+        "items": [{"ext_id": "nested", "name": "a"}],
+    });
+
+    // A bare glob segment is positional, like a literal field name.
+    let top_only = filter_fields(&value, &["ext_*"]);
+    assert_eq!(
+        top_only,
+        serde_json::json!({"items": [{"ext_id": "nested", "name": "a"}]})
+    );
+
+    // Combined with the leading `*`, it matches at any depth.
+    let any_depth = filter_fields(&value, &["*.ext_*"]);
+    assert_eq!(any_depth, serde_json::json!({"items": [{"name": "a"}]}));
+
+    // Globs also work as path segments.
+    let nested = filter_fields(&value, &["it*.ext_*"]);
+    assert_eq!(
+        nested,
+        serde_json::json!({"ext_id": "top", "items": [{"name": "a"}]})
+    );
+}
+
 // ============================================================================
 // 4. Array element filtering
 // ============================================================================
@@ -562,3 +618,167 @@ fn filter_deep_nested_with_arrays_and_objects() {
         "location.name should be preserved"
     );
 }
+
+// ============================================================================
+// 11. Redaction
+// ============================================================================
+
+fn attendee_list_json() -> &'static str {
+    r#"{"items":[{"name":"Alice","email":"alice@example.com"},{"name":"Bob","email":"bob@example.com"}]}"#
+}
+
+#[test]
+fn redact_replaces_value_and_keeps_key() {
+    let value: serde_json::Value = serde_json::from_str(attendee_list_json()).unwrap();
+    let redacted = redact_fields(&value, &["items.*.email"], &serde_json::json!("[redacted]"));
+    assert_eq!(
+        redacted,
+        serde_json::json!({"items": [
+            {"name": "Alice", "email": "[redacted]"},
+            {"name": "Bob", "email": "[redacted]"},
+        ]})
+    );
+}
+
+#[test]
+fn redact_and_encode_preserves_tabular_form() {
+    let toon = redact_and_encode(
+        attendee_list_json(),
+        &["items.*.email"],
+        &serde_json::json!("[redacted]"),
+    )
+    .unwrap();
+    assert_eq!(
+        toon,
+        "items[2]{name,email}:\n  Alice,\"[redacted]\"\n  Bob,\"[redacted]\""
+    );
+    assert!(!toon.contains("@example.com"), "emails should be gone");
+}
+
+#[test]
+fn redact_with_empty_patterns_is_identity() {
+    let value: serde_json::Value = serde_json::from_str(attendee_list_json()).unwrap();
+    assert_eq!(redact_fields(&value, &[], &serde_json::Value::Null), value);
+}
+
+#[test]
+fn redact_replaces_whole_subtree() {
+    let value: serde_json::Value = serde_json::from_str(single_event_json()).unwrap();
+    let redacted = redact_fields(&value, &["creator"], &serde_json::Value::Null);
+    assert!(redacted["creator"].is_null());
+    assert_eq!(redacted["summary"], "Team Standup");
+}
+
+// ============================================================================
+// 12. Element filters
+// ============================================================================
+
+/// Calendar list response mixing confirmed, tentative and cancelled events.
+fn mixed_status_json() -> serde_json::Value {
+    serde_json::json!({
+        "kind": "calendar#events",
+        "items": [
+            {"id": "e1", "summary": "Standup", "status": "confirmed", "tags": ["team"]},
+            {"id": "e2", "summary": "Retro", "status": "cancelled", "tags": ["team", "optional"]},
+            {"id": "e3", "summary": "1:1 (moved)", "status": "tentative", "tags": []},
+            {"id": "e4", "summary": "Offsite", "status": "cancelled", "tags": ["optional"]}
+        ]
+    })
+}
+
+fn status_equals(status: &str) -> ElementPredicate {
+    ElementPredicate::Equals("status".to_string(), serde_json::json!(status))
+}
+
+#[test]
+fn filter_elements_drops_cancelled_events() {
+    let value = mixed_status_json();
+    let filtered = filter_elements(&value, "items", &status_equals("cancelled"));
+
+    let ids: Vec<&str> = filtered["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, ["e1", "e3"]);
+    assert_eq!(filtered["kind"], "calendar#events");
+
+    // The shorter array still encodes as a table and round-trips.
+    let toon = encode(&filtered.to_string()).unwrap();
+    assert!(toon.contains("items[2]"), "{toon}");
+    assert!(!toon.contains("cancelled"), "{toon}");
+    let decoded: serde_json::Value = serde_json::from_str(&decode(&toon).unwrap()).unwrap();
+    assert_eq!(decoded, filtered);
+}
+
+#[test]
+fn filter_elements_contains_matches_substrings_and_array_members() {
+    let value = mixed_status_json();
+
+    let moved = ElementPredicate::Contains("summary".to_string(), serde_json::json!("(moved)"));
+    let filtered = filter_elements(&value, "items", &moved);
+    assert_eq!(filtered["items"].as_array().unwrap().len(), 3);
+
+    let optional = ElementPredicate::Contains("tags".to_string(), serde_json::json!("optional"));
+    let filtered = filter_elements(&value, "items", &optional);
+    let ids: Vec<&str> = filtered["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, ["e1", "e3"]);
+}
+
+#[test]
+fn filter_elements_follows_nested_paths_and_fields() {
+    let value = serde_json::json!({
+        "calendars": [
+            {"events": [
+                {"organizer": {"email": "bot@example.com"}, "n": 1},
+                {"organizer": {"email": "ada@example.com"}, "n": 2}
+            ]},
+            {"events": [{"organizer": {"email": "bot@example.com"}, "n": 3}]}
+        ]
+    });
+    let from_bot = ElementPredicate::Equals(
+        "organizer.email".to_string(),
+        serde_json::json!("bot@example.com"),
+    );
+    let filtered = filter_elements(&value, "calendars.events", &from_bot);
+    assert_eq!(
+        filtered,
+        serde_json::json!({
+            "calendars": [
+                {"events": [{"organizer": {"email": "ada@example.com"}, "n": 2}]},
+                {"events": []}
+            ]
+        })
+    );
+}
+
+#[test]
+fn filter_elements_leaves_unmatched_shapes_alone() {
+    let value = mixed_status_json();
+    // No array at the path, or no element with the field: nothing changes.
+    assert_eq!(
+        filter_elements(&value, "missing", &status_equals("cancelled")),
+        value
+    );
+    assert_eq!(
+        filter_elements(&value, "kind", &status_equals("cancelled")),
+        value
+    );
+    assert_eq!(
+        filter_elements(&value, "items", &status_equals("declined")),
+        value
+    );
+
+    // A root array with non-object elements keeps them.
+    let root = serde_json::json!([1, {"status": "cancelled"}, "x", {"status": "confirmed"}]);
+    assert_eq!(
+        filter_elements(&root, "", &status_equals("cancelled")),
+        serde_json::json!([1, "x", {"status": "confirmed"}])
+    );
+}
@@ -0,0 +1,90 @@
+//! Tests for RFC 7386 merge patching of TOON documents.
+
+use toon_core::{decode, encode, merge_documents};
+
+/// Encode a JSON literal to TOON for use as a test input.
+fn toon(json: &str) -> String {
+    encode(json).unwrap()
+}
+
+// ============================================================================
+// Field updates
+// ============================================================================
+
+#[test]
+fn patch_changes_existing_field() {
+    let base = toon(r#"{"summary":"Standup","status":"tentative","sequence":1}"#);
+    let patch = toon(r#"{"status":"confirmed"}"#);
+    let merged = merge_documents(&base, &patch).unwrap();
+    assert_eq!(
+        decode(&merged).unwrap(),
+        r#"{"summary":"Standup","status":"confirmed","sequence":1}"#
+    );
+}
+
+#[test]
+fn patch_adds_new_field_at_end() {
+    let base = toon(r#"{"summary":"Standup"}"#);
+    let patch = toon(r#"{"location":"Room 4"}"#);
+    let merged = merge_documents(&base, &patch).unwrap();
+    assert_eq!(merged, "summary: Standup\nlocation: Room 4");
+}
+
+#[test]
+fn nested_objects_merge_recursively() {
+    let base = toon(r#"{"start":{"dateTime":"2026-02-17T09:00:00Z","timeZone":"UTC"}}"#);
+    let patch = toon(r#"{"start":{"dateTime":"2026-02-17T10:00:00Z"}}"#);
+    let merged = merge_documents(&base, &patch).unwrap();
+    assert_eq!(
+        decode(&merged).unwrap(),
+        r#"{"start":{"dateTime":"2026-02-17T10:00:00Z","timeZone":"UTC"}}"#
+    );
+}
+
+// ============================================================================
+// Deletion via null
+// ============================================================================
+
+#[test]
+fn null_in_patch_deletes_field() {
+    let base = toon(r#"{"summary":"Standup","etag":"abc","status":"confirmed"}"#);
+    let patch = toon(r#"{"etag":null}"#);
+    let merged = merge_documents(&base, &patch).unwrap();
+    // Remaining keys keep their original order.
+    assert_eq!(merged, "summary: Standup\nstatus: confirmed");
+}
+
+#[test]
+fn null_for_missing_field_is_noop() {
+    let base = toon(r#"{"summary":"Standup"}"#);
+    let patch = toon(r#"{"etag":null}"#);
+    assert_eq!(merge_documents(&base, &patch).unwrap(), base);
+}
+
+// ============================================================================
+// Wholesale replacement
+// ============================================================================
+
+#[test]
+fn arrays_are_replaced_wholesale() {
+    let base = toon(r#"{"attendees":[{"email":"a@x.com"},{"email":"b@x.com"}],"tags":[1,2,3]}"#);
+    let patch = toon(r#"{"attendees":[{"email":"c@x.com"}]}"#);
+    let merged = merge_documents(&base, &patch).unwrap();
+    assert_eq!(
+        decode(&merged).unwrap(),
+        r#"{"attendees":[{"email":"c@x.com"}],"tags":[1,2,3]}"#
+    );
+}
+
+#[test]
+fn non_object_patch_replaces_document() {
+    let base = toon(r#"{"summary":"Standup"}"#);
+    let patch = toon(r#"[1,2]"#);
+    assert_eq!(merge_documents(&base, &patch).unwrap(), patch);
+}
+
+#[test]
+fn invalid_patch_is_an_error() {
+    let base = toon(r#"{"summary":"Standup"}"#);
+    assert!(merge_documents(&base, "tags[1]: \"oops").is_err());
+}
@@ -0,0 +1,155 @@
+/// Property-Based Robustness Tests for decoding untrusted TOON
+///
+/// `prop_roundtrip.rs` only feeds the decoder output produced by the encoder.
+/// The CLI and bindings also pass user-supplied text straight to `decode`, so
+/// these properties generate arbitrary strings and assert the decoder always
+/// returns `Ok` or `Err` — a panic fails the test.
+///
+/// Strategies generate:
+/// - Arbitrary Unicode strings
+/// - "TOON-ish" soups of structural tokens (`[`, `]`, `{`, `}`, `:`, `"`, `\`,
+///   `- `, indentation, newlines, delimiters) mixed with multibyte characters,
+///   which reach far deeper into the parser than uniformly random text
+use proptest::prelude::*;
+use toon_core::{
+    decode, decode_from_reader, decode_from_reader_with_options, decode_with_options,
+    decode_with_spans, DecodeOptions, Delimiter, KeywordCase,
+};
+
+// ============================================================================
+// Strategies
+// ============================================================================
+
+/// Concatenations of TOON structural fragments, words, numbers, and multibyte text.
+fn arb_toonish() -> impl Strategy<Value = String> {
+    let fragment = prop_oneof![
+        Just("[".to_string()),
+        Just("]".to_string()),
+        Just("{".to_string()),
+        Just("}".to_string()),
+        Just(":".to_string()),
+        Just(": ".to_string()),
+        Just("\"".to_string()),
+        Just("\\".to_string()),
+        Just("- ".to_string()),
+        Just("  ".to_string()),
+        Just("\n".to_string()),
+        Just(",".to_string()),
+        Just("\t".to_string()),
+        Just("|".to_string()),
+        Just("\u{00e9}".to_string()),
+        Just("\u{4f60}".to_string()),
+        Just("\u{1f600}".to_string()),
+        Just("\u{3000}".to_string()),
+        "[a-z]{1,3}",
+        "[0-9]{1,3}",
+    ];
+    prop::collection::vec(fragment, 0..40).prop_map(|parts| parts.concat())
+}
+
+/// Every combination of decode options, over the delimiters and keyword cases.
+fn arb_decode_options() -> impl Strategy<Value = DecodeOptions> {
+    let delimiter = prop_oneof![
+        Just(Delimiter::Comma),
+        Just(Delimiter::Tab),
+        Just(Delimiter::Pipe),
+    ];
+    let keyword_case = prop_oneof![
+        Just(KeywordCase::Lower),
+        Just(KeywordCase::Upper),
+        Just(KeywordCase::Title),
+    ];
+    (
+        delimiter,
+        keyword_case,
+        prop::array::uniform7(any::<bool>()),
+    )
+        .prop_map(|(delimiter, keyword_case, flags)| DecodeOptions {
+            strict_tabular: flags[0],
+            delimiter,
+            empty_cells_as_strings: flags[1],
+            strict_escapes: flags[2],
+            assert_counts: flags[3],
+            strict_nesting: flags[4],
+            require_full_consume: flags[5],
+            keyword_case,
+        })
+}
+
+/// Either arbitrary Unicode or a TOON-ish token soup.
+fn arb_untrusted_input() -> impl Strategy<Value = String> {
+    prop_oneof![any::<String>(), arb_toonish()]
+}
+
+// ============================================================================
+// Property Tests
+// ============================================================================
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(1000))]
+
+    /// `decode` never panics on arbitrary input.
+    #[test]
+    fn decode_never_panics_on_arbitrary_input(s in arb_untrusted_input()) {
+        let _ = decode(&s);
+    }
+
+    /// Strict tabular, count, nesting and full-consume checking, non-comma
+    /// delimiters, empty-cell handling and keyword cases never panic either.
+    #[test]
+    fn decode_with_options_never_panics_on_arbitrary_input(
+        s in arb_untrusted_input(),
+        options in arb_decode_options(),
+    ) {
+        let _ = decode_with_options(&s, &options);
+    }
+
+    /// Span recording follows the same paths and never panics.
+    #[test]
+    fn decode_with_spans_never_panics_on_arbitrary_input(s in arb_untrusted_input()) {
+        let _ = decode_with_spans(&s);
+    }
+
+    /// Reading line by line never panics and agrees with in-memory decoding.
+    #[test]
+    fn decode_from_reader_agrees_with_decode(s in arb_untrusted_input()) {
+        let streamed = decode_from_reader(s.as_bytes());
+        let in_memory = decode(&s);
+        match (streamed, in_memory) {
+            (Ok(streamed), Ok(json)) => {
+                let expected: serde_json::Value = serde_json::from_str(&json).unwrap();
+                prop_assert_eq!(streamed, expected);
+            }
+            (Err(_), Err(_)) => {}
+            (streamed, in_memory) => prop_assert!(
+                false,
+                "reader: {:?}, in-memory: {:?}",
+                streamed.map(|v| v.to_string()),
+                in_memory
+            ),
+        }
+    }
+
+    /// With options too, reading line by line agrees with in-memory decoding.
+    #[test]
+    fn decode_from_reader_with_options_agrees_with_decode_with_options(
+        s in arb_untrusted_input(),
+        options in arb_decode_options(),
+    ) {
+        let streamed = decode_from_reader_with_options(s.as_bytes(), &options);
+        let in_memory = decode_with_options(&s, &options);
+        match (streamed, in_memory) {
+            (Ok(streamed), Ok(json)) => {
+                let expected: serde_json::Value = serde_json::from_str(&json).unwrap();
+                prop_assert_eq!(streamed, expected);
+            }
+            (Err(_), Err(_)) => {}
+            (streamed, in_memory) => prop_assert!(
+                false,
+                "reader: {:?}, in-memory: {:?}",
+                streamed.map(|v| v.to_string()),
+                in_memory
+            ),
+        }
+    }
+}
@@ -15,7 +15,6 @@
 /// Known limitations excluded from testing:
 /// - Empty objects inside expanded list items (encoder gap: `- ` with nothing after)
 /// - Float precision loss through `format!("{}", f)` display (last-digit rounding)
-/// - Empty arrays produce trailing space in inline format (`[0]: `)
 use proptest::prelude::*;
 use serde_json::{json, Map, Number, Value};
 use toon_core::{decode, encode};
@@ -371,24 +370,19 @@ fn no_trailing_newline(value in arb_json_value()) {
         );
     }
 
-    /// TOON output never has trailing spaces on any line, except for lines containing
-    /// empty array inline syntax `[0]: ` which is a known minor encoder artifact.
+    /// TOON output never has trailing spaces on any line.
     #[test]
     fn no_trailing_spaces(value in arb_json_value()) {
         let json_str = serde_json::to_string(&value).unwrap();
         let toon = encode(&json_str).unwrap();
         for (i, line) in toon.lines().enumerate() {
-            if line.ends_with(' ') {
-                // Allow any line that contains the empty-array inline pattern "[0]: "
-                // This can appear as "key[0]: ", "  - [0]: ", "[0]: " etc.
-                prop_assert!(
-                    line.contains("[0]: "),
-                    "Line {} has unexpected trailing space: {:?} (full TOON: {:?})",
-                    i,
-                    line,
-                    toon
-                );
-            }
+            prop_assert!(
+                !line.ends_with(' '),
+                "Line {} has unexpected trailing space: {:?} (full TOON: {:?})",
+                i,
+                line,
+                toon
+            );
         }
     }
 
@@ -81,6 +81,27 @@ fn roundtrip_string_with_tab() {
     assert_roundtrip(r#""col1\tcol2""#);
 }
 
+#[test]
+fn roundtrip_root_string_that_looks_like_number() {
+    // The whole document is the quoted scalar; it must not decode as 42.
+    assert_eq!(encode(r#""42""#).unwrap(), r#""42""#);
+    assert_roundtrip(r#""42""#);
+    assert_roundtrip(r#""-1.5""#);
+}
+
+#[test]
+fn roundtrip_root_string_that_looks_like_literal() {
+    assert_eq!(encode(r#""true""#).unwrap(), r#""true""#);
+    assert_roundtrip(r#""true""#);
+    assert_roundtrip(r#""null""#);
+}
+
+#[test]
+fn roundtrip_root_string_that_looks_like_key_value() {
+    assert_roundtrip(r#""a: b""#);
+    assert_roundtrip(r#""key[2]: 1,2""#);
+}
+
 // ============================================================================
 // Object Roundtrips
 // ============================================================================
@@ -125,6 +146,32 @@ fn roundtrip_quoted_key() {
     assert_roundtrip(r#"{"my key":"value"}"#);
 }
 
+#[test]
+fn roundtrip_numeric_string_keys() {
+    assert_roundtrip(r#"{"0":"zero","42":"answer","3.14":"pi"}"#);
+    assert_roundtrip(r#"{"-1":true,"1e5":null,"007":"bond"}"#);
+    // Sparse-array style maps, nested and inside arrays.
+    assert_roundtrip(r#"{"scores":{"2024":[1,2],"2025":{"0":"a"}}}"#);
+    assert_roundtrip(r#"{"rows":[{"7":1,"8":2},{"7":3,"8":4}]}"#);
+    assert_roundtrip(r#"{"items":[{"1":"a"},{"10":1,"x":2},"s"]}"#);
+}
+
+#[test]
+fn numeric_keys_are_quoted_and_decode_as_string_keys() {
+    let toon = encode(r#"{"0":"zero","42":1,"3.14":2}"#).unwrap();
+    assert_eq!(toon, "\"0\": zero\n\"42\": 1\n\"3.14\": 2");
+
+    let decoded: serde_json::Value = serde_json::from_str(&decode(&toon).unwrap()).unwrap();
+    let keys: Vec<&str> = decoded
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+    assert_eq!(keys, vec!["0", "42", "3.14"]);
+    assert_eq!(decoded["0"], "zero");
+}
+
 #[test]
 fn roundtrip_object_with_special_strings() {
     assert_roundtrip(r#"{"a":"","b":"true","c":"null","d":"42","e":"05","f":"hello:world"}"#);
@@ -159,6 +206,29 @@ fn roundtrip_root_array() {
     assert_roundtrip("[1,2,3]");
 }
 
+#[test]
+fn empty_object_and_array_edge_cases_stay_distinct() {
+    // (JSON, TOON): each decodes back to its own shape, and no line ends in a space.
+    let cases = [
+        ("{}", ""),
+        ("[]", "[0]:"),
+        (r#"{"config":{}}"#, "config:"),
+        (r#"{"items":[]}"#, "items[0]:"),
+        (r#"{"a":{"b":{},"c":[]}}"#, "a:\n  b:\n  c[0]:"),
+        ("[[],[1]]", "[2]:\n  - [0]:\n  - [1]: 1"),
+        (r#"[{"x":{},"y":[]}]"#, "[1]:\n  - x:\n    y[0]:"),
+    ];
+    for (json, expected) in cases {
+        let toon = encode(json).unwrap();
+        assert_eq!(toon, expected, "encoding {json}");
+        assert!(
+            toon.lines().all(|line| !line.ends_with(' ')),
+            "trailing space in {toon:?}"
+        );
+        assert_roundtrip(json);
+    }
+}
+
 // ============================================================================
 // Tabular Roundtrips
 // ============================================================================
@@ -175,6 +245,14 @@ fn roundtrip_tabular_with_quoted_comma() {
     assert_roundtrip(r#"{"items":[{"name":"a,b","id":1},{"name":"c","id":2}]}"#);
 }
 
+#[test]
+fn roundtrip_root_tabular_array() {
+    let json = r#"[{"id":1,"name":"Alice"},{"id":2,"name":"Bob"}]"#;
+    assert!(encode(json).unwrap().starts_with("[2]{id,name}:\n"));
+    assert_roundtrip(json);
+    assert_typed_roundtrip(json);
+}
+
 #[test]
 fn roundtrip_tabular_single_row() {
     assert_roundtrip(r#"{"data":[{"x":10,"y":20}]}"#);
@@ -199,6 +277,23 @@ fn roundtrip_non_uniform_objects() {
     assert_roundtrip(r#"{"items":[{"a":1},{"b":2}]}"#);
 }
 
+#[test]
+fn roundtrip_objects_with_stray_primitive() {
+    assert_roundtrip(r#"{"items":[{"a":1},{"a":2},"oops"]}"#);
+    assert_roundtrip(r#"[{"a":1},{"a":2},"oops"]"#);
+    assert_roundtrip(r#"["oops",{"a":1},{"a":2}]"#);
+    assert_roundtrip(r#"[{"a":1},42,{"a":2}]"#);
+}
+
+#[test]
+fn roundtrip_objects_with_differing_keys() {
+    // Missing key, extra key, and same key count with different names.
+    assert_roundtrip(r#"{"items":[{"a":1,"b":2},{"a":3}]}"#);
+    assert_roundtrip(r#"[{"a":1,"b":2},{"a":3}]"#);
+    assert_roundtrip(r#"{"items":[{"a":1},{"a":2,"b":3}]}"#);
+    assert_roundtrip(r#"{"items":[{"a":1,"b":2},{"a":3,"c":4}]}"#);
+}
+
 #[test]
 fn roundtrip_array_of_arrays() {
     assert_roundtrip(r#"{"matrix":[[1,2,3],[4,5,6]]}"#);
@@ -209,6 +304,20 @@ fn roundtrip_root_mixed_array() {
     assert_roundtrip(r#"["hello",[1,2],{"name":"Alice","age":30}]"#);
 }
 
+#[test]
+fn roundtrip_list_item_strings_starting_with_marker() {
+    // The nested object forces expanded list form, so "- dash" is emitted after
+    // a "- " marker and must be quoted to survive decoding.
+    let json = r#"{"items":["- dash","normal","-","-5x",{"k":1}]}"#;
+    let toon = encode(json).unwrap();
+    assert_eq!(
+        toon,
+        "items[5]:\n  - \"- dash\"\n  - normal\n  - \"-\"\n  - \"-5x\"\n  - k: 1"
+    );
+    assert_roundtrip(json);
+    assert_roundtrip(r#"["- dash","normal",[1]]"#);
+}
+
 // ============================================================================
 // Complex / Calendar-like Roundtrips
 // ============================================================================
@@ -234,6 +343,18 @@ fn roundtrip_list_item_with_nested_object() {
     );
 }
 
+#[test]
+fn roundtrip_list_item_with_tabular_field() {
+    // Every row, not just the first, must stay nested under the field's header.
+    let json = r#"{"items":[{"id":1,"rows":[{"a":1,"b":2},{"a":3,"b":4}]},{"id":2}]}"#;
+    let toon = encode(json).unwrap();
+    assert_eq!(
+        toon,
+        "items[2]:\n  - id: 1\n    rows[2]{a,b}:\n      1,2\n      3,4\n  - id: 2"
+    );
+    assert_roundtrip(json);
+}
+
 #[test]
 fn roundtrip_list_item_with_array_field() {
     assert_roundtrip(r#"{"items":[{"name":"Alice","tags":["admin","user"]}]}"#);
@@ -271,3 +392,207 @@ fn assert_json_eq(a: &str, b: &str) {
     let vb: serde_json::Value = serde_json::from_str(b).unwrap();
     assert_eq!(va, vb, "JSON mismatch:\n  actual: {a}\n  expected: {b}");
 }
+
+// ============================================================================
+// Typed tabular columns
+// ============================================================================
+
+fn assert_typed_roundtrip(json: &str) {
+    let opts = toon_core::EncodeOptions {
+        typed_columns: true,
+        ..toon_core::EncodeOptions::default()
+    };
+    let toon = toon_core::encode_with_options(json, &opts).expect("encode failed");
+    let decoded = decode(&toon).expect("decode failed");
+    assert_json_eq(&decoded, json);
+}
+
+#[test]
+fn roundtrip_typed_columns() {
+    assert_typed_roundtrip(
+        r#"{"items":[{"id":1,"name":"Alice","active":true,"score":9.5},{"id":2,"name":"Bob","active":false,"score":7}]}"#,
+    );
+}
+
+#[test]
+fn roundtrip_typed_str_column_with_lookalikes() {
+    assert_typed_roundtrip(
+        r#"{"codes":[{"code":"42","zip":"05"},{"code":"true","zip":"null"},{"code":"","zip":null}]}"#,
+    );
+}
+
+#[test]
+fn roundtrip_typed_columns_with_tab_delimiter() {
+    let json =
+        r#"{"rows":[{"id":"1","addr":"1 Main St, Springfield"},{"id":"2","addr":"9 Elm Rd"}]}"#;
+    let opts = toon_core::EncodeOptions {
+        typed_columns: true,
+        delimiter: toon_core::Delimiter::Tab,
+        ..toon_core::EncodeOptions::default()
+    };
+    let toon = toon_core::encode_with_options(json, &opts).unwrap();
+    let decode_opts = toon_core::DecodeOptions {
+        delimiter: toon_core::Delimiter::Tab,
+        ..toon_core::DecodeOptions::default()
+    };
+    assert_json_eq(
+        &toon_core::decode_with_options(&toon, &decode_opts).unwrap(),
+        json,
+    );
+}
+
+// ============================================================================
+// roundtrips() / validate()
+// ============================================================================
+
+#[test]
+fn roundtrips_reports_clean_roundtrip() {
+    assert!(toon_core::roundtrips(r#"{"users":[{"id":1,"name":"Ada"}],"tags":["a,b"]}"#).unwrap());
+    // Number normalization is not a loss.
+    assert!(toon_core::roundtrips(r#"{"x":1.0,"y":-0.0,"z":1e30}"#).unwrap());
+}
+
+#[test]
+fn roundtrips_reports_lossy_input() {
+    // An array holding an empty object decodes as an empty array.
+    assert!(!toon_core::roundtrips(r#"{"a":[{}]}"#).unwrap());
+    assert!(toon_core::roundtrips("not json").is_err());
+}
+
+#[test]
+fn validate_accepts_encoder_output_and_rejects_malformed_toon() {
+    let toon = encode(r#"{"rows":[{"a":"x,y"}],"s":"q\"uote"}"#).unwrap();
+    assert!(toon_core::validate(&toon).is_ok());
+    assert!(matches!(
+        toon_core::validate("\"key: 1\nb: 2"),
+        Err(toon_core::ToonError::ToonParse { .. })
+    ));
+}
+
+#[test]
+fn roundtrip_forward_slashes_stay_unquoted() {
+    let toon = encode(r#"{"p":"path/to/file","tags":["a/b","c"]}"#).unwrap();
+    assert_eq!(toon, "p: path/to/file\ntags[2]: a/b,c");
+    assert_roundtrip(r#"{"url":"https://example.com/a/b","p":"path/to"}"#);
+    // JSON's escaped form is the same string.
+    assert_roundtrip(r#"{"p":"path\/to"}"#);
+}
+
+// ============================================================================
+// Unicode keys
+// ============================================================================
+
+#[test]
+fn roundtrip_unicode_keys_are_quoted() {
+    let toon = encode(r#"{"café":1,"日本語":"東京","🎉":true}"#).unwrap();
+    assert_eq!(toon, "\"café\": 1\n\"日本語\": 東京\n\"🎉\": true");
+}
+
+#[test]
+fn roundtrip_unicode_keys_in_every_position() {
+    // Nested objects, inline arrays, tabular headers and list items.
+    assert_roundtrip(r#"{"café":{"naïve":"oui","日本語":[1,2]}}"#);
+    assert_roundtrip(r#"{"出席者":[{"名前":"Ada","🎂":"12-10"},{"名前":"Grace","🎂":"12-09"}]}"#);
+    assert_roundtrip(r#"{"événements":[{"titre":"Réunion","🗓":{"début":"09:00"}},"𝄞"]}"#);
+    assert_roundtrip(r#"[{"ключ":"значение"},{"ключ":"ещё","キー":null}]"#);
+}
+
+#[test]
+fn roundtrip_unicode_keys_with_escapes_and_quotes() {
+    assert_roundtrip(r#"{"\"引用\"":1,"tab\té":2,"é\\":3,"a: ü":{"b[2]":"ç"}}"#);
+}
+
+// ============================================================================
+// Keyword case (EncodeOptions/DecodeOptions::keyword_case)
+// ============================================================================
+
+fn encode_with_case(json: &str, keyword_case: toon_core::KeywordCase) -> String {
+    let options = toon_core::EncodeOptions {
+        keyword_case,
+        ..Default::default()
+    };
+    toon_core::encode_with_options(json, &options).unwrap()
+}
+
+fn decode_with_case(toon: &str, keyword_case: toon_core::KeywordCase) -> serde_json::Value {
+    let options = toon_core::DecodeOptions {
+        keyword_case,
+        ..Default::default()
+    };
+    serde_json::from_str(&toon_core::decode_with_options(toon, &options).unwrap()).unwrap()
+}
+
+#[test]
+fn keyword_case_upper_encodes_and_decodes_booleans() {
+    use toon_core::KeywordCase;
+
+    let toon = encode_with_case(r#"{"a":true,"b":false,"c":null}"#, KeywordCase::Upper);
+    assert_eq!(toon, "a: TRUE\nb: FALSE\nc: NULL");
+    assert_eq!(
+        decode_with_case(&toon, KeywordCase::Upper),
+        serde_json::json!({"a": true, "b": false, "c": null})
+    );
+    // Under the default the uppercase forms are plain strings.
+    assert_eq!(
+        decode_with_case(&toon, KeywordCase::Lower),
+        serde_json::json!({"a": "TRUE", "b": "FALSE", "c": "NULL"})
+    );
+}
+
+#[test]
+fn keyword_case_title_encodes_python_style() {
+    use toon_core::KeywordCase;
+
+    let toon = encode_with_case(r#"{"flags":[true,false,null]}"#, KeywordCase::Title);
+    assert_eq!(toon, "flags[3]: True,False,Null");
+    assert_eq!(
+        decode_with_case(&toon, KeywordCase::Title),
+        serde_json::json!({"flags": [true, false, null]})
+    );
+}
+
+#[test]
+fn keyword_case_quotes_strings_that_look_like_keywords() {
+    use toon_core::KeywordCase;
+
+    let json = r#"{"s":"TRUE","t":"true","rows":[{"v":"NULL","b":true},{"v":"x","b":false}]}"#;
+    let toon = encode_with_case(json, KeywordCase::Upper);
+    assert_eq!(
+        toon,
+        "s: \"TRUE\"\nt: \"true\"\nrows[2]{v,b}:\n  \"NULL\",TRUE\n  x,FALSE"
+    );
+    let original: serde_json::Value = serde_json::from_str(json).unwrap();
+    assert_eq!(decode_with_case(&toon, KeywordCase::Upper), original);
+}
+
+#[test]
+fn keyword_case_still_reads_lowercase_keywords() {
+    use toon_core::KeywordCase;
+
+    for case in [KeywordCase::Upper, KeywordCase::Title] {
+        assert_eq!(
+            decode_with_case("a: true\nb: null\nc: TRUE\nd: True", case),
+            match case {
+                KeywordCase::Upper =>
+                    serde_json::json!({"a": true, "b": null, "c": true, "d": "True"}),
+                _ => serde_json::json!({"a": true, "b": null, "c": "TRUE", "d": true}),
+            }
+        );
+    }
+}
+
+#[test]
+fn keyword_case_applies_to_typed_bool_columns() {
+    use toon_core::KeywordCase;
+
+    let options = toon_core::EncodeOptions {
+        keyword_case: KeywordCase::Upper,
+        typed_columns: true,
+        ..Default::default()
+    };
+    let json = r#"{"rows":[{"ok":true},{"ok":null}]}"#;
+    let toon = toon_core::encode_with_options(json, &options).unwrap();
+    assert_eq!(toon, "rows[2]{ok:bool}:\n  TRUE\n  NULL");
+    let original: serde_json::Value = serde_json::from_str(json).unwrap();
+    assert_eq!(decode_with_case(&toon, KeywordCase::Upper), original);
+}
@@ -7,7 +7,10 @@
 ///
 /// Every test verifies roundtrip fidelity: decode(encode(json)) == json
 /// unless otherwise noted.
-use toon_core::{decode, encode};
+use toon_core::{
+    decode, decode_with_options, encode, encode_with_options, DecodeOptions, Delimiter,
+    EncodeOptions,
+};
 
 /// Assert that encode -> decode roundtrips to the same JSON value.
 fn assert_roundtrip(json: &str) {
@@ -283,6 +286,21 @@ fn string_looks_like_negative() {
         assert_encode(r#""-1""#, r#""-1""#);
     }
 
+    #[test]
+    fn string_looks_like_plus_signed() {
+        assert_roundtrip(r#""+7""#);
+        assert_encode(r#""+7""#, r#""+7""#);
+        assert_encode(r#"{"a":"+7","b":7}"#, "a: \"+7\"\nb: 7");
+        assert_roundtrip(r#"{"a":"+7","b":7}"#);
+        assert_roundtrip(r#"["+7",7,"+0.5","+05"]"#);
+    }
+
+    #[test]
+    fn bare_plus_signed_number_decodes_as_number() {
+        // The encoder never emits `+`, but hand-written TOON may.
+        assert_eq!(decode("n: +7").unwrap(), r#"{"n":7}"#);
+    }
+
     #[test]
     fn string_looks_like_zero() {
         assert_roundtrip(r#""0""#);
@@ -578,6 +596,77 @@ fn key_with_bracket_requires_quoting() {
         assert_roundtrip(r#"{"a[0]":"value"}"#);
     }
 
+    #[test]
+    fn key_with_comma_requires_quoting() {
+        assert_encode(r#"{"a,b":"value"}"#, "\"a,b\": value");
+        assert_roundtrip(r#"{"a,b":"value"}"#);
+    }
+
+    #[test]
+    fn key_with_newline_requires_quoting() {
+        assert_encode(r#"{"a\nb":"value"}"#, "\"a\\nb\": value");
+        assert_roundtrip(r#"{"a\nb":"value"}"#);
+    }
+
+    #[test]
+    fn key_with_leading_and_trailing_space_requires_quoting() {
+        assert_encode(r#"{" a ":"value"}"#, "\" a \": value");
+        assert_roundtrip(r#"{" a ":"value"}"#);
+    }
+
+    #[test]
+    fn tabular_header_quotes_special_field_names() {
+        assert_encode(
+            r#"{"rows":[{"a,b":1,"c":2},{"a,b":3,"c":4}]}"#,
+            "rows[2]{\"a,b\",c}:\n  1,2\n  3,4",
+        );
+    }
+
+    #[test]
+    fn special_keys_roundtrip_in_every_position() {
+        let keys = ["a:b", "a,b", "a[0]", "a\nb", " a ", "a|b", "a\tb", "a{b}"];
+        for key in keys {
+            let k = serde_json::to_string(key).unwrap();
+            // Object field, nested object, array field, list-item field, and tabular header.
+            assert_roundtrip(&format!(r#"{{{k}:"value"}}"#));
+            assert_roundtrip(&format!(r#"{{"outer":{{{k}:1}}}}"#));
+            assert_roundtrip(&format!(r#"{{{k}:[1,2]}}"#));
+            assert_roundtrip(&format!(r#"[{{{k}:1,"x":[1]}},"s"]"#));
+            assert_roundtrip(&format!(r#"[{{{k}:1,"b":2}},{{{k}:3,"b":4}}]"#));
+            assert_roundtrip(&format!(r#"{{"rows":[{{"b":1,{k}:2}},{{"b":3,{k}:4}}]}}"#));
+        }
+    }
+
+    #[test]
+    fn special_keys_roundtrip_with_non_comma_delimiters() {
+        let keys = ["a,b", "a|b", "a\tb"];
+        for delimiter in [Delimiter::Tab, Delimiter::Pipe] {
+            for key in keys {
+                let k = serde_json::to_string(key).unwrap();
+                let json = format!(r#"[{{{k}:1,"b":2}},{{{k}:3,"b":4}}]"#);
+                let toon = encode_with_options(
+                    &json,
+                    &EncodeOptions {
+                        delimiter,
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+                let decoded = decode_with_options(
+                    &toon,
+                    &DecodeOptions {
+                        delimiter,
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+                let original: serde_json::Value = serde_json::from_str(&json).unwrap();
+                let roundtripped: serde_json::Value = serde_json::from_str(&decoded).unwrap();
+                assert_eq!(original, roundtripped, "key {key:?}, TOON:\n{toon}");
+            }
+        }
+    }
+
     #[test]
     fn mixed_keys_some_quoted_some_not() {
         let json = r#"{"name":"Alice","my-key":"val","age":30,"123":"num"}"#;
@@ -701,9 +790,8 @@ fn empty_array_in_object() {
 
     #[test]
     fn empty_root_array() {
-        // Encoder uses inline format for empty array: "[0]: " (with space after colon)
-        // because all_primitives([]) is true -> inline path
-        assert_encode("[]", "[0]: ");
+        // Same `[0]:` form as a field, with no trailing space
+        assert_encode("[]", "[0]:");
         assert_roundtrip("[]");
     }
 
@@ -0,0 +1,122 @@
+//! Tests for JSON vs TOON compression statistics, pluggable token counting, and
+//! structural document statistics.
+
+use toon_core::{
+    compression_stats, compression_stats_with_counter, document_stats, encode, DocumentStats,
+    TokenCounter, WhitespaceTokenCounter,
+};
+
+/// Stub counter that treats every character as a token.
+struct CharCounter;
+
+impl TokenCounter for CharCounter {
+    fn count(&self, s: &str) -> usize {
+        s.chars().count()
+    }
+}
+
+const JSON: &str = r#"{"name":"Zoë","tags":["a","b"]}"#;
+
+#[test]
+fn stats_report_byte_sizes() {
+    let stats = compression_stats(JSON).unwrap();
+    assert_eq!(stats.json_bytes, JSON.len());
+    assert_eq!(stats.toon_bytes, encode(JSON).unwrap().len());
+    assert!(stats.byte_reduction() > 0.0);
+}
+
+#[test]
+fn default_counter_charges_structural_characters() {
+    // `{`, `"`, name, `"`, `:`, `"`, Alice, `"`, `}`
+    assert_eq!(WhitespaceTokenCounter.count(r#"{"name":"Alice"}"#), 9);
+    // name, `:`, Alice
+    assert_eq!(WhitespaceTokenCounter.count("name: Alice"), 3);
+    assert_eq!(WhitespaceTokenCounter.count(""), 0);
+}
+
+#[test]
+fn stats_use_injected_counter() {
+    let stats = compression_stats_with_counter(JSON, &CharCounter).unwrap();
+    let toon = encode(JSON).unwrap();
+    assert_eq!(stats.json_tokens, JSON.chars().count());
+    assert_eq!(stats.toon_tokens, toon.chars().count());
+    assert_ne!(
+        stats.json_tokens,
+        compression_stats(JSON).unwrap().json_tokens
+    );
+}
+
+#[test]
+fn stats_accept_trait_object_counter() {
+    let counter: &dyn TokenCounter = &CharCounter;
+    let stats = compression_stats_with_counter(JSON, counter).unwrap();
+    assert_eq!(stats.json_tokens, JSON.chars().count());
+}
+
+#[test]
+fn stats_of_invalid_json_is_an_error() {
+    let err = compression_stats("{not json").unwrap_err();
+    assert_eq!(err.code(), "invalid_json");
+}
+
+// ============================================================================
+// Document statistics
+// ============================================================================
+
+/// A calendar-like document: an expanded list of events, each holding a tabular
+/// attendee array and an inline tag array.
+const CALENDAR: &str = r#"{"summary":"Work","items":[
+    {"id":"e1","tags":["standup","daily"],"start":{"dateTime":"2024-01-15T09:00:00Z"},
+     "attendees":[{"email":"a@co.com","ok":true},{"email":"b@co.com","ok":false}]},
+    {"id":"e2","tags":[],"start":{"dateTime":"2024-01-15T10:00:00Z"},
+     "attendees":[{"email":"c@co.com","ok":true}]}
+]}"#;
+
+#[test]
+fn document_stats_classify_array_forms() {
+    let stats = document_stats(&encode(CALENDAR).unwrap()).unwrap();
+    assert_eq!(stats.tabular_arrays, 2);
+    assert_eq!(stats.expanded_arrays, 1);
+    // `["standup","daily"]` and the empty `[]`
+    assert_eq!(stats.inline_arrays, 2);
+    assert_eq!(stats.arrays(), 5);
+}
+
+#[test]
+fn document_stats_count_containers_and_depth() {
+    let stats = document_stats(&encode(CALENDAR).unwrap()).unwrap();
+    // root, 2 events, 2 `start` objects, 3 attendee rows
+    assert_eq!(stats.objects, 8);
+    // root → items → event → attendees → attendee row
+    assert_eq!(stats.max_depth, 5);
+    // summary, 2×id, 2 tags, 2×dateTime, 3×(email, ok)
+    assert_eq!(stats.scalars, 13);
+}
+
+#[test]
+fn document_stats_count_quoted_strings() {
+    let stats = document_stats(&encode(CALENDAR).unwrap()).unwrap();
+    // Only the timestamps contain `:` and need quotes.
+    assert_eq!(stats.quoted_strings, 2);
+    assert_eq!(stats.unquoted_strings, 8);
+    assert!((stats.quoted_ratio() - 0.2).abs() < 1e-9);
+}
+
+#[test]
+fn document_stats_of_scalar_document() {
+    assert_eq!(
+        document_stats(r#""42""#).unwrap(),
+        DocumentStats {
+            scalars: 1,
+            quoted_strings: 1,
+            ..DocumentStats::default()
+        }
+    );
+    assert_eq!(document_stats("42").unwrap().max_depth, 0);
+}
+
+#[test]
+fn document_stats_of_invalid_toon_is_an_error() {
+    let err = document_stats("items[2]{a,b}:\n  \"open").unwrap_err();
+    assert_eq!(err.code(), "parse_error");
+}
@@ -0,0 +1,161 @@
+//! Tests for re-delimiting TOON documents with `transcode_delimiter`.
+
+use toon_core::{
+    decode_with_options, encode_with_options, transcode_delimiter, DecodeOptions, Delimiter,
+    EncodeOptions, KeywordCase,
+};
+
+fn decode_as(toon: &str, delimiter: Delimiter) -> serde_json::Value {
+    let opts = DecodeOptions {
+        delimiter,
+        ..DecodeOptions::default()
+    };
+    serde_json::from_str(&decode_with_options(toon, &opts).unwrap()).unwrap()
+}
+
+/// Cells containing a comma, a tab (escaped), a semicolon, and lookalikes that
+/// must stay quoted under any delimiter.
+const MIXED_JSON: &str = r#"{"places":[{"id":1,"addr":"1 Main St, Springfield","note":"a;b"},{"id":2,"addr":"9 Elm Rd","note":"x\ty"}],"tags":["a,b","c","42",""],"nested":{"list":[{"k":1,"cells":["p,q","r"],"rows":[{"a":"s,t"},{"a":"u"}]},{"k":2}]}}"#;
+
+fn mixed_toon(delimiter: Delimiter) -> String {
+    let opts = EncodeOptions {
+        delimiter,
+        ..EncodeOptions::default()
+    };
+    encode_with_options(MIXED_JSON, &opts).unwrap()
+}
+
+// ============================================================================
+// Comma ↔ tab
+// ============================================================================
+
+#[test]
+fn comma_to_tab_requotes_cells() {
+    let comma = mixed_toon(Delimiter::Comma);
+    let tab =
+        transcode_delimiter(&comma, Delimiter::Comma, Delimiter::Tab, KeywordCase::Lower).unwrap();
+
+    // The comma cell loses its quotes; the tab cell was already quoted (escaped).
+    assert!(tab.contains("  1\t1 Main St, Springfield\ta;b"), "{tab}");
+    assert!(tab.contains("  2\t9 Elm Rd\t\"x\\ty\""), "{tab}");
+    assert!(tab.contains("tags[4]: a,b\tc\t\"42\"\t\"\""), "{tab}");
+    assert!(tab.contains("cells[2]: p,q\tr"), "{tab}");
+    assert!(tab.contains("        s,t\n        u"), "{tab}");
+
+    assert_eq!(
+        decode_as(&tab, Delimiter::Tab),
+        decode_as(&comma, Delimiter::Comma)
+    );
+    assert_eq!(tab, mixed_toon(Delimiter::Tab));
+}
+
+#[test]
+fn tab_to_comma_requotes_cells() {
+    let tab = mixed_toon(Delimiter::Tab);
+    let comma =
+        transcode_delimiter(&tab, Delimiter::Tab, Delimiter::Comma, KeywordCase::Lower).unwrap();
+
+    // A bare cell holding a comma gains quotes.
+    assert!(
+        comma.contains("  1,\"1 Main St, Springfield\",a;b"),
+        "{comma}"
+    );
+    assert!(comma.contains("tags[4]: \"a,b\",c,\"42\",\"\""), "{comma}");
+
+    assert_eq!(
+        decode_as(&comma, Delimiter::Comma),
+        decode_as(&tab, Delimiter::Tab)
+    );
+    assert_eq!(comma, mixed_toon(Delimiter::Comma));
+}
+
+#[test]
+fn comma_to_semicolon_quotes_cells_with_semicolons() {
+    let comma = mixed_toon(Delimiter::Comma);
+    let semi = transcode_delimiter(
+        &comma,
+        Delimiter::Comma,
+        Delimiter::Semicolon,
+        KeywordCase::Lower,
+    )
+    .unwrap();
+    assert!(
+        semi.contains("  1;1 Main St, Springfield;\"a;b\""),
+        "{semi}"
+    );
+    assert_eq!(
+        decode_as(&semi, Delimiter::Semicolon),
+        decode_as(&comma, Delimiter::Comma)
+    );
+}
+
+// ============================================================================
+// Untouched text
+// ============================================================================
+
+#[test]
+fn numbers_and_other_lines_are_copied_verbatim() {
+    // A decode/encode round trip would normalize 1.50 and 1e3; transcoding must not.
+    let toon = "total: 1.50\nrows[2]{a,b}:\n  1.50,x\n  1e3,y\nvals[2]: 007,2.0\n";
+    let tab =
+        transcode_delimiter(toon, Delimiter::Comma, Delimiter::Tab, KeywordCase::Lower).unwrap();
+    assert_eq!(
+        tab,
+        "total: 1.50\nrows[2]{a,b}:\n  1.50\tx\n  1e3\ty\nvals[2]: 007\t2.0\n"
+    );
+}
+
+#[test]
+fn same_delimiter_is_identity() {
+    let toon = mixed_toon(Delimiter::Comma);
+    assert_eq!(
+        transcode_delimiter(
+            &toon,
+            Delimiter::Comma,
+            Delimiter::Comma,
+            KeywordCase::Lower
+        )
+        .unwrap(),
+        toon
+    );
+}
+
+#[test]
+fn keyword_lookalikes_keep_their_quotes_under_the_document_keyword_case() {
+    let json = r#"{"a":["TRUE,x",true,"TRUE"]}"#;
+    let encode_opts = EncodeOptions {
+        keyword_case: KeywordCase::Upper,
+        ..EncodeOptions::default()
+    };
+    let comma = encode_with_options(json, &encode_opts).unwrap();
+    let pipe = transcode_delimiter(
+        &comma,
+        Delimiter::Comma,
+        Delimiter::Pipe,
+        KeywordCase::Upper,
+    )
+    .unwrap();
+    assert_eq!(pipe, r#"a[3]: TRUE,x|TRUE|"TRUE""#);
+
+    let decode_opts = DecodeOptions {
+        delimiter: Delimiter::Pipe,
+        keyword_case: KeywordCase::Upper,
+        ..DecodeOptions::default()
+    };
+    assert_eq!(decode_with_options(&pipe, &decode_opts).unwrap(), json);
+}
+
+#[test]
+fn unterminated_quote_reports_line() {
+    let err = transcode_delimiter(
+        "a: 1\ntags[2]: \"oops,b",
+        Delimiter::Comma,
+        Delimiter::Tab,
+        KeywordCase::Lower,
+    )
+    .unwrap_err();
+    assert!(
+        matches!(err, toon_core::ToonError::ToonParse { line: 2, .. }),
+        "{err}"
+    );
+}
@@ -0,0 +1,64 @@
+use toon_core::types::ToonValue;
+
+fn to_toon(value: &ToonValue) -> String {
+    let json = serde_json::Value::from(value).to_string();
+    toon_core::encode(&json).unwrap()
+}
+
+// ============================================================================
+// walk_mut
+// ============================================================================
+
+#[test]
+fn walk_mut_uppercases_every_string_leaf() {
+    let json = r#"{"team":"core","users":[{"id":1,"name":"ada"},{"id":2,"name":"grace"}],"tags":["x","y"]}"#;
+    let original: serde_json::Value = serde_json::from_str(json).unwrap();
+    let mut value = ToonValue::from(&original);
+
+    value.walk_mut(|node| {
+        if let ToonValue::String(s) = node {
+            *s = s.to_uppercase();
+        }
+    });
+
+    assert_eq!(
+        to_toon(&value),
+        "team: CORE\nusers[2]{id,name}:\n  1,ADA\n  2,GRACE\ntags[2]: X,Y"
+    );
+}
+
+#[test]
+fn walk_mut_visits_children_before_parent() {
+    let mut value = ToonValue::Object(vec![
+        (
+            "a".to_string(),
+            ToonValue::Array(vec![ToonValue::Integer(1), ToonValue::Integer(2)]),
+        ),
+        ("b".to_string(), ToonValue::Bool(true)),
+    ]);
+    let mut order = Vec::new();
+    value.walk_mut(|node| {
+        order.push(match node {
+            ToonValue::Integer(i) => i.to_string(),
+            ToonValue::Bool(_) => "bool".to_string(),
+            ToonValue::Array(_) => "array".to_string(),
+            ToonValue::Object(_) => "object".to_string(),
+            _ => "other".to_string(),
+        });
+    });
+    assert_eq!(order, ["1", "2", "array", "bool", "object"]);
+}
+
+#[test]
+fn walk_mut_drops_null_fields_before_encoding() {
+    let json = r#"{"name":"Ada","email":null,"rows":[{"id":1,"note":null},{"id":2,"note":null}]}"#;
+    let mut value = ToonValue::from(&serde_json::from_str::<serde_json::Value>(json).unwrap());
+
+    value.walk_mut(|node| {
+        if let ToonValue::Object(fields) = node {
+            fields.retain(|(_, v)| *v != ToonValue::Null);
+        }
+    });
+
+    assert_eq!(to_toon(&value), "name: Ada\nrows[2]{id}:\n  1\n  2");
+}
@@ -39,20 +39,63 @@ fn from(e: &ExpandedEvent) -> Self {
     }
 }
 
+#[derive(Serialize)]
+struct DiagnosticDto {
+    code: &'static str,
+    message: String,
+}
+
+impl From<&truth_engine::Diagnostic> for DiagnosticDto {
+    fn from(d: &truth_engine::Diagnostic) -> Self {
+        Self {
+            code: d.code.as_str(),
+            message: d.message.clone(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ExpansionDiagnosticsDto {
+    events: Vec<ExpandedEventDto>,
+    diagnostics: Vec<DiagnosticDto>,
+}
+
 #[derive(Serialize)]
 struct ConflictDto {
     event_a: ExpandedEventDto,
     event_b: ExpandedEventDto,
+    overlap_start: String,
+    overlap_end: String,
     overlap_minutes: i64,
+    severity: &'static str,
 }
 
-#[derive(Serialize)]
+impl From<&truth_engine::conflict::Conflict> for ConflictDto {
+    fn from(c: &truth_engine::conflict::Conflict) -> Self {
+        Self {
+            event_a: ExpandedEventDto::from(&c.event_a),
+            event_b: ExpandedEventDto::from(&c.event_b),
+            overlap_start: c.overlap_start.to_rfc3339(),
+            overlap_end: c.overlap_end.to_rfc3339(),
+            overlap_minutes: c.overlap_minutes,
+            severity: c.severity.as_str(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
 struct FreeSlotDto {
     start: String,
     end: String,
     duration_minutes: i64,
 }
 
+#[derive(Serialize)]
+struct RRuleFreeBusyDto {
+    busy: Vec<ExpandedEventDto>,
+    free: Vec<FreeSlotDto>,
+}
+
 /// Input format for events passed from JavaScript.
 #[derive(Deserialize)]
 struct EventInput {
@@ -79,25 +122,46 @@ fn parse_datetime(s: &str) -> Result<DateTime<Utc>, JsValue> {
         .map_err(|e| JsValue::from_str(&format!("Invalid datetime '{}': {}", s, e)))
 }
 
+/// Convert a `{start, end}` input into an `ExpandedEvent`.
+///
+/// Rejects events whose end is before their start: a reversed event would
+/// otherwise produce negative overlaps and nonsense free slots downstream.
+fn parse_event(input: &EventInput) -> Result<ExpandedEvent, JsValue> {
+    let start = parse_datetime(&input.start)?;
+    let end = parse_datetime(&input.end)?;
+    if end < start {
+        return Err(JsValue::from_str(&format!(
+            "Invalid event: end '{}' is before start '{}'",
+            input.end, input.start
+        )));
+    }
+    Ok(ExpandedEvent { start, end })
+}
+
 /// Convert a JSON array of `{start, end}` event objects into `Vec<ExpandedEvent>`.
 fn parse_events_json(json: &str) -> Result<Vec<ExpandedEvent>, JsValue> {
     let inputs: Vec<EventInput> = serde_json::from_str(json)
         .map_err(|e| JsValue::from_str(&format!("Invalid events JSON: {}", e)))?;
 
-    inputs
-        .into_iter()
-        .map(|input| {
-            let start = parse_datetime(&input.start)?;
-            let end = parse_datetime(&input.end)?;
-            Ok(ExpandedEvent { start, end })
-        })
-        .collect()
+    inputs.iter().map(parse_event).collect()
 }
 
 // ---------------------------------------------------------------------------
 // WASM exports
 // ---------------------------------------------------------------------------
 
+/// Parse a duration mode string: `"fixed"` or `"wallclock"`.
+fn parse_duration_mode(s: &str) -> Result<truth_engine::DurationMode, JsValue> {
+    match s {
+        "fixed" => Ok(truth_engine::DurationMode::Fixed),
+        "wallclock" => Ok(truth_engine::DurationMode::WallClock),
+        _ => Err(JsValue::from_str(&format!(
+            "Invalid duration mode '{}': expected fixed or wallclock",
+            s
+        ))),
+    }
+}
+
 /// Expand an RRULE string into concrete datetime instances.
 ///
 /// Returns a JSON string containing an array of `{start, end}` objects with
@@ -109,7 +173,10 @@ fn parse_events_json(json: &str) -> Result<Vec<ExpandedEvent>, JsValue> {
 /// - `duration_minutes` -- Duration of each instance in minutes
 /// - `timezone` -- IANA timezone (e.g., "America/Los_Angeles")
 /// - `until` -- Optional end boundary for expansion (local datetime string)
-/// - `max_count` -- Optional maximum number of instances
+/// - `max_count` -- Optional maximum number of instances; with a COUNT in the RRULE, the smaller wins
+/// - `duration_mode` -- Optional `"fixed"` (default: `end = start + duration`) or
+///   `"wallclock"` (duration added in local time, so ends keep their local time
+///   across DST)
 #[wasm_bindgen(js_name = "expandRRule")]
 pub fn expand_rrule(
     rrule: &str,
@@ -118,14 +185,20 @@ pub fn expand_rrule(
     timezone: &str,
     until: Option<String>,
     max_count: Option<u32>,
+    duration_mode: Option<String>,
 ) -> Result<String, JsValue> {
-    let events = truth_engine::expand_rrule(
+    let options = truth_engine::ExpandOptions {
+        duration_mode: parse_duration_mode(duration_mode.as_deref().unwrap_or("fixed"))?,
+        ..truth_engine::ExpandOptions::default()
+    };
+    let events = truth_engine::expand_rrule_with_options(
         rrule,
         dtstart,
         duration_minutes,
         timezone,
         until.as_deref(),
         max_count,
+        &options,
     )
     .map_err(|e| JsValue::from_str(&e.to_string()))?;
 
@@ -135,11 +208,56 @@ pub fn expand_rrule(
         .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
 }
 
+/// Check that an RRULE is valid without expanding it.
+///
+/// Needs no DTSTART or timezone. Returns nothing for a valid rule and throws
+/// an error naming the problem otherwise.
+#[wasm_bindgen(js_name = "validateRRule")]
+pub fn validate_rrule(rrule: &str) -> Result<(), JsValue> {
+    truth_engine::validate_rrule(rrule).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// Expand an RRULE and report notable conditions found along the way.
+///
+/// Arguments are as for [`expand_rrule`] (without `duration_mode`). Returns a
+/// JSON string with `{events, diagnostics}`: `events` is the same array
+/// `expandRRule` returns, and each diagnostic is a `{code, message}` object
+/// whose `code` is `"instance_cap_reached"`, `"skipped_months"` or
+/// `"dtstart_not_in_rule"`.
+#[wasm_bindgen(js_name = "expandRRuleDiagnostics")]
+pub fn expand_rrule_diagnostics(
+    rrule: &str,
+    dtstart: &str,
+    duration_minutes: u32,
+    timezone: &str,
+    until: Option<String>,
+    max_count: Option<u32>,
+) -> Result<String, JsValue> {
+    let (events, diagnostics) = truth_engine::expand_rrule_diagnostics(
+        rrule,
+        dtstart,
+        duration_minutes,
+        timezone,
+        until.as_deref(),
+        max_count,
+    )
+    .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let dto = ExpansionDiagnosticsDto {
+        events: events.iter().map(ExpandedEventDto::from).collect(),
+        diagnostics: diagnostics.iter().map(DiagnosticDto::from).collect(),
+    };
+
+    serde_json::to_string(&dto)
+        .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
+}
+
 /// Find all pairwise conflicts (overlapping time ranges) between two event lists.
 ///
 /// Both arguments must be JSON arrays of `{start, end}` objects with ISO 8601
 /// datetime strings. Returns a JSON string containing an array of conflict objects,
-/// each with `event_a`, `event_b`, and `overlap_minutes`.
+/// each with `event_a`, `event_b`, `overlap_start`, `overlap_end` (RFC 3339), and
+/// `overlap_minutes`.
 #[wasm_bindgen(js_name = "findConflicts")]
 pub fn find_conflicts(events_a_json: &str, events_b_json: &str) -> Result<String, JsValue> {
     let events_a = parse_events_json(events_a_json)?;
@@ -147,14 +265,40 @@ pub fn find_conflicts(events_a_json: &str, events_b_json: &str) -> Result<String
 
     let conflicts = truth_engine::find_conflicts(&events_a, &events_b);
 
-    let dtos: Vec<ConflictDto> = conflicts
-        .iter()
-        .map(|c| ConflictDto {
-            event_a: ExpandedEventDto::from(&c.event_a),
-            event_b: ExpandedEventDto::from(&c.event_b),
-            overlap_minutes: c.overlap_minutes,
-        })
-        .collect();
+    let dtos: Vec<ConflictDto> = conflicts.iter().map(ConflictDto::from).collect();
+
+    serde_json::to_string(&dtos)
+        .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
+}
+
+/// Find the occurrences of a recurring event that overlap one proposed event.
+///
+/// `proposed_json` is a single `{start, end}` object; the recurrence is given as
+/// for [`expand_rrule`]. Only occurrences around the proposed time are expanded.
+/// Returns a JSON string containing an array of conflict objects shaped like
+/// those of [`find_conflicts`], with the proposed event as `event_a`.
+#[wasm_bindgen(js_name = "conflictsWithRecurrence")]
+pub fn conflicts_with_recurrence(
+    proposed_json: &str,
+    rrule: &str,
+    dtstart: &str,
+    duration_minutes: u32,
+    timezone: &str,
+) -> Result<String, JsValue> {
+    let input: EventInput = serde_json::from_str(proposed_json)
+        .map_err(|e| JsValue::from_str(&format!("Invalid event JSON: {}", e)))?;
+    let proposed = parse_event(&input)?;
+    let spec = truth_engine::RRuleSpec {
+        rrule: rrule.to_string(),
+        dtstart: dtstart.to_string(),
+        duration_minutes,
+        timezone: timezone.to_string(),
+    };
+
+    let conflicts = truth_engine::conflicts_with_recurrence(&proposed, &spec)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let dtos: Vec<ConflictDto> = conflicts.iter().map(ConflictDto::from).collect();
 
     serde_json::to_string(&dtos)
         .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
@@ -190,6 +334,67 @@ pub fn find_free_slots(
         .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
 }
 
+/// Expand a recurring event over a window and compute the free time around it.
+///
+/// The recurrence is given as for [`expand_rrule`]; `window_start` and
+/// `window_end` are ISO 8601 datetime strings. Returns a JSON string with
+/// `{busy, free}`: `busy` holds the `{start, end}` instances overlapping the
+/// window and `free` the `{start, end, duration_minutes}` gaps between them, as
+/// [`find_free_slots`] would return for those instances.
+#[wasm_bindgen(js_name = "rruleFreeBusy")]
+pub fn rrule_free_busy(
+    rrule: &str,
+    dtstart: &str,
+    duration_minutes: u32,
+    timezone: &str,
+    window_start: &str,
+    window_end: &str,
+) -> Result<String, JsValue> {
+    let ws = parse_datetime(window_start)?;
+    let we = parse_datetime(window_end)?;
+    let spec = truth_engine::RRuleSpec {
+        rrule: rrule.to_string(),
+        dtstart: dtstart.to_string(),
+        duration_minutes,
+        timezone: timezone.to_string(),
+    };
+
+    let (busy, free) = truth_engine::rrule_free_busy(&spec, ws, we)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let dto = RRuleFreeBusyDto {
+        busy: busy.iter().map(ExpandedEventDto::from).collect(),
+        free: free
+            .iter()
+            .map(|s| FreeSlotDto {
+                start: s.start.to_rfc3339(),
+                end: s.end.to_rfc3339(),
+                duration_minutes: s.duration_minutes,
+            })
+            .collect(),
+    };
+
+    serde_json::to_string(&dto)
+        .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
+}
+
+/// Move every event by `by_minutes` (negative moves earlier), keeping durations.
+///
+/// `events_json` must be a JSON array of `{start, end}` objects. The shift is in
+/// absolute time, so instances keep their spacing across DST. Returns a JSON
+/// string containing an array of `{start, end}` objects with RFC 3339 strings.
+#[wasm_bindgen(js_name = "shiftEvents")]
+pub fn shift_events(events_json: &str, by_minutes: i32) -> Result<String, JsValue> {
+    let events = parse_events_json(events_json)?;
+
+    let shifted = truth_engine::shift_events(&events, chrono::Duration::minutes(by_minutes.into()));
+
+    let dtos: Vec<ExpandedEventDto> = shifted.iter().map(ExpandedEventDto::from).collect();
+
+    serde_json::to_string(&dtos)
+        .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
+}
+
 // ---------------------------------------------------------------------------
 // Multi-stream availability DTOs
 // ---------------------------------------------------------------------------
@@ -199,13 +404,72 @@ pub fn find_free_slots(
 struct EventStreamInput {
     stream_id: String,
     events: Vec<EventInput>,
+    #[serde(default)]
+    display_name: Option<String>,
+    #[serde(default)]
+    color: Option<String>,
 }
 
-#[derive(Serialize)]
+/// Convert stream inputs into `EventStream`s, rejecting reversed events.
+fn to_streams(inputs: Vec<EventStreamInput>) -> Result<Vec<truth_engine::EventStream>, JsValue> {
+    inputs
+        .into_iter()
+        .map(|si| {
+            Ok(truth_engine::EventStream {
+                events: si
+                    .events
+                    .iter()
+                    .map(parse_event)
+                    .collect::<Result<_, _>>()?,
+                stream_id: si.stream_id,
+                display_name: si.display_name,
+                color: si.color,
+            })
+        })
+        .collect()
+}
+
+/// Convert a JSON array of `{stream_id, events}` stream objects into `EventStream`s.
+fn parse_streams_json(json: &str) -> Result<Vec<truth_engine::EventStream>, JsValue> {
+    let inputs: Vec<EventStreamInput> = serde_json::from_str(json)
+        .map_err(|e| JsValue::from_str(&format!("Invalid streams JSON: {}", e)))?;
+    to_streams(inputs)
+}
+
+#[derive(Serialize, Deserialize)]
 struct BusyBlockDto {
     start: String,
     end: String,
     source_count: usize,
+    #[serde(default)]
+    sources: Vec<BusySourceDto>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BusySourceDto {
+    stream_id: String,
+    display_name: Option<String>,
+    color: Option<String>,
+}
+
+impl From<&truth_engine::BusySource> for BusySourceDto {
+    fn from(source: &truth_engine::BusySource) -> Self {
+        Self {
+            stream_id: source.stream_id.clone(),
+            display_name: source.display_name.clone(),
+            color: source.color.clone(),
+        }
+    }
+}
+
+impl From<&BusySourceDto> for truth_engine::BusySource {
+    fn from(dto: &BusySourceDto) -> Self {
+        Self {
+            stream_id: dto.stream_id.clone(),
+            display_name: dto.display_name.clone(),
+            color: dto.color.clone(),
+        }
+    }
 }
 
 #[derive(Serialize)]
@@ -217,56 +481,88 @@ struct UnifiedAvailabilityDto {
     privacy: String,
 }
 
+/// Input format for a meeting participant passed from JavaScript.
+#[derive(Deserialize)]
+struct ParticipantInput {
+    streams: Vec<EventStreamInput>,
+    window_start: String,
+    window_end: String,
+}
+
+/// Input format for an attendee with a home timezone and working hours.
+#[derive(Deserialize)]
+struct TzParticipantInput {
+    streams: Vec<EventStreamInput>,
+    timezone: String,
+    work_start_hour: u32,
+    work_end_hour: u32,
+}
+
+/// Input format for a `mergeAvailability` result passed back from JavaScript.
+#[derive(Deserialize)]
+struct UnifiedAvailabilityInput {
+    busy: Vec<BusyBlockDto>,
+    free: Vec<FreeSlotDto>,
+    window_start: String,
+    window_end: String,
+    #[serde(default)]
+    privacy: Option<String>,
+}
+
+#[derive(Serialize)]
+struct DayAvailabilityDto {
+    date: String,
+    busy: Vec<BusyBlockDto>,
+    free: Vec<FreeSlotDto>,
+}
+
 // ---------------------------------------------------------------------------
 // Multi-stream availability WASM exports
 // ---------------------------------------------------------------------------
 
+/// Parse a privacy string: `"full"`, `"opaque"`, or `"free_only"`.
+fn parse_privacy(s: &str) -> Result<truth_engine::PrivacyLevel, JsValue> {
+    match s {
+        "full" => Ok(truth_engine::PrivacyLevel::Full),
+        "opaque" => Ok(truth_engine::PrivacyLevel::Opaque),
+        "free_only" => Ok(truth_engine::PrivacyLevel::FreeOnly),
+        _ => Err(JsValue::from_str(&format!(
+            "Invalid privacy '{}': expected full, opaque, or free_only",
+            s
+        ))),
+    }
+}
+
 /// Merge N event streams into unified availability within a time window.
 ///
-/// `streams_json` must be a JSON array of `{stream_id, events: [{start, end}]}`.
+/// `streams_json` must be a JSON array of `{stream_id, events: [{start, end}]}`,
+/// optionally with `display_name` and `color` per stream.
 /// `window_start` and `window_end` are ISO 8601 datetime strings.
 /// `opaque` controls privacy: true = hide source counts, false = show them.
+/// `privacy`, when given, overrides `opaque` with `"full"`, `"opaque"`, or
+/// `"free_only"` (free slots only, no busy blocks).
 ///
 /// Returns a JSON string with `{busy, free, window_start, window_end, privacy}`.
+/// Under `"full"` privacy each busy block lists its contributing streams in
+/// `sources` as `{stream_id, display_name, color}`; otherwise `sources` is empty.
 #[wasm_bindgen(js_name = "mergeAvailability")]
 pub fn merge_availability(
     streams_json: &str,
     window_start: &str,
     window_end: &str,
     opaque: bool,
+    privacy: Option<String>,
 ) -> Result<String, JsValue> {
-    let stream_inputs: Vec<EventStreamInput> = serde_json::from_str(streams_json)
-        .map_err(|e| JsValue::from_str(&format!("Invalid streams JSON: {}", e)))?;
-
+    let streams = parse_streams_json(streams_json)?;
     let ws = parse_datetime(window_start)?;
     let we = parse_datetime(window_end)?;
 
-    let privacy = if opaque {
-        truth_engine::PrivacyLevel::Opaque
-    } else {
-        truth_engine::PrivacyLevel::Full
+    let privacy = match privacy.as_deref() {
+        Some(p) => parse_privacy(p)?,
+        None if opaque => truth_engine::PrivacyLevel::Opaque,
+        None => truth_engine::PrivacyLevel::Full,
     };
 
-    // Convert inputs to truth-engine types.
-    let streams: Vec<truth_engine::EventStream> = stream_inputs
-        .into_iter()
-        .map(|si| {
-            let events: Result<Vec<ExpandedEvent>, JsValue> = si
-                .events
-                .into_iter()
-                .map(|ei| {
-                    let start = parse_datetime(&ei.start)?;
-                    let end = parse_datetime(&ei.end)?;
-                    Ok(ExpandedEvent { start, end })
-                })
-                .collect();
-            Ok(truth_engine::EventStream {
-                stream_id: si.stream_id,
-                events: events?,
-            })
-        })
-        .collect::<Result<Vec<_>, JsValue>>()?;
-
     let result = truth_engine::merge_availability(&streams, ws, we, privacy);
 
     let dto = UnifiedAvailabilityDto {
@@ -277,6 +573,7 @@ pub fn merge_availability(
                 start: b.start.to_rfc3339(),
                 end: b.end.to_rfc3339(),
                 source_count: b.source_count,
+                sources: b.sources.iter().map(BusySourceDto::from).collect(),
             })
             .collect(),
         free: result
@@ -293,6 +590,7 @@ pub fn merge_availability(
         privacy: match result.privacy {
             truth_engine::PrivacyLevel::Full => "full".to_string(),
             truth_engine::PrivacyLevel::Opaque => "opaque".to_string(),
+            truth_engine::PrivacyLevel::FreeOnly => "free_only".to_string(),
         },
     };
 
@@ -312,31 +610,10 @@ pub fn find_first_free_across(
     window_end: &str,
     min_duration_minutes: i64,
 ) -> Result<String, JsValue> {
-    let stream_inputs: Vec<EventStreamInput> = serde_json::from_str(streams_json)
-        .map_err(|e| JsValue::from_str(&format!("Invalid streams JSON: {}", e)))?;
-
+    let streams = parse_streams_json(streams_json)?;
     let ws = parse_datetime(window_start)?;
     let we = parse_datetime(window_end)?;
 
-    let streams: Vec<truth_engine::EventStream> = stream_inputs
-        .into_iter()
-        .map(|si| {
-            let events: Result<Vec<ExpandedEvent>, JsValue> = si
-                .events
-                .into_iter()
-                .map(|ei| {
-                    let start = parse_datetime(&ei.start)?;
-                    let end = parse_datetime(&ei.end)?;
-                    Ok(ExpandedEvent { start, end })
-                })
-                .collect();
-            Ok(truth_engine::EventStream {
-                stream_id: si.stream_id,
-                events: events?,
-            })
-        })
-        .collect::<Result<Vec<_>, JsValue>>()?;
-
     let slot = truth_engine::find_first_free_across(&streams, ws, we, min_duration_minutes);
 
     match slot {
@@ -353,6 +630,277 @@ pub fn find_first_free_across(
     }
 }
 
+/// Find every free slot of at least `min_duration_minutes` across N merged
+/// event streams.
+///
+/// `streams_json` must be a JSON array of `{stream_id, events: [{start, end}]}`.
+/// Returns a JSON string containing an array of `{start, end, duration_minutes}`
+/// objects, sorted by start.
+#[wasm_bindgen(js_name = "findAllFreeAcross")]
+pub fn find_all_free_across(
+    streams_json: &str,
+    window_start: &str,
+    window_end: &str,
+    min_duration_minutes: i64,
+) -> Result<String, JsValue> {
+    let streams = parse_streams_json(streams_json)?;
+    let ws = parse_datetime(window_start)?;
+    let we = parse_datetime(window_end)?;
+
+    let slots: Vec<FreeSlotDto> =
+        truth_engine::find_all_free_across(&streams, ws, we, min_duration_minutes)
+            .iter()
+            .map(|s| FreeSlotDto {
+                start: s.start.to_rfc3339(),
+                end: s.end.to_rfc3339(),
+                duration_minutes: s.duration_minutes,
+            })
+            .collect();
+
+    serde_json::to_string(&slots)
+        .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
+}
+
+/// Find the next `n` free slots of at least `min_duration_minutes` across N
+/// merged event streams, starting at or after `after`.
+///
+/// `streams_json` must be a JSON array of `{stream_id, events: [{start, end}]}`.
+/// Returns a JSON string containing an array of up to `n`
+/// `{start, end, duration_minutes}` objects, sorted by start.
+#[wasm_bindgen(js_name = "nextFreeSlots")]
+pub fn next_free_slots(
+    streams_json: &str,
+    after: &str,
+    window_end: &str,
+    min_duration_minutes: i64,
+    n: usize,
+) -> Result<String, JsValue> {
+    let streams = parse_streams_json(streams_json)?;
+    let after = parse_datetime(after)?;
+    let we = parse_datetime(window_end)?;
+
+    let slots: Vec<FreeSlotDto> =
+        truth_engine::next_free_slots(&streams, after, we, min_duration_minutes, n)
+            .iter()
+            .map(|s| FreeSlotDto {
+                start: s.start.to_rfc3339(),
+                end: s.end.to_rfc3339(),
+                duration_minutes: s.duration_minutes,
+            })
+            .collect();
+
+    serde_json::to_string(&slots)
+        .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
+}
+
+/// The fraction of working time within the window that is busy, from 0.0 to 1.0.
+///
+/// `streams_json` must be a JSON array of `{stream_id, events: [{start, end}]}`.
+/// Working hours run from `work_start_hour` to `work_end_hour` local time in the
+/// IANA `timezone` on every date; busy time outside them is not counted.
+/// Returns 0.0 when the window holds no working time.
+#[wasm_bindgen(js_name = "utilization")]
+pub fn utilization(
+    streams_json: &str,
+    timezone: &str,
+    work_start_hour: u32,
+    work_end_hour: u32,
+    window_start: &str,
+    window_end: &str,
+) -> Result<f64, JsValue> {
+    let streams = parse_streams_json(streams_json)?;
+    let ws = parse_datetime(window_start)?;
+    let we = parse_datetime(window_end)?;
+    let tz: chrono_tz::Tz = timezone
+        .parse()
+        .map_err(|_| JsValue::from_str(&format!("Invalid timezone: {}", timezone)))?;
+
+    let hours = truth_engine::WorkingHours {
+        timezone: tz,
+        start_hour: work_start_hour,
+        end_hour: work_end_hour,
+    };
+    Ok(truth_engine::utilization(&streams, &hours, ws, we))
+}
+
+/// Find time slots free for every participant, each with their own window.
+///
+/// `participants_json` must be a JSON array of
+/// `{streams: [{stream_id, events: [{start, end}]}], window_start, window_end}`.
+/// Returns a JSON string containing an array of `{start, end, duration_minutes}`
+/// objects of at least `min_duration_minutes`.
+#[wasm_bindgen(js_name = "findCommonFree")]
+pub fn find_common_free(
+    participants_json: &str,
+    min_duration_minutes: i64,
+) -> Result<String, JsValue> {
+    let inputs: Vec<ParticipantInput> = serde_json::from_str(participants_json)
+        .map_err(|e| JsValue::from_str(&format!("Invalid participants JSON: {}", e)))?;
+
+    let participants: Vec<truth_engine::Participant> = inputs
+        .into_iter()
+        .map(|pi| {
+            let streams = to_streams(pi.streams)?;
+            Ok(truth_engine::Participant {
+                streams,
+                window_start: parse_datetime(&pi.window_start)?,
+                window_end: parse_datetime(&pi.window_end)?,
+            })
+        })
+        .collect::<Result<Vec<_>, JsValue>>()?;
+
+    let slots: Vec<FreeSlotDto> =
+        truth_engine::find_common_free(&participants, min_duration_minutes)
+            .iter()
+            .map(|s| FreeSlotDto {
+                start: s.start.to_rfc3339(),
+                end: s.end.to_rfc3339(),
+                duration_minutes: s.duration_minutes,
+            })
+            .collect();
+
+    serde_json::to_string(&slots)
+        .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
+}
+
+/// Find the earliest meeting inside every attendee's local working hours.
+///
+/// `attendees_json` must be a JSON array of
+/// `{streams: [{stream_id, events: [{start, end}]}], timezone, work_start_hour, work_end_hour}`,
+/// where `timezone` is an IANA name and the hours are local to it.
+/// Returns a JSON string with `{start, end}` or `null`.
+#[wasm_bindgen(js_name = "findSlotAcrossTimezones")]
+pub fn find_slot_across_timezones(
+    attendees_json: &str,
+    duration_minutes: i64,
+    window_start: &str,
+    window_end: &str,
+) -> Result<String, JsValue> {
+    let inputs: Vec<TzParticipantInput> = serde_json::from_str(attendees_json)
+        .map_err(|e| JsValue::from_str(&format!("Invalid attendees JSON: {}", e)))?;
+
+    let attendees: Vec<truth_engine::TzParticipant> = inputs
+        .into_iter()
+        .map(|ai| {
+            let streams = to_streams(ai.streams)?;
+            let timezone: chrono_tz::Tz = ai
+                .timezone
+                .parse()
+                .map_err(|_| JsValue::from_str(&format!("Invalid timezone: {}", ai.timezone)))?;
+            Ok(truth_engine::TzParticipant {
+                streams,
+                timezone,
+                work_start_hour: ai.work_start_hour,
+                work_end_hour: ai.work_end_hour,
+            })
+        })
+        .collect::<Result<Vec<_>, JsValue>>()?;
+
+    let ws = parse_datetime(window_start)?;
+    let we = parse_datetime(window_end)?;
+
+    match truth_engine::find_slot_across_timezones(&attendees, duration_minutes, ws, we) {
+        Some(event) => serde_json::to_string(&ExpandedEventDto::from(&event))
+            .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e))),
+        None => Ok("null".to_string()),
+    }
+}
+
+/// Split a merged availability into per-day buckets in a local timezone.
+///
+/// `availability_json` must be the `{busy, free, window_start, window_end, privacy}`
+/// object returned by `mergeAvailability`. `timezone` is an IANA timezone name.
+///
+/// Returns a JSON string containing an array of `{date, busy, free}` objects,
+/// where `date` is a local `YYYY-MM-DD` date and blocks are clipped to that day.
+#[wasm_bindgen(js_name = "bucketByDay")]
+pub fn bucket_by_day(availability_json: &str, timezone: &str) -> Result<String, JsValue> {
+    let input: UnifiedAvailabilityInput = serde_json::from_str(availability_json)
+        .map_err(|e| JsValue::from_str(&format!("Invalid availability JSON: {}", e)))?;
+
+    let tz: chrono_tz::Tz = timezone
+        .parse()
+        .map_err(|_| JsValue::from_str(&format!("Invalid timezone: {}", timezone)))?;
+
+    let availability = truth_engine::UnifiedAvailability {
+        busy: input
+            .busy
+            .iter()
+            .map(|b| {
+                Ok(truth_engine::BusyBlock {
+                    start: parse_datetime(&b.start)?,
+                    end: parse_datetime(&b.end)?,
+                    source_count: b.source_count,
+                    sources: b.sources.iter().map(Into::into).collect(),
+                })
+            })
+            .collect::<Result<Vec<_>, JsValue>>()?,
+        free: input
+            .free
+            .iter()
+            .map(|s| {
+                Ok(truth_engine::FreeSlot {
+                    start: parse_datetime(&s.start)?,
+                    end: parse_datetime(&s.end)?,
+                    duration_minutes: s.duration_minutes,
+                })
+            })
+            .collect::<Result<Vec<_>, JsValue>>()?,
+        window_start: parse_datetime(&input.window_start)?,
+        window_end: parse_datetime(&input.window_end)?,
+        privacy: match input.privacy.as_deref() {
+            Some("full") => truth_engine::PrivacyLevel::Full,
+            Some("free_only") => truth_engine::PrivacyLevel::FreeOnly,
+            _ => truth_engine::PrivacyLevel::Opaque,
+        },
+        truncated_streams: Vec::new(),
+    };
+
+    let days: Vec<DayAvailabilityDto> = truth_engine::bucket_by_day(&availability, tz)
+        .into_iter()
+        .map(|day| DayAvailabilityDto {
+            date: day.date.to_string(),
+            busy: day
+                .busy
+                .iter()
+                .map(|b| BusyBlockDto {
+                    start: b.start.to_rfc3339(),
+                    end: b.end.to_rfc3339(),
+                    source_count: b.source_count,
+                    sources: b.sources.iter().map(BusySourceDto::from).collect(),
+                })
+                .collect(),
+            free: day
+                .free
+                .iter()
+                .map(|s| FreeSlotDto {
+                    start: s.start.to_rfc3339(),
+                    end: s.end.to_rfc3339(),
+                    duration_minutes: s.duration_minutes,
+                })
+                .collect(),
+        })
+        .collect();
+
+    serde_json::to_string(&days)
+        .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
+}
+
+/// Parse the VEVENTs of an iCalendar (`.ics`) document.
+///
+/// Returns a JSON string containing an array of
+/// `{summary, dtstart, timezone, duration_minutes, rrule, exdates}` objects.
+/// `dtstart` and each of `exdates` are local datetimes in `timezone`, so
+/// `rrule`, `dtstart`, `duration_minutes`, and `timezone` can be passed straight
+/// to `expandRRule`.
+#[wasm_bindgen(js_name = "parseIcs")]
+pub fn parse_ics(ics: &str) -> Result<String, JsValue> {
+    let events = truth_engine::parse_ics(ics).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    serde_json::to_string(&events)
+        .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
+}
+
 // ---------------------------------------------------------------------------
 // Temporal computation WASM exports
 // ---------------------------------------------------------------------------
@@ -452,6 +1000,33 @@ pub fn resolve_relative_with_options(
         .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
 }
 
+/// Resolve a named window spec to a `{start, end}` range.
+///
+/// `spec` is one of `"today"`, `"tomorrow"`, `"this_week"`, `"next_week"`,
+/// `"this_month"`, `"next_month"` or `"next_N_days"` (e.g., `"next_7_days"`).
+/// `now` is an RFC 3339 datetime; day, week (Monday start) and month boundaries
+/// are local midnights in the IANA `timezone`.
+///
+/// Returns a JSON string with `{start, end}` as RFC 3339 UTC strings; the end is
+/// exclusive.
+#[wasm_bindgen(js_name = "parseRelativeWindow")]
+pub fn parse_relative_window(spec: &str, now: &str, timezone: &str) -> Result<String, JsValue> {
+    let now_dt = parse_datetime(now)?;
+    let tz: chrono_tz::Tz = timezone
+        .parse()
+        .map_err(|_| JsValue::from_str(&format!("Invalid timezone: {}", timezone)))?;
+
+    let (start, end) = truth_engine::temporal::parse_relative_window(spec, now_dt, tz)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    let dto = ExpandedEventDto {
+        start: start.to_rfc3339(),
+        end: end.to_rfc3339(),
+    };
+    serde_json::to_string(&dto)
+        .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
+}
+
 /// Input format for resolve options passed from JavaScript.
 #[derive(Deserialize)]
 struct ResolveOptionsInput {
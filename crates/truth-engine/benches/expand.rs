@@ -0,0 +1,44 @@
+//! RRULE expansion baselines.
+//!
+//! Run with `cargo bench -p truth-engine`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+use truth_engine::expand_rrule;
+
+fn bench_expand(c: &mut Criterion) {
+    // Without an external count expansion stops at 500 instances, whatever the
+    // rule's COUNT, so pass it explicitly to expand all 1000.
+    let mut group = c.benchmark_group("expand_rrule");
+    group.bench_function("daily_count_1000_utc", |b| {
+        b.iter(|| {
+            expand_rrule(
+                black_box("FREQ=DAILY;COUNT=1000"),
+                "2026-01-01T09:00:00",
+                60,
+                "UTC",
+                None,
+                Some(1000),
+            )
+            .unwrap()
+        })
+    });
+    // A DST-observing zone exercises the per-instance offset lookups.
+    group.bench_function("daily_count_1000_new_york", |b| {
+        b.iter(|| {
+            expand_rrule(
+                black_box("FREQ=DAILY;COUNT=1000"),
+                "2026-01-01T09:00:00",
+                60,
+                "America/New_York",
+                None,
+                Some(1000),
+            )
+            .unwrap()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_expand);
+criterion_main!(benches);
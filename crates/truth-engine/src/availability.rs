@@ -7,30 +7,57 @@
 //! This module is the core of the "Unified Availability Graph" — it computes the
 //! single source of truth for a user's availability across all their calendars.
 
-use chrono::{DateTime, Utc};
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Result, TruthError};
 use crate::expander::ExpandedEvent;
 use crate::freebusy::{self, FreeSlot};
 
 /// A named event stream from a single calendar source.
-#[derive(Debug, Clone)]
+///
+/// Build one with [`EventStream::new`] and set `display_name`/`color` with
+/// struct update syntax when the calendar has them.
+#[derive(Debug, Clone, Default)]
 pub struct EventStream {
     /// Opaque identifier for this stream (e.g., "work-google", "personal-icloud").
     pub stream_id: String,
     /// The events in this stream (already expanded from RRULEs if applicable).
     pub events: Vec<ExpandedEvent>,
+    /// Human-readable calendar name, echoed on busy blocks under `Full` privacy.
+    pub display_name: Option<String>,
+    /// Calendar color (any string, e.g. `"#1a73e8"`), echoed like `display_name`.
+    pub color: Option<String>,
+}
+
+impl EventStream {
+    /// A stream of `events` with no display metadata.
+    pub fn new(stream_id: impl Into<String>, events: Vec<ExpandedEvent>) -> Self {
+        Self {
+            stream_id: stream_id.into(),
+            events,
+            display_name: None,
+            color: None,
+        }
+    }
 }
 
 /// Privacy level for availability output.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum PrivacyLevel {
-    /// Show time ranges and source count per busy block.
+    /// Show time ranges, source count and contributing streams per busy block.
     Full,
     /// Show only busy/free time ranges — no source details leak through.
-    /// `source_count` is set to 0 for all busy blocks.
+    /// `source_count` is set to 0 and `sources` left empty for all busy blocks.
     #[default]
     Opaque,
+    /// Show only free slots — `busy` is left empty, so not even the times you
+    /// are busy are revealed. For publishing availability externally.
+    FreeOnly,
 }
 
 /// A merged busy block in the unified availability view.
@@ -43,6 +70,31 @@ pub struct BusyBlock {
     /// Number of source streams that contributed events to this block.
     /// Set to 0 when privacy is `Opaque`.
     pub source_count: usize,
+    /// The contributing streams, in input order, with their display metadata.
+    /// Empty when privacy is `Opaque`.
+    #[serde(default)]
+    pub sources: Vec<BusySource>,
+}
+
+/// A stream that contributed to a [`BusyBlock`], as labeled by the caller.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BusySource {
+    /// The stream's [`EventStream::stream_id`].
+    pub stream_id: String,
+    /// The stream's [`EventStream::display_name`].
+    pub display_name: Option<String>,
+    /// The stream's [`EventStream::color`].
+    pub color: Option<String>,
+}
+
+impl From<&EventStream> for BusySource {
+    fn from(stream: &EventStream) -> Self {
+        Self {
+            stream_id: stream.stream_id.clone(),
+            display_name: stream.display_name.clone(),
+            color: stream.color.clone(),
+        }
+    }
 }
 
 /// Unified availability result after merging N event streams.
@@ -58,6 +110,62 @@ pub struct UnifiedAvailability {
     pub window_end: DateTime<Utc>,
     /// Privacy level applied to this result.
     pub privacy: PrivacyLevel,
+    /// IDs of streams whose in-window events exceeded
+    /// [`MergeOptions::max_events_per_stream`] and were truncated.
+    #[serde(default)]
+    pub truncated_streams: Vec<String>,
+}
+
+/// One attendee's calendars and the window they are willing to meet in.
+#[derive(Debug, Clone)]
+pub struct Participant {
+    /// The participant's event streams (one per calendar).
+    pub streams: Vec<EventStream>,
+    /// Start of the participant's availability window (e.g., local working hours).
+    pub window_start: DateTime<Utc>,
+    /// End of the participant's availability window.
+    pub window_end: DateTime<Utc>,
+}
+
+/// An attendee with their calendars, home timezone, and local working hours.
+#[derive(Debug, Clone)]
+pub struct TzParticipant {
+    /// The attendee's event streams (one per calendar).
+    pub streams: Vec<EventStream>,
+    /// The attendee's IANA timezone; working hours are local to it.
+    pub timezone: Tz,
+    /// Local hour the working day starts (0-23).
+    pub work_start_hour: u32,
+    /// Local hour the working day ends (1-24, exclusive of the hour itself).
+    pub work_end_hour: u32,
+}
+
+/// Local working hours, the same every day, in one timezone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkingHours {
+    /// The IANA timezone the hours are local to.
+    pub timezone: Tz,
+    /// Local hour the working day starts (0-23).
+    pub start_hour: u32,
+    /// Local hour the working day ends (1-24, exclusive of the hour itself).
+    pub end_hour: u32,
+}
+
+/// Options for [`merge_availability_with_options`].
+#[derive(Debug, Clone, Default)]
+pub struct MergeOptions {
+    /// Cap on the number of in-window events considered per stream. When a stream
+    /// exceeds it, only its earliest-starting events are kept and its ID is
+    /// reported in [`UnifiedAvailability::truncated_streams`]. `None` = no cap.
+    pub max_events_per_stream: Option<usize>,
+    /// Collapse exact-duplicate events (same start and end) within each stream
+    /// before merging and before `max_events_per_stream` is applied, so a sync
+    /// that lists the same meeting twice costs one slot of the cap. Duplicates
+    /// across streams are kept: two calendars both holding an event is meaningful.
+    ///
+    /// Busy time and `source_count` are unaffected either way — overlapping
+    /// events merge into one block, and a stream counts once per block.
+    pub dedup_events: bool,
 }
 
 /// Merge N event streams into unified availability within a time window.
@@ -65,27 +173,58 @@ pub struct UnifiedAvailability {
 /// All events from all streams are flattened, clipped to the window, and merged
 /// into non-overlapping busy blocks. Free slots are the gaps between busy blocks.
 ///
-/// When `privacy` is `Opaque`, `source_count` is set to 0 on all busy blocks —
-/// no information about how many calendars contributed leaks through.
+/// When `privacy` is `Full`, each busy block lists its contributing streams in
+/// `sources`, with their `display_name` and `color`. When it is `Opaque`,
+/// `source_count` is set to 0 and `sources` left empty on all busy blocks —
+/// no information about how many calendars contributed leaks through. When it
+/// is `FreeOnly`, `busy` is empty and only the free slots are returned.
+///
+/// Zero-duration events (`start == end`, such as reminders) do not block time:
+/// they create no busy block, never split a free slot, and are not counted
+/// toward `source_count` or [`MergeOptions::max_events_per_stream`].
 ///
 /// # Arguments
 ///
 /// * `streams` — The event streams to merge (from different calendars/providers).
 /// * `window_start` — Start of the time window to analyze.
 /// * `window_end` — End of the time window to analyze.
-/// * `privacy` — Controls whether source count is included in busy blocks.
+/// * `privacy` — Controls whether busy blocks, and their source counts, are included.
 pub fn merge_availability(
     streams: &[EventStream],
     window_start: DateTime<Utc>,
     window_end: DateTime<Utc>,
     privacy: PrivacyLevel,
+) -> UnifiedAvailability {
+    merge_availability_with_options(
+        streams,
+        window_start,
+        window_end,
+        privacy,
+        &MergeOptions::default(),
+    )
+}
+
+/// Merge N event streams into unified availability with options.
+///
+/// Identical to [`merge_availability`] but accepts [`MergeOptions`]. With
+/// `max_events_per_stream` set, a misbehaving source that returns an enormous
+/// event list is cut down to its earliest-starting in-window events before
+/// merging, and its ID is listed in `truncated_streams` so the caller knows
+/// data was dropped. With `dedup_events` set, exact-duplicate events within a
+/// stream are collapsed first.
+pub fn merge_availability_with_options(
+    streams: &[EventStream],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    privacy: PrivacyLevel,
+    options: &MergeOptions,
 ) -> UnifiedAvailability {
     if streams.is_empty() || window_start >= window_end {
         let free = if window_start < window_end {
             vec![FreeSlot {
                 start: window_start,
                 end: window_end,
-                duration_minutes: (window_end - window_start).num_minutes(),
+                duration_minutes: freebusy::minutes_between(window_start, window_end),
             }]
         } else {
             vec![]
@@ -96,69 +235,1024 @@ pub fn merge_availability(
             window_start,
             window_end,
             privacy,
+            truncated_streams: vec![],
         };
     }
 
-    // Flatten all events from all streams into a single list.
-    let all_events: Vec<ExpandedEvent> = streams
+    let deduped = if options.dedup_events {
+        dedup_stream_events(streams)
+    } else {
+        Cow::Borrowed(streams)
+    };
+    let (streams, truncated_streams) = match options.max_events_per_stream {
+        Some(limit) => limit_stream_events(&deduped, window_start, window_end, limit),
+        None => (Cow::Borrowed(&*deduped), Vec::new()),
+    };
+    let streams: &[EventStream] = &streams;
+
+    // Fast path: one event spanning the whole window makes it a single busy
+    // block with no free time, so there is nothing to sort or sweep.
+    let covered = streams.iter().any(|s| {
+        s.events
+            .iter()
+            .any(|e| e.start <= window_start && e.end >= window_end)
+    });
+
+    let (merged_intervals, free) = if covered {
+        (vec![(window_start, window_end)], Vec::new())
+    } else {
+        // Flatten all events from all streams into a single list.
+        let all_events: Vec<ExpandedEvent> = streams
+            .iter()
+            .flat_map(|s| s.events.iter().cloned())
+            .collect();
+
+        // Compute merged busy periods and free slots using the freebusy algorithm.
+        (
+            freebusy::merge_busy_periods(&all_events, window_start, window_end),
+            freebusy::find_free_slots(&all_events, window_start, window_end),
+        )
+    };
+
+    let busy = busy_blocks(
+        streams,
+        &merged_intervals,
+        window_start,
+        window_end,
+        privacy,
+    );
+
+    UnifiedAvailability {
+        busy,
+        free,
+        window_start,
+        window_end,
+        privacy,
+        truncated_streams,
+    }
+}
+
+/// Merged availability that can be updated one stream at a time.
+///
+/// [`merge_availability`] re-merges every stream on every call. A long-lived
+/// service that recomputes availability whenever one calendar changes can keep
+/// an `AvailabilityIndex` instead: each stream's events are merged into busy
+/// periods once, when the stream is added or replaced with
+/// [`update_stream`](Self::update_stream), and [`query`](Self::query) only
+/// combines the cached periods that fall inside the requested window.
+///
+/// `query` returns exactly what [`merge_availability`] returns for the same
+/// streams, window and privacy level.
+///
+/// # Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::{AvailabilityIndex, ExpandedEvent, PrivacyLevel};
+///
+/// let at = |h| Utc.with_ymd_and_hms(2026, 3, 16, h, 0, 0).unwrap();
+/// let mut index = AvailabilityIndex::new(PrivacyLevel::Full);
+/// index.update_stream("work", &[ExpandedEvent { start: at(9), end: at(10) }]);
+/// index.update_stream("work", &[ExpandedEvent { start: at(13), end: at(14) }]);
+///
+/// let availability = index.query(at(8), at(17));
+/// assert_eq!(availability.busy.len(), 1);
+/// assert_eq!(availability.busy[0].start, at(13));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AvailabilityIndex {
+    privacy: PrivacyLevel,
+    streams: Vec<IndexedStream>,
+}
+
+/// One stream's events, merged into sorted, non-overlapping busy periods.
+#[derive(Debug, Clone)]
+struct IndexedStream {
+    source: BusySource,
+    busy: Vec<(DateTime<Utc>, DateTime<Utc>)>,
+}
+
+impl AvailabilityIndex {
+    /// An empty index whose queries apply `privacy`.
+    pub fn new(privacy: PrivacyLevel) -> Self {
+        Self {
+            privacy,
+            streams: Vec::new(),
+        }
+    }
+
+    /// An index holding `streams`, whose queries apply `privacy`.
+    ///
+    /// The streams' `display_name` and `color` are kept for busy-block `sources`.
+    /// Streams sharing a `stream_id` are indexed as one stream holding all their
+    /// events, with the first one's display metadata.
+    pub fn from_streams(streams: &[EventStream], privacy: PrivacyLevel) -> Self {
+        let mut index = Self::new(privacy);
+        for stream in streams {
+            if index
+                .streams
+                .iter()
+                .any(|s| s.source.stream_id == stream.stream_id)
+            {
+                continue;
+            }
+            let events: Vec<ExpandedEvent> = streams
+                .iter()
+                .filter(|s| s.stream_id == stream.stream_id)
+                .flat_map(|s| s.events.iter().cloned())
+                .collect();
+            index.update_stream(&stream.stream_id, &events);
+            if let Some(indexed) = index
+                .streams
+                .iter_mut()
+                .find(|s| s.source.stream_id == stream.stream_id)
+            {
+                indexed.source = BusySource::from(stream);
+            }
+        }
+        index
+    }
+
+    /// Replace the events of stream `stream_id`, adding the stream if it is new.
+    ///
+    /// Only this stream's busy periods are recomputed; the others are reused. A
+    /// replaced stream keeps its display metadata; a new one has none.
+    pub fn update_stream(&mut self, stream_id: &str, events: &[ExpandedEvent]) {
+        let busy = freebusy::merge_busy_periods(
+            events,
+            DateTime::<Utc>::MIN_UTC,
+            DateTime::<Utc>::MAX_UTC,
+        );
+        match self
+            .streams
+            .iter_mut()
+            .find(|s| s.source.stream_id == stream_id)
+        {
+            Some(stream) => stream.busy = busy,
+            None => self.streams.push(IndexedStream {
+                source: BusySource {
+                    stream_id: stream_id.to_string(),
+                    display_name: None,
+                    color: None,
+                },
+                busy,
+            }),
+        }
+    }
+
+    /// Drop stream `stream_id`. Returns `false` if the index did not hold it.
+    pub fn remove_stream(&mut self, stream_id: &str) -> bool {
+        let before = self.streams.len();
+        self.streams.retain(|s| s.source.stream_id != stream_id);
+        self.streams.len() != before
+    }
+
+    /// Unified availability within the window, as [`merge_availability`] would
+    /// compute it for the streams currently in the index.
+    pub fn query(
+        &self,
+        window_start: DateTime<Utc>,
+        window_end: DateTime<Utc>,
+    ) -> UnifiedAvailability {
+        let mut busy = Vec::new();
+        let mut free = Vec::new();
+        if window_start < window_end {
+            // Each stream's periods are sorted with increasing ends, so the
+            // in-window ones are a contiguous run found by binary search.
+            let mut periods: Vec<(DateTime<Utc>, DateTime<Utc>, usize)> = Vec::new();
+            for (i, stream) in self.streams.iter().enumerate() {
+                let first = stream.busy.partition_point(|&(_, end)| end <= window_start);
+                periods.extend(
+                    stream.busy[first..]
+                        .iter()
+                        .take_while(|&&(start, _)| start < window_end)
+                        .map(|&(start, end)| (start.max(window_start), end.min(window_end), i)),
+                );
+            }
+            periods.sort_unstable();
+
+            // Merge across streams, tracking which streams feed each block.
+            let mut blocks: Vec<(DateTime<Utc>, DateTime<Utc>, HashSet<usize>)> = Vec::new();
+            for (start, end, stream) in periods {
+                match blocks.last_mut() {
+                    Some(last) if start <= last.1 => {
+                        last.1 = last.1.max(end);
+                        last.2.insert(stream);
+                    }
+                    _ => blocks.push((start, end, HashSet::from([stream]))),
+                }
+            }
+
+            let merged: Vec<(DateTime<Utc>, DateTime<Utc>)> = blocks
+                .iter()
+                .map(|(start, end, _)| (*start, *end))
+                .collect();
+            free = freebusy::gaps_between(&merged, window_start, window_end);
+            busy = match self.privacy {
+                PrivacyLevel::Full => blocks
+                    .into_iter()
+                    .map(|(start, end, sources)| {
+                        let mut sources: Vec<usize> = sources.into_iter().collect();
+                        sources.sort_unstable();
+                        BusyBlock {
+                            start,
+                            end,
+                            source_count: sources.len(),
+                            sources: sources
+                                .into_iter()
+                                .map(|i| self.streams[i].source.clone())
+                                .collect(),
+                        }
+                    })
+                    .collect(),
+                PrivacyLevel::Opaque => merged
+                    .into_iter()
+                    .map(|(start, end)| BusyBlock {
+                        start,
+                        end,
+                        source_count: 0,
+                        sources: Vec::new(),
+                    })
+                    .collect(),
+                PrivacyLevel::FreeOnly => Vec::new(),
+            };
+        }
+
+        UnifiedAvailability {
+            busy,
+            free,
+            window_start,
+            window_end,
+            privacy: self.privacy,
+            truncated_streams: vec![],
+        }
+    }
+}
+
+/// Drop repeated `(start, end)` pairs within each stream, keeping the first of each.
+///
+/// Streams without duplicates are passed through untouched.
+fn dedup_stream_events(streams: &[EventStream]) -> Cow<'_, [EventStream]> {
+    let has_duplicates = |s: &EventStream| {
+        let mut seen = HashSet::new();
+        !s.events.iter().all(|e| seen.insert((e.start, e.end)))
+    };
+    if !streams.iter().any(has_duplicates) {
+        return Cow::Borrowed(streams);
+    }
+
+    let deduped = streams
         .iter()
-        .flat_map(|s| s.events.iter().cloned())
+        .map(|stream| {
+            let mut seen = HashSet::new();
+            EventStream {
+                events: stream
+                    .events
+                    .iter()
+                    .filter(|e| seen.insert((e.start, e.end)))
+                    .cloned()
+                    .collect(),
+                ..stream.clone()
+            }
+        })
+        .collect();
+    Cow::Owned(deduped)
+}
+
+/// Cap each stream at `limit` in-window events, keeping the earliest-starting ones.
+///
+/// Returns the (possibly trimmed) streams plus the IDs of streams that were cut.
+/// Streams within the limit are passed through untouched.
+fn limit_stream_events(
+    streams: &[EventStream],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    limit: usize,
+) -> (Cow<'_, [EventStream]>, Vec<String>) {
+    let in_window =
+        |e: &&ExpandedEvent| e.start < e.end && e.start < window_end && e.end > window_start;
+    let over_limit = |s: &EventStream| s.events.iter().filter(in_window).count() > limit;
+
+    if !streams.iter().any(over_limit) {
+        return (Cow::Borrowed(streams), Vec::new());
+    }
+
+    let mut truncated = Vec::new();
+    let limited = streams
+        .iter()
+        .map(|stream| {
+            if !over_limit(stream) {
+                return stream.clone();
+            }
+            let mut events: Vec<ExpandedEvent> =
+                stream.events.iter().filter(in_window).cloned().collect();
+            events.sort_by_key(|e| (e.start, e.end));
+            events.truncate(limit);
+            truncated.push(stream.stream_id.clone());
+            EventStream {
+                events,
+                ..stream.clone()
+            }
+        })
+        .collect();
+
+    (Cow::Owned(limited), truncated)
+}
+
+/// Merge N event streams into unified availability, treating whole local dates
+/// as fully busy.
+///
+/// Each date in `blackout_dates` (company holidays, PTO days) is interpreted in
+/// `timezone` and its local 00:00–24:00 span is marked busy before free slots are
+/// computed, so no free time is offered on those days. Day bounds are computed
+/// in local time, so 23- and 25-hour DST transition days are covered exactly.
+///
+/// Blackout days are busy time but not a calendar: under `PrivacyLevel::Full`
+/// they are not counted in `source_count` or listed in `sources`, so a block
+/// covered only by a blackout has no sources.
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidTimezone`] if `timezone` is not a valid IANA name.
+pub fn merge_availability_with_blackouts(
+    streams: &[EventStream],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    privacy: PrivacyLevel,
+    blackout_dates: &[NaiveDate],
+    timezone: &str,
+) -> Result<UnifiedAvailability> {
+    let tz: Tz = timezone
+        .parse()
+        .map_err(|_| TruthError::InvalidTimezone(timezone.to_string()))?;
+
+    let blackout_events: Vec<ExpandedEvent> = blackout_dates
+        .iter()
+        .filter_map(|date| {
+            let start = local_day_start(*date, &tz)?;
+            let end = local_day_start(date.succ_opt()?, &tz)?;
+            Some(ExpandedEvent { start, end })
+        })
         .collect();
 
-    // Compute merged busy periods using the existing freebusy algorithm.
+    if blackout_events.is_empty() || window_start >= window_end {
+        return Ok(merge_availability(
+            streams,
+            window_start,
+            window_end,
+            privacy,
+        ));
+    }
+
+    // Blackouts join the busy set directly rather than as a stream, so they
+    // never show up as a source.
+    let mut all_events: Vec<ExpandedEvent> = streams
+        .iter()
+        .flat_map(|s| s.events.iter().cloned())
+        .collect();
+    all_events.extend(blackout_events);
     let merged_intervals = freebusy::merge_busy_periods(&all_events, window_start, window_end);
+    let free = freebusy::gaps_between(&merged_intervals, window_start, window_end);
+    let busy = busy_blocks(
+        streams,
+        &merged_intervals,
+        window_start,
+        window_end,
+        privacy,
+    );
 
-    // Build busy blocks with source count tracking.
-    let busy: Vec<BusyBlock> = if privacy == PrivacyLevel::Full {
+    Ok(UnifiedAvailability {
+        busy,
+        free,
+        window_start,
+        window_end,
+        privacy,
+        truncated_streams: Vec::new(),
+    })
+}
+
+/// Build the busy blocks for already-merged busy intervals, with the source
+/// detail `privacy` allows.
+fn busy_blocks(
+    streams: &[EventStream],
+    merged_intervals: &[(DateTime<Utc>, DateTime<Utc>)],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    privacy: PrivacyLevel,
+) -> Vec<BusyBlock> {
+    match privacy {
         // For Full privacy, compute source counts via sweep-line.
-        compute_busy_blocks_with_sources(streams, &merged_intervals, window_start, window_end)
-    } else {
-        // For Opaque privacy, source_count is always 0.
-        merged_intervals
+        PrivacyLevel::Full => {
+            compute_busy_blocks_with_sources(streams, merged_intervals, window_start, window_end)
+        }
+        // For Opaque privacy, source_count is always 0 and no sources are named.
+        PrivacyLevel::Opaque => merged_intervals
             .iter()
             .map(|(start, end)| BusyBlock {
                 start: *start,
                 end: *end,
                 source_count: 0,
+                sources: Vec::new(),
+            })
+            .collect(),
+        // For FreeOnly privacy, busy blocks are withheld entirely.
+        PrivacyLevel::FreeOnly => Vec::new(),
+    }
+}
+
+/// The UTC instant of local midnight on `date` in `tz`.
+///
+/// A few zones (e.g., America/Santiago) move their clocks at midnight, so 00:00
+/// may not exist; in that case the day starts at the first valid local time
+/// after the gap.
+pub(crate) fn local_day_start(date: NaiveDate, tz: &Tz) -> Option<DateTime<Utc>> {
+    local_hour_start(date, 0, tz)
+}
+
+/// The UTC instant of local `hour`:00 on `date` in `tz` (24 is the next midnight).
+///
+/// If that time falls in a DST gap, the first valid local time after it is used.
+fn local_hour_start(date: NaiveDate, hour: u32, tz: &Tz) -> Option<DateTime<Utc>> {
+    let local = date.and_hms_opt(0, 0, 0)? + Duration::hours(i64::from(hour));
+    tz.from_local_datetime(&local)
+        .earliest()
+        .or_else(|| {
+            tz.from_local_datetime(&(local + Duration::hours(1)))
+                .earliest()
+        })
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Busy and free blocks for a single local calendar date.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayAvailability {
+    /// The local date in the bucketing timezone.
+    pub date: NaiveDate,
+    /// Busy blocks clipped to this day's local bounds.
+    pub busy: Vec<BusyBlock>,
+    /// Free slots clipped to this day's local bounds.
+    pub free: Vec<FreeSlot>,
+}
+
+/// Split a merged availability into per-day buckets in `timezone`.
+///
+/// One [`DayAvailability`] is produced for every local date that intersects the
+/// availability window. Each day spans local 00:00 to the next local 00:00, so
+/// 23- and 25-hour DST days get their true length. Blocks that cross local
+/// midnight are split, with each piece clipped to its own day; free-slot
+/// durations are recomputed for the clipped pieces.
+pub fn bucket_by_day(availability: &UnifiedAvailability, timezone: Tz) -> Vec<DayAvailability> {
+    let (window_start, window_end) = (availability.window_start, availability.window_end);
+    if window_start >= window_end {
+        return Vec::new();
+    }
+
+    let mut days = Vec::new();
+    let mut date = window_start.with_timezone(&timezone).date_naive();
+    while let Some(day_start) = local_day_start(date, &timezone) {
+        if day_start >= window_end {
+            break;
+        }
+        let Some(next_date) = date.succ_opt() else {
+            break;
+        };
+        let Some(day_end) = local_day_start(next_date, &timezone) else {
+            break;
+        };
+        let (lo, hi) = (day_start.max(window_start), day_end.min(window_end));
+
+        let busy = availability
+            .busy
+            .iter()
+            .filter(|b| b.start < hi && b.end > lo)
+            .map(|b| BusyBlock {
+                start: b.start.max(lo),
+                end: b.end.min(hi),
+                source_count: b.source_count,
+                sources: b.sources.clone(),
             })
-            .collect()
+            .collect();
+        let free = availability
+            .free
+            .iter()
+            .filter(|f| f.start < hi && f.end > lo)
+            .map(|f| {
+                let (start, end) = (f.start.max(lo), f.end.min(hi));
+                FreeSlot {
+                    start,
+                    end,
+                    duration_minutes: freebusy::minutes_between(start, end),
+                }
+            })
+            .collect();
+
+        days.push(DayAvailability { date, busy, free });
+        date = next_date;
+    }
+    days
+}
+
+/// Split events into one segment per local calendar day in `timezone`.
+///
+/// Each event is cut at every local midnight it crosses, so a conference from
+/// 09:00 Monday to 17:00 Wednesday becomes three segments: Monday 09:00–24:00,
+/// all of Tuesday, and Wednesday 00:00–17:00. Cut points are computed in local
+/// time, so 23- and 25-hour DST days get their true length. Events within a
+/// single day (including zero-length ones) are returned unchanged, and segments
+/// keep the input order.
+pub fn split_at_day_boundaries(events: &[ExpandedEvent], timezone: Tz) -> Vec<ExpandedEvent> {
+    let mut segments = Vec::with_capacity(events.len());
+    for event in events {
+        let mut start = event.start;
+        let mut date = start.with_timezone(&timezone).date_naive();
+        while let Some(next_date) = date.succ_opt() {
+            match local_day_start(next_date, &timezone) {
+                Some(cut) if cut < event.end => {
+                    segments.push(ExpandedEvent { start, end: cut });
+                    start = cut;
+                    date = next_date;
+                }
+                _ => break,
+            }
+        }
+        segments.push(ExpandedEvent {
+            start,
+            end: event.end,
+        });
+    }
+    segments
+}
+
+/// The free stretches at either edge of a merged availability window.
+///
+/// Returns `(leading, trailing)`: the free time from `window_start` to the first
+/// busy block, and from the last busy block to `window_end`. Each side is `None`
+/// when there is no margin there (the busy time touches that edge), and both are
+/// `None` when there are no busy blocks at all.
+pub fn day_margins(availability: &UnifiedAvailability) -> (Option<FreeSlot>, Option<FreeSlot>) {
+    let (Some(first), Some(last)) = (availability.busy.first(), availability.busy.last()) else {
+        return (None, None);
     };
+    let margin = |start: DateTime<Utc>, end: DateTime<Utc>| {
+        (start < end).then(|| FreeSlot {
+            start,
+            end,
+            duration_minutes: freebusy::minutes_between(start, end),
+        })
+    };
+    (
+        margin(availability.window_start, first.start),
+        margin(last.end, availability.window_end),
+    )
+}
 
-    // Compute free slots from the merged intervals.
-    let free = freebusy::find_free_slots(&all_events, window_start, window_end);
+/// Stitch availability computed over consecutive windows into one result.
+///
+/// The inverse of [`bucket_by_day`]: per-day [`merge_availability`] results can
+/// be joined into a week-long view. Parts must be given in order, with each
+/// window starting exactly where the previous one ends. Where a busy block (or
+/// free slot) ends at a seam and the next part's first busy block (or free
+/// slot) starts there, the two are coalesced into one, so a free slot from
+/// 18:00 to 08:00 the next morning comes back whole. Coalesced busy blocks
+/// combine their `sources`; free-slot durations are recomputed.
+///
+/// # Errors
+///
+/// Returns [`TruthError::Availability`] if `parts` is empty, if two consecutive
+/// windows leave a gap or overlap, or if the parts use different privacy levels.
+pub fn concat_availability(parts: &[UnifiedAvailability]) -> Result<UnifiedAvailability> {
+    let Some((first, rest)) = parts.split_first() else {
+        return Err(TruthError::Availability(
+            "no availability parts to concatenate".to_string(),
+        ));
+    };
+    let mut combined = first.clone();
 
-    UnifiedAvailability {
-        busy,
-        free,
-        window_start,
-        window_end,
-        privacy,
+    for part in rest {
+        if part.window_start != combined.window_end {
+            let problem = if part.window_start > combined.window_end {
+                "gap"
+            } else {
+                "overlap"
+            };
+            return Err(TruthError::Availability(format!(
+                "{} between window ending {} and window starting {}",
+                problem, combined.window_end, part.window_start
+            )));
+        }
+        if part.privacy != combined.privacy {
+            return Err(TruthError::Availability(format!(
+                "cannot concatenate {:?} and {:?} availability",
+                combined.privacy, part.privacy
+            )));
+        }
+        let seam = part.window_start;
+
+        let mut busy = part.busy.iter();
+        if let (Some(last), Some(next)) = (combined.busy.last_mut(), part.busy.first()) {
+            if last.end == seam && next.start == seam {
+                last.end = next.end;
+                for source in &next.sources {
+                    if !last.sources.iter().any(|s| s.stream_id == source.stream_id) {
+                        last.sources.push(source.clone());
+                    }
+                }
+                if combined.privacy == PrivacyLevel::Full {
+                    last.source_count = last.sources.len();
+                }
+                busy.next();
+            }
+        }
+        combined.busy.extend(busy.cloned());
+
+        let mut free = part.free.iter();
+        if let (Some(last), Some(next)) = (combined.free.last_mut(), part.free.first()) {
+            if last.end == seam && next.start == seam {
+                last.end = next.end;
+                last.duration_minutes = freebusy::minutes_between(last.start, last.end);
+                free.next();
+            }
+        }
+        combined.free.extend(free.cloned());
+
+        for id in &part.truncated_streams {
+            if !combined.truncated_streams.contains(id) {
+                combined.truncated_streams.push(id.clone());
+            }
+        }
+        combined.window_end = part.window_end;
     }
+    Ok(combined)
+}
+
+/// Find every free slot of at least `min_duration_minutes` across N merged
+/// event streams, sorted by start.
+///
+/// Merges the streams with [`merge_availability`] and keeps the free slots that
+/// meet the minimum duration, so callers can offer the full set of options.
+pub fn find_all_free_across(
+    streams: &[EventStream],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    min_duration_minutes: i64,
+) -> Vec<FreeSlot> {
+    merge_availability(streams, window_start, window_end, PrivacyLevel::Opaque)
+        .free
+        .into_iter()
+        .filter(|slot| slot.duration_minutes >= min_duration_minutes)
+        .collect()
 }
 
 /// Find the first free slot of at least `min_duration_minutes` across N merged
 /// event streams.
 ///
-/// This is a convenience function that merges all streams and returns the first
-/// slot meeting the minimum duration requirement.
+/// This is a convenience function that returns the earliest slot from
+/// [`find_all_free_across`].
 pub fn find_first_free_across(
     streams: &[EventStream],
     window_start: DateTime<Utc>,
     window_end: DateTime<Utc>,
     min_duration_minutes: i64,
 ) -> Option<FreeSlot> {
+    find_all_free_across(streams, window_start, window_end, min_duration_minutes)
+        .into_iter()
+        .next()
+}
+
+/// Find the next `n` free slots of at least `min_duration_minutes` across N
+/// merged event streams, starting at or after `after`.
+///
+/// The search runs from `after` to `window_end`, so a free slot already under
+/// way at `after` counts from `after` on. Slots are in chronological order;
+/// fewer than `n` are returned when the window holds fewer.
+pub fn next_free_slots(
+    streams: &[EventStream],
+    after: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    min_duration_minutes: i64,
+    n: usize,
+) -> Vec<FreeSlot> {
+    let mut slots = find_all_free_across(streams, after, window_end, min_duration_minutes);
+    slots.truncate(n);
+    slots
+}
+
+/// How [`find_best_free_slot`] chooses among the places a meeting could go.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SlotStrategy {
+    /// The earliest possible start.
+    #[default]
+    Earliest,
+    /// The latest possible end.
+    Latest,
+    /// Leave the fewest, and the longest, free blocks behind: prefer a free
+    /// block the meeting fills exactly, then the shortest block that fits, with
+    /// the meeting flush against an existing busy block rather than a window edge.
+    MinimizeFragmentation,
+}
+
+/// Find where to put a meeting of `duration_minutes` across N merged event
+/// streams, chosen by `strategy`.
+///
+/// Every candidate lies inside one free slot and is flush against one of its
+/// ends. Under [`SlotStrategy::MinimizeFragmentation`] candidates are ranked
+/// by, in order: the number of non-empty free blocks left in the window after
+/// placing the meeting (an exact fit removes one); the free time left over in
+/// the chosen slot (smaller is better, so long blocks stay whole); whether the
+/// meeting touches an existing busy block; and start time.
+///
+/// Returns `None` if no free slot is long enough, `duration_minutes` is not
+/// positive, or the meeting's end is not a representable datetime.
+pub fn find_best_free_slot(
+    streams: &[EventStream],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    duration_minutes: i64,
+    strategy: SlotStrategy,
+) -> Option<ExpandedEvent> {
+    let duration = Duration::try_minutes(duration_minutes).filter(|d| *d > Duration::zero())?;
+    let slots = find_all_free_across(streams, window_start, window_end, duration_minutes);
+
+    let at_start = |slot: &FreeSlot| {
+        Some(ExpandedEvent {
+            start: slot.start,
+            end: slot.start.checked_add_signed(duration)?,
+        })
+    };
+    let at_end = |slot: &FreeSlot| {
+        Some(ExpandedEvent {
+            start: slot.end.checked_sub_signed(duration)?,
+            end: slot.end,
+        })
+    };
+
+    match strategy {
+        SlotStrategy::Earliest => slots.first().and_then(at_start),
+        SlotStrategy::Latest => slots.last().and_then(at_end),
+        SlotStrategy::MinimizeFragmentation => slots
+            .iter()
+            .filter_map(|slot| {
+                let leftover = slot.end - slot.start - duration;
+                // Flush against the busy block bounding the slot, if there is one.
+                let after_busy = slot.start > window_start;
+                let before_busy = slot.end < window_end;
+                let placed = if after_busy || !before_busy {
+                    at_start(slot)?
+                } else {
+                    at_end(slot)?
+                };
+                let leaves_block = leftover > Duration::zero();
+                let touches_busy = after_busy || before_busy;
+                Some((
+                    (leaves_block, leftover, !touches_busy, placed.start),
+                    placed,
+                ))
+            })
+            .min_by_key(|(rank, _)| *rank)
+            .map(|(_, placed)| placed),
+    }
+}
+
+/// Largest bitmap [`availability_bitmap`] will allocate.
+const MAX_BITMAP_SLOTS: usize = 1 << 20;
+
+/// Render the merged availability of N event streams as a fixed-granularity
+/// bitmap, one element per `slot_minutes` slot starting at `window_start`.
+///
+/// An element is `true` when any busy interval overlaps its slot, even
+/// partially: a meeting from 09:10 to 09:20 marks the whole 09:00–09:15 and
+/// 09:15–09:30 slots busy. Intervals that merely touch a slot boundary (an
+/// event ending exactly at 09:15) do not mark the following slot. If the
+/// window is not a whole number of slots, the final shorter slot is included.
+///
+/// Returns an empty bitmap when `slot_minutes` is not positive or the window
+/// is empty.
+///
+/// # Errors
+/// Returns `TruthError::Availability` if the window holds more than
+/// `MAX_BITMAP_SLOTS` (1,048,576) slots, about two years of one-minute slots,
+/// rather than allocating an unbounded bitmap.
+pub fn availability_bitmap(
+    streams: &[EventStream],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+    slot_minutes: i64,
+) -> Result<Vec<bool>> {
+    let Some(slot_seconds) = slot_minutes
+        .checked_mul(60)
+        .and_then(|s| u64::try_from(s).ok())
+        .filter(|s| *s > 0)
+    else {
+        return Ok(Vec::new());
+    };
+    let window_seconds = (window_end - window_start).num_seconds().max(0) as u64;
+    let slot_count = window_seconds.div_ceil(slot_seconds);
+    if slot_count > MAX_BITMAP_SLOTS as u64 {
+        return Err(TruthError::Availability(format!(
+            "bitmap would have {} slots of {} minutes; at most {} are allowed",
+            slot_count, slot_minutes, MAX_BITMAP_SLOTS
+        )));
+    }
+    let slot_count = slot_count as usize;
+    let mut bitmap = vec![false; slot_count];
+
+    let merged = merge_availability(streams, window_start, window_end, PrivacyLevel::Opaque);
+    for block in &merged.busy {
+        // Busy blocks are clipped to the window, so both offsets are non-negative.
+        let offset_start = (block.start - window_start).num_seconds() as u64;
+        let offset_end = (block.end - window_start).num_seconds() as u64;
+        let first = (offset_start / slot_seconds) as usize;
+        let last = (offset_end.div_ceil(slot_seconds) as usize).min(slot_count);
+        for slot in &mut bitmap[first..last] {
+            *slot = true;
+        }
+    }
+    Ok(bitmap)
+}
+
+/// Find time slots that are free for every participant.
+///
+/// Each participant's streams are merged and their free slots computed within
+/// their own window; the slot lists are then intersected, so a result slot lies
+/// inside every participant's window and overlaps none of their events. Only
+/// slots of at least `min_duration_minutes` are returned, sorted by start.
+///
+/// Returns an empty list when `participants` is empty.
+pub fn find_common_free(participants: &[Participant], min_duration_minutes: i64) -> Vec<FreeSlot> {
+    let mut per_participant = participants.iter().map(|p| {
+        let all_events: Vec<ExpandedEvent> = p
+            .streams
+            .iter()
+            .flat_map(|s| s.events.iter().cloned())
+            .collect();
+        freebusy::find_free_slots(&all_events, p.window_start, p.window_end)
+    });
+
+    let Some(first) = per_participant.next() else {
+        return Vec::new();
+    };
+    let common = per_participant.fold(first, |acc, slots| intersect_free_slots(&acc, &slots));
+
+    common
+        .into_iter()
+        .filter(|slot| slot.duration_minutes >= min_duration_minutes)
+        .collect()
+}
+
+/// Find the earliest meeting of `duration_minutes` that falls inside every
+/// attendee's local working hours and overlaps none of their events.
+///
+/// Each attendee's free time within the window is intersected with their working
+/// hours (evaluated per local date, so DST shifts are respected), and the result
+/// is intersected across attendees. The meeting starts at the beginning of the
+/// first shared slot that is long enough.
+///
+/// Returns `None` if no such slot exists, if `attendees` is empty, if an
+/// attendee's working hours are empty or invalid (`work_start_hour` must be less
+/// than `work_end_hour`, which must be at most 24), or if the meeting's end is
+/// not a representable datetime.
+pub fn find_slot_across_timezones(
+    attendees: &[TzParticipant],
+    duration_minutes: i64,
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> Option<ExpandedEvent> {
+    let mut per_attendee = attendees.iter().map(|a| {
+        let all_events: Vec<ExpandedEvent> = a
+            .streams
+            .iter()
+            .flat_map(|s| s.events.iter().cloned())
+            .collect();
+        let free = freebusy::find_free_slots(&all_events, window_start, window_end);
+        let hours = WorkingHours {
+            timezone: a.timezone,
+            start_hour: a.work_start_hour,
+            end_hour: a.work_end_hour,
+        };
+        intersect_free_slots(&free, &working_hour_slots(&hours, window_start, window_end))
+    });
+
+    let first = per_attendee.next()?;
+    let common = per_attendee.fold(first, |acc, slots| intersect_free_slots(&acc, &slots));
+    common
+        .into_iter()
+        .find(|slot| slot.duration_minutes >= duration_minutes)
+        .and_then(|slot| {
+            // Checked: a negative `duration_minutes` matches every slot, and
+            // `i64::MIN` minutes is not a representable `TimeDelta`.
+            let end = slot
+                .start
+                .checked_add_signed(Duration::try_minutes(duration_minutes)?)?;
+            Some(ExpandedEvent {
+                start: slot.start,
+                end,
+            })
+        })
+}
+
+/// The fraction of working time within the window that is busy, from 0.0 to 1.0.
+///
+/// Busy time is the union of all streams' events, so overlapping meetings count
+/// once, and only the part inside `working_hours` counts: a 07:00 meeting before
+/// a 09:00 start adds nothing. Working hours are evaluated per local date, so
+/// DST shifts are respected. The result is busy working seconds divided by total
+/// working seconds in the window.
+///
+/// Returns 0.0 when the window holds no working time, including when the
+/// working hours are empty or invalid (`start_hour` must be less than
+/// `end_hour`, which must be at most 24).
+pub fn utilization(
+    streams: &[EventStream],
+    working_hours: &WorkingHours,
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> f64 {
+    let working = working_hour_slots(working_hours, window_start, window_end);
+    let total: i64 = working
+        .iter()
+        .map(|s| (s.end - s.start).num_seconds())
+        .sum();
+    if total == 0 {
+        return 0.0;
+    }
+
     let all_events: Vec<ExpandedEvent> = streams
         .iter()
         .flat_map(|s| s.events.iter().cloned())
         .collect();
+    let free = freebusy::find_free_slots(&all_events, window_start, window_end);
+    let free_working: i64 = intersect_free_slots(&free, &working)
+        .iter()
+        .map(|s| (s.end - s.start).num_seconds())
+        .sum();
+
+    (total - free_working) as f64 / total as f64
+}
 
-    freebusy::find_first_free_slot(&all_events, window_start, window_end, min_duration_minutes)
+/// Working hours within the window, one slot per local date.
+fn working_hour_slots(
+    hours: &WorkingHours,
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> Vec<FreeSlot> {
+    let (start_hour, end_hour) = (hours.start_hour, hours.end_hour);
+    if start_hour >= end_hour || end_hour > 24 || window_start >= window_end {
+        return Vec::new();
+    }
+    let tz = &hours.timezone;
+    let last_date = window_end.with_timezone(tz).date_naive();
+    let mut date = window_start.with_timezone(tz).date_naive();
+    let mut slots = Vec::new();
+    while date <= last_date {
+        if let (Some(day_start), Some(day_end)) = (
+            local_hour_start(date, start_hour, tz),
+            local_hour_start(date, end_hour, tz),
+        ) {
+            let start = day_start.max(window_start);
+            let end = day_end.min(window_end);
+            if start < end {
+                slots.push(FreeSlot {
+                    start,
+                    end,
+                    duration_minutes: freebusy::minutes_between(start, end),
+                });
+            }
+        }
+        match date.succ_opt() {
+            Some(next) => date = next,
+            None => break,
+        }
+    }
+    slots
+}
+
+/// Intersect two sorted, non-overlapping free-slot lists.
+fn intersect_free_slots(a: &[FreeSlot], b: &[FreeSlot]) -> Vec<FreeSlot> {
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        let start = a[i].start.max(b[j].start);
+        let end = a[i].end.min(b[j].end);
+        if start < end {
+            result.push(FreeSlot {
+                start,
+                end,
+                duration_minutes: freebusy::minutes_between(start, end),
+            });
+        }
+        // Advance whichever slot ends first; the other may still overlap the next.
+        if a[i].end <= b[j].end {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    result
 }
 
-/// Compute busy blocks with per-block source counts.
+/// Compute busy blocks with per-block source counts and sources.
 ///
-/// For each merged interval, count how many distinct streams contributed at least
-/// one event that overlaps with that interval.
+/// For each merged interval, collect the distinct streams that contributed at
+/// least one event overlapping that interval.
 fn compute_busy_blocks_with_sources(
     streams: &[EventStream],
     merged_intervals: &[(DateTime<Utc>, DateTime<Utc>)],
@@ -168,11 +1262,14 @@ fn compute_busy_blocks_with_sources(
     merged_intervals
         .iter()
         .map(|(interval_start, interval_end)| {
-            // Count how many streams have at least one event overlapping this interval.
-            let source_count = streams
+            // The streams with at least one event overlapping this interval.
+            let sources: Vec<BusySource> = streams
                 .iter()
                 .filter(|stream| {
                     stream.events.iter().any(|event| {
+                        if event.start >= event.end {
+                            return false;
+                        }
                         // Clip event to window first.
                         let ev_start = event.start.max(window_start);
                         let ev_end = event.end.min(window_end);
@@ -180,11 +1277,13 @@ fn compute_busy_blocks_with_sources(
                         ev_start < *interval_end && ev_end > *interval_start
                     })
                 })
-                .count();
+                .map(BusySource::from)
+                .collect();
             BusyBlock {
                 start: *interval_start,
                 end: *interval_end,
-                source_count,
+                source_count: sources.len(),
+                sources,
             }
         })
         .collect()
@@ -1,16 +1,72 @@
 //! Detect overlapping events in expanded schedules.
 //!
 //! Performs pairwise comparison between two event lists to find time overlaps.
-//! Adjacent events (where one ends exactly when another starts) are NOT conflicts.
+//! Adjacent events (where one ends exactly when another starts) are NOT conflicts,
+//! unless travel time between them is required (see [`find_conflicts_with_travel`]).
 
-use crate::expander::ExpandedEvent;
+use crate::error::Result;
+use crate::expander::{self, ExpandedEvent, RRuleSpec};
+use crate::freebusy::minutes_between;
+use chrono::{DateTime, TimeDelta, Utc};
 
 /// A detected conflict between two events.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Conflict {
     pub event_a: ExpandedEvent,
     pub event_b: ExpandedEvent,
+    /// Start of the overlapping window (the later of the two starts).
+    pub overlap_start: DateTime<Utc>,
+    /// End of the overlapping window (the earlier of the two ends).
+    pub overlap_end: DateTime<Utc>,
+    /// Length of `overlap_start..overlap_end` in whole minutes.
     pub overlap_minutes: i64,
+    /// The events themselves do not overlap; they only clash once travel time
+    /// is added after each one (see [`find_conflicts_with_travel`]).
+    pub travel_induced: bool,
+    /// How the two events' own spans relate, ignoring any travel padding.
+    pub severity: ConflictSeverity,
+}
+
+/// How two conflicting events relate, from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConflictSeverity {
+    /// The events only meet at a boundary: they share less than a minute, or
+    /// (for a travel-induced conflict) none at all. `overlap_minutes` is 0
+    /// unless travel padding widened the window.
+    Touch,
+    /// The events overlap, but neither lies entirely within the other.
+    Partial,
+    /// One event's span lies entirely within the other's.
+    Contained,
+    /// Both events have the same start and end.
+    Identical,
+}
+
+impl ConflictSeverity {
+    /// A machine-readable name, stable across releases.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConflictSeverity::Touch => "touch",
+            ConflictSeverity::Partial => "partial",
+            ConflictSeverity::Contained => "contained",
+            ConflictSeverity::Identical => "identical",
+        }
+    }
+
+    /// Classify the relationship between two events' unpadded spans.
+    fn classify(a: &ExpandedEvent, b: &ExpandedEvent) -> Self {
+        if a.start == b.start && a.end == b.end {
+            ConflictSeverity::Identical
+        } else if !(a.start < b.end && b.start < a.end) {
+            ConflictSeverity::Touch
+        } else if (a.start <= b.start && b.end <= a.end) || (b.start <= a.start && a.end <= b.end) {
+            ConflictSeverity::Contained
+        } else if minutes_between(a.start.max(b.start), a.end.min(b.end)) == 0 {
+            ConflictSeverity::Touch
+        } else {
+            ConflictSeverity::Partial
+        }
+    }
 }
 
 /// Find all pairwise conflicts (overlapping time ranges) between two event lists.
@@ -20,21 +76,60 @@ pub struct Conflict {
 ///
 /// Adjacent events where one ends exactly when another starts are NOT conflicts.
 pub fn find_conflicts(events_a: &[ExpandedEvent], events_b: &[ExpandedEvent]) -> Vec<Conflict> {
+    find_padded_conflicts(events_a, events_b, TimeDelta::zero())
+}
+
+/// Find conflicts between two event lists, allowing `travel_minutes` after each
+/// event to get to the next one.
+///
+/// Each event's end is extended by `travel_minutes` before the overlap check, so
+/// a meeting ending at 10:00 and another starting at 10:15 conflict when 20
+/// minutes of travel are needed. The overlap window and `overlap_minutes` are
+/// those of the padded events; conflicts that exist only because of the padding
+/// have `travel_induced` set. A `travel_minutes` of 0 or less behaves like
+/// [`find_conflicts`].
+pub fn find_conflicts_with_travel(
+    events_a: &[ExpandedEvent],
+    events_b: &[ExpandedEvent],
+    travel_minutes: i64,
+) -> Vec<Conflict> {
+    let travel = TimeDelta::try_minutes(travel_minutes.max(0)).unwrap_or(TimeDelta::MAX);
+    find_padded_conflicts(events_a, events_b, travel)
+}
+
+/// Pairwise overlap check with each event's end extended by `padding`.
+fn find_padded_conflicts(
+    events_a: &[ExpandedEvent],
+    events_b: &[ExpandedEvent],
+    padding: TimeDelta,
+) -> Vec<Conflict> {
+    // Saturate rather than panic for an event near chrono's maximum date.
+    let padded_end = |e: &ExpandedEvent| {
+        e.end
+            .checked_add_signed(padding)
+            .unwrap_or(DateTime::<Utc>::MAX_UTC)
+    };
     let mut conflicts = Vec::new();
 
     for a in events_a {
+        let a_end = padded_end(a);
         for b in events_b {
+            let b_end = padded_end(b);
             // Two intervals overlap iff a.start < b.end AND b.start < a.end.
             // This excludes the adjacent case where a.end == b.start.
-            if a.start < b.end && b.start < a.end {
+            if a.start < b_end && b.start < a_end {
                 let overlap_start = a.start.max(b.start);
-                let overlap_end = a.end.min(b.end);
-                let overlap_minutes = (overlap_end - overlap_start).num_minutes();
+                let overlap_end = a_end.min(b_end);
+                let overlap_minutes = minutes_between(overlap_start, overlap_end);
 
                 conflicts.push(Conflict {
                     event_a: a.clone(),
                     event_b: b.clone(),
+                    overlap_start,
+                    overlap_end,
                     overlap_minutes,
+                    travel_induced: !(a.start < b.end && b.start < a.end),
+                    severity: ConflictSeverity::classify(a, b),
                 });
             }
         }
@@ -42,3 +137,29 @@ pub fn find_conflicts(events_a: &[ExpandedEvent], events_b: &[ExpandedEvent]) ->
 
     conflicts
 }
+
+/// Find the occurrences of a recurring event that overlap a single proposed event.
+///
+/// Answers "does this meeting clash with my standup?" without materializing the
+/// whole series: only the occurrences overlapping `proposed` are collected, so a
+/// meeting years after DTSTART is checked against the series as it stands then.
+/// Each conflict has `proposed` as `event_a` and the occurrence as `event_b`; as
+/// with [`find_conflicts`], touching is not overlapping.
+///
+/// # Errors
+/// Same as [`expander::expand_rrule`] for an invalid `spec`, and
+/// `TruthError::Expansion` if more than `u16::MAX` occurrences overlap `proposed`.
+pub fn conflicts_with_recurrence(
+    proposed: &ExpandedEvent,
+    spec: &RRuleSpec,
+) -> Result<Vec<Conflict>> {
+    let occurrences = expander::expand_rrule_window(
+        &spec.rrule,
+        &spec.dtstart,
+        spec.duration_minutes,
+        &spec.timezone,
+        proposed.start,
+        proposed.end,
+    )?;
+    Ok(find_conflicts(std::slice::from_ref(proposed), &occurrences))
+}
@@ -24,6 +24,9 @@ pub enum TruthError {
 
     #[error("Availability error: {0}")]
     Availability(String),
+
+    #[error("Invalid ICS: {0}")]
+    InvalidIcs(String),
 }
 
 pub type Result<T> = std::result::Result<T, TruthError>;
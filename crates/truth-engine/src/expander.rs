@@ -4,8 +4,12 @@
 //! of RFC 5545 recurrence rules with correct DST handling.
 
 use crate::error::{Result, TruthError};
-use chrono::{DateTime, Duration, Utc};
+use chrono::{
+    DateTime, Datelike, Duration, Months, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone,
+    Utc,
+};
 use rrule::RRuleSet;
+use std::collections::BTreeMap;
 
 /// A single expanded event instance with start and end times.
 #[derive(Debug, Clone, PartialEq)]
@@ -14,19 +18,124 @@ pub struct ExpandedEvent {
     pub end: DateTime<Utc>,
 }
 
+/// An expanded instance numbered by its position in the recurrence set.
+///
+/// `seq` is 1-based and counted from the rule's DTSTART, so the same occurrence
+/// keeps its number however the expansion window is chosen.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpandedEventSeq {
+    pub seq: usize,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+/// A recurring event's expansion inputs, as taken by [`expand_rrule`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RRuleSpec {
+    /// RFC 5545 RRULE string (e.g., "FREQ=WEEKLY;BYDAY=TU,TH").
+    pub rrule: String,
+    /// Local datetime string in `timezone` (e.g., "2026-02-17T14:00:00").
+    pub dtstart: String,
+    /// Duration of each instance in minutes.
+    pub duration_minutes: u32,
+    /// IANA timezone (e.g., "America/Los_Angeles").
+    pub timezone: String,
+}
+
+/// Instances expanded when neither `count` nor `until` bounds the rule.
+const DEFAULT_MAX_INSTANCES: u16 = 500;
+
+/// A notable but non-fatal condition found while expanding a rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub code: DiagnosticCode,
+    /// Human-readable explanation, suitable for showing as a note in a UI.
+    pub message: String,
+}
+
+/// The kind of a [`Diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticCode {
+    /// Expansion stopped at a safety cap rather than at the rule's own end, so
+    /// later instances are missing.
+    InstanceCapReached,
+    /// A `FREQ=MONTHLY` rule's BYMONTHDAY days do not exist in some months
+    /// (e.g. the 31st in April), which therefore have no instance.
+    SkippedMonths,
+    /// DTSTART does not match the rule and was not emitted; see
+    /// [`ExpandOptions::dtstart_inclusive`].
+    DtstartNotInRule,
+}
+
+impl DiagnosticCode {
+    /// A machine-readable code, stable across releases.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiagnosticCode::InstanceCapReached => "instance_cap_reached",
+            DiagnosticCode::SkippedMonths => "skipped_months",
+            DiagnosticCode::DtstartNotInRule => "dtstart_not_in_rule",
+        }
+    }
+}
+
+/// Options for [`expand_rrule_with_options`].
+#[derive(Debug, Clone, Default)]
+pub struct ExpandOptions {
+    /// Local datetime strings to exclude (EXDATE), same format as `dtstart`.
+    pub exdates: Vec<String>,
+    /// Extra local datetime strings to include (RDATE), same format as `dtstart`.
+    /// An RDATE that coincides with an instance of the rule adds nothing: the
+    /// recurrence set holds each start time once.
+    pub rdates: Vec<String>,
+    /// Emit DTSTART as the first instance even when it does not match the rule's
+    /// BYxxx parts (e.g., a Sunday DTSTART with `FREQ=WEEKLY;BYDAY=MO`), and count
+    /// it toward COUNT, as RFC 5545 describes.
+    ///
+    /// Defaults to `false`: an unsynchronized DTSTART is dropped and only dates
+    /// matching the rule are returned, like the `rrule` crate and most calendar
+    /// clients (RFC 5545 leaves such recurrence sets undefined).
+    pub dtstart_inclusive: bool,
+    /// How `duration_minutes` turns each instance's start into its end.
+    pub duration_mode: DurationMode,
+}
+
+/// How an instance's duration is applied to its start.
+///
+/// The two only differ when a DST transition falls inside an instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DurationMode {
+    /// `end = start + duration` in absolute time, so an instance always lasts
+    /// exactly `duration_minutes`.
+    #[default]
+    Fixed,
+    /// The duration is added to the local wall-clock start, so the end keeps its
+    /// local time across DST: an all-day (1440-minute) event on a spring-forward
+    /// day ends at the next local midnight, 23 hours later. An end falling in a
+    /// DST gap moves forward by the gap's length (02:30 becomes 03:30).
+    WallClock,
+}
+
 /// Expand an RRULE string into concrete datetime instances.
 ///
+/// A DTSTART that does not match the rule (a Sunday DTSTART with `BYDAY=MO`) is
+/// not emitted; see [`ExpandOptions::dtstart_inclusive`].
+///
 /// # Arguments
 /// - `rrule` -- RFC 5545 RRULE string (e.g., "FREQ=WEEKLY;BYDAY=TU,TH")
-/// - `dtstart` -- Local datetime string (e.g., "2026-02-17T14:00:00")
+/// - `dtstart` -- Local datetime string (e.g., "2026-02-17T14:00:00"); seconds may be
+///   omitted ("2026-02-17T14:00") and fractional seconds are truncated. The iCal
+///   basic forms pasted from `.ics` files are accepted too: "20260217T140000", or
+///   "20260217" for midnight
 /// - `duration_minutes` -- Duration of each instance in minutes
 /// - `timezone` -- IANA timezone (e.g., "America/Los_Angeles")
 /// - `until` -- Optional end boundary for expansion (local datetime string)
-/// - `count` -- Optional maximum number of instances (overrides COUNT in rrule)
+/// - `count` -- Optional maximum number of instances; if the rrule also has COUNT,
+///   the smaller of the two wins
 ///
 /// # Errors
 /// Returns `TruthError::InvalidRule` if the RRULE string is empty or unparseable.
 /// Returns `TruthError::InvalidTimezone` if the timezone is not a valid IANA identifier.
+/// Returns `TruthError::InvalidDatetime` if `dtstart` is not a local datetime.
 pub fn expand_rrule(
     rrule: &str,
     dtstart: &str,
@@ -46,6 +155,42 @@ pub fn expand_rrule(
     )
 }
 
+/// Check that an RRULE string is valid without expanding it or needing a DTSTART.
+///
+/// Parses the rule (FREQ present, known parts, well-formed values) and applies the
+/// same RFC 5545 checks [`expand_rrule`] does, such as BYxxx values in range and
+/// allowed for the frequency, and UNTIL being in UTC. Whether UNTIL is after
+/// DTSTART is not checked.
+///
+/// # Example
+/// ```
+/// use truth_engine::validate_rrule;
+///
+/// assert!(validate_rrule("FREQ=WEEKLY;BYDAY=TU,TH").is_ok());
+/// assert!(validate_rrule("BYDAY=TU").is_err());
+/// ```
+///
+/// # Errors
+/// Returns `TruthError::InvalidRule` describing the first problem found.
+pub fn validate_rrule(rrule: &str) -> Result<()> {
+    if rrule.trim().is_empty() {
+        return Err(TruthError::InvalidRule("empty RRULE string".to_string()));
+    }
+    let invalid = |e: rrule::RRuleError| TruthError::InvalidRule(e.to_string());
+    // Parse the content line as the expander writes it, so a stray "RRULE:"
+    // prefix is rejected here too.
+    let parsed: rrule::RRule<rrule::Unvalidated> =
+        format!("RRULE:{}", rrule).parse().map_err(invalid)?;
+    // Only the UNTIL checks depend on DTSTART. Expansion always gives DTSTART a
+    // TZID, which requires a UTC UNTIL; starting at UNTIL itself passes the
+    // ordering check.
+    let dt_start = match parsed.get_until() {
+        Some(until) => until.with_timezone(&rrule::Tz::UTC),
+        None => rrule::Tz::UTC.timestamp_opt(0, 0).unwrap(),
+    };
+    parsed.validate(dt_start).map(drop).map_err(invalid)
+}
+
 /// Expand an RRULE string into concrete datetime instances, with EXDATE exclusions.
 ///
 /// Identical to [`expand_rrule`] but accepts a list of exception dates that will be
@@ -57,12 +202,14 @@ pub fn expand_rrule(
 /// - `duration_minutes` -- Duration of each instance in minutes
 /// - `timezone` -- IANA timezone (e.g., "America/Los_Angeles")
 /// - `until` -- Optional end boundary for expansion (local datetime string)
-/// - `count` -- Optional maximum number of instances (overrides COUNT in rrule)
+/// - `count` -- Optional maximum number of instances; if the rrule also has COUNT,
+///   the smaller of the two wins
 /// - `exdates` -- Slice of local datetime strings to exclude (same format as `dtstart`)
 ///
 /// # Errors
 /// Returns `TruthError::InvalidRule` if the RRULE string is empty or unparseable.
 /// Returns `TruthError::InvalidTimezone` if the timezone is not a valid IANA identifier.
+/// Returns `TruthError::InvalidDatetime` if `dtstart` is not a local datetime.
 pub fn expand_rrule_with_exdates(
     rrule: &str,
     dtstart: &str,
@@ -72,8 +219,501 @@ pub fn expand_rrule_with_exdates(
     count: Option<u32>,
     exdates: &[&str],
 ) -> Result<Vec<ExpandedEvent>> {
+    expand_rrule_with_options(
+        rrule,
+        dtstart,
+        duration_minutes,
+        timezone,
+        until,
+        count,
+        &ExpandOptions {
+            exdates: exdates.iter().map(|d| d.to_string()).collect(),
+            ..ExpandOptions::default()
+        },
+    )
+}
+
+/// Expand an RRULE string into concrete datetime instances with [`ExpandOptions`].
+///
+/// Identical to [`expand_rrule_with_exdates`] with the exclusions taken from
+/// `options.exdates`, plus extra RDATE instances from `options.rdates` and
+/// control over whether an unsynchronized DTSTART is emitted.
+///
+/// # Example
+/// ```
+/// use truth_engine::expander::{expand_rrule_with_options, ExpandOptions};
+///
+/// // 2026-03-01 is a Sunday; the rule only matches Mondays.
+/// let options = ExpandOptions {
+///     dtstart_inclusive: true,
+///     ..ExpandOptions::default()
+/// };
+/// let events = expand_rrule_with_options(
+///     "FREQ=WEEKLY;BYDAY=MO;COUNT=3",
+///     "2026-03-01T09:00:00",
+///     30,
+///     "UTC",
+///     None,
+///     None,
+///     &options,
+/// )
+/// .unwrap();
+/// let days: Vec<String> = events.iter().map(|e| e.start.format("%a %d").to_string()).collect();
+/// assert_eq!(days, ["Sun 01", "Mon 02", "Mon 09"]);
+/// ```
+///
+/// # Errors
+/// Same as [`expand_rrule`].
+pub fn expand_rrule_with_options(
+    rrule: &str,
+    dtstart: &str,
+    duration_minutes: u32,
+    timezone: &str,
+    until: Option<&str>,
+    count: Option<u32>,
+    options: &ExpandOptions,
+) -> Result<Vec<ExpandedEvent>> {
+    expand_with_limit(
+        rrule,
+        dtstart,
+        duration_minutes,
+        timezone,
+        until,
+        count,
+        options,
+        DEFAULT_MAX_INSTANCES,
+    )
+}
+
+/// Expand an RRULE like [`expand_rrule`], also reporting [`Diagnostic`]s for
+/// quirks of the rule that shaped the result without being errors.
+///
+/// Reported conditions:
+/// - [`DiagnosticCode::InstanceCapReached`] — with no `count`, expansion stopped
+///   at 500 instances before the rule's end (whether it has none, or its own
+///   COUNT or UNTIL lies further out), or `count` exceeds the 65,535 cap
+/// - [`DiagnosticCode::SkippedMonths`] — a `FREQ=MONTHLY` rule whose BYMONTHDAY
+///   days are all past the 28th skips the months lacking them, counted from
+///   DTSTART's month to the last instance's among the months BYMONTH and
+///   INTERVAL select
+/// - [`DiagnosticCode::DtstartNotInRule`] — DTSTART does not match the rule, so
+///   the first instance is later than DTSTART
+///
+/// The instances are exactly those [`expand_rrule`] returns.
+///
+/// # Example
+/// ```
+/// use truth_engine::expander::{expand_rrule_diagnostics, DiagnosticCode};
+///
+/// let (events, notes) = expand_rrule_diagnostics(
+///     "FREQ=MONTHLY;BYMONTHDAY=31",
+///     "2026-01-31T09:00:00",
+///     60,
+///     "UTC",
+///     Some("2026-12-31T23:59:59"),
+///     None,
+/// )
+/// .unwrap();
+/// assert_eq!(events.len(), 7);
+/// assert_eq!(notes[0].code, DiagnosticCode::SkippedMonths);
+/// ```
+///
+/// # Errors
+/// Same as [`expand_rrule`].
+pub fn expand_rrule_diagnostics(
+    rrule: &str,
+    dtstart: &str,
+    duration_minutes: u32,
+    timezone: &str,
+    until: Option<&str>,
+    count: Option<u32>,
+) -> Result<(Vec<ExpandedEvent>, Vec<Diagnostic>)> {
+    let events = expand_rrule(rrule, dtstart, duration_minutes, timezone, until, count)?;
+    let mut diagnostics = Vec::new();
+
+    let rule_part = |name: &str| {
+        rrule.split(';').find_map(|part| {
+            let (key, value) = part.split_once('=')?;
+            key.trim().eq_ignore_ascii_case(name).then(|| value.trim())
+        })
+    };
+
+    // Safety caps: without an external count expansion stops at
+    // DEFAULT_MAX_INSTANCES, even when the rule's own COUNT or UNTIL lies
+    // further out, and an external count is narrowed to u16. Whether the cap
+    // cut the series short is settled by asking for one instance more.
+    let unbounded = count.is_none()
+        && until.is_none()
+        && rule_part("COUNT").is_none()
+        && rule_part("UNTIL").is_none();
+    let capped = count.is_none()
+        && events.len() == usize::from(DEFAULT_MAX_INSTANCES)
+        && expand_with_limit(
+            rrule,
+            dtstart,
+            duration_minutes,
+            timezone,
+            until,
+            None,
+            &ExpandOptions::default(),
+            DEFAULT_MAX_INSTANCES + 1,
+        )?
+        .len()
+            > events.len();
+    if capped && unbounded {
+        diagnostics.push(Diagnostic {
+            code: DiagnosticCode::InstanceCapReached,
+            message: format!(
+                "the rule has no end; expansion stopped at {} instances (pass count or until to bound it)",
+                DEFAULT_MAX_INSTANCES
+            ),
+        });
+    } else if capped {
+        diagnostics.push(Diagnostic {
+            code: DiagnosticCode::InstanceCapReached,
+            message: format!(
+                "expansion stopped at {} instances before the rule's end (pass count to raise the cap)",
+                DEFAULT_MAX_INSTANCES
+            ),
+        });
+    } else if let Some(c) = count.filter(|&c| c > u32::from(u16::MAX)) {
+        if events.len() == usize::from(u16::MAX) {
+            diagnostics.push(Diagnostic {
+                code: DiagnosticCode::InstanceCapReached,
+                message: format!(
+                    "count {} exceeds the {}-instance cap; later instances are missing",
+                    c,
+                    u16::MAX
+                ),
+            });
+        }
+    }
+
+    let tz: chrono_tz::Tz = timezone
+        .parse()
+        .map_err(|_| TruthError::InvalidTimezone(timezone.to_string()))?;
+    let local_start = parse_local(dtstart)?;
+
+    // Months skipped because none of the BYMONTHDAY days exist in them.
+    let monthly = rule_part("FREQ").is_some_and(|f| f.eq_ignore_ascii_case("MONTHLY"));
+    let min_day = rule_part("BYMONTHDAY").and_then(|days| {
+        days.split(',')
+            .map(|d| d.trim().parse::<u32>().ok())
+            .collect::<Option<Vec<u32>>>()?
+            .into_iter()
+            .min()
+    });
+    // Only months the rule would otherwise produce count: those in BYMONTH,
+    // if given, and every INTERVAL-th month from DTSTART's.
+    let by_month: Option<Vec<u32>> = rule_part("BYMONTH").and_then(|months| {
+        months
+            .split(',')
+            .map(|m| m.trim().parse::<u32>().ok())
+            .collect()
+    });
+    let interval = rule_part("INTERVAL")
+        .and_then(|i| i.parse::<usize>().ok())
+        .filter(|&i| i > 0)
+        .unwrap_or(1);
+    if let (true, Some(day), Some(last)) = (monthly, min_day.filter(|&d| d > 28), events.last()) {
+        let last = last.start.with_timezone(&tz).date_naive();
+        let mut month = local_start.date().with_day(1);
+        let mut skipped = 0;
+        let mut index = 0;
+        while let Some(first_of_month) = month.filter(|m| *m <= last) {
+            let next = first_of_month.checked_add_months(Months::new(1));
+            let month_len = next.map_or(31, |n| (n - first_of_month).num_days());
+            let produced = index % interval == 0
+                && by_month
+                    .as_ref()
+                    .is_none_or(|months| months.contains(&first_of_month.month()));
+            if produced && month_len < i64::from(day) {
+                skipped += 1;
+            }
+            month = next;
+            index += 1;
+        }
+        if skipped > 0 {
+            diagnostics.push(Diagnostic {
+                code: DiagnosticCode::SkippedMonths,
+                message: format!(
+                    "{} {} skipped because {} a {}{} day",
+                    skipped,
+                    if skipped == 1 {
+                        "month was"
+                    } else {
+                        "months were"
+                    },
+                    if skipped == 1 {
+                        "it lacks"
+                    } else {
+                        "they lack"
+                    },
+                    day,
+                    ordinal_suffix(day)
+                ),
+            });
+        }
+    }
+
+    // DTSTART dropped because the rule does not match it.
+    if let (Some(first), Some(start)) = (events.first(), resolve_local(&local_start, &tz)) {
+        if first.start > start {
+            diagnostics.push(Diagnostic {
+                code: DiagnosticCode::DtstartNotInRule,
+                message: format!(
+                    "DTSTART {} does not match the rule and was not emitted; the first instance is {}",
+                    dtstart,
+                    first.start.with_timezone(&tz).format("%Y-%m-%dT%H:%M:%S")
+                ),
+            });
+        }
+    }
+
+    Ok((events, diagnostics))
+}
+
+/// English ordinal suffix for a day of the month (`st`, `nd`, `rd`, `th`).
+fn ordinal_suffix(day: u32) -> &'static str {
+    match (day % 10, day % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+/// Expand an RRULE in `event_tz` and group the instances by the local date of
+/// their start in `display_tz`.
+///
+/// For month and week grids shown in a timezone other than the event's own: a
+/// 23:00 Pacific instance starts at 02:00 Eastern the next day, so it is filed
+/// under that next date. Instances are keyed by start only (an instance running
+/// past midnight is not repeated under the following date), and each date's
+/// instances stay in chronological order. Dates with no instances are absent.
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use truth_engine::expander::expand_rrule_grouped_by_local_date;
+///
+/// let days = expand_rrule_grouped_by_local_date(
+///     "FREQ=DAILY;COUNT=2",
+///     "2026-03-02T23:00:00",
+///     30,
+///     "America/Los_Angeles",
+///     "America/New_York",
+///     None,
+///     None,
+/// )
+/// .unwrap();
+/// let dates: Vec<NaiveDate> = days.keys().copied().collect();
+/// assert_eq!(
+///     dates,
+///     [
+///         NaiveDate::from_ymd_opt(2026, 3, 3).unwrap(),
+///         NaiveDate::from_ymd_opt(2026, 3, 4).unwrap(),
+///     ]
+/// );
+/// ```
+///
+/// # Errors
+/// Same as [`expand_rrule`]; `TruthError::InvalidTimezone` also covers
+/// `display_tz`.
+pub fn expand_rrule_grouped_by_local_date(
+    rrule: &str,
+    dtstart: &str,
+    duration_minutes: u32,
+    event_tz: &str,
+    display_tz: &str,
+    until: Option<&str>,
+    count: Option<u32>,
+) -> Result<BTreeMap<NaiveDate, Vec<ExpandedEvent>>> {
+    let display: chrono_tz::Tz = display_tz
+        .parse()
+        .map_err(|_| TruthError::InvalidTimezone(display_tz.to_string()))?;
+    let events = expand_rrule(rrule, dtstart, duration_minutes, event_tz, until, count)?;
+
+    let mut days: BTreeMap<NaiveDate, Vec<ExpandedEvent>> = BTreeMap::new();
+    for event in events {
+        let date = event.start.with_timezone(&display).date_naive();
+        days.entry(date).or_default().push(event);
+    }
+    Ok(days)
+}
+
+/// Expand the instances of an RRULE that overlap `[window_start, window_end)`,
+/// each tagged with its 1-based sequence number in the full recurrence set.
+///
+/// Numbering always starts at DTSTART, not at the window, so a daily rule that
+/// began ten days before `window_start` reports its first in-window instance as
+/// `seq` 11. This gives occurrences a stable identity (as `RECURRENCE-ID`
+/// overrides need) while the window slides.
+///
+/// The rule is expanded from DTSTART up to `window_end`, so cost grows with
+/// the distance between the two; at most `u16::MAX` instances are numbered.
+///
+/// # Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use truth_engine::expander::expand_rrule_seq;
+///
+/// let events = expand_rrule_seq(
+///     "FREQ=WEEKLY",
+///     "2026-01-05T09:00:00",
+///     60,
+///     "UTC",
+///     Utc.with_ymd_and_hms(2026, 2, 1, 0, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2026, 2, 15, 0, 0, 0).unwrap(),
+/// )
+/// .unwrap();
+/// let seqs: Vec<usize> = events.iter().map(|e| e.seq).collect();
+/// assert_eq!(seqs, [5, 6]);
+/// ```
+///
+/// # Errors
+/// Same as [`expand_rrule`].
+pub fn expand_rrule_seq(
+    rrule: &str,
+    dtstart: &str,
+    duration_minutes: u32,
+    timezone: &str,
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> Result<Vec<ExpandedEventSeq>> {
+    let tz: chrono_tz::Tz = timezone
+        .parse()
+        .map_err(|_| TruthError::InvalidTimezone(timezone.to_string()))?;
+    if window_start >= window_end {
+        return Ok(Vec::new());
+    }
+
+    // Nothing starting at or after `window_end` can be in the window, so expand
+    // only up to there (in local time, as the UNTIL injection expects). An UNTIL
+    // before DTSTART is invalid, so a window ending before the series starts
+    // expands up to DTSTART and the filter below drops everything.
+    let until = window_end
+        .with_timezone(&tz)
+        .naive_local()
+        .max(parse_local(dtstart)?)
+        .format("%Y-%m-%dT%H:%M:%S")
+        .to_string();
+    let events = expand_with_limit(
+        rrule,
+        dtstart,
+        duration_minutes,
+        timezone,
+        Some(&until),
+        None,
+        &ExpandOptions::default(),
+        u16::MAX,
+    )?;
+
+    Ok(events
+        .into_iter()
+        .enumerate()
+        .filter(|(_, e)| {
+            // Zero-duration instances count when they fall inside the window.
+            e.start < window_end && (e.end > window_start || e.start >= window_start)
+        })
+        .map(|(i, e)| ExpandedEventSeq {
+            seq: i + 1,
+            start: e.start,
+            end: e.end,
+        })
+        .collect())
+}
+
+/// Move every event by `by`, keeping its duration.
+///
+/// The shift is absolute: each instance moves by exactly `by` regardless of
+/// DST. To move a recurring series so it keeps its local wall-clock time, shift
+/// the rule's DTSTART with [`shift_dtstart`] and re-expand instead. Instants
+/// that would overflow the representable range saturate at its bounds.
+pub fn shift_events(events: &[ExpandedEvent], by: Duration) -> Vec<ExpandedEvent> {
+    let bound = if by < Duration::zero() {
+        DateTime::<Utc>::MIN_UTC
+    } else {
+        DateTime::<Utc>::MAX_UTC
+    };
+    let shift = |dt: DateTime<Utc>| dt.checked_add_signed(by).unwrap_or(bound);
+    events
+        .iter()
+        .map(|e| ExpandedEvent {
+            start: shift(e.start),
+            end: shift(e.end),
+        })
+        .collect()
+}
+
+/// Shift a recurrence's local DTSTART by `by`, returning the new DTSTART to pass
+/// to [`expand_rrule`] alongside the unchanged rule and timezone.
+///
+/// `mode` picks the semantics, as for durations:
+/// - [`DurationMode::Fixed`] moves the start by exactly `by` of elapsed time, so
+///   shifting a 09:00 start by 24 hours across a spring-forward night lands on
+///   10:00 local the next day.
+/// - [`DurationMode::WallClock`] moves the local clock reading, so the same
+///   shift lands on 09:00 local. A result inside a DST gap, which the expansion
+///   would reject, moves forward by the gap's length (02:30 becomes 03:30).
+///
+/// The result is formatted `YYYY-MM-DDTHH:MM:SS`. `dtstart` accepts the formats
+/// of [`expand_rrule`]. Rule parts that pin the time of day (`BYHOUR`,
+/// `BYMINUTE`) and any `until` bound are not rewritten.
+///
+/// # Errors
+/// Returns `TruthError::InvalidTimezone` if the timezone is not a valid IANA identifier.
+/// Returns `TruthError::InvalidDatetime` if `dtstart` is not a local datetime, or
+/// the shifted start is out of range.
+pub fn shift_dtstart(
+    dtstart: &str,
+    timezone: &str,
+    by: Duration,
+    mode: DurationMode,
+) -> Result<String> {
+    let tz: chrono_tz::Tz = timezone
+        .parse()
+        .map_err(|_| TruthError::InvalidTimezone(timezone.to_string()))?;
+    let local = parse_local(dtstart)?;
+    let out_of_range =
+        || TruthError::InvalidDatetime(format!("'{}' shifted by {} is out of range", dtstart, by));
+    let shifted = match mode {
+        DurationMode::WallClock => {
+            let shifted = local.checked_add_signed(by).ok_or_else(out_of_range)?;
+            resolve_local(&shifted, &tz)
+                .ok_or_else(out_of_range)?
+                .with_timezone(&tz)
+                .naive_local()
+        }
+        DurationMode::Fixed => resolve_local(&local, &tz)
+            .ok_or_else(|| {
+                TruthError::InvalidDatetime(format!("'{}' does not exist in {}", dtstart, tz))
+            })?
+            .checked_add_signed(by)
+            .ok_or_else(out_of_range)?
+            .with_timezone(&tz)
+            .naive_local(),
+    };
+    Ok(shifted.format("%Y-%m-%dT%H:%M:%S").to_string())
+}
+
+/// Shared expansion body; `default_limit` caps instances when `count` is unset.
+#[allow(clippy::too_many_arguments)]
+fn expand_with_limit(
+    rrule: &str,
+    dtstart: &str,
+    duration_minutes: u32,
+    timezone: &str,
+    until: Option<&str>,
+    count: Option<u32>,
+    options: &ExpandOptions,
+    default_limit: u16,
+) -> Result<Vec<ExpandedEvent>> {
+    let exdates = &options.exdates;
+
     // Validate inputs.
-    if rrule.is_empty() {
+    if rrule.trim().is_empty() {
         return Err(TruthError::InvalidRule("empty RRULE string".to_string()));
     }
 
@@ -82,19 +722,127 @@ pub fn expand_rrule_with_exdates(
         return Ok(Vec::new());
     }
 
+    let (rrule_set, tz) = build_rrule_set(rrule, dtstart, timezone, until, count, options)?;
+
+    // Determine the max count for expansion to prevent unbounded expansion.
+    // When we have exdates, we need a higher limit because the rrule crate's
+    // `.all(limit)` counts BEFORE exdate filtering, so we may need more raw
+    // instances to get `count` results after exclusion. Add exdate count as buffer,
+    // and likewise the rdate count for RDATEs that duplicate a rule instance.
+    let exdate_buffer = (exdates.len() + options.rdates.len()) as u16;
+    let max_count: u16 = count
+        .map(|c| {
+            u16::try_from(c)
+                .unwrap_or(u16::MAX)
+                .saturating_add(exdate_buffer)
+        })
+        .unwrap_or(default_limit);
+
+    let mut instances = if options.dtstart_inclusive {
+        all_with_dtstart(rrule_set, max_count)
+    } else {
+        rrule_set.all(max_count).dates
+    };
+    // The rrule crate merges RDATEs into the sorted sequence without dropping
+    // those that repeat a rule instance; a recurrence set holds each start once.
+    instances.dedup();
+    let mut events = to_events(instances, duration_minutes, &tz, options.duration_mode);
+
+    // If the caller specified an external count limit, truncate to that many results.
+    // (EXDATE filtering by the rrule crate may have already reduced the count, but
+    // the `.all()` limit is a pre-filter cap, not a post-filter cap.)
+    if let Some(c) = count {
+        events.truncate(c as usize);
+    }
+
+    Ok(events)
+}
+
+/// Expand the instances of an RRULE that overlap `[window_start, window_end)`.
+///
+/// The rule is still walked from DTSTART, but only the instances overlapping
+/// the window are collected, so unlike [`expand_rrule_seq`] a window any
+/// distance from DTSTART is covered. More than `u16::MAX` instances inside the
+/// window is reported as an error rather than silently cut short.
+pub(crate) fn expand_rrule_window(
+    rrule: &str,
+    dtstart: &str,
+    duration_minutes: u32,
+    timezone: &str,
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> Result<Vec<ExpandedEvent>> {
+    if rrule.trim().is_empty() {
+        return Err(TruthError::InvalidRule("empty RRULE string".to_string()));
+    }
+    let (rrule_set, tz) = build_rrule_set(
+        rrule,
+        dtstart,
+        timezone,
+        None,
+        None,
+        &ExpandOptions::default(),
+    )?;
+    if window_start >= window_end {
+        return Ok(Vec::new());
+    }
+
+    // An instance starting up to one duration before the window still overlaps
+    // it; the rrule crate's bounds are inclusive, so the filter below trims them.
+    let duration = Duration::minutes(duration_minutes as i64);
+    let after = window_start
+        .checked_sub_signed(duration)
+        .unwrap_or(DateTime::<Utc>::MIN_UTC);
+    let result = rrule_set
+        .after(after.with_timezone(&rrule::Tz::UTC))
+        .before(window_end.with_timezone(&rrule::Tz::UTC))
+        .all(u16::MAX);
+    if result.limited {
+        return Err(TruthError::Expansion(format!(
+            "more than {} instances between {} and {}",
+            u16::MAX,
+            window_start.to_rfc3339(),
+            window_end.to_rfc3339()
+        )));
+    }
+
+    Ok(
+        to_events(result.dates, duration_minutes, &tz, DurationMode::Fixed)
+            .into_iter()
+            .filter(|e| {
+                // Zero-duration instances count when they fall inside the window.
+                e.start < window_end && (e.end > window_start || e.start >= window_start)
+            })
+            .collect(),
+    )
+}
+
+/// Parse the rule, DTSTART and options into an `RRuleSet` in `timezone`,
+/// injecting `count` and `until` into the rule unless it sets its own.
+fn build_rrule_set(
+    rrule: &str,
+    dtstart: &str,
+    timezone: &str,
+    until: Option<&str>,
+    count: Option<u32>,
+    options: &ExpandOptions,
+) -> Result<(RRuleSet, chrono_tz::Tz)> {
+    let exdates = &options.exdates;
+
     // Validate timezone by parsing it as a chrono-tz Tz.
-    let _tz: chrono_tz::Tz = timezone
+    let tz: chrono_tz::Tz = timezone
         .parse()
         .map_err(|_| TruthError::InvalidTimezone(timezone.to_string()))?;
 
     // Convert the dtstart from "2026-02-17T14:00:00" to iCalendar format "20260217T140000".
-    let dtstart_ical = dtstart.replace(['-', ':'], "");
+    let dtstart_ical = to_ical_local(dtstart)?;
 
     // Build the RRULE text block. We may need to inject COUNT or UNTIL.
     let mut rrule_str = rrule.to_string();
 
     // If the caller provides an external `count`, inject it into the RRULE
-    // (unless the RRULE already has a COUNT).
+    // (unless the RRULE already has a COUNT). Either way the external count is
+    // also applied as a cap below, so the tighter of the two bounds wins.
     if let Some(c) = count {
         if !rrule_str.to_uppercase().contains("COUNT=") {
             rrule_str = format!("{};COUNT={}", rrule_str, c);
@@ -102,14 +850,11 @@ pub fn expand_rrule_with_exdates(
     }
 
     // If the caller provides an `until`, inject it into the RRULE.
-    // The rrule crate requires UNTIL and DTSTART to share the same timezone.
-    // For UTC, UNTIL must end with "Z"; for other timezones, use bare local time.
+    // The rrule crate requires UNTIL in UTC ("Z") whenever DTSTART has a TZID,
+    // so the local `until` is resolved in `timezone` and converted.
     if let Some(until_str) = until {
         if !rrule_str.to_uppercase().contains("UNTIL=") {
-            let mut until_ical = until_str.replace(['-', ':'], "");
-            if timezone == "UTC" {
-                until_ical.push('Z');
-            }
+            let until_ical = to_ical_utc(until_str, &tz)?;
             rrule_str = format!("{};UNTIL={}", rrule_str, until_ical);
         }
     }
@@ -122,7 +867,10 @@ pub fn expand_rrule_with_exdates(
 
     // Append EXDATE lines if any exclusion dates were provided.
     if !exdates.is_empty() {
-        let exdate_icals: Vec<String> = exdates.iter().map(|d| d.replace(['-', ':'], "")).collect();
+        let exdate_icals = exdates
+            .iter()
+            .map(|d| to_ical_local(d))
+            .collect::<Result<Vec<_>>>()?;
         rrule_text.push_str(&format!(
             "\nEXDATE;TZID={}:{}",
             timezone,
@@ -130,41 +878,143 @@ pub fn expand_rrule_with_exdates(
         ));
     }
 
-    // Parse and expand.
+    // Append RDATE lines for extra instances.
+    if !options.rdates.is_empty() {
+        let rdate_icals = options
+            .rdates
+            .iter()
+            .map(|d| to_ical_local(d))
+            .collect::<Result<Vec<_>>>()?;
+        rrule_text.push_str(&format!(
+            "\nRDATE;TZID={}:{}",
+            timezone,
+            rdate_icals.join(",")
+        ));
+    }
+
+    // Parse.
     let rrule_set: RRuleSet = rrule_text
         .parse()
         .map_err(|e| TruthError::InvalidRule(format!("{}", e)))?;
+    Ok((rrule_set, tz))
+}
 
-    // Determine the max count for expansion to prevent unbounded expansion.
-    // When we have exdates, we need a higher limit because the rrule crate's
-    // `.all(limit)` counts BEFORE exdate filtering, so we may need more raw
-    // instances to get `count` results after exclusion. Add exdate count as buffer.
-    let exdate_buffer = exdates.len() as u16;
-    let max_count: u16 = count
-        .map(|c| (c as u16).saturating_add(exdate_buffer))
-        .unwrap_or(500);
-
-    let instances = rrule_set.all(max_count);
+/// Turn expanded start instants into UTC events lasting `duration_minutes`.
+fn to_events(
+    instances: Vec<DateTime<rrule::Tz>>,
+    duration_minutes: u32,
+    tz: &chrono_tz::Tz,
+    mode: DurationMode,
+) -> Vec<ExpandedEvent> {
     let duration = Duration::minutes(duration_minutes as i64);
-
-    let mut events: Vec<ExpandedEvent> = instances
-        .dates
+    instances
         .into_iter()
         .map(|dt| {
             let start_utc: DateTime<Utc> = dt.with_timezone(&Utc);
+            let end = match mode {
+                DurationMode::Fixed => None,
+                DurationMode::WallClock => {
+                    let local_end = start_utc.with_timezone(tz).naive_local() + duration;
+                    resolve_local(&local_end, tz)
+                }
+            };
+            // Saturate rather than panic for an instance near chrono's maximum date.
+            let fixed_end = start_utc
+                .checked_add_signed(duration)
+                .unwrap_or(DateTime::<Utc>::MAX_UTC);
             ExpandedEvent {
                 start: start_utc,
-                end: start_utc + duration,
+                end: end.unwrap_or(fixed_end),
             }
         })
-        .collect();
+        .collect()
+}
 
-    // If the caller specified an external count limit, truncate to that many results.
-    // (EXDATE filtering by the rrule crate may have already reduced the count, but
-    // the `.all()` limit is a pre-filter cap, not a post-filter cap.)
-    if let Some(c) = count {
-        events.truncate(c as usize);
+/// Expand `rrule_set`, emitting its DTSTART first even if the rule does not match it.
+///
+/// The `rrule` crate drops an unsynchronized DTSTART, so in that case the rule is
+/// expanded on its own, DTSTART is prepended and takes one slot of COUNT, the
+/// set's RDATEs are merged in, and its EXDATEs are applied. Like
+/// [`RRuleSet::all`], at most `limit` dates are returned.
+fn all_with_dtstart(rrule_set: RRuleSet, limit: u16) -> Vec<DateTime<rrule::Tz>> {
+    let dtstart = *rrule_set.get_dt_start();
+    let Some(rule) = rrule_set.get_rrule().first().cloned() else {
+        return rrule_set.all(limit).dates;
+    };
+    let before_until = rule.get_until().is_none_or(|until| dtstart <= *until);
+    let mut dates = RRuleSet::new(dtstart).rrule(rule.clone()).all(limit).dates;
+    if dates.first() == Some(&dtstart) || !before_until {
+        return rrule_set.all(limit).dates;
     }
 
-    Ok(events)
+    if let Some(rule_count) = rule.get_count() {
+        dates.truncate(rule_count.saturating_sub(1) as usize);
+    }
+    dates.insert(0, dtstart);
+    dates.extend(rrule_set.get_rdate().iter().copied());
+    dates.sort();
+    let exdates = rrule_set.get_exdate();
+    dates.retain(|d| !exdates.contains(d));
+    dates.truncate(usize::from(limit));
+    dates
+}
+
+/// Convert a local datetime string into iCalendar's basic form ("20260217T140000").
+///
+/// Accepts `YYYY-MM-DDTHH:MM`, `YYYY-MM-DDTHH:MM:SS`, and `YYYY-MM-DDTHH:MM:SS.fff`,
+/// plus iCalendar's own basic forms `YYYYMMDDTHHMMSS` and date-only `YYYYMMDD`
+/// (midnight). iCalendar has no sub-second precision, so fractional seconds are
+/// truncated.
+fn to_ical_local(s: &str) -> Result<String> {
+    Ok(parse_local(s)?.format("%Y%m%dT%H%M%S").to_string())
+}
+
+/// Convert a local datetime string in `tz` into iCalendar's UTC form
+/// ("20260217T220000Z"), accepting the same formats as [`to_ical_local`].
+///
+/// An ambiguous local time (DST fall-back) resolves to its earlier instant; a
+/// nonexistent one (spring-forward gap) as described on [`resolve_local`].
+fn to_ical_utc(s: &str, tz: &chrono_tz::Tz) -> Result<String> {
+    let resolved = resolve_local(&parse_local(s)?, tz)
+        .ok_or_else(|| TruthError::InvalidDatetime(format!("'{}' does not exist in {}", s, tz)))?;
+    Ok(resolved.format("%Y%m%dT%H%M%SZ").to_string())
+}
+
+/// The UTC instant of local time `local` in `tz`: the earlier one if ambiguous.
+///
+/// A nonexistent time (inside a DST gap) is read with the UTC offset in force
+/// before the gap, as RFC 5545 does, which moves it forward by the gap's length:
+/// 02:30 on a one-hour spring-forward night is 03:30.
+pub(crate) fn resolve_local(local: &NaiveDateTime, tz: &chrono_tz::Tz) -> Option<DateTime<Utc>> {
+    if let Some(dt) = tz.from_local_datetime(local).earliest() {
+        return Some(dt.with_timezone(&Utc));
+    }
+    // Gaps are far more than a day apart, so the offset a day earlier is the
+    // one in force before this gap.
+    let before = tz
+        .offset_from_utc_datetime(&local.checked_sub_signed(Duration::days(1))?)
+        .fix();
+    let utc = local.checked_sub_signed(Duration::seconds(i64::from(before.local_minus_utc())))?;
+    Some(Utc.from_utc_datetime(&utc))
+}
+
+/// Parse a local datetime string in any format accepted by [`to_ical_local`].
+fn parse_local(s: &str) -> Result<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f")
+        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M"))
+        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y%m%dT%H%M%S"))
+        .ok()
+        // `%Y` is greedy, so insist on exactly eight digits for the date-only form.
+        .or_else(|| {
+            (s.len() == 8)
+                .then(|| NaiveDate::parse_from_str(s, "%Y%m%d").ok())
+                .flatten()
+                .map(|date| date.and_time(NaiveTime::MIN))
+        })
+        .ok_or_else(|| {
+            TruthError::InvalidDatetime(format!(
+                "'{}' (expected YYYY-MM-DDTHH:MM[:SS[.fff]], YYYYMMDDTHHMMSS or YYYYMMDD)",
+                s
+            ))
+        })
 }
@@ -3,7 +3,8 @@
 //! Sorts events by start time, merges overlapping busy periods, then computes
 //! the gaps between merged periods within a given time window.
 
-use crate::expander::ExpandedEvent;
+use crate::error::Result;
+use crate::expander::{self, ExpandedEvent, RRuleSpec};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -15,18 +16,44 @@ pub struct FreeSlot {
     pub duration_minutes: i64,
 }
 
+/// A run of events with no gap longer than the chain's threshold between them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BusyChain {
+    /// Start of the first event in the chain.
+    pub start: DateTime<Utc>,
+    /// Latest end of any event in the chain.
+    pub end: DateTime<Utc>,
+    /// Number of events in the chain.
+    pub event_count: usize,
+    /// Minutes actually spent in events; overlaps count once and the tolerated
+    /// gaps are excluded, so this is at most `end - start`.
+    pub total_minutes: i64,
+}
+
+/// Whole minutes from `start` to `end`, negative if `end` is earlier.
+///
+/// Every duration count in the engine goes through here. Subtracting two
+/// `DateTime<Utc>` values cannot overflow — chrono's datetime range (about
+/// ±262,000 years) is far inside `TimeDelta`'s (about ±292 million years) — so
+/// even events at `DateTime::<Utc>::MIN_UTC` and `MAX_UTC` yield an exact count.
+pub(crate) fn minutes_between(start: DateTime<Utc>, end: DateTime<Utc>) -> i64 {
+    end.signed_duration_since(start).num_minutes()
+}
+
 /// Merge overlapping or adjacent busy periods, clipped to the given window.
 ///
-/// Returns a sorted, non-overlapping list of (start, end) intervals.
+/// Zero-duration events (`start == end`, e.g. reminders) occupy no time and are
+/// ignored. Returns a sorted, non-overlapping list of (start, end) intervals.
 pub(crate) fn merge_busy_periods(
     events: &[ExpandedEvent],
     window_start: DateTime<Utc>,
     window_end: DateTime<Utc>,
 ) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
-    // Collect events clipped to the window, discarding events entirely outside.
+    // Collect events clipped to the window, discarding events entirely outside
+    // and zero-duration events.
     let mut intervals: Vec<(DateTime<Utc>, DateTime<Utc>)> = events
         .iter()
-        .filter(|e| e.start < window_end && e.end > window_start)
+        .filter(|e| e.start < e.end && e.start < window_end && e.end > window_start)
         .map(|e| (e.start.max(window_start), e.end.min(window_end)))
         .collect();
 
@@ -53,6 +80,44 @@ pub(crate) fn merge_busy_periods(
     merged
 }
 
+/// Group events into back-to-back chains.
+///
+/// Events are taken in start order; an event joins the current chain when the
+/// gap since the chain's end is at most `max_gap_minutes` (overlapping events
+/// always join), otherwise it starts a new chain. With `max_gap_minutes` of 0
+/// only touching or overlapping events chain. Zero-duration events are ignored.
+///
+/// Unlike [`merge_busy_periods`], this tolerates small gaps and counts the
+/// events in each chain. Returns chains sorted by start, including single-event
+/// chains; filter on `event_count` or `end - start` to flag long runs.
+pub fn find_busy_chains(events: &[ExpandedEvent], max_gap_minutes: i64) -> Vec<BusyChain> {
+    let mut sorted: Vec<&ExpandedEvent> = events.iter().filter(|e| e.start < e.end).collect();
+    sorted.sort_by_key(|e| (e.start, e.end));
+
+    let mut chains: Vec<BusyChain> = Vec::new();
+    for event in sorted {
+        if let Some(chain) = chains.last_mut() {
+            if minutes_between(chain.end, event.start) <= max_gap_minutes {
+                // Only the part of the event past the chain's end is new busy time.
+                if event.end > chain.end {
+                    chain.total_minutes += minutes_between(chain.end.max(event.start), event.end);
+                    chain.end = event.end;
+                }
+                chain.event_count += 1;
+                continue;
+            }
+        }
+        chains.push(BusyChain {
+            start: event.start,
+            end: event.end,
+            event_count: 1,
+            total_minutes: minutes_between(event.start, event.end),
+        });
+    }
+
+    chains
+}
+
 /// Find free time slots within a given time window, given a list of busy events.
 ///
 /// Events may overlap -- overlapping busy periods are merged before computing gaps.
@@ -63,13 +128,52 @@ pub fn find_free_slots(
     window_end: DateTime<Utc>,
 ) -> Vec<FreeSlot> {
     let merged = merge_busy_periods(events, window_start, window_end);
+    gaps_between(&merged, window_start, window_end)
+}
 
+/// Expand a recurring event over a window and compute the free time around it.
+///
+/// Returns the instances that overlap `window_start..window_end` (unclipped, in
+/// start order) and the free slots left in the window, as [`find_free_slots`]
+/// would for those instances. The rule is walked from DTSTART, but only the
+/// instances overlapping the window are kept, so the window may lie any
+/// distance after DTSTART.
+///
+/// # Errors
+/// Same as [`expander::expand_rrule`] for an invalid `spec`, and
+/// `TruthError::Expansion` if more than `u16::MAX` instances overlap the window.
+pub fn rrule_free_busy(
+    spec: &RRuleSpec,
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> Result<(Vec<ExpandedEvent>, Vec<FreeSlot>)> {
+    let busy = expander::expand_rrule_window(
+        &spec.rrule,
+        &spec.dtstart,
+        spec.duration_minutes,
+        &spec.timezone,
+        window_start,
+        window_end,
+    )?;
+    let free = find_free_slots(&busy, window_start, window_end);
+    Ok((busy, free))
+}
+
+/// The free slots left in the window around already-merged busy periods.
+///
+/// `merged` must be sorted and non-overlapping, as returned by
+/// [`merge_busy_periods`].
+pub(crate) fn gaps_between(
+    merged: &[(DateTime<Utc>, DateTime<Utc>)],
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> Vec<FreeSlot> {
     let mut free_slots = Vec::new();
     let mut cursor = window_start;
 
-    for (busy_start, busy_end) in &merged {
+    for (busy_start, busy_end) in merged {
         if cursor < *busy_start {
-            let duration_minutes = (*busy_start - cursor).num_minutes();
+            let duration_minutes = minutes_between(cursor, *busy_start);
             free_slots.push(FreeSlot {
                 start: cursor,
                 end: *busy_start,
@@ -81,7 +185,7 @@ pub fn find_free_slots(
 
     // Trailing free slot after the last busy period.
     if cursor < window_end {
-        let duration_minutes = (window_end - cursor).num_minutes();
+        let duration_minutes = minutes_between(cursor, window_end);
         free_slots.push(FreeSlot {
             start: cursor,
             end: window_end,
@@ -92,6 +196,40 @@ pub fn find_free_slots(
     free_slots
 }
 
+/// Find free time slots within the union of several allowed windows.
+///
+/// Use this when the search range has bands that are off limits without being
+/// calendar events (lunch, commute): pass the bands in between as separate
+/// windows rather than synthesizing busy events for them. Overlapping or
+/// touching windows are merged first, so a free stretch spanning two adjacent
+/// windows is one slot. Empty or reversed windows are ignored.
+///
+/// Returns free slots sorted by start time; each lies within one merged window.
+pub fn find_free_slots_multi_window(
+    events: &[ExpandedEvent],
+    allowed_windows: &[(DateTime<Utc>, DateTime<Utc>)],
+) -> Vec<FreeSlot> {
+    let mut windows: Vec<(DateTime<Utc>, DateTime<Utc>)> = allowed_windows
+        .iter()
+        .copied()
+        .filter(|(start, end)| start < end)
+        .collect();
+    windows.sort();
+
+    let mut merged: Vec<(DateTime<Utc>, DateTime<Utc>)> = Vec::new();
+    for (start, end) in windows {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    merged
+        .into_iter()
+        .flat_map(|(start, end)| find_free_slots(events, start, end))
+        .collect()
+}
+
 /// Find the first free slot of at least `min_duration_minutes` within the window.
 ///
 /// Delegates to [`find_free_slots`] and returns the first slot meeting the minimum
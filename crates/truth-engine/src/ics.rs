@@ -0,0 +1,311 @@
+//! iCalendar (RFC 5545) parsing -- extracts VEVENTs from `.ics` text.
+//!
+//! Covers the common subset needed to feed the expander: DTSTART, DTEND or
+//! DURATION, a single RRULE, EXDATE, SUMMARY, and TZID parameters. Other
+//! components (VTIMEZONE, VTODO, VALARM) and properties are ignored, including
+//! those of components nested inside a VEVENT; timezones
+//! are resolved by IANA name via `chrono-tz` rather than from VTIMEZONE blocks.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Result, TruthError};
+use crate::expander::{expand_rrule_with_exdates, resolve_local, ExpandedEvent};
+use crate::freebusy::minutes_between;
+
+/// A VEVENT reduced to the fields the expander needs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalendarEvent {
+    /// SUMMARY, unescaped.
+    pub summary: Option<String>,
+    /// DTSTART as a local datetime in `timezone` (e.g., "2026-02-17T14:00:00").
+    pub dtstart: String,
+    /// IANA timezone from the DTSTART TZID, or "UTC" for UTC and floating times.
+    pub timezone: String,
+    /// Length of each instance, from DTEND or DURATION (0 if neither is present).
+    pub duration_minutes: u32,
+    /// RRULE value (e.g., "FREQ=WEEKLY;BYDAY=TU"), if the event recurs.
+    pub rrule: Option<String>,
+    /// EXDATE values converted to local datetimes in `timezone`.
+    pub exdates: Vec<String>,
+}
+
+impl CalendarEvent {
+    /// Expand this event into concrete instances.
+    ///
+    /// Recurring events go through [`expand_rrule_with_exdates`] with the given
+    /// `until`/`count` bounds; a non-recurring event yields its single instance
+    /// (or none if an EXDATE removes it).
+    pub fn expand(&self, until: Option<&str>, count: Option<u32>) -> Result<Vec<ExpandedEvent>> {
+        let exdates: Vec<&str> = self.exdates.iter().map(String::as_str).collect();
+        let (rrule, count) = match &self.rrule {
+            Some(rrule) => (rrule.as_str(), count),
+            None => ("FREQ=DAILY;COUNT=1", None),
+        };
+        expand_rrule_with_exdates(
+            rrule,
+            &self.dtstart,
+            self.duration_minutes,
+            &self.timezone,
+            until,
+            count,
+            &exdates,
+        )
+    }
+}
+
+/// Parse every VEVENT in an iCalendar document.
+///
+/// Floating times (no `Z` suffix and no TZID) are treated as UTC. All-day
+/// `VALUE=DATE` values start at local midnight.
+///
+/// # Errors
+/// Returns `TruthError::InvalidIcs` for unterminated VEVENTs, a VEVENT without
+/// DTSTART, or a DTEND before DTSTART, `TruthError::InvalidTimezone` for an unknown TZID, and
+/// `TruthError::InvalidDatetime` / `TruthError::InvalidDuration` for malformed
+/// date-time or DURATION values.
+pub fn parse_ics(ics: &str) -> Result<Vec<CalendarEvent>> {
+    let mut events = Vec::new();
+    let mut current: Option<Vec<Property>> = None;
+    // Depth of components nested inside the current VEVENT (e.g. VALARM), whose
+    // properties (DURATION, SUMMARY, ...) must not be taken for the event's own.
+    let mut nested = 0usize;
+
+    for line in unfold_lines(ics) {
+        let prop = parse_property(&line)?;
+        match (prop.name.as_str(), prop.value.as_str()) {
+            ("BEGIN", "VEVENT") if current.is_none() => current = Some(Vec::new()),
+            ("BEGIN", _) if current.is_some() => nested += 1,
+            ("END", _) if nested > 0 => nested -= 1,
+            ("END", "VEVENT") => {
+                let props = current
+                    .take()
+                    .ok_or_else(|| TruthError::InvalidIcs("END:VEVENT without BEGIN".into()))?;
+                events.push(build_event(&props)?);
+            }
+            _ => {
+                if let Some(props) = current.as_mut().filter(|_| nested == 0) {
+                    props.push(prop);
+                }
+            }
+        }
+    }
+
+    if current.is_some() {
+        return Err(TruthError::InvalidIcs("unterminated VEVENT".into()));
+    }
+    Ok(events)
+}
+
+/// A content line split into name, parameters, and value.
+struct Property {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+impl Property {
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Join folded lines (RFC 5545 Section 3.1): a line starting with a space or
+/// tab continues the previous one.
+fn unfold_lines(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in ics.lines() {
+        let raw = raw.trim_end_matches('\r');
+        if let Some(cont) = raw.strip_prefix([' ', '\t']) {
+            if let Some(last) = lines.last_mut() {
+                last.push_str(cont);
+                continue;
+            }
+        }
+        if !raw.trim().is_empty() {
+            lines.push(raw.to_string());
+        }
+    }
+    lines
+}
+
+/// Split `NAME;PARAM=V;...:VALUE`. Colons inside quoted parameter values are
+/// not treated as the value separator.
+fn parse_property(line: &str) -> Result<Property> {
+    let mut in_quotes = false;
+    let colon = line
+        .char_indices()
+        .find(|&(_, c)| {
+            if c == '"' {
+                in_quotes = !in_quotes;
+            }
+            c == ':' && !in_quotes
+        })
+        .map(|(i, _)| i)
+        .ok_or_else(|| TruthError::InvalidIcs(format!("content line without ':': {}", line)))?;
+
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+    let mut parts = head.split(';');
+    let name = parts.next().unwrap_or_default().to_ascii_uppercase();
+    let params = parts
+        .filter_map(|p| p.split_once('='))
+        .map(|(k, v)| (k.to_ascii_uppercase(), v.trim_matches('"').to_string()))
+        .collect();
+
+    Ok(Property {
+        name,
+        params,
+        value: value.to_string(),
+    })
+}
+
+/// Assemble a [`CalendarEvent`] from the properties of one VEVENT.
+fn build_event(props: &[Property]) -> Result<CalendarEvent> {
+    let find = |name: &str| props.iter().find(|p| p.name == name);
+
+    let dtstart_prop =
+        find("DTSTART").ok_or_else(|| TruthError::InvalidIcs("VEVENT without DTSTART".into()))?;
+    let (start_local, tz) = parse_ics_datetime(dtstart_prop)?;
+    let start_utc = to_utc(start_local, tz)?;
+
+    let duration_minutes = if let Some(dtend) = find("DTEND") {
+        let (end_local, end_tz) = parse_ics_datetime(dtend)?;
+        let end_utc = to_utc(end_local, end_tz)?;
+        let minutes = minutes_between(start_utc, end_utc);
+        if minutes < 0 {
+            return Err(TruthError::InvalidIcs(format!(
+                "DTEND {} is before DTSTART {}",
+                dtend.value, dtstart_prop.value
+            )));
+        }
+        u32::try_from(minutes).map_err(|_| {
+            TruthError::InvalidIcs(format!(
+                "DTEND {} is too far after DTSTART {}",
+                dtend.value, dtstart_prop.value
+            ))
+        })?
+    } else if let Some(duration) = find("DURATION") {
+        parse_ics_duration(&duration.value)?
+    } else {
+        0
+    };
+
+    let mut exdates = Vec::new();
+    for prop in props.iter().filter(|p| p.name == "EXDATE") {
+        for value in prop.value.split(',') {
+            let single = Property {
+                name: prop.name.clone(),
+                params: prop.params.clone(),
+                value: value.to_string(),
+            };
+            let (ex_local, ex_tz) = parse_ics_datetime(&single)?;
+            let ex_in_event_tz = to_utc(ex_local, ex_tz)?.with_timezone(&tz).naive_local();
+            exdates.push(format_local(ex_in_event_tz));
+        }
+    }
+
+    Ok(CalendarEvent {
+        summary: find("SUMMARY").map(|p| unescape_text(&p.value)),
+        // A DTSTART skipped by a DST gap is stored as the wall-clock time it
+        // resolves to, which the expander accepts.
+        dtstart: format_local(start_utc.with_timezone(&tz).naive_local()),
+        timezone: tz.name().to_string(),
+        duration_minutes,
+        rrule: find("RRULE").map(|p| p.value.clone()),
+        exdates,
+    })
+}
+
+/// Parse a DATE or DATE-TIME property value into a local time and its timezone.
+fn parse_ics_datetime(prop: &Property) -> Result<(NaiveDateTime, Tz)> {
+    let tz = match prop.param("TZID") {
+        Some(tzid) => tzid
+            .parse::<Tz>()
+            .map_err(|_| TruthError::InvalidTimezone(tzid.to_string()))?,
+        None => Tz::UTC,
+    };
+    let value = prop.value.trim();
+    let invalid = || TruthError::InvalidDatetime(format!("{}:{}", prop.name, value));
+
+    let local = if prop.param("VALUE") == Some("DATE") || value.len() == 8 {
+        NaiveDate::parse_from_str(value, "%Y%m%d")
+            .map_err(|_| invalid())?
+            .and_hms_opt(0, 0, 0)
+            .ok_or_else(invalid)?
+    } else {
+        NaiveDateTime::parse_from_str(value.trim_end_matches('Z'), "%Y%m%dT%H%M%S")
+            .map_err(|_| invalid())?
+    };
+    Ok((local, tz))
+}
+
+/// Resolve a local time to UTC the way the expander does: the earlier instant
+/// for ambiguous times, and the pre-gap offset for times skipped by a DST gap.
+fn to_utc(local: NaiveDateTime, tz: Tz) -> Result<DateTime<Utc>> {
+    resolve_local(&local, &tz).ok_or_else(|| {
+        TruthError::InvalidDatetime(format!("{} is out of range in {}", local, tz.name()))
+    })
+}
+
+/// Parse an RFC 5545 DURATION (e.g., "PT1H30M", "P1D", "P2W") into minutes.
+fn parse_ics_duration(s: &str) -> Result<u32> {
+    let invalid = || TruthError::InvalidDuration(s.to_string());
+    let body = s.trim().strip_prefix('+').unwrap_or(s.trim());
+    let body = body.strip_prefix('P').ok_or_else(invalid)?;
+
+    let mut minutes: u64 = 0;
+    let mut number = String::new();
+    let mut in_time = false;
+    for c in body.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'T' if !in_time && number.is_empty() => in_time = true,
+            _ => {
+                let n: u64 = number.parse().map_err(|_| invalid())?;
+                number.clear();
+                let part = match (c, in_time) {
+                    ('W', false) => n.checked_mul(7 * 24 * 60),
+                    ('D', false) => n.checked_mul(24 * 60),
+                    ('H', true) => n.checked_mul(60),
+                    ('M', true) => Some(n),
+                    ('S', true) => Some(n / 60),
+                    _ => return Err(invalid()),
+                };
+                minutes = part
+                    .and_then(|part| minutes.checked_add(part))
+                    .ok_or_else(invalid)?;
+            }
+        }
+    }
+    if !number.is_empty() {
+        return Err(invalid());
+    }
+    u32::try_from(minutes).map_err(|_| invalid())
+}
+
+/// Format a local time the way the expander expects ("2026-02-17T14:00:00").
+fn format_local(dt: NaiveDateTime) -> String {
+    dt.format("%Y-%m-%dT%H:%M:%S").to_string()
+}
+
+/// Undo TEXT escaping (RFC 5545 Section 3.3.11).
+fn unescape_text(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') | Some('N') => out.push('\n'),
+                Some(other) => out.push(other),
+                None => out.push('\\'),
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
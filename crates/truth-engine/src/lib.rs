@@ -14,7 +14,8 @@
 //! - [`conflict`] — Detect overlapping events in expanded schedules
 //! - [`freebusy`] — Compute free time slots from event lists
 //! - [`availability`] — Merge N event streams into unified busy/free with privacy control
-//! - [`temporal`] — Timezone conversion, duration computation, timestamp adjustment, relative datetime resolution
+//! - [`ics`] — iCalendar (.ics) text → VEVENTs ready for expansion
+//! - [`temporal`] — Timezone conversion, duration computation, timestamp adjustment, relative datetime resolution, named window construction
 //! - [`error`] — Error types
 
 pub mod availability;
@@ -23,18 +24,34 @@
 pub mod error;
 pub mod expander;
 pub mod freebusy;
+pub mod ics;
 pub mod temporal;
 
 pub use availability::{
-    find_first_free_across, merge_availability, BusyBlock, EventStream, PrivacyLevel,
-    UnifiedAvailability,
+    availability_bitmap, bucket_by_day, concat_availability, day_margins, find_all_free_across,
+    find_best_free_slot, find_common_free, find_first_free_across, find_slot_across_timezones,
+    merge_availability, merge_availability_with_blackouts, merge_availability_with_options,
+    next_free_slots, split_at_day_boundaries, utilization, AvailabilityIndex, BusyBlock,
+    BusySource, DayAvailability, EventStream, MergeOptions, Participant, PrivacyLevel,
+    SlotStrategy, TzParticipant, UnifiedAvailability, WorkingHours,
+};
+pub use conflict::{
+    conflicts_with_recurrence, find_conflicts, find_conflicts_with_travel, ConflictSeverity,
 };
-pub use conflict::find_conflicts;
 pub use error::TruthError;
-pub use expander::{expand_rrule, expand_rrule_with_exdates, ExpandedEvent};
-pub use freebusy::{find_free_slots, FreeSlot};
+pub use expander::{
+    expand_rrule, expand_rrule_diagnostics, expand_rrule_grouped_by_local_date, expand_rrule_seq,
+    expand_rrule_with_exdates, expand_rrule_with_options, shift_dtstart, shift_events,
+    validate_rrule, Diagnostic, DiagnosticCode, DurationMode, ExpandOptions, ExpandedEvent,
+    ExpandedEventSeq, RRuleSpec,
+};
+pub use freebusy::{
+    find_busy_chains, find_free_slots, find_free_slots_multi_window, rrule_free_busy, BusyChain,
+    FreeSlot,
+};
+pub use ics::{parse_ics, CalendarEvent};
 pub use temporal::{
-    adjust_timestamp, compute_duration, convert_timezone, resolve_relative,
+    adjust_timestamp, compute_duration, convert_timezone, parse_relative_window, resolve_relative,
     resolve_relative_with_options, AdjustedTimestamp, ConvertedDatetime, DurationInfo,
     ResolveOptions, ResolvedDatetime, WeekStartDay,
 };
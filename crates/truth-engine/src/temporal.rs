@@ -18,6 +18,7 @@
 //! - [`compute_duration`] — Calculate the duration between two timestamps
 //! - [`adjust_timestamp`] — Add or subtract a duration from a timestamp
 //! - [`resolve_relative`] — Resolve a relative time expression to an absolute datetime
+//! - [`parse_relative_window`] — Resolve a named window (`"today"`, `"this_week"`, …) to a UTC range
 //!
 //! # Datetime Accuracy
 //!
@@ -25,10 +26,13 @@
 //! which reads the OS kernel clock (NTP-synchronized on modern systems, typically
 //! <50ms accuracy). No online time service is used.
 
-use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Offset, TimeZone, Utc, Weekday};
+use chrono::{
+    DateTime, Datelike, Days, Months, NaiveDate, NaiveTime, Offset, TimeZone, Utc, Weekday,
+};
 use chrono_tz::Tz;
 use serde::Serialize;
 
+use crate::availability::local_day_start;
 use crate::error::TruthError;
 
 // ── Configurable week start ─────────────────────────────────────────────────
@@ -417,6 +421,92 @@ pub fn resolve_relative_with_options(
     })
 }
 
+// ── parse_relative_window ───────────────────────────────────────────────────
+
+/// Resolve a named window spec to a half-open `[start, end)` range in UTC.
+///
+/// Day, week and month boundaries are local midnights in `tz`, so `"today"` is
+/// the local calendar day rather than the UTC one. Weeks start on Monday
+/// (ISO 8601).
+///
+/// # Supported Specs
+///
+/// - `"today"`, `"tomorrow"` — one local day
+/// - `"this_week"`, `"next_week"` — Monday 00:00 to the following Monday 00:00
+/// - `"this_month"`, `"next_month"` — the 1st at 00:00 to the 1st of the following month
+/// - `"next_N_days"` (e.g., `"next_7_days"`) — from `now` to the same local
+///   wall-clock time N days later
+///
+/// # Errors
+///
+/// Returns [`TruthError::InvalidExpression`] for an unknown spec, a day count
+/// of zero, or one that reaches past the range of representable dates.
+pub fn parse_relative_window(
+    spec: &str,
+    now: DateTime<Utc>,
+    tz: Tz,
+) -> Result<(DateTime<Utc>, DateTime<Utc>), TruthError> {
+    let local = now.with_timezone(&tz);
+    let today = local.date_naive();
+    let name = spec.trim();
+    let invalid = || TruthError::InvalidExpression(format!("unknown window: '{}'", name));
+    let out_of_range = || TruthError::InvalidExpression(format!("window out of range: '{}'", name));
+    let add_days =
+        |date: NaiveDate, n: u64| date.checked_add_days(Days::new(n)).ok_or_else(out_of_range);
+
+    let (first, last) = match name {
+        "today" => (today, add_days(today, 1)?),
+        "tomorrow" => (add_days(today, 1)?, add_days(today, 2)?),
+        "this_week" | "next_week" => {
+            let days_since_start = days_from_week_start(today.weekday(), WeekStartDay::Monday);
+            let mut start = today
+                .checked_sub_days(Days::new(days_since_start as u64))
+                .ok_or_else(out_of_range)?;
+            if name == "next_week" {
+                start = add_days(start, 7)?;
+            }
+            (start, add_days(start, 7)?)
+        }
+        "this_month" | "next_month" => {
+            let mut start = today.with_day(1).ok_or_else(invalid)?;
+            if name == "next_month" {
+                start = start
+                    .checked_add_months(Months::new(1))
+                    .ok_or_else(invalid)?;
+            }
+            (
+                start,
+                start
+                    .checked_add_months(Months::new(1))
+                    .ok_or_else(invalid)?,
+            )
+        }
+        other => {
+            let days = other
+                .strip_prefix("next_")
+                .and_then(|rest| rest.strip_suffix("_days"))
+                .and_then(|n| n.parse::<i64>().ok())
+                .filter(|n| *n > 0)
+                .ok_or_else(invalid)?;
+            let span = chrono::TimeDelta::try_days(days).ok_or_else(out_of_range)?;
+            let target = local
+                .naive_local()
+                .checked_add_signed(span)
+                .ok_or_else(out_of_range)?;
+            // A wall-clock time skipped by a DST gap falls back to a fixed offset.
+            let end = match tz.from_local_datetime(&target).earliest() {
+                Some(dt) => dt.with_timezone(&Utc),
+                None => now.checked_add_signed(span).ok_or_else(out_of_range)?,
+            };
+            return Ok((now, end));
+        }
+    };
+
+    let start = local_day_start(first, &tz).ok_or_else(invalid)?;
+    let end = local_day_start(last, &tz).ok_or_else(invalid)?;
+    Ok((start, end))
+}
+
 // ── Internal helpers ────────────────────────────────────────────────────────
 
 /// Parse an RFC 3339 datetime string into `DateTime<Utc>`.
@@ -985,10 +1075,8 @@ fn try_compound_period(
 ) -> Option<DateTime<Tz>> {
     let (is_start, rest) = if let Some(r) = s.strip_prefix("start of ") {
         (true, r)
-    } else if let Some(r) = s.strip_prefix("end of ") {
-        (false, r)
     } else {
-        return None;
+        (false, s.strip_prefix("end of ")?)
     };
 
     match rest {
@@ -2042,4 +2130,83 @@ fn test_resolve_next_week_sunday() {
         assert!(result.resolved_utc.contains("2026-02-22"));
         assert!(result.resolved_utc.contains("00:00:00"));
     }
+
+    // ── parse_relative_window tests ─────────────────────────────────────
+
+    #[test]
+    fn test_window_today_uses_local_day() {
+        // 2026-02-19 03:00 UTC is still Wed Feb 18 (22:00 EST) in New York
+        let now = Utc.with_ymd_and_hms(2026, 2, 19, 3, 0, 0).unwrap();
+        let (start, end) = parse_relative_window("today", now, Tz::America__New_York).unwrap();
+        assert_eq!(start, Utc.with_ymd_and_hms(2026, 2, 18, 5, 0, 0).unwrap());
+        assert_eq!(end, Utc.with_ymd_and_hms(2026, 2, 19, 5, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_window_this_week_in_tokyo() {
+        // 2026-02-22 16:00 UTC is Mon Feb 23 01:00 JST, so the Tokyo week is Feb 23–Mar 2
+        let now = Utc.with_ymd_and_hms(2026, 2, 22, 16, 0, 0).unwrap();
+        let (start, end) = parse_relative_window("this_week", now, Tz::Asia__Tokyo).unwrap();
+        assert_eq!(start, Utc.with_ymd_and_hms(2026, 2, 22, 15, 0, 0).unwrap());
+        assert_eq!(end, Utc.with_ymd_and_hms(2026, 3, 1, 15, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_window_this_week_spans_dst_change() {
+        // Week of Mon Mar 2 – Mon Mar 9, 2026; US spring forward is Sun Mar 8
+        let (start, end) =
+            parse_relative_window("this_week", anchor_march(), Tz::America__New_York).unwrap();
+        assert_eq!(start, Utc.with_ymd_and_hms(2026, 3, 2, 5, 0, 0).unwrap());
+        assert_eq!(end, Utc.with_ymd_and_hms(2026, 3, 9, 4, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_window_this_month_and_next_month() {
+        let (start, end) = parse_relative_window("this_month", anchor(), Tz::UTC).unwrap();
+        assert_eq!(start, Utc.with_ymd_and_hms(2026, 2, 1, 0, 0, 0).unwrap());
+        assert_eq!(end, Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap());
+
+        let (start, end) = parse_relative_window("next_month", anchor(), Tz::UTC).unwrap();
+        assert_eq!(start, Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap());
+        assert_eq!(end, Utc.with_ymd_and_hms(2026, 4, 1, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_window_next_n_days_keeps_wall_clock() {
+        // 7 local days from Thu Mar 5 09:00 EST crosses spring forward → Thu Mar 12 09:00 EDT
+        let (start, end) =
+            parse_relative_window("next_7_days", anchor_march(), Tz::America__New_York).unwrap();
+        assert_eq!(start, anchor_march());
+        assert_eq!(end, Utc.with_ymd_and_hms(2026, 3, 12, 13, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_window_rejects_unknown_spec() {
+        for spec in ["yesterday", "next_0_days", "next_x_days", ""] {
+            let err = parse_relative_window(spec, anchor(), Tz::UTC).unwrap_err();
+            assert!(matches!(err, TruthError::InvalidExpression(_)), "{spec}");
+        }
+    }
+
+    #[test]
+    fn test_window_rejects_out_of_range_day_count() {
+        for spec in ["next_999999999999_days", "next_100000000_days"] {
+            let err = parse_relative_window(spec, anchor(), Tz::UTC).unwrap_err();
+            assert!(matches!(err, TruthError::InvalidExpression(_)), "{spec}");
+        }
+    }
+
+    #[test]
+    fn test_window_rejects_days_past_the_calendar_end() {
+        let now = DateTime::<Utc>::MAX_UTC;
+        for spec in ["today", "tomorrow", "this_week", "next_week", "next_month"] {
+            let err = parse_relative_window(spec, now, Tz::UTC).unwrap_err();
+            assert!(matches!(err, TruthError::InvalidExpression(_)), "{spec}");
+        }
+    }
+
+    fn anchor_march() -> DateTime<Utc> {
+        // Thursday, March 5, 2026, 09:00 EST
+        Utc.with_ymd_and_hms(2026, 3, 5, 14, 0, 0).unwrap()
+    }
 }
@@ -2,9 +2,14 @@
 //!
 //! Follows TDD: tests were written first (RED), then the implementation (GREEN).
 
-use chrono::{TimeZone, Utc};
+use chrono::{NaiveDate, TimeZone, Timelike, Utc};
 use truth_engine::availability::{
-    find_first_free_across, merge_availability, EventStream, PrivacyLevel,
+    availability_bitmap, bucket_by_day, concat_availability, day_margins, find_all_free_across,
+    find_best_free_slot, find_common_free, find_first_free_across, find_slot_across_timezones,
+    merge_availability, merge_availability_with_blackouts, merge_availability_with_options,
+    next_free_slots, split_at_day_boundaries, utilization, AvailabilityIndex, BusySource,
+    EventStream, MergeOptions, Participant, PrivacyLevel, SlotStrategy, TzParticipant,
+    WorkingHours,
 };
 use truth_engine::expander::ExpandedEvent;
 
@@ -21,6 +26,8 @@ fn stream(id: &str, events: Vec<ExpandedEvent>) -> EventStream {
     EventStream {
         stream_id: id.to_string(),
         events,
+        display_name: None,
+        color: None,
     }
 }
 
@@ -391,3 +398,1380 @@ fn find_first_free_across_no_qualifying_slot() {
     let slot = find_first_free_across(&[stream_a], window_start, window_end, 30);
     assert!(slot.is_none());
 }
+
+// ── Test 13: Blackout date leaves no free time that day ─────────────────────
+
+#[test]
+fn blackout_date_has_no_free_slots() {
+    let stream_a = stream(
+        "work",
+        vec![event("2026-03-16T09:00:00Z", "2026-03-16T10:00:00Z")],
+    );
+
+    // Mon Mar 16 – Thu Mar 19 (UTC), with Tue Mar 17 blacked out.
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 0, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 19, 0, 0, 0).unwrap();
+    let holiday = NaiveDate::from_ymd_opt(2026, 3, 17).unwrap();
+
+    let result = merge_availability_with_blackouts(
+        &[stream_a],
+        window_start,
+        window_end,
+        PrivacyLevel::Opaque,
+        &[holiday],
+        "UTC",
+    )
+    .unwrap();
+
+    let day_start = Utc.with_ymd_and_hms(2026, 3, 17, 0, 0, 0).unwrap();
+    let day_end = Utc.with_ymd_and_hms(2026, 3, 18, 0, 0, 0).unwrap();
+    assert!(
+        result
+            .free
+            .iter()
+            .all(|slot| slot.end <= day_start || slot.start >= day_end),
+        "no free slot may overlap the blackout date"
+    );
+    assert!(result
+        .busy
+        .iter()
+        .any(|b| b.start <= day_start && b.end >= day_end));
+}
+
+// ── Test 14: Days adjacent to a blackout are unaffected ─────────────────────
+
+#[test]
+fn blackout_date_leaves_adjacent_days_unaffected() {
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 0, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 19, 0, 0, 0).unwrap();
+    let holiday = NaiveDate::from_ymd_opt(2026, 3, 17).unwrap();
+
+    let result = merge_availability_with_blackouts(
+        &[],
+        window_start,
+        window_end,
+        PrivacyLevel::Opaque,
+        &[holiday],
+        "UTC",
+    )
+    .unwrap();
+
+    // Monday and Wednesday stay entirely free.
+    assert_eq!(result.free.len(), 2);
+    assert_eq!(result.free[0].start, window_start);
+    assert_eq!(result.free[0].duration_minutes, 24 * 60);
+    assert_eq!(
+        result.free[1].start,
+        Utc.with_ymd_and_hms(2026, 3, 18, 0, 0, 0).unwrap()
+    );
+    assert_eq!(result.free[1].end, window_end);
+}
+
+// ── Test 15: Blackout on a DST day uses local day bounds ────────────────────
+
+#[test]
+fn blackout_date_on_dst_day_covers_local_day() {
+    // Sun Mar 8 2026 is the US spring-forward day: a 23-hour local day.
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 7, 0, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 10, 0, 0, 0).unwrap();
+    let dst_day = NaiveDate::from_ymd_opt(2026, 3, 8).unwrap();
+
+    let result = merge_availability_with_blackouts(
+        &[],
+        window_start,
+        window_end,
+        PrivacyLevel::Opaque,
+        &[dst_day],
+        "America/New_York",
+    )
+    .unwrap();
+
+    assert_eq!(result.busy.len(), 1);
+    // 00:00 EST = 05:00 UTC; next midnight is 00:00 EDT = 04:00 UTC.
+    assert_eq!(
+        result.busy[0].start,
+        Utc.with_ymd_and_hms(2026, 3, 8, 5, 0, 0).unwrap()
+    );
+    assert_eq!(
+        result.busy[0].end,
+        Utc.with_ymd_and_hms(2026, 3, 9, 4, 0, 0).unwrap()
+    );
+}
+
+#[test]
+fn blackout_is_not_counted_as_a_source() {
+    // A real calendar named "blackout" must not be confused with blackout days.
+    let streams = [
+        stream(
+            "work",
+            vec![event("2026-03-17T09:00:00Z", "2026-03-17T10:00:00Z")],
+        ),
+        stream(
+            "blackout",
+            vec![event("2026-03-16T09:00:00Z", "2026-03-16T10:00:00Z")],
+        ),
+    ];
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 0, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 19, 0, 0, 0).unwrap();
+    let holiday = NaiveDate::from_ymd_opt(2026, 3, 17).unwrap();
+
+    let result = merge_availability_with_blackouts(
+        &streams,
+        window_start,
+        window_end,
+        PrivacyLevel::Full,
+        &[holiday],
+        "UTC",
+    )
+    .unwrap();
+
+    assert_eq!(result.busy.len(), 2);
+    let ids = |b: &truth_engine::availability::BusyBlock| -> Vec<String> {
+        b.sources.iter().map(|s| s.stream_id.clone()).collect()
+    };
+    // Monday: only the real "blackout" calendar.
+    assert_eq!(result.busy[0].source_count, 1);
+    assert_eq!(ids(&result.busy[0]), ["blackout"]);
+    // Tuesday, the blackout day: the work meeting is the only source.
+    assert_eq!(
+        result.busy[1].start,
+        Utc.with_ymd_and_hms(2026, 3, 17, 0, 0, 0).unwrap()
+    );
+    assert_eq!(result.busy[1].source_count, 1);
+    assert_eq!(ids(&result.busy[1]), ["work"]);
+}
+
+#[test]
+fn blackout_with_invalid_timezone_returns_error() {
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 0, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 17, 0, 0, 0).unwrap();
+    let result = merge_availability_with_blackouts(
+        &[],
+        window_start,
+        window_end,
+        PrivacyLevel::Opaque,
+        &[],
+        "Not/AZone",
+    );
+    assert!(result.is_err());
+}
+
+// ── Test 16: Stream over the event limit is truncated and reported ──────────
+
+#[test]
+fn stream_over_event_limit_is_truncated_and_reported() {
+    let noisy = stream(
+        "noisy",
+        vec![
+            // Deliberately out of order: the earliest two must survive.
+            event("2026-03-16T15:00:00Z", "2026-03-16T16:00:00Z"),
+            event("2026-03-16T09:00:00Z", "2026-03-16T10:00:00Z"),
+            event("2026-03-16T11:00:00Z", "2026-03-16T12:00:00Z"),
+        ],
+    );
+    let quiet = stream(
+        "quiet",
+        vec![event("2026-03-16T13:00:00Z", "2026-03-16T14:00:00Z")],
+    );
+
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 8, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 16, 17, 0, 0).unwrap();
+    let options = MergeOptions {
+        max_events_per_stream: Some(2),
+        ..Default::default()
+    };
+
+    let result = merge_availability_with_options(
+        &[noisy, quiet],
+        window_start,
+        window_end,
+        PrivacyLevel::Full,
+        &options,
+    );
+
+    assert_eq!(result.truncated_streams, vec!["noisy".to_string()]);
+    // 09-10 and 11-12 from "noisy", 13-14 from "quiet"; 15-16 was dropped.
+    assert_eq!(result.busy.len(), 3);
+    assert_eq!(
+        result.busy[0].start,
+        Utc.with_ymd_and_hms(2026, 3, 16, 9, 0, 0).unwrap()
+    );
+    assert_eq!(
+        result.busy[2].end,
+        Utc.with_ymd_and_hms(2026, 3, 16, 14, 0, 0).unwrap()
+    );
+}
+
+// ── Test 17: Streams under the event limit report nothing ───────────────────
+
+#[test]
+fn streams_under_event_limit_report_nothing() {
+    let stream_a = stream(
+        "work",
+        vec![
+            event("2026-03-16T09:00:00Z", "2026-03-16T10:00:00Z"),
+            // Outside the window: does not count toward the limit.
+            event("2026-03-17T09:00:00Z", "2026-03-17T10:00:00Z"),
+        ],
+    );
+
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 8, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 16, 17, 0, 0).unwrap();
+    let options = MergeOptions {
+        max_events_per_stream: Some(1),
+        ..Default::default()
+    };
+
+    let result = merge_availability_with_options(
+        std::slice::from_ref(&stream_a),
+        window_start,
+        window_end,
+        PrivacyLevel::Full,
+        &options,
+    );
+    assert!(result.truncated_streams.is_empty());
+
+    let unlimited = merge_availability(&[stream_a], window_start, window_end, PrivacyLevel::Full);
+    assert_eq!(result.busy, unlimited.busy);
+    assert_eq!(result.free, unlimited.free);
+}
+
+// ── Test 18: Busy block crossing local midnight lands in both days ──────────
+
+#[test]
+fn bucket_by_day_splits_block_crossing_local_midnight() {
+    // 22:00–02:00 America/New_York (EST, UTC-5) on the night of Feb 17.
+    let streams = vec![stream(
+        "work",
+        vec![event("2026-02-18T03:00:00Z", "2026-02-18T07:00:00Z")],
+    )];
+    let window_start = Utc.with_ymd_and_hms(2026, 2, 17, 5, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 2, 19, 5, 0, 0).unwrap();
+    let merged = merge_availability(&streams, window_start, window_end, PrivacyLevel::Full);
+
+    let days = bucket_by_day(&merged, chrono_tz::America::New_York);
+    assert_eq!(days.len(), 2);
+    let midnight = Utc.with_ymd_and_hms(2026, 2, 18, 5, 0, 0).unwrap();
+
+    assert_eq!(days[0].date, NaiveDate::from_ymd_opt(2026, 2, 17).unwrap());
+    assert_eq!(days[0].busy.len(), 1);
+    assert_eq!(
+        days[0].busy[0].start,
+        Utc.with_ymd_and_hms(2026, 2, 18, 3, 0, 0).unwrap()
+    );
+    assert_eq!(days[0].busy[0].end, midnight);
+    assert_eq!(days[0].busy[0].source_count, 1);
+
+    assert_eq!(days[1].date, NaiveDate::from_ymd_opt(2026, 2, 18).unwrap());
+    assert_eq!(days[1].busy.len(), 1);
+    assert_eq!(days[1].busy[0].start, midnight);
+    assert_eq!(
+        days[1].busy[0].end,
+        Utc.with_ymd_and_hms(2026, 2, 18, 7, 0, 0).unwrap()
+    );
+
+    // Free time is clipped too: day 1 is 00:00–22:00, day 2 is 02:00–24:00.
+    assert_eq!(days[0].free.len(), 1);
+    assert_eq!(days[0].free[0].duration_minutes, 22 * 60);
+    assert_eq!(days[1].free.len(), 1);
+    assert_eq!(days[1].free[0].duration_minutes, 22 * 60);
+}
+
+// ── Test 19: DST days keep their local length ───────────────────────────────
+
+#[test]
+fn bucket_by_day_handles_dst_day_length() {
+    // 2026-03-08 is the spring-forward day in America/New_York (23 hours).
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 8, 5, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 10, 4, 0, 0).unwrap();
+    let merged = merge_availability(&[], window_start, window_end, PrivacyLevel::Opaque);
+
+    let days = bucket_by_day(&merged, chrono_tz::America::New_York);
+    assert_eq!(days.len(), 2);
+    assert_eq!(days[0].date, NaiveDate::from_ymd_opt(2026, 3, 8).unwrap());
+    assert_eq!(days[0].free[0].duration_minutes, 23 * 60);
+    assert_eq!(
+        days[0].free[0].end,
+        Utc.with_ymd_and_hms(2026, 3, 9, 4, 0, 0).unwrap()
+    );
+    assert_eq!(days[1].free[0].duration_minutes, 24 * 60);
+}
+
+// ── Test 20: Common free time across participants with partial overlap ─────
+
+#[test]
+fn common_free_intersects_participant_windows_and_events() {
+    // Alice works 09:00–17:00 UTC and is busy 12:00–13:00.
+    let alice = Participant {
+        streams: vec![stream(
+            "alice-work",
+            vec![event("2026-03-16T12:00:00Z", "2026-03-16T13:00:00Z")],
+        )],
+        window_start: Utc.with_ymd_and_hms(2026, 3, 16, 9, 0, 0).unwrap(),
+        window_end: Utc.with_ymd_and_hms(2026, 3, 16, 17, 0, 0).unwrap(),
+    };
+    // Bob works 11:00–19:00 UTC and is busy 14:00–14:45 across two calendars.
+    let bob = Participant {
+        streams: vec![
+            stream(
+                "bob-work",
+                vec![event("2026-03-16T14:00:00Z", "2026-03-16T14:30:00Z")],
+            ),
+            stream(
+                "bob-personal",
+                vec![event("2026-03-16T14:15:00Z", "2026-03-16T14:45:00Z")],
+            ),
+        ],
+        window_start: Utc.with_ymd_and_hms(2026, 3, 16, 11, 0, 0).unwrap(),
+        window_end: Utc.with_ymd_and_hms(2026, 3, 16, 19, 0, 0).unwrap(),
+    };
+
+    let slots = find_common_free(&[alice.clone(), bob.clone()], 30);
+    let ranges: Vec<_> = slots
+        .iter()
+        .map(|s| (s.start, s.end, s.duration_minutes))
+        .collect();
+    assert_eq!(
+        ranges,
+        vec![
+            (
+                Utc.with_ymd_and_hms(2026, 3, 16, 11, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2026, 3, 16, 12, 0, 0).unwrap(),
+                60,
+            ),
+            (
+                Utc.with_ymd_and_hms(2026, 3, 16, 13, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2026, 3, 16, 14, 0, 0).unwrap(),
+                60,
+            ),
+            (
+                Utc.with_ymd_and_hms(2026, 3, 16, 14, 45, 0).unwrap(),
+                Utc.with_ymd_and_hms(2026, 3, 16, 17, 0, 0).unwrap(),
+                135,
+            ),
+        ]
+    );
+
+    // Raising the minimum leaves only the long afternoon slot.
+    let long = find_common_free(&[alice, bob], 90);
+    assert_eq!(long.len(), 1);
+    assert_eq!(long[0].duration_minutes, 135);
+}
+
+// ── Test 21: Disjoint windows or no participants yield nothing ──────────────
+
+#[test]
+fn common_free_empty_for_disjoint_windows_or_no_participants() {
+    let morning = Participant {
+        streams: vec![],
+        window_start: Utc.with_ymd_and_hms(2026, 3, 16, 8, 0, 0).unwrap(),
+        window_end: Utc.with_ymd_and_hms(2026, 3, 16, 12, 0, 0).unwrap(),
+    };
+    let evening = Participant {
+        streams: vec![],
+        window_start: Utc.with_ymd_and_hms(2026, 3, 16, 18, 0, 0).unwrap(),
+        window_end: Utc.with_ymd_and_hms(2026, 3, 16, 22, 0, 0).unwrap(),
+    };
+
+    assert!(find_common_free(&[morning.clone(), evening], 15).is_empty());
+    assert!(find_common_free(&[], 15).is_empty());
+
+    // A single participant gets their own free slots back.
+    let solo = find_common_free(&[morning], 15);
+    assert_eq!(solo.len(), 1);
+    assert_eq!(solo[0].duration_minutes, 240);
+}
+
+// ── Test 22: Day margins before the first and after the last busy block ────
+
+#[test]
+fn day_margins_returns_leading_and_trailing_free_time() {
+    let streams = vec![stream(
+        "work",
+        vec![
+            event("2026-03-16T10:00:00Z", "2026-03-16T11:00:00Z"),
+            event("2026-03-16T14:00:00Z", "2026-03-16T15:30:00Z"),
+        ],
+    )];
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 8, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 16, 17, 0, 0).unwrap();
+    let merged = merge_availability(&streams, window_start, window_end, PrivacyLevel::Opaque);
+
+    let (leading, trailing) = day_margins(&merged);
+    let leading = leading.expect("free time before 10:00");
+    assert_eq!(leading.start, window_start);
+    assert_eq!(
+        leading.end,
+        Utc.with_ymd_and_hms(2026, 3, 16, 10, 0, 0).unwrap()
+    );
+    assert_eq!(leading.duration_minutes, 120);
+
+    let trailing = trailing.expect("free time after 15:30");
+    assert_eq!(
+        trailing.start,
+        Utc.with_ymd_and_hms(2026, 3, 16, 15, 30, 0).unwrap()
+    );
+    assert_eq!(trailing.end, window_end);
+    assert_eq!(trailing.duration_minutes, 90);
+}
+
+// ── Test 23: Fully busy or fully free days have no margins ─────────────────
+
+#[test]
+fn day_margins_none_when_fully_busy_or_no_busy_blocks() {
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 8, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 16, 17, 0, 0).unwrap();
+
+    // Busy from before the window opens until after it closes.
+    let busy_all_day = vec![stream(
+        "work",
+        vec![event("2026-03-16T07:00:00Z", "2026-03-16T18:00:00Z")],
+    )];
+    let merged = merge_availability(
+        &busy_all_day,
+        window_start,
+        window_end,
+        PrivacyLevel::Opaque,
+    );
+    assert_eq!(day_margins(&merged), (None, None));
+
+    // No busy blocks: the whole window is free, but there is no "margin".
+    let merged = merge_availability(&[], window_start, window_end, PrivacyLevel::Opaque);
+    assert_eq!(day_margins(&merged), (None, None));
+}
+
+// ── Test 24: find_all_free_across returns every qualifying slot in order ────
+
+#[test]
+fn find_all_free_across_returns_every_qualifying_slot() {
+    let streams = vec![
+        stream(
+            "work",
+            vec![
+                event("2026-03-16T09:00:00Z", "2026-03-16T10:00:00Z"),
+                event("2026-03-16T12:00:00Z", "2026-03-16T13:00:00Z"),
+            ],
+        ),
+        stream(
+            "personal",
+            vec![
+                // Leaves only a 15-minute gap after it, too short to qualify.
+                event("2026-03-16T13:15:00Z", "2026-03-16T15:00:00Z"),
+            ],
+        ),
+    ];
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 8, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 16, 17, 0, 0).unwrap();
+
+    let slots = find_all_free_across(&streams, window_start, window_end, 30);
+
+    let ranges: Vec<_> = slots.iter().map(|s| (s.start, s.end)).collect();
+    assert_eq!(
+        ranges,
+        vec![
+            (
+                window_start,
+                Utc.with_ymd_and_hms(2026, 3, 16, 9, 0, 0).unwrap()
+            ),
+            (
+                Utc.with_ymd_and_hms(2026, 3, 16, 10, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2026, 3, 16, 12, 0, 0).unwrap()
+            ),
+            (
+                Utc.with_ymd_and_hms(2026, 3, 16, 15, 0, 0).unwrap(),
+                window_end
+            ),
+        ]
+    );
+    assert_eq!(
+        find_first_free_across(&streams, window_start, window_end, 30),
+        slots.first().cloned()
+    );
+}
+
+// ── Test 25: Meeting slot inside Pacific and London working hours ───────────
+
+fn pacific_and_london(london_events: Vec<ExpandedEvent>) -> Vec<TzParticipant> {
+    vec![
+        // 08:00-17:00 PST = 16:00-01:00 UTC
+        TzParticipant {
+            streams: vec![stream("sf", vec![])],
+            timezone: chrono_tz::America::Los_Angeles,
+            work_start_hour: 8,
+            work_end_hour: 17,
+        },
+        // 09:00-18:00 GMT = 09:00-18:00 UTC
+        TzParticipant {
+            streams: vec![stream("london", london_events)],
+            timezone: chrono_tz::Europe::London,
+            work_start_hour: 9,
+            work_end_hour: 18,
+        },
+    ]
+}
+
+#[test]
+fn slot_across_timezones_uses_narrow_working_hours_overlap() {
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 3, 0, 0, 0).unwrap();
+    // The overlap is 16:00-18:00 UTC (8-10am Pacific, 4-6pm London); London is
+    // busy for its first half hour.
+    let attendees = pacific_and_london(vec![event("2026-03-02T16:00:00Z", "2026-03-02T16:30:00Z")]);
+
+    let slot = find_slot_across_timezones(&attendees, 60, window_start, window_end)
+        .expect("an hour fits in the shared window");
+    assert_eq!(
+        slot.start,
+        Utc.with_ymd_and_hms(2026, 3, 2, 16, 30, 0).unwrap()
+    );
+    assert_eq!(
+        slot.end,
+        Utc.with_ymd_and_hms(2026, 3, 2, 17, 30, 0).unwrap()
+    );
+}
+
+// ── Test 26: No slot when the overlap is too short ──────────────────────────
+
+#[test]
+fn slot_across_timezones_none_when_overlap_too_short() {
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 3, 0, 0, 0).unwrap();
+    let attendees = pacific_and_london(vec![]);
+
+    // The shared window is only two hours long.
+    assert!(find_slot_across_timezones(&attendees, 150, window_start, window_end).is_none());
+    assert!(find_slot_across_timezones(&[], 30, window_start, window_end).is_none());
+}
+
+#[test]
+fn slot_across_timezones_unrepresentable_duration_does_not_panic() {
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 3, 0, 0, 0).unwrap();
+    let attendees = pacific_and_london(vec![]);
+
+    assert!(find_slot_across_timezones(&attendees, i64::MAX, window_start, window_end).is_none());
+    assert!(find_slot_across_timezones(&attendees, i64::MIN, window_start, window_end).is_none());
+}
+
+// ── Test 27: Zero-duration events do not block time ─────────────────────────
+
+#[test]
+fn zero_duration_events_are_ignored() {
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 2, 17, 0, 0).unwrap();
+    let streams = vec![
+        stream(
+            "work",
+            vec![
+                event("2026-03-02T10:00:00Z", "2026-03-02T11:00:00Z"),
+                // A reminder in the middle of free time and one inside the meeting.
+                event("2026-03-02T13:00:00Z", "2026-03-02T13:00:00Z"),
+                event("2026-03-02T10:30:00Z", "2026-03-02T10:30:00Z"),
+            ],
+        ),
+        stream(
+            "reminders",
+            vec![event("2026-03-02T10:15:00Z", "2026-03-02T10:15:00Z")],
+        ),
+    ];
+
+    let result = merge_availability(&streams, window_start, window_end, PrivacyLevel::Full);
+
+    assert_eq!(result.busy.len(), 1);
+    assert_eq!(
+        result.busy[0].start,
+        Utc.with_ymd_and_hms(2026, 3, 2, 10, 0, 0).unwrap()
+    );
+    assert_eq!(
+        result.busy[0].end,
+        Utc.with_ymd_and_hms(2026, 3, 2, 11, 0, 0).unwrap()
+    );
+    assert_eq!(result.busy[0].source_count, 1);
+
+    assert_eq!(result.free.len(), 2);
+    assert_eq!(result.free[1].start, result.busy[0].end);
+    assert_eq!(result.free[1].end, window_end);
+    assert_eq!(result.free[1].duration_minutes, 360);
+}
+
+// ── Test 28: Duplicate events within a stream are collapsed ─────────────────
+
+#[test]
+fn duplicate_events_within_stream_count_once() {
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 8, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 16, 17, 0, 0).unwrap();
+    let synced = stream(
+        "work",
+        vec![
+            event("2026-03-16T10:00:00Z", "2026-03-16T11:00:00Z"),
+            event("2026-03-16T10:00:00Z", "2026-03-16T11:00:00Z"),
+            event("2026-03-16T13:00:00Z", "2026-03-16T14:00:00Z"),
+        ],
+    );
+    let options = MergeOptions {
+        max_events_per_stream: Some(2),
+        dedup_events: true,
+    };
+
+    let result = merge_availability_with_options(
+        std::slice::from_ref(&synced),
+        window_start,
+        window_end,
+        PrivacyLevel::Full,
+        &options,
+    );
+
+    // The duplicate does not use up the cap, so the 13:00 meeting survives.
+    assert!(result.truncated_streams.is_empty());
+    assert_eq!(result.busy.len(), 2);
+    assert_eq!(result.busy[0].source_count, 1);
+    assert_eq!(
+        (result.busy[0].end - result.busy[0].start).num_minutes(),
+        60
+    );
+
+    // Without dedup the duplicate is one of the two kept events.
+    let capped = merge_availability_with_options(
+        &[synced],
+        window_start,
+        window_end,
+        PrivacyLevel::Full,
+        &MergeOptions {
+            max_events_per_stream: Some(2),
+            ..Default::default()
+        },
+    );
+    assert_eq!(capped.truncated_streams, vec!["work".to_string()]);
+    assert_eq!(capped.busy.len(), 1);
+}
+
+// ── Test 29: The same event on two calendars is kept for both ───────────────
+
+#[test]
+fn duplicate_events_across_streams_are_not_collapsed() {
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 8, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 16, 17, 0, 0).unwrap();
+    let meeting = || vec![event("2026-03-16T10:00:00Z", "2026-03-16T11:00:00Z")];
+    let options = MergeOptions {
+        dedup_events: true,
+        ..Default::default()
+    };
+
+    let result = merge_availability_with_options(
+        &[stream("work", meeting()), stream("personal", meeting())],
+        window_start,
+        window_end,
+        PrivacyLevel::Full,
+        &options,
+    );
+
+    assert_eq!(result.busy.len(), 1);
+    assert_eq!(result.busy[0].source_count, 2);
+}
+
+// ── Test 30: FreeOnly privacy withholds busy blocks ─────────────────────────
+
+#[test]
+fn free_only_privacy_returns_free_slots_without_busy() {
+    let streams = vec![
+        stream(
+            "work",
+            vec![
+                event("2026-03-16T09:00:00Z", "2026-03-16T10:00:00Z"),
+                event("2026-03-16T14:00:00Z", "2026-03-16T15:00:00Z"),
+            ],
+        ),
+        stream(
+            "personal",
+            vec![event("2026-03-16T09:30:00Z", "2026-03-16T11:00:00Z")],
+        ),
+    ];
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 8, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 16, 17, 0, 0).unwrap();
+
+    let full = merge_availability(&streams, window_start, window_end, PrivacyLevel::Full);
+    let free_only = merge_availability(&streams, window_start, window_end, PrivacyLevel::FreeOnly);
+
+    assert!(free_only.busy.is_empty());
+    assert_eq!(free_only.free, full.free);
+    assert_eq!(free_only.free.len(), 3);
+    assert_eq!(free_only.privacy, PrivacyLevel::FreeOnly);
+}
+
+// ── Test 31: A multi-day event splits at each local midnight ────────────────
+
+#[test]
+fn split_at_day_boundaries_cuts_at_local_midnight() {
+    // Mon 09:00 to Wed 17:00 America/New_York (EST, UTC-5).
+    let events = vec![event("2026-02-16T14:00:00Z", "2026-02-18T22:00:00Z")];
+    let segments = split_at_day_boundaries(&events, chrono_tz::America::New_York);
+
+    assert_eq!(
+        segments,
+        vec![
+            event("2026-02-16T14:00:00Z", "2026-02-17T05:00:00Z"),
+            event("2026-02-17T05:00:00Z", "2026-02-18T05:00:00Z"),
+            event("2026-02-18T05:00:00Z", "2026-02-18T22:00:00Z"),
+        ]
+    );
+}
+
+// ── Test 32: A single-day event passes through unchanged ────────────────────
+
+#[test]
+fn split_at_day_boundaries_keeps_single_day_event() {
+    let events = vec![
+        event("2026-02-16T14:00:00Z", "2026-02-16T15:00:00Z"),
+        // Ends exactly at local midnight: still one day.
+        event("2026-02-16T20:00:00Z", "2026-02-17T05:00:00Z"),
+    ];
+    let segments = split_at_day_boundaries(&events, chrono_tz::America::New_York);
+    assert_eq!(segments, events);
+}
+
+// ── Test 33: Segments on a DST day have the day's true length ───────────────
+
+#[test]
+fn split_at_day_boundaries_handles_dst_day() {
+    // Sat 12:00 EST to Mon 12:00 EDT across the 2026-03-08 spring-forward.
+    let events = vec![event("2026-03-07T17:00:00Z", "2026-03-09T16:00:00Z")];
+    let segments = split_at_day_boundaries(&events, chrono_tz::America::New_York);
+
+    assert_eq!(segments.len(), 3);
+    // Sunday runs from midnight EST (05:00Z) to midnight EDT (04:00Z): 23 hours.
+    assert_eq!(
+        segments[1],
+        event("2026-03-08T05:00:00Z", "2026-03-09T04:00:00Z")
+    );
+    assert_eq!((segments[1].end - segments[1].start).num_hours(), 23);
+}
+
+// ── Test 34: AvailabilityIndex matches a full merge after updates ───────────
+
+fn assert_index_matches_merge(
+    index: &AvailabilityIndex,
+    streams: &[EventStream],
+    privacy: PrivacyLevel,
+    window: (&str, &str),
+) {
+    let (window_start, window_end) = (window.0.parse().unwrap(), window.1.parse().unwrap());
+    let expected = merge_availability(streams, window_start, window_end, privacy);
+    let actual = index.query(window_start, window_end);
+    assert_eq!(actual.busy, expected.busy, "busy differs for {window:?}");
+    assert_eq!(actual.free, expected.free, "free differs for {window:?}");
+    assert_eq!(actual.privacy, expected.privacy);
+}
+
+fn index_fixture() -> Vec<EventStream> {
+    vec![
+        stream(
+            "work",
+            vec![
+                event("2026-03-16T09:00:00Z", "2026-03-16T10:00:00Z"),
+                event("2026-03-16T09:30:00Z", "2026-03-16T11:00:00Z"),
+                event("2026-03-16T14:00:00Z", "2026-03-16T15:00:00Z"),
+                // Zero-duration reminder: never busy.
+                event("2026-03-16T12:00:00Z", "2026-03-16T12:00:00Z"),
+            ],
+        ),
+        stream(
+            "personal",
+            vec![
+                event("2026-03-16T10:30:00Z", "2026-03-16T12:00:00Z"),
+                // Adjacent to the work meeting: merges into one block.
+                event("2026-03-16T15:00:00Z", "2026-03-16T16:00:00Z"),
+                event("2026-03-17T07:00:00Z", "2026-03-17T09:00:00Z"),
+            ],
+        ),
+    ]
+}
+
+const INDEX_WINDOWS: [(&str, &str); 4] = [
+    ("2026-03-16T08:00:00Z", "2026-03-16T17:00:00Z"),
+    // Window edges cut through busy blocks.
+    ("2026-03-16T09:45:00Z", "2026-03-16T14:30:00Z"),
+    ("2026-03-16T00:00:00Z", "2026-03-18T00:00:00Z"),
+    // Empty window.
+    ("2026-03-16T12:00:00Z", "2026-03-16T12:00:00Z"),
+];
+
+#[test]
+fn availability_index_matches_full_merge_after_update() {
+    for privacy in [
+        PrivacyLevel::Full,
+        PrivacyLevel::Opaque,
+        PrivacyLevel::FreeOnly,
+    ] {
+        let mut streams = index_fixture();
+        let mut index = AvailabilityIndex::from_streams(&streams, privacy);
+        for window in INDEX_WINDOWS {
+            assert_index_matches_merge(&index, &streams, privacy, window);
+        }
+
+        // Move the personal block into the afternoon gap.
+        let updated = vec![event("2026-03-16T12:30:00Z", "2026-03-16T14:30:00Z")];
+        index.update_stream("personal", &updated);
+        streams[1].events = updated;
+        for window in INDEX_WINDOWS {
+            assert_index_matches_merge(&index, &streams, privacy, window);
+        }
+    }
+}
+
+#[test]
+fn availability_index_combines_streams_sharing_an_id() {
+    let streams = vec![
+        stream(
+            "a",
+            vec![event("2026-03-16T09:00:00Z", "2026-03-16T10:00:00Z")],
+        ),
+        stream(
+            "b",
+            vec![event("2026-03-16T12:00:00Z", "2026-03-16T13:00:00Z")],
+        ),
+        stream(
+            "a",
+            vec![event("2026-03-16T15:00:00Z", "2026-03-16T16:00:00Z")],
+        ),
+    ];
+    for privacy in [PrivacyLevel::Full, PrivacyLevel::Opaque] {
+        let index = AvailabilityIndex::from_streams(&streams, privacy);
+        assert_index_matches_merge(&index, &streams, privacy, INDEX_WINDOWS[0]);
+        let (start, end) = (
+            INDEX_WINDOWS[0].0.parse().unwrap(),
+            INDEX_WINDOWS[0].1.parse().unwrap(),
+        );
+        assert_eq!(index.query(start, end).busy.len(), 3);
+    }
+}
+
+// ── Test 35: AvailabilityIndex adds and removes streams ─────────────────────
+
+#[test]
+fn availability_index_adds_and_removes_streams() {
+    let mut streams = index_fixture();
+    let mut index = AvailabilityIndex::new(PrivacyLevel::Full);
+    assert_index_matches_merge(&index, &[], PrivacyLevel::Full, INDEX_WINDOWS[0]);
+
+    for s in &streams {
+        index.update_stream(&s.stream_id, &s.events);
+    }
+    let gym = stream(
+        "gym",
+        vec![event("2026-03-16T10:00:00Z", "2026-03-16T13:00:00Z")],
+    );
+    index.update_stream(&gym.stream_id, &gym.events);
+    streams.push(gym);
+    assert_index_matches_merge(&index, &streams, PrivacyLevel::Full, INDEX_WINDOWS[0]);
+
+    assert!(index.remove_stream("work"));
+    assert!(!index.remove_stream("work"));
+    streams.remove(0);
+    for window in INDEX_WINDOWS {
+        assert_index_matches_merge(&index, &streams, PrivacyLevel::Full, window);
+    }
+}
+
+// ── Test 36: Stream display metadata reaches busy blocks only in Full mode ──
+
+#[test]
+fn stream_display_metadata_propagates_in_full_and_clears_in_opaque() {
+    let work = EventStream {
+        display_name: Some("Work".to_string()),
+        color: Some("#1a73e8".to_string()),
+        ..stream(
+            "work-google",
+            vec![event("2026-03-16T09:00:00Z", "2026-03-16T10:00:00Z")],
+        )
+    };
+    let personal = stream(
+        "personal",
+        vec![event("2026-03-16T09:30:00Z", "2026-03-16T10:30:00Z")],
+    );
+    let streams = [work, personal];
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 8, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 16, 17, 0, 0).unwrap();
+
+    let full = merge_availability(&streams, window_start, window_end, PrivacyLevel::Full);
+    assert_eq!(full.busy.len(), 1);
+    assert_eq!(
+        full.busy[0].sources,
+        vec![
+            BusySource {
+                stream_id: "work-google".to_string(),
+                display_name: Some("Work".to_string()),
+                color: Some("#1a73e8".to_string()),
+            },
+            BusySource {
+                stream_id: "personal".to_string(),
+                display_name: None,
+                color: None,
+            },
+        ]
+    );
+    let indexed = AvailabilityIndex::from_streams(&streams, PrivacyLevel::Full)
+        .query(window_start, window_end);
+    assert_eq!(indexed.busy, full.busy);
+
+    let opaque = merge_availability(&streams, window_start, window_end, PrivacyLevel::Opaque);
+    assert_eq!(opaque.busy.len(), 1);
+    assert!(opaque.busy[0].sources.is_empty());
+}
+
+// ── Test 37: Availability bitmap ────────────────────────────────────────────
+
+#[test]
+fn bitmap_marks_one_hour_block_as_four_quarter_hour_slots() {
+    let streams = [stream(
+        "work",
+        vec![event("2026-03-16T09:00:00Z", "2026-03-16T10:00:00Z")],
+    )];
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 8, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 16, 12, 0, 0).unwrap();
+
+    let bitmap = availability_bitmap(&streams, window_start, window_end, 15).unwrap();
+    assert_eq!(bitmap.len(), 16);
+    assert_eq!(bitmap.iter().filter(|busy| **busy).count(), 4);
+    assert!(bitmap[4..8].iter().all(|busy| *busy));
+}
+
+#[test]
+fn bitmap_partial_overlap_marks_slot_and_touching_boundary_does_not() {
+    let streams = [stream(
+        "work",
+        vec![
+            // Aligned: exactly 08:15–08:30, only slot 1.
+            event("2026-03-16T08:15:00Z", "2026-03-16T08:30:00Z"),
+            // Misaligned: 09:10–09:20 straddles slots 4 and 5.
+            event("2026-03-16T09:10:00Z", "2026-03-16T09:20:00Z"),
+        ],
+    )];
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 8, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 16, 10, 0, 0).unwrap();
+
+    let bitmap = availability_bitmap(&streams, window_start, window_end, 15).unwrap();
+    assert_eq!(
+        bitmap,
+        vec![false, true, false, false, true, true, false, false]
+    );
+}
+
+#[test]
+fn bitmap_includes_trailing_partial_slot_and_rejects_bad_granularity() {
+    let streams = [stream(
+        "work",
+        vec![event("2026-03-16T08:50:00Z", "2026-03-16T09:30:00Z")],
+    )];
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 8, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 16, 9, 0, 0).unwrap();
+
+    // 60 minutes at 25-minute slots: two full slots plus a 10-minute tail.
+    let bitmap = availability_bitmap(&streams, window_start, window_end, 25).unwrap();
+    assert_eq!(bitmap, vec![false, false, true]);
+
+    assert!(availability_bitmap(&streams, window_start, window_end, 0)
+        .unwrap()
+        .is_empty());
+    assert!(availability_bitmap(&streams, window_end, window_start, 15)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn bitmap_rejects_windows_with_too_many_slots() {
+    let streams = [stream(
+        "work",
+        vec![event("2026-03-16T09:00:00Z", "2026-03-16T10:00:00Z")],
+    )];
+    let err = availability_bitmap(
+        &streams,
+        chrono::DateTime::<Utc>::MIN_UTC,
+        chrono::DateTime::<Utc>::MAX_UTC,
+        1,
+    )
+    .unwrap_err();
+    assert!(
+        matches!(err, truth_engine::TruthError::Availability(_)),
+        "got {err:?}"
+    );
+    // A year of one-minute slots is still allowed.
+    let start = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2027, 1, 1, 0, 0, 0).unwrap();
+    assert_eq!(
+        availability_bitmap(&streams, start, end, 1).unwrap().len(),
+        525_600
+    );
+}
+
+// ── Test 38: Concatenating availability across windows ──────────────────────
+
+#[test]
+fn concat_adjacent_days_coalesces_free_slot_across_midnight() {
+    let streams = [stream(
+        "work",
+        vec![
+            event("2026-03-16T09:00:00Z", "2026-03-16T17:00:00Z"),
+            event("2026-03-17T09:00:00Z", "2026-03-17T17:00:00Z"),
+        ],
+    )];
+    let monday = Utc.with_ymd_and_hms(2026, 3, 16, 0, 0, 0).unwrap();
+    let tuesday = Utc.with_ymd_and_hms(2026, 3, 17, 0, 0, 0).unwrap();
+    let wednesday = Utc.with_ymd_and_hms(2026, 3, 18, 0, 0, 0).unwrap();
+
+    let parts = [
+        merge_availability(&streams, monday, tuesday, PrivacyLevel::Opaque),
+        merge_availability(&streams, tuesday, wednesday, PrivacyLevel::Opaque),
+    ];
+    let week = concat_availability(&parts).unwrap();
+    let whole = merge_availability(&streams, monday, wednesday, PrivacyLevel::Opaque);
+
+    assert_eq!(week.window_start, monday);
+    assert_eq!(week.window_end, wednesday);
+    assert_eq!(week.busy, whole.busy);
+    assert_eq!(week.free, whole.free);
+
+    // Monday 17:00 → Tuesday 09:00 comes back as a single 16-hour slot.
+    assert_eq!(week.free.len(), 3);
+    assert_eq!(
+        week.free[1].start,
+        Utc.with_ymd_and_hms(2026, 3, 16, 17, 0, 0).unwrap()
+    );
+    assert_eq!(
+        week.free[1].end,
+        Utc.with_ymd_and_hms(2026, 3, 17, 9, 0, 0).unwrap()
+    );
+    assert_eq!(week.free[1].duration_minutes, 16 * 60);
+}
+
+#[test]
+fn concat_coalesces_busy_block_across_seam_and_unions_sources() {
+    let work = stream(
+        "work",
+        vec![event("2026-03-16T23:00:00Z", "2026-03-17T00:00:00Z")],
+    );
+    let personal = stream(
+        "personal",
+        vec![event("2026-03-17T00:00:00Z", "2026-03-17T01:00:00Z")],
+    );
+    let streams = [work, personal];
+    let monday = Utc.with_ymd_and_hms(2026, 3, 16, 0, 0, 0).unwrap();
+    let tuesday = Utc.with_ymd_and_hms(2026, 3, 17, 0, 0, 0).unwrap();
+    let wednesday = Utc.with_ymd_and_hms(2026, 3, 18, 0, 0, 0).unwrap();
+
+    let week = concat_availability(&[
+        merge_availability(&streams, monday, tuesday, PrivacyLevel::Full),
+        merge_availability(&streams, tuesday, wednesday, PrivacyLevel::Full),
+    ])
+    .unwrap();
+
+    assert_eq!(week.busy.len(), 1);
+    assert_eq!(
+        week.busy[0].start,
+        Utc.with_ymd_and_hms(2026, 3, 16, 23, 0, 0).unwrap()
+    );
+    assert_eq!(
+        week.busy[0].end,
+        Utc.with_ymd_and_hms(2026, 3, 17, 1, 0, 0).unwrap()
+    );
+    assert_eq!(week.busy[0].source_count, 2);
+    let ids: Vec<&str> = week.busy[0]
+        .sources
+        .iter()
+        .map(|s| s.stream_id.as_str())
+        .collect();
+    assert_eq!(ids, vec!["work", "personal"]);
+}
+
+#[test]
+fn concat_rejects_gaps_overlaps_and_empty_input() {
+    let streams = [stream("work", vec![])];
+    let day = |d: u32| Utc.with_ymd_and_hms(2026, 3, d, 0, 0, 0).unwrap();
+    let part =
+        |from: u32, to: u32| merge_availability(&streams, day(from), day(to), PrivacyLevel::Opaque);
+
+    let gap = concat_availability(&[part(16, 17), part(18, 19)]).unwrap_err();
+    assert!(gap.to_string().contains("gap"), "got: {gap}");
+
+    let overlap = concat_availability(&[part(16, 18), part(17, 19)]).unwrap_err();
+    assert!(overlap.to_string().contains("overlap"), "got: {overlap}");
+
+    assert!(concat_availability(&[]).is_err());
+}
+
+// ── Test 39: Utilization over working hours ──────────────────────────────────
+
+/// 09:00–17:00 New York (EDT, UTC-4 in the week of March 16, 2026).
+fn new_york_nine_to_five() -> WorkingHours {
+    WorkingHours {
+        timezone: chrono_tz::America::New_York,
+        start_hour: 9,
+        end_hour: 17,
+    }
+}
+
+/// Monday March 16 to Saturday March 21, 2026, local midnight to local midnight.
+fn new_york_work_week() -> (chrono::DateTime<Utc>, chrono::DateTime<Utc>) {
+    (
+        Utc.with_ymd_and_hms(2026, 3, 16, 4, 0, 0).unwrap(),
+        Utc.with_ymd_and_hms(2026, 3, 21, 4, 0, 0).unwrap(),
+    )
+}
+
+/// One event per weekday, from local `from` to `to` o'clock (EDT).
+fn weekday_events(from: u32, to: u32) -> Vec<ExpandedEvent> {
+    (16..=20)
+        .map(|d| ExpandedEvent {
+            start: Utc.with_ymd_and_hms(2026, 3, d, from + 4, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2026, 3, d, to + 4, 0, 0).unwrap(),
+        })
+        .collect()
+}
+
+#[test]
+fn utilization_of_free_week_is_zero() {
+    let (start, end) = new_york_work_week();
+    let streams = [stream("work", vec![])];
+    assert_eq!(
+        utilization(&streams, &new_york_nine_to_five(), start, end),
+        0.0
+    );
+}
+
+#[test]
+fn utilization_of_half_booked_week_is_half() {
+    let (start, end) = new_york_work_week();
+    // Mornings are booked on one calendar, with a duplicate hold on another.
+    let streams = [
+        stream("work", weekday_events(9, 13)),
+        stream("personal", weekday_events(10, 11)),
+    ];
+    let ratio = utilization(&streams, &new_york_nine_to_five(), start, end);
+    assert!((ratio - 0.5).abs() < 1e-9, "got {ratio}");
+}
+
+#[test]
+fn utilization_ignores_busy_time_outside_working_hours() {
+    let (start, end) = new_york_work_week();
+    let hours = new_york_nine_to_five();
+
+    let early = [stream("work", weekday_events(7, 9))];
+    assert_eq!(utilization(&early, &hours, start, end), 0.0);
+
+    let long_days = [stream("work", weekday_events(7, 19))];
+    assert_eq!(utilization(&long_days, &hours, start, end), 1.0);
+}
+
+// ── Test 40: Best free slot by strategy ─────────────────────────────────────
+
+/// 08:00–18:00 with meetings 10:00–11:00 and 15:00–16:00, leaving free blocks
+/// of 2h, 4h and 2h.
+fn two_meeting_day() -> [EventStream; 1] {
+    [stream(
+        "work",
+        vec![
+            event("2026-03-16T10:00:00Z", "2026-03-16T11:00:00Z"),
+            event("2026-03-16T15:00:00Z", "2026-03-16T16:00:00Z"),
+        ],
+    )]
+}
+
+fn best_slot(streams: &[EventStream], minutes: i64, strategy: SlotStrategy) -> ExpandedEvent {
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 8, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 16, 18, 0, 0).unwrap();
+    find_best_free_slot(streams, window_start, window_end, minutes, strategy).unwrap()
+}
+
+#[test]
+fn best_slot_earliest_and_latest_hug_the_window_edges() {
+    let streams = two_meeting_day();
+    assert_eq!(
+        best_slot(&streams, 60, SlotStrategy::Earliest),
+        event("2026-03-16T08:00:00Z", "2026-03-16T09:00:00Z")
+    );
+    assert_eq!(
+        best_slot(&streams, 60, SlotStrategy::Latest),
+        event("2026-03-16T17:00:00Z", "2026-03-16T18:00:00Z")
+    );
+}
+
+#[test]
+fn best_slot_minimize_fragmentation_sits_next_to_a_meeting() {
+    let streams = two_meeting_day();
+    // Earliest splits off 08:00–09:00 at the window edge; minimizing
+    // fragmentation keeps the 4h block whole and docks against 10:00.
+    assert_eq!(
+        best_slot(&streams, 60, SlotStrategy::MinimizeFragmentation),
+        event("2026-03-16T09:00:00Z", "2026-03-16T10:00:00Z")
+    );
+    // A 3h meeting only fits the 4h block, flush after the 10:00 meeting.
+    assert_eq!(
+        best_slot(&streams, 180, SlotStrategy::MinimizeFragmentation),
+        event("2026-03-16T11:00:00Z", "2026-03-16T14:00:00Z")
+    );
+}
+
+#[test]
+fn best_slot_minimize_fragmentation_prefers_an_exact_fit() {
+    let mut streams = two_meeting_day();
+    streams[0]
+        .events
+        .push(event("2026-03-16T12:00:00Z", "2026-03-16T15:00:00Z"));
+    // 11:00–12:00 is filled exactly, leaving no new free block.
+    assert_eq!(
+        best_slot(&streams, 60, SlotStrategy::MinimizeFragmentation),
+        event("2026-03-16T11:00:00Z", "2026-03-16T12:00:00Z")
+    );
+    assert_eq!(
+        best_slot(&streams, 60, SlotStrategy::Earliest),
+        event("2026-03-16T08:00:00Z", "2026-03-16T09:00:00Z")
+    );
+}
+
+#[test]
+fn best_slot_is_none_when_nothing_fits() {
+    let streams = two_meeting_day();
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 8, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 16, 18, 0, 0).unwrap();
+    for strategy in [
+        SlotStrategy::Earliest,
+        SlotStrategy::Latest,
+        SlotStrategy::MinimizeFragmentation,
+    ] {
+        assert!(find_best_free_slot(&streams, window_start, window_end, 300, strategy).is_none());
+        assert!(find_best_free_slot(&streams, window_start, window_end, 0, strategy).is_none());
+    }
+}
+
+// ── Test 41: Covered window fast path ───────────────────────────────────────
+
+#[test]
+fn covered_window_fast_path_matches_general_path() {
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 8, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 16, 17, 0, 0).unwrap();
+    let personal = stream(
+        "personal",
+        vec![
+            event("2026-03-16T12:00:00Z", "2026-03-16T13:00:00Z"),
+            event("2026-03-17T09:00:00Z", "2026-03-17T10:00:00Z"),
+        ],
+    );
+    // A single event spanning the window takes the early exit...
+    let covering = stream(
+        "work",
+        vec![event("2026-03-16T07:00:00Z", "2026-03-16T18:00:00Z")],
+    );
+    // ...while the same span split in two goes through the general merge.
+    let split = stream(
+        "work",
+        vec![
+            event("2026-03-16T07:00:00Z", "2026-03-16T12:30:00Z"),
+            event("2026-03-16T12:30:00Z", "2026-03-16T18:00:00Z"),
+        ],
+    );
+
+    for privacy in [
+        PrivacyLevel::Full,
+        PrivacyLevel::Opaque,
+        PrivacyLevel::FreeOnly,
+    ] {
+        let fast = merge_availability(
+            &[covering.clone(), personal.clone()],
+            window_start,
+            window_end,
+            privacy,
+        );
+        let general = merge_availability(
+            &[split.clone(), personal.clone()],
+            window_start,
+            window_end,
+            privacy,
+        );
+        assert_eq!(fast.busy, general.busy, "{privacy:?}");
+        assert_eq!(fast.free, general.free, "{privacy:?}");
+        assert!(fast.free.is_empty());
+    }
+
+    let full = merge_availability(
+        &[covering, personal],
+        window_start,
+        window_end,
+        PrivacyLevel::Full,
+    );
+    assert_eq!(full.busy.len(), 1);
+    assert_eq!(full.busy[0].source_count, 2);
+    let ids: Vec<&str> = full.busy[0]
+        .sources
+        .iter()
+        .map(|s| s.stream_id.as_str())
+        .collect();
+    assert_eq!(ids, ["work", "personal"]);
+}
+
+// ── Test 42: Next N free slots after a reference time ───────────────────────
+
+#[test]
+fn next_free_slots_returns_only_the_first_n() {
+    let streams = vec![
+        stream(
+            "work",
+            vec![
+                event("2026-03-16T09:00:00Z", "2026-03-16T10:00:00Z"),
+                event("2026-03-16T11:00:00Z", "2026-03-16T12:00:00Z"),
+                event("2026-03-16T13:00:00Z", "2026-03-16T14:00:00Z"),
+            ],
+        ),
+        stream(
+            "personal",
+            vec![event("2026-03-16T15:00:00Z", "2026-03-16T16:00:00Z")],
+        ),
+    ];
+    let after = Utc.with_ymd_and_hms(2026, 3, 16, 8, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 16, 18, 0, 0).unwrap();
+
+    // Five qualifying openings: 8-9, 10-11, 12-13, 14-15, 16-18.
+    assert_eq!(
+        find_all_free_across(&streams, after, window_end, 30).len(),
+        5
+    );
+
+    let slots = next_free_slots(&streams, after, window_end, 30, 3);
+    let starts: Vec<u32> = slots.iter().map(|s| s.start.hour()).collect();
+    assert_eq!(starts, [8, 10, 12]);
+    assert!(slots.iter().all(|s| s.duration_minutes == 60));
+
+    // Asking for more than exist returns what there is; zero returns nothing.
+    assert_eq!(
+        next_free_slots(&streams, after, window_end, 30, 10).len(),
+        5
+    );
+    assert!(next_free_slots(&streams, after, window_end, 30, 0).is_empty());
+}
+
+#[test]
+fn next_free_slots_starts_at_the_reference_time() {
+    let streams = vec![stream(
+        "work",
+        vec![
+            event("2026-03-16T09:00:00Z", "2026-03-16T10:00:00Z"),
+            event("2026-03-16T11:00:00Z", "2026-03-16T12:00:00Z"),
+        ],
+    )];
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 16, 13, 0, 0).unwrap();
+
+    // At 10:40 the 10-11 opening has 20 minutes left, too short for 30.
+    let after = Utc.with_ymd_and_hms(2026, 3, 16, 10, 40, 0).unwrap();
+    let slots = next_free_slots(&streams, after, window_end, 30, 2);
+    assert_eq!(slots.len(), 1);
+    assert_eq!(
+        slots[0].start,
+        Utc.with_ymd_and_hms(2026, 3, 16, 12, 0, 0).unwrap()
+    );
+
+    // With a 15-minute minimum the rest of it counts, from 10:40.
+    let slots = next_free_slots(&streams, after, window_end, 15, 2);
+    assert_eq!(slots[0].start, after);
+    assert_eq!(slots[0].duration_minutes, 20);
+
+    // A reference time past the window end finds nothing.
+    assert!(next_free_slots(&streams, window_end, after, 15, 2).is_empty());
+}
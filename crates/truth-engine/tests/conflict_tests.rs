@@ -2,7 +2,7 @@
 //!
 //! All tests should compile but fail with `todo!()` panics until implementation.
 
-use chrono::{TimeZone, Utc};
+use chrono::{DateTime, TimeZone, Utc};
 use truth_engine::expander::ExpandedEvent;
 use truth_engine::find_conflicts;
 
@@ -125,3 +125,290 @@ fn one_empty_list_no_conflicts() {
         "one empty list should produce no conflicts"
     );
 }
+
+#[test]
+fn partial_overlap_reports_overlap_window() {
+    // Event A: 09:00-10:00, Event B: 09:30-10:30 → overlap window 09:30-10:00
+    let a = vec![event(2026, 3, 1, 9, 0, 10, 0)];
+    let b = vec![event(2026, 3, 1, 9, 30, 10, 30)];
+
+    let conflicts = find_conflicts(&a, &b);
+
+    assert_eq!(conflicts.len(), 1);
+    let c = &conflicts[0];
+    assert_eq!(
+        c.overlap_start,
+        Utc.with_ymd_and_hms(2026, 3, 1, 9, 30, 0).unwrap()
+    );
+    assert_eq!(
+        c.overlap_end,
+        Utc.with_ymd_and_hms(2026, 3, 1, 10, 0, 0).unwrap()
+    );
+    assert_eq!(
+        c.overlap_minutes,
+        (c.overlap_end - c.overlap_start).num_minutes()
+    );
+}
+
+#[test]
+fn full_containment_overlap_window_is_inner_event() {
+    // Event A: 09:00-12:00, Event B: 10:00-10:45 → overlap window is B itself
+    let a = vec![event(2026, 3, 1, 9, 0, 12, 0)];
+    let b = vec![event(2026, 3, 1, 10, 0, 10, 45)];
+
+    let conflicts = find_conflicts(&a, &b);
+
+    assert_eq!(conflicts.len(), 1);
+    let c = &conflicts[0];
+    assert_eq!(c.overlap_start, b[0].start);
+    assert_eq!(c.overlap_end, b[0].end);
+    assert_eq!(c.overlap_minutes, 45);
+    assert_eq!(
+        c.overlap_minutes,
+        (c.overlap_end - c.overlap_start).num_minutes()
+    );
+}
+
+#[test]
+fn extreme_datetimes_do_not_overflow_overlap_minutes() {
+    let everything = ExpandedEvent {
+        start: DateTime::<Utc>::MIN_UTC,
+        end: DateTime::<Utc>::MAX_UTC,
+    };
+    let conflicts = find_conflicts(
+        std::slice::from_ref(&everything),
+        std::slice::from_ref(&everything),
+    );
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(
+        conflicts[0].overlap_minutes,
+        (DateTime::<Utc>::MAX_UTC - DateTime::<Utc>::MIN_UTC).num_minutes()
+    );
+    assert!(conflicts[0].overlap_minutes > 0);
+}
+
+fn weekly_standup() -> truth_engine::RRuleSpec {
+    // Mondays 09:00-09:30 UTC from 2026-03-02.
+    truth_engine::RRuleSpec {
+        rrule: "FREQ=WEEKLY;BYDAY=MO".to_string(),
+        dtstart: "2026-03-02T09:00:00".to_string(),
+        duration_minutes: 30,
+        timezone: "UTC".to_string(),
+    }
+}
+
+#[test]
+fn proposed_meeting_conflicts_with_one_weekly_occurrence() {
+    use truth_engine::conflicts_with_recurrence;
+
+    // Monday 2026-03-16 09:15-10:00 clips the third standup only.
+    let proposed = event(2026, 3, 16, 9, 15, 10, 0);
+    let conflicts = conflicts_with_recurrence(&proposed, &weekly_standup()).unwrap();
+
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].event_a, proposed);
+    assert_eq!(conflicts[0].event_b, event(2026, 3, 16, 9, 0, 9, 30));
+    assert_eq!(conflicts[0].overlap_minutes, 15);
+}
+
+#[test]
+fn proposed_meeting_missing_every_occurrence_has_no_conflicts() {
+    use truth_engine::conflicts_with_recurrence;
+
+    // Tuesday, and a Monday slot that only touches the standup's end.
+    for proposed in [
+        event(2026, 3, 17, 9, 0, 10, 0),
+        event(2026, 3, 16, 9, 30, 10, 0),
+        event(2026, 2, 23, 9, 0, 10, 0),
+    ] {
+        let conflicts = conflicts_with_recurrence(&proposed, &weekly_standup()).unwrap();
+        assert!(conflicts.is_empty(), "{proposed:?} should not conflict");
+    }
+}
+
+#[test]
+fn proposed_meeting_far_after_dtstart_still_conflicts() {
+    // Hourly since 2018: far more than u16::MAX occurrences precede the meeting.
+    let spec = truth_engine::RRuleSpec {
+        rrule: "FREQ=HOURLY".to_string(),
+        dtstart: "2018-01-01T00:00:00".to_string(),
+        duration_minutes: 30,
+        timezone: "UTC".to_string(),
+    };
+    let proposed = event(2026, 3, 16, 9, 15, 10, 0);
+    let conflicts = truth_engine::conflicts_with_recurrence(&proposed, &spec).unwrap();
+
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].event_b, event(2026, 3, 16, 9, 0, 9, 30));
+}
+
+#[test]
+fn conflicts_with_recurrence_reports_invalid_rule() {
+    let spec = truth_engine::RRuleSpec {
+        rrule: "FREQ=SOMETIMES".to_string(),
+        ..weekly_standup()
+    };
+    let proposed = event(2026, 3, 16, 9, 0, 10, 0);
+    assert!(truth_engine::conflicts_with_recurrence(&proposed, &spec).is_err());
+}
+
+#[test]
+fn events_fifteen_minutes_apart_conflict_with_twenty_minutes_travel() {
+    use truth_engine::find_conflicts_with_travel;
+
+    // A ends 10:00, B starts 10:15: no calendar overlap, but 20 minutes of
+    // travel after A runs 5 minutes into B.
+    let a = vec![event(2026, 3, 1, 9, 0, 10, 0)];
+    let b = vec![event(2026, 3, 1, 10, 15, 11, 0)];
+    assert!(find_conflicts(&a, &b).is_empty());
+
+    let conflicts = find_conflicts_with_travel(&a, &b, 20);
+    assert_eq!(conflicts.len(), 1);
+    let c = &conflicts[0];
+    assert!(c.travel_induced);
+    assert_eq!(
+        c.overlap_start,
+        Utc.with_ymd_and_hms(2026, 3, 1, 10, 15, 0).unwrap()
+    );
+    assert_eq!(
+        c.overlap_end,
+        Utc.with_ymd_and_hms(2026, 3, 1, 10, 20, 0).unwrap()
+    );
+    assert_eq!(c.overlap_minutes, 5);
+    assert_eq!(c.event_a, a[0]);
+    assert_eq!(c.event_b, b[0]);
+
+    // Padding applies to both lists, so argument order does not matter and a
+    // meeting 10 minutes after B in the first list is caught too.
+    assert_eq!(find_conflicts_with_travel(&b, &a, 20).len(), 1);
+    let later = vec![event(2026, 3, 1, 11, 10, 12, 0)];
+    assert_eq!(find_conflicts_with_travel(&later, &b, 20).len(), 1);
+}
+
+#[test]
+fn events_thirty_minutes_apart_do_not_conflict_with_twenty_minutes_travel() {
+    use truth_engine::find_conflicts_with_travel;
+
+    let a = vec![event(2026, 3, 1, 9, 0, 10, 0)];
+    let b = vec![event(2026, 3, 1, 10, 30, 11, 0)];
+    assert!(find_conflicts_with_travel(&a, &b, 20).is_empty());
+    // Arriving exactly on time is not a conflict.
+    assert!(find_conflicts_with_travel(&a, &b, 30).is_empty());
+}
+
+#[test]
+fn travel_conflicts_flag_only_padding_induced_overlaps() {
+    use truth_engine::find_conflicts_with_travel;
+
+    // A real 30-minute overlap grows to 50 minutes but is not travel-induced.
+    let a = vec![event(2026, 3, 1, 9, 0, 10, 0)];
+    let b = vec![event(2026, 3, 1, 9, 30, 10, 30)];
+    let conflicts = find_conflicts_with_travel(&a, &b, 20);
+    assert_eq!(conflicts.len(), 1);
+    assert!(!conflicts[0].travel_induced);
+    assert_eq!(conflicts[0].overlap_minutes, 50);
+
+    // Zero or negative travel is plain conflict detection.
+    assert_eq!(
+        find_conflicts_with_travel(&a, &b, 0),
+        find_conflicts(&a, &b)
+    );
+    assert_eq!(
+        find_conflicts_with_travel(&a, &b, -15),
+        find_conflicts(&a, &b)
+    );
+    assert!(find_conflicts(&a, &b).iter().all(|c| !c.travel_induced));
+}
+
+#[test]
+fn travel_padding_saturates_at_extreme_values() {
+    use truth_engine::find_conflicts_with_travel;
+
+    let a = vec![event(2026, 3, 1, 9, 0, 10, 0)];
+    let b = vec![ExpandedEvent {
+        start: DateTime::<Utc>::MAX_UTC - chrono::TimeDelta::hours(1),
+        end: DateTime::<Utc>::MAX_UTC,
+    }];
+    let conflicts = find_conflicts_with_travel(&a, &b, i64::MAX);
+    assert_eq!(conflicts.len(), 1);
+    assert!(conflicts[0].travel_induced);
+    assert_eq!(conflicts[0].overlap_end, DateTime::<Utc>::MAX_UTC);
+}
+
+#[test]
+fn severity_partial_for_ordinary_overlap() {
+    use truth_engine::ConflictSeverity;
+
+    let a = vec![event(2026, 3, 1, 9, 0, 10, 0)];
+    let b = vec![event(2026, 3, 1, 9, 45, 11, 0)];
+    let conflicts = find_conflicts(&a, &b);
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].severity, ConflictSeverity::Partial);
+    assert_eq!(conflicts[0].overlap_minutes, 15);
+}
+
+#[test]
+fn severity_contained_when_one_event_is_inside_the_other() {
+    use truth_engine::ConflictSeverity;
+
+    let outer = vec![event(2026, 3, 1, 9, 0, 12, 0)];
+    let inner = vec![event(2026, 3, 1, 10, 0, 10, 30)];
+    // Either argument order, and sharing a boundary still counts as inside.
+    let flush = vec![event(2026, 3, 1, 9, 0, 9, 30)];
+    for (a, b) in [(&outer, &inner), (&inner, &outer), (&outer, &flush)] {
+        let conflicts = find_conflicts(a, b);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].severity, ConflictSeverity::Contained);
+    }
+    assert_eq!(find_conflicts(&outer, &inner)[0].overlap_minutes, 30);
+}
+
+#[test]
+fn severity_identical_when_start_and_end_match() {
+    use truth_engine::ConflictSeverity;
+
+    let a = vec![event(2026, 3, 1, 9, 0, 10, 0)];
+    let conflicts = find_conflicts(&a, &a);
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].severity, ConflictSeverity::Identical);
+    assert_eq!(conflicts[0].overlap_minutes, 60);
+}
+
+#[test]
+fn severity_touch_for_boundary_meetings() {
+    use truth_engine::{find_conflicts_with_travel, ConflictSeverity};
+
+    // Back-to-back events are not conflicts at all...
+    let a = vec![event(2026, 3, 1, 9, 0, 10, 0)];
+    let b = vec![event(2026, 3, 1, 10, 0, 11, 0)];
+    assert!(find_conflicts(&a, &b).is_empty());
+    // ...but are a touch once travel time makes them clash.
+    let conflicts = find_conflicts_with_travel(&a, &b, 10);
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].severity, ConflictSeverity::Touch);
+    assert!(conflicts[0].travel_induced);
+
+    // Sharing less than a minute is a touch with a zero-minute overlap.
+    let c = vec![ExpandedEvent {
+        start: Utc.with_ymd_and_hms(2026, 3, 1, 9, 59, 30).unwrap(),
+        end: Utc.with_ymd_and_hms(2026, 3, 1, 11, 0, 0).unwrap(),
+    }];
+    let conflicts = find_conflicts(&a, &c);
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].severity, ConflictSeverity::Touch);
+    assert_eq!(conflicts[0].overlap_minutes, 0);
+}
+
+#[test]
+fn severity_ignores_travel_padding() {
+    use truth_engine::{find_conflicts_with_travel, ConflictSeverity};
+
+    // Padding makes B's window swallow A's, but the events only partly overlap.
+    let a = vec![event(2026, 3, 1, 9, 0, 10, 0)];
+    let b = vec![event(2026, 3, 1, 8, 0, 9, 30)];
+    let conflicts = find_conflicts_with_travel(&a, &b, 60);
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].severity, ConflictSeverity::Partial);
+    assert!(ConflictSeverity::Touch < ConflictSeverity::Partial);
+    assert!(ConflictSeverity::Contained < ConflictSeverity::Identical);
+}
@@ -206,6 +206,12 @@ fn empty_rrule_returns_error() {
     assert!(result.is_err(), "empty RRULE should return an error");
 }
 
+#[test]
+fn blank_rrule_reports_empty_rule() {
+    let err = expand_rrule("  \t", "2026-03-01T09:00:00", 30, "UTC", None, None).unwrap_err();
+    assert_eq!(err.to_string(), "Invalid RRULE: empty RRULE string");
+}
+
 #[test]
 fn invalid_timezone_returns_error() {
     let result = expand_rrule(
@@ -257,6 +263,180 @@ fn single_instance_count_one() {
     );
 }
 
+// ---------------------------------------------------------------------------
+// DTSTART precision
+// ---------------------------------------------------------------------------
+
+#[test]
+fn dtstart_full_precision() {
+    let result = expand_rrule(
+        "FREQ=DAILY;COUNT=1",
+        "2026-03-01T09:15:30",
+        30,
+        "UTC",
+        None,
+        None,
+    )
+    .expect("HH:MM:SS dtstart should parse");
+
+    assert_eq!(
+        result[0].start,
+        Utc.with_ymd_and_hms(2026, 3, 1, 9, 15, 30).unwrap()
+    );
+}
+
+#[test]
+fn dtstart_without_seconds_defaults_to_zero() {
+    let result = expand_rrule(
+        "FREQ=DAILY;COUNT=2",
+        "2026-03-01T09:15",
+        30,
+        "UTC",
+        None,
+        None,
+    )
+    .expect("HH:MM dtstart should parse");
+
+    assert_eq!(result.len(), 2);
+    assert_eq!(
+        result[0].start,
+        Utc.with_ymd_and_hms(2026, 3, 1, 9, 15, 0).unwrap()
+    );
+}
+
+#[test]
+fn dtstart_fractional_seconds_truncated() {
+    let result = expand_rrule(
+        "FREQ=DAILY;COUNT=1",
+        "2026-03-01T09:15:30.750",
+        30,
+        "America/New_York",
+        None,
+        None,
+    )
+    .expect("HH:MM:SS.fff dtstart should parse");
+
+    // EST (UTC-5): 09:15:30 local = 14:15:30 UTC, sub-second part dropped.
+    assert_eq!(
+        result[0].start,
+        Utc.with_ymd_and_hms(2026, 3, 1, 14, 15, 30).unwrap()
+    );
+}
+
+#[test]
+fn malformed_dtstart_returns_error() {
+    let result = expand_rrule(
+        "FREQ=DAILY;COUNT=1",
+        "2026-03-01 09:15",
+        30,
+        "UTC",
+        None,
+        None,
+    );
+    assert!(
+        matches!(result, Err(truth_engine::TruthError::InvalidDatetime(_))),
+        "malformed dtstart should be an InvalidDatetime error, got {:?}",
+        result
+    );
+}
+
+#[test]
+fn ical_basic_dtstart_matches_extended_format() {
+    let extended = expand_rrule(
+        "FREQ=WEEKLY;BYDAY=TU,TH;COUNT=4",
+        "2026-02-17T14:00:00",
+        60,
+        "America/Los_Angeles",
+        None,
+        None,
+    )
+    .unwrap();
+    let basic = expand_rrule(
+        "FREQ=WEEKLY;BYDAY=TU,TH;COUNT=4",
+        "20260217T140000",
+        60,
+        "America/Los_Angeles",
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(extended.len(), 4);
+    assert_eq!(basic, extended);
+}
+
+#[test]
+fn ical_date_only_dtstart_starts_at_midnight() {
+    let date_only = expand_rrule("FREQ=DAILY;COUNT=3", "20260217", 30, "UTC", None, None).unwrap();
+    let extended = expand_rrule(
+        "FREQ=DAILY;COUNT=3",
+        "2026-02-17T00:00:00",
+        30,
+        "UTC",
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        date_only[0].start,
+        Utc.with_ymd_and_hms(2026, 2, 17, 0, 0, 0).unwrap()
+    );
+    assert_eq!(date_only, extended);
+}
+
+#[test]
+fn exdates_accept_every_dtstart_format() {
+    use chrono::Datelike;
+
+    // Each form names Feb 18 14:00, the second of three daily instances.
+    for exdate in [
+        "2026-02-18T14:00",
+        "2026-02-18T14:00:00",
+        "2026-02-18T14:00:00.000",
+        "20260218T140000",
+    ] {
+        let events = truth_engine::expand_rrule_with_exdates(
+            "FREQ=DAILY;COUNT=3",
+            "2026-02-17T14:00",
+            30,
+            "UTC",
+            None,
+            None,
+            &[exdate],
+        )
+        .unwrap_or_else(|e| panic!("{exdate}: {e}"));
+        let days: Vec<u32> = events.iter().map(|e| e.start.day()).collect();
+        assert_eq!(days, vec![17, 19], "{exdate}");
+    }
+
+    // A date-only EXDATE excludes the midnight instance.
+    let events = truth_engine::expand_rrule_with_exdates(
+        "FREQ=DAILY;COUNT=3",
+        "20260217",
+        30,
+        "UTC",
+        None,
+        None,
+        &["20260218"],
+    )
+    .unwrap();
+    let days: Vec<u32> = events.iter().map(|e| e.start.day()).collect();
+    assert_eq!(days, vec![17, 19]);
+}
+
+#[test]
+fn unrecognized_dtstart_format_lists_accepted_forms() {
+    for dtstart in ["2026021714", "202602171400", "17/02/2026"] {
+        let err = expand_rrule("FREQ=DAILY;COUNT=1", dtstart, 30, "UTC", None, None).unwrap_err();
+        match err {
+            truth_engine::TruthError::InvalidDatetime(msg) => {
+                assert!(msg.contains(dtstart), "message should echo input: {msg}");
+                assert!(msg.contains("YYYYMMDDTHHMMSS"), "message: {msg}");
+            }
+            other => panic!("expected InvalidDatetime for {dtstart}, got {other:?}"),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Until boundary
 // ---------------------------------------------------------------------------
@@ -281,6 +461,57 @@ fn until_boundary_limits_expansion() {
     );
 }
 
+#[test]
+fn until_boundary_in_non_utc_timezone() {
+    // UNTIL is local time: 09:00 PST on Mar 4 is the last instance.
+    let result = expand_rrule(
+        "FREQ=DAILY",
+        "2026-03-01T09:00:00",
+        30,
+        "America/Los_Angeles",
+        Some("2026-03-04T09:00:00"),
+        None,
+    )
+    .expect("should expand with a local until boundary");
+
+    assert_eq!(result.len(), 4, "should produce 4 instances (Mar 1-4)");
+    assert_eq!(
+        result[3].start,
+        Utc.with_ymd_and_hms(2026, 3, 4, 17, 0, 0).unwrap()
+    );
+}
+
+#[test]
+fn until_boundary_across_dst_east_of_utc() {
+    // Berlin springs forward on Mar 29, so UNTIL 09:00 local on Mar 30 is
+    // 07:00Z, not the 08:00Z it would be at the pre-DST offset.
+    let inclusive = expand_rrule(
+        "FREQ=DAILY",
+        "2026-03-27T09:00:00",
+        60,
+        "Europe/Berlin",
+        Some("2026-03-30T09:00:00"),
+        None,
+    )
+    .expect("should expand with a local until boundary across DST");
+    assert_eq!(inclusive.len(), 4, "Mar 27-30, UNTIL is inclusive");
+    assert_eq!(
+        inclusive[3].start,
+        Utc.with_ymd_and_hms(2026, 3, 30, 7, 0, 0).unwrap()
+    );
+
+    let exclusive = expand_rrule(
+        "FREQ=DAILY",
+        "2026-03-27T09:00:00",
+        60,
+        "Europe/Berlin",
+        Some("2026-03-30T08:59:00"),
+        None,
+    )
+    .unwrap();
+    assert_eq!(exclusive.len(), 3, "a minute before the last instance");
+}
+
 // ---------------------------------------------------------------------------
 // Duration correctness
 // ---------------------------------------------------------------------------
@@ -307,3 +538,745 @@ fn duration_applied_correctly() {
         Utc.with_ymd_and_hms(2026, 3, 1, 10, 30, 0).unwrap()
     );
 }
+
+// ---------------------------------------------------------------------------
+// DTSTART not matching the rule
+// ---------------------------------------------------------------------------
+
+/// Start days of a Sunday-DTSTART, Monday-only weekly rule.
+fn sunday_dtstart_monday_rule(options: &truth_engine::ExpandOptions) -> Vec<u32> {
+    use chrono::Datelike;
+
+    // 2026-03-01 is a Sunday.
+    truth_engine::expand_rrule_with_options(
+        "FREQ=WEEKLY;BYDAY=MO;COUNT=3",
+        "2026-03-01T09:00:00",
+        30,
+        "UTC",
+        None,
+        None,
+        options,
+    )
+    .expect("should expand")
+    .iter()
+    .map(|e| e.start.day())
+    .collect()
+}
+
+#[test]
+fn unsynchronized_dtstart_dropped_by_default() {
+    let days = sunday_dtstart_monday_rule(&truth_engine::ExpandOptions::default());
+    assert_eq!(days, vec![2, 9, 16], "only Mondays are emitted");
+}
+
+#[test]
+fn unsynchronized_dtstart_included_and_counted_when_inclusive() {
+    let options = truth_engine::ExpandOptions {
+        dtstart_inclusive: true,
+        ..Default::default()
+    };
+    // RFC 5545: DTSTART is the first instance and counts toward COUNT.
+    assert_eq!(sunday_dtstart_monday_rule(&options), vec![1, 2, 9]);
+}
+
+#[test]
+fn inclusive_dtstart_with_rdates_stays_within_the_default_cap() {
+    // Unbounded rule: the Mondays alone fill the 500-instance cap, so the
+    // prepended DTSTART and the RDATE must not push the result past it.
+    let events = truth_engine::expand_rrule_with_options(
+        "FREQ=WEEKLY;BYDAY=MO",
+        "2026-03-01T09:00:00",
+        30,
+        "UTC",
+        None,
+        None,
+        &truth_engine::ExpandOptions {
+            dtstart_inclusive: true,
+            rdates: vec!["2026-03-04T09:00:00".to_string()],
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(events.len(), 500);
+    assert_eq!(
+        events[0].start,
+        Utc.with_ymd_and_hms(2026, 3, 1, 9, 0, 0).unwrap()
+    );
+    assert_eq!(
+        events[2].start,
+        Utc.with_ymd_and_hms(2026, 3, 4, 9, 0, 0).unwrap()
+    );
+}
+
+#[test]
+fn inclusive_dtstart_respects_exdates_and_synchronized_rules() {
+    use chrono::Datelike;
+
+    let options = truth_engine::ExpandOptions {
+        dtstart_inclusive: true,
+        exdates: vec!["2026-03-02T09:00:00".to_string()],
+        ..Default::default()
+    };
+    // The excluded Monday still used up a COUNT slot.
+    assert_eq!(sunday_dtstart_monday_rule(&options), vec![1, 9]);
+
+    // A DTSTART that already matches is not duplicated.
+    let days: Vec<u32> = truth_engine::expand_rrule_with_options(
+        "FREQ=WEEKLY;BYDAY=MO;COUNT=3",
+        "2026-03-02T09:00:00",
+        30,
+        "UTC",
+        None,
+        None,
+        &truth_engine::ExpandOptions {
+            dtstart_inclusive: true,
+            ..Default::default()
+        },
+    )
+    .unwrap()
+    .iter()
+    .map(|e| e.start.day())
+    .collect();
+    assert_eq!(days, vec![2, 9, 16]);
+}
+
+// ---------------------------------------------------------------------------
+// RDATE
+// ---------------------------------------------------------------------------
+
+/// Weekly on Tuesdays from Tue 2026-03-03, four instances, plus `rdates`.
+fn tuesdays_with_rdates(rdates: &[&str], inclusive: bool) -> Vec<String> {
+    let options = truth_engine::ExpandOptions {
+        rdates: rdates.iter().map(|d| d.to_string()).collect(),
+        dtstart_inclusive: inclusive,
+        ..Default::default()
+    };
+    truth_engine::expand_rrule_with_options(
+        "FREQ=WEEKLY;BYDAY=TU;COUNT=4",
+        "2026-03-03T09:00:00",
+        30,
+        "America/New_York",
+        None,
+        None,
+        &options,
+    )
+    .expect("should expand")
+    .iter()
+    .map(|e| e.start.format("%a %m-%d %H:%M").to_string())
+    .collect()
+}
+
+#[test]
+fn rdate_on_a_rule_instance_yields_it_once() {
+    let tuesdays = tuesdays_with_rdates(&[], false);
+    assert_eq!(
+        tuesdays,
+        [
+            "Tue 03-03 14:00",
+            "Tue 03-10 13:00",
+            "Tue 03-17 13:00",
+            "Tue 03-24 13:00"
+        ]
+    );
+    for inclusive in [false, true] {
+        // Repeating the RDATE itself does not duplicate it either.
+        let days = tuesdays_with_rdates(&["2026-03-10T09:00:00", "2026-03-10T09:00:00"], inclusive);
+        assert_eq!(days, tuesdays);
+    }
+}
+
+#[test]
+fn rdate_off_the_rule_adds_an_instance() {
+    for inclusive in [false, true] {
+        let days = tuesdays_with_rdates(&["2026-03-12T09:00:00"], inclusive);
+        assert_eq!(
+            days,
+            [
+                "Tue 03-03 14:00",
+                "Tue 03-10 13:00",
+                "Thu 03-12 13:00",
+                "Tue 03-17 13:00",
+                "Tue 03-24 13:00"
+            ]
+        );
+    }
+    // An RDATE at another time of day on a Tuesday is a distinct instance.
+    let days = tuesdays_with_rdates(&["2026-03-10T15:00:00"], false);
+    assert_eq!(days.len(), 5);
+    assert_eq!(days[2], "Tue 03-10 19:00");
+}
+
+#[test]
+fn rdate_rejects_unparseable_datetime() {
+    let result = truth_engine::expand_rrule_with_options(
+        "FREQ=WEEKLY;BYDAY=TU;COUNT=4",
+        "2026-03-03T09:00:00",
+        30,
+        "UTC",
+        None,
+        None,
+        &truth_engine::ExpandOptions {
+            rdates: vec!["next tuesday".to_string()],
+            ..Default::default()
+        },
+    );
+    assert!(matches!(
+        result,
+        Err(truth_engine::TruthError::InvalidDatetime(_))
+    ));
+}
+
+// ---------------------------------------------------------------------------
+// Sequence numbers
+// ---------------------------------------------------------------------------
+
+#[test]
+fn windowed_seq_numbers_count_from_dtstart() {
+    // Daily from Mar 1; the window opens on Mar 11, ten instances later.
+    let result = truth_engine::expand_rrule_seq(
+        "FREQ=DAILY",
+        "2026-03-01T09:00:00",
+        30,
+        "UTC",
+        Utc.with_ymd_and_hms(2026, 3, 11, 0, 0, 0).unwrap(),
+        Utc.with_ymd_and_hms(2026, 3, 14, 0, 0, 0).unwrap(),
+    )
+    .expect("should expand");
+
+    let seqs: Vec<usize> = result.iter().map(|e| e.seq).collect();
+    assert_eq!(seqs, vec![11, 12, 13]);
+    assert_eq!(
+        result[0].start,
+        Utc.with_ymd_and_hms(2026, 3, 11, 9, 0, 0).unwrap()
+    );
+}
+
+#[test]
+fn windowed_seq_includes_instance_spanning_window_start() {
+    // The Mar 3 instance runs 23:00–01:00 and overlaps a window opening at midnight.
+    let result = truth_engine::expand_rrule_seq(
+        "FREQ=DAILY;COUNT=5",
+        "2026-03-01T23:00:00",
+        120,
+        "UTC",
+        Utc.with_ymd_and_hms(2026, 3, 4, 0, 0, 0).unwrap(),
+        Utc.with_ymd_and_hms(2026, 3, 5, 0, 0, 0).unwrap(),
+    )
+    .expect("should expand");
+
+    let seqs: Vec<usize> = result.iter().map(|e| e.seq).collect();
+    assert_eq!(seqs, vec![3, 4]);
+}
+
+#[test]
+fn windowed_seq_beyond_default_instance_limit() {
+    // More than 500 daily instances lie before the window.
+    let result = truth_engine::expand_rrule_seq(
+        "FREQ=DAILY",
+        "2024-01-01T09:00:00",
+        30,
+        "America/New_York",
+        Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+        Utc.with_ymd_and_hms(2026, 1, 2, 0, 0, 0).unwrap(),
+    )
+    .expect("should expand");
+
+    // 2024 (366 days) + 2025 (365 days) precede Jan 1, 2026.
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].seq, 732);
+}
+
+// ---------------------------------------------------------------------------
+// Duration mode
+// ---------------------------------------------------------------------------
+
+/// All-day instances around the 2026-03-08 US spring-forward, as (start, end).
+fn all_day_across_spring_forward(
+    mode: truth_engine::DurationMode,
+) -> Vec<(chrono::DateTime<Utc>, chrono::DateTime<Utc>)> {
+    truth_engine::expand_rrule_with_options(
+        "FREQ=DAILY;COUNT=2",
+        "2026-03-07T00:00:00",
+        1440,
+        "America/New_York",
+        None,
+        None,
+        &truth_engine::ExpandOptions {
+            duration_mode: mode,
+            ..Default::default()
+        },
+    )
+    .expect("should expand")
+    .iter()
+    .map(|e| (e.start, e.end))
+    .collect()
+}
+
+#[test]
+fn fixed_duration_is_absolute_across_dst() {
+    let events = all_day_across_spring_forward(truth_engine::DurationMode::Fixed);
+    // Mar 8 starts at 00:00 EST; 24 hours later is 01:00 EDT on Mar 9.
+    assert_eq!(
+        events[1].0,
+        Utc.with_ymd_and_hms(2026, 3, 8, 5, 0, 0).unwrap()
+    );
+    assert_eq!(
+        events[1].1,
+        Utc.with_ymd_and_hms(2026, 3, 9, 5, 0, 0).unwrap()
+    );
+}
+
+#[test]
+fn wallclock_duration_keeps_local_end_across_dst() {
+    let events = all_day_across_spring_forward(truth_engine::DurationMode::WallClock);
+    // A normal day is unchanged.
+    assert_eq!(events[0].1 - events[0].0, chrono::Duration::hours(24));
+    // Mar 8 ends at local midnight, 00:00 EDT on Mar 9: a 23-hour day.
+    assert_eq!(
+        events[1].1,
+        Utc.with_ymd_and_hms(2026, 3, 9, 4, 0, 0).unwrap()
+    );
+}
+
+#[test]
+fn wallclock_end_in_dst_gap_moves_forward() {
+    // 01:30 + 60 minutes is 02:30, which does not exist on Mar 8.
+    let events = truth_engine::expand_rrule_with_options(
+        "FREQ=DAILY;COUNT=1",
+        "2026-03-08T01:30:00",
+        60,
+        "America/New_York",
+        None,
+        None,
+        &truth_engine::ExpandOptions {
+            duration_mode: truth_engine::DurationMode::WallClock,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    // 03:30 EDT
+    assert_eq!(
+        events[0].end,
+        Utc.with_ymd_and_hms(2026, 3, 8, 7, 30, 0).unwrap()
+    );
+}
+
+#[test]
+fn wallclock_end_in_half_hour_dst_gap_moves_by_the_gap_length() {
+    // Lord Howe springs forward 30 minutes at 02:00 on Oct 4, so 01:45 + 30
+    // minutes is 02:15, which does not exist: it becomes 02:45 (+11:00).
+    let events = truth_engine::expand_rrule_with_options(
+        "FREQ=DAILY;COUNT=1",
+        "2026-10-04T01:45:00",
+        30,
+        "Australia/Lord_Howe",
+        None,
+        None,
+        &truth_engine::ExpandOptions {
+            duration_mode: truth_engine::DurationMode::WallClock,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        events[0].end,
+        Utc.with_ymd_and_hms(2026, 10, 3, 15, 45, 0).unwrap()
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Grouping by local date in a display timezone
+// ---------------------------------------------------------------------------
+
+#[test]
+fn grouped_by_local_date_shifts_late_pacific_event_to_next_eastern_day() {
+    use chrono::NaiveDate;
+
+    let date = |d: u32| NaiveDate::from_ymd_opt(2026, 3, d).unwrap();
+    // Weekly on Mon/Wed at 23:00 Pacific: 02:00 Eastern on Tue/Thu.
+    let args = ("FREQ=WEEKLY;BYDAY=MO,WE;COUNT=3", "2026-03-02T23:00:00");
+
+    let pacific = truth_engine::expand_rrule_grouped_by_local_date(
+        args.0,
+        args.1,
+        60,
+        "America/Los_Angeles",
+        "America/Los_Angeles",
+        None,
+        None,
+    )
+    .unwrap();
+    let eastern = truth_engine::expand_rrule_grouped_by_local_date(
+        args.0,
+        args.1,
+        60,
+        "America/Los_Angeles",
+        "America/New_York",
+        None,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(
+        pacific.keys().copied().collect::<Vec<_>>(),
+        [date(2), date(4), date(9)]
+    );
+    assert_eq!(
+        eastern.keys().copied().collect::<Vec<_>>(),
+        [date(3), date(5), date(10)]
+    );
+    // Same instances, only the bucketing differs.
+    assert_eq!(
+        eastern[&date(3)][0].start,
+        Utc.with_ymd_and_hms(2026, 3, 3, 7, 0, 0).unwrap()
+    );
+    assert_eq!(pacific[&date(2)], eastern[&date(3)]);
+}
+
+#[test]
+fn grouped_by_local_date_keeps_same_day_instances_together() {
+    let days = truth_engine::expand_rrule_grouped_by_local_date(
+        "FREQ=HOURLY;INTERVAL=6;COUNT=5",
+        "2026-03-02T00:00:00",
+        30,
+        "UTC",
+        "Asia/Tokyo",
+        None,
+        None,
+    )
+    .unwrap();
+    // 00, 06, 12, 18, 24 UTC are 09, 15, 21 JST on Mar 2 and 03, 09 JST on Mar 3.
+    let sizes: Vec<usize> = days.values().map(Vec::len).collect();
+    assert_eq!(sizes, [3, 2]);
+    assert!(days
+        .values()
+        .all(|events| events.windows(2).all(|w| w[0].start < w[1].start)));
+}
+
+#[test]
+fn grouped_by_local_date_rejects_invalid_display_timezone() {
+    let err = truth_engine::expand_rrule_grouped_by_local_date(
+        "FREQ=DAILY;COUNT=1",
+        "2026-03-02T09:00:00",
+        30,
+        "UTC",
+        "Mars/Olympus_Mons",
+        None,
+        None,
+    )
+    .unwrap_err();
+    assert!(
+        matches!(err, truth_engine::TruthError::InvalidTimezone(tz) if tz == "Mars/Olympus_Mons")
+    );
+}
+
+// ── Diagnostics ─────────────────────────────────────────────────────────────
+
+#[test]
+fn diagnostics_report_months_without_a_31st() {
+    let (events, diagnostics) = truth_engine::expand_rrule_diagnostics(
+        "FREQ=MONTHLY;BYMONTHDAY=31",
+        "2026-01-31T09:00:00",
+        60,
+        "America/New_York",
+        Some("2026-12-31T23:59:59"),
+        None,
+    )
+    .unwrap();
+
+    // Jan, Mar, May, Jul, Aug, Oct, Dec.
+    assert_eq!(events.len(), 7);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].code,
+        truth_engine::DiagnosticCode::SkippedMonths
+    );
+    assert_eq!(diagnostics[0].code.as_str(), "skipped_months");
+    assert_eq!(
+        diagnostics[0].message,
+        "5 months were skipped because they lack a 31st day"
+    );
+    // The instances are exactly those of plain expand_rrule.
+    let plain = expand_rrule(
+        "FREQ=MONTHLY;BYMONTHDAY=31",
+        "2026-01-31T09:00:00",
+        60,
+        "America/New_York",
+        Some("2026-12-31T23:59:59"),
+        None,
+    )
+    .unwrap();
+    assert_eq!(events, plain);
+}
+
+#[test]
+fn diagnostics_report_unbounded_cap_and_unsynchronized_dtstart() {
+    // 2026-03-01 is a Sunday; the rule only matches Mondays and never ends.
+    let (events, diagnostics) = truth_engine::expand_rrule_diagnostics(
+        "FREQ=WEEKLY;BYDAY=MO",
+        "2026-03-01T09:00:00",
+        30,
+        "UTC",
+        None,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(events.len(), 500);
+    let codes: Vec<&str> = diagnostics.iter().map(|d| d.code.as_str()).collect();
+    assert_eq!(codes, ["instance_cap_reached", "dtstart_not_in_rule"]);
+    assert!(diagnostics[1].message.contains("2026-03-02T09:00:00"));
+}
+
+#[test]
+fn diagnostics_report_cap_below_the_rules_own_count() {
+    let (events, diagnostics) = truth_engine::expand_rrule_diagnostics(
+        "FREQ=DAILY;COUNT=1000",
+        "2026-01-01T09:00:00",
+        30,
+        "UTC",
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(events.len(), 500);
+    let codes: Vec<&str> = diagnostics.iter().map(|d| d.code.as_str()).collect();
+    assert_eq!(codes, ["instance_cap_reached"]);
+
+    // A COUNT the cap does not reach is the rule's own end.
+    let (events, diagnostics) = truth_engine::expand_rrule_diagnostics(
+        "FREQ=DAILY;COUNT=500",
+        "2026-01-01T09:00:00",
+        30,
+        "UTC",
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(events.len(), 500);
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn diagnostics_skipped_months_only_count_months_in_bymonth() {
+    // Only February and March are eligible, and only February lacks a 31st.
+    let (events, diagnostics) = truth_engine::expand_rrule_diagnostics(
+        "FREQ=MONTHLY;BYMONTH=2,3;BYMONTHDAY=31",
+        "2026-01-01T09:00:00",
+        60,
+        "UTC",
+        Some("2026-12-31T23:59:59"),
+        None,
+    )
+    .unwrap();
+    assert_eq!(events.len(), 1);
+    let skipped: Vec<&str> = diagnostics
+        .iter()
+        .filter(|d| d.code == truth_engine::DiagnosticCode::SkippedMonths)
+        .map(|d| d.message.as_str())
+        .collect();
+    assert_eq!(skipped, ["1 month was skipped because it lacks a 31st day"]);
+
+    // Every other month from January 2026 to January 2027; of those, only
+    // September and November lack a 31st.
+    let (_, diagnostics) = truth_engine::expand_rrule_diagnostics(
+        "FREQ=MONTHLY;INTERVAL=2;BYMONTHDAY=31",
+        "2026-01-31T09:00:00",
+        60,
+        "UTC",
+        Some("2027-01-31T23:59:59"),
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        diagnostics[0].message,
+        "2 months were skipped because they lack a 31st day"
+    );
+}
+
+#[test]
+fn diagnostics_empty_for_unremarkable_rule() {
+    let (events, diagnostics) = truth_engine::expand_rrule_diagnostics(
+        "FREQ=MONTHLY;BYMONTHDAY=15;COUNT=12",
+        "2026-01-15T09:00:00",
+        60,
+        "UTC",
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(events.len(), 12);
+    assert!(diagnostics.is_empty());
+}
+
+// ── Shifting a series ───────────────────────────────────────────────────────
+
+#[test]
+fn shift_series_forward_thirty_minutes() {
+    use chrono::Duration;
+    use truth_engine::{shift_dtstart, shift_events, DurationMode};
+
+    let rule = "FREQ=WEEKLY;BYDAY=TU,TH;COUNT=4";
+    let tz = "America/Los_Angeles";
+    let original = expand_rrule(rule, "2026-02-17T14:00:00", 60, tz, None, None).unwrap();
+    let shifted = shift_events(&original, Duration::minutes(30));
+
+    assert_eq!(shifted.len(), original.len());
+    for (before, after) in original.iter().zip(&shifted) {
+        assert_eq!(after.start - before.start, Duration::minutes(30));
+        assert_eq!(after.end - after.start, Duration::minutes(60));
+    }
+
+    // Outside DST transitions both rule-level modes agree with the event shift.
+    for mode in [DurationMode::Fixed, DurationMode::WallClock] {
+        let dtstart =
+            shift_dtstart("2026-02-17T14:00:00", tz, Duration::minutes(30), mode).unwrap();
+        assert_eq!(dtstart, "2026-02-17T14:30:00");
+        assert_eq!(
+            expand_rrule(rule, &dtstart, 60, tz, None, None).unwrap(),
+            shifted
+        );
+    }
+}
+
+#[test]
+fn shift_dtstart_across_dst_wall_clock_vs_absolute() {
+    use chrono::Duration;
+    use truth_engine::{shift_dtstart, DurationMode};
+
+    // 2026-03-08 is the US spring-forward day: 24 elapsed hours after Saturday
+    // 09:00 PST is Sunday 10:00 PDT.
+    let tz = "America/Los_Angeles";
+    let by = Duration::hours(24);
+    let wall = shift_dtstart("2026-03-07T09:00:00", tz, by, DurationMode::WallClock).unwrap();
+    let absolute = shift_dtstart("2026-03-07T09:00:00", tz, by, DurationMode::Fixed).unwrap();
+    assert_eq!(wall, "2026-03-08T09:00:00");
+    assert_eq!(absolute, "2026-03-08T10:00:00");
+
+    let wall_events = expand_rrule("FREQ=DAILY;COUNT=2", &wall, 30, tz, None, None).unwrap();
+    let absolute_events =
+        expand_rrule("FREQ=DAILY;COUNT=2", &absolute, 30, tz, None, None).unwrap();
+    assert_eq!(
+        wall_events[0].start,
+        Utc.with_ymd_and_hms(2026, 3, 8, 16, 0, 0).unwrap()
+    );
+    assert_eq!(
+        absolute_events[0].start,
+        Utc.with_ymd_and_hms(2026, 3, 8, 17, 0, 0).unwrap()
+    );
+    // Either way the series then keeps its new local time.
+    assert_eq!(absolute_events[1].start.hour(), 17);
+}
+
+#[test]
+fn shift_dtstart_wall_clock_into_dst_gap_returns_an_expandable_start() {
+    use chrono::Duration;
+    use truth_engine::{shift_dtstart, DurationMode};
+
+    // 02:30 does not exist on Mar 8 in Los Angeles; the shifted start moves
+    // forward by the one-hour gap instead of failing the expansion.
+    let tz = "America/Los_Angeles";
+    let by = Duration::hours(24);
+    let wall = shift_dtstart("2026-03-07T02:30:00", tz, by, DurationMode::WallClock).unwrap();
+    assert_eq!(wall, "2026-03-08T03:30:00");
+
+    let events = expand_rrule("FREQ=DAILY;COUNT=1", &wall, 30, tz, None, None).unwrap();
+    assert_eq!(
+        events[0].start,
+        Utc.with_ymd_and_hms(2026, 3, 8, 10, 30, 0).unwrap()
+    );
+}
+
+#[test]
+fn shift_events_across_dst_moves_each_instance_by_elapsed_time() {
+    use chrono::Duration;
+    use truth_engine::shift_events;
+
+    // Daily 09:00 LA spanning spring-forward: 17:00Z before, 16:00Z after.
+    let events = expand_rrule(
+        "FREQ=DAILY;COUNT=3",
+        "2026-03-07T09:00:00",
+        60,
+        "America/Los_Angeles",
+        None,
+        None,
+    )
+    .unwrap();
+    let shifted = shift_events(&events, Duration::minutes(30));
+    let hours: Vec<(u32, u32)> = shifted
+        .iter()
+        .map(|e| (e.start.hour(), e.start.minute()))
+        .collect();
+    assert_eq!(hours, vec![(17, 30), (16, 30), (16, 30)]);
+}
+
+#[test]
+fn shift_dtstart_rejects_bad_input() {
+    use chrono::Duration;
+    use truth_engine::{shift_dtstart, DurationMode};
+
+    let by = Duration::minutes(30);
+    assert!(matches!(
+        shift_dtstart("not a date", "UTC", by, DurationMode::Fixed),
+        Err(truth_engine::TruthError::InvalidDatetime(_))
+    ));
+    assert!(matches!(
+        shift_dtstart(
+            "2026-02-17T14:00:00",
+            "Mars/Olympus_Mons",
+            by,
+            DurationMode::Fixed
+        ),
+        Err(truth_engine::TruthError::InvalidTimezone(_))
+    ));
+}
+
+// ── Validating a rule ───────────────────────────────────────────────────────
+
+#[test]
+fn validate_rrule_accepts_valid_rules() {
+    for rule in [
+        "FREQ=WEEKLY;BYDAY=TU,TH",
+        "FREQ=MONTHLY;BYDAY=TU;BYSETPOS=3",
+        "FREQ=DAILY;UNTIL=20260601T000000Z",
+        "freq=daily;count=5",
+    ] {
+        assert!(truth_engine::validate_rrule(rule).is_ok(), "{rule}");
+    }
+}
+
+#[test]
+fn validate_rrule_requires_freq() {
+    let err = truth_engine::validate_rrule("BYDAY=TU;COUNT=3").unwrap_err();
+    assert!(matches!(err, truth_engine::TruthError::InvalidRule(_)));
+    assert!(err.to_string().contains("FREQ"), "{err}");
+}
+
+#[test]
+fn validate_rrule_names_an_unknown_weekday() {
+    let err = truth_engine::validate_rrule("FREQ=WEEKLY;BYDAY=XX").unwrap_err();
+    assert!(matches!(err, truth_engine::TruthError::InvalidRule(_)));
+    assert!(
+        err.to_string().contains("`XX` is not a valid weekday"),
+        "{err}"
+    );
+}
+
+#[test]
+fn validate_rrule_agrees_with_expansion() {
+    // Out-of-range values, parts the frequency does not allow, unknown parts and
+    // a local UNTIL all fail validation exactly as they fail expansion.
+    for rule in [
+        "",
+        "FREQ=YEARLY;BYMONTH=13",
+        "FREQ=WEEKLY;BYWEEKNO=3",
+        "FREQ=DAILY;FOO=1",
+        "FREQ=DAILY;UNTIL=20260601T000000",
+        "RRULE:FREQ=DAILY",
+    ] {
+        let validated = truth_engine::validate_rrule(rule).unwrap_err();
+        let expanded =
+            expand_rrule(rule, "2026-03-01T09:00:00", 30, "UTC", None, Some(3)).unwrap_err();
+        assert_eq!(validated.to_string(), expanded.to_string(), "{rule:?}");
+    }
+}
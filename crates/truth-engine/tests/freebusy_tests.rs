@@ -2,9 +2,13 @@
 //!
 //! All tests should compile but fail with `todo!()` panics until implementation.
 
-use chrono::{TimeZone, Utc};
+use chrono::{DateTime, TimeZone, Utc};
 use truth_engine::expander::ExpandedEvent;
-use truth_engine::freebusy::{find_first_free_slot, find_free_slots};
+use truth_engine::freebusy::{
+    find_busy_chains, find_first_free_slot, find_free_slots, find_free_slots_multi_window,
+    rrule_free_busy,
+};
+use truth_engine::RRuleSpec;
 
 /// Helper to create an ExpandedEvent from hour ranges on a given day.
 fn event(
@@ -196,3 +200,239 @@ fn multiple_gaps_between_events() {
     assert_eq!(slots[2].duration_minutes, 120); // 13:00-15:00
     assert_eq!(slots[3].duration_minutes, 120); // 16:00-18:00
 }
+
+#[test]
+fn zero_duration_event_does_not_split_free_slot() {
+    // Window: 08:00-17:00, Reminder: 12:00-12:00 (zero length)
+    // Expected free: the whole window in one slot
+    let events = vec![event(2026, 3, 1, 12, 0, 12, 0)];
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 1, 8, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 1, 17, 0, 0).unwrap();
+
+    let slots = find_free_slots(&events, window_start, window_end);
+
+    assert_eq!(
+        slots.len(),
+        1,
+        "a zero-length event should not split free time"
+    );
+    assert_eq!(slots[0].duration_minutes, 540);
+}
+
+fn at(hour: u32, min: u32) -> chrono::DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 1, hour, min, 0).unwrap()
+}
+
+#[test]
+fn multi_window_finds_free_slots_in_each_window() {
+    // Allowed 09:00-12:00 and 13:00-17:00 (lunch excluded); busy 10:00-11:00.
+    let events = vec![event(2026, 3, 1, 10, 0, 11, 0)];
+    let windows = [(at(9, 0), at(12, 0)), (at(13, 0), at(17, 0))];
+
+    let slots = find_free_slots_multi_window(&events, &windows);
+
+    let spans: Vec<_> = slots.iter().map(|s| (s.start, s.end)).collect();
+    assert_eq!(
+        spans,
+        vec![
+            (at(9, 0), at(10, 0)),
+            (at(11, 0), at(12, 0)),
+            (at(13, 0), at(17, 0)),
+        ]
+    );
+    assert_eq!(slots[2].duration_minutes, 240);
+}
+
+#[test]
+fn multi_window_event_spanning_gap_only_affects_overlapped_windows() {
+    // Busy 11:30-13:30 covers the end of the morning and the start of the
+    // afternoon; the lunch gap itself is never offered either way.
+    let events = vec![event(2026, 3, 1, 11, 30, 13, 30)];
+    let windows = [
+        (at(13, 0), at(17, 0)),
+        (at(9, 0), at(12, 0)),
+        (at(18, 0), at(19, 0)),
+    ];
+
+    let slots = find_free_slots_multi_window(&events, &windows);
+
+    let spans: Vec<_> = slots.iter().map(|s| (s.start, s.end)).collect();
+    assert_eq!(
+        spans,
+        vec![
+            (at(9, 0), at(11, 30)),
+            (at(13, 30), at(17, 0)),
+            (at(18, 0), at(19, 0)),
+        ]
+    );
+}
+
+#[test]
+fn multi_window_merges_touching_windows_and_skips_empty_ones() {
+    let windows = [
+        (at(9, 0), at(12, 0)),
+        (at(12, 0), at(14, 0)),
+        (at(15, 0), at(15, 0)),
+        (at(17, 0), at(16, 0)),
+    ];
+    let slots = find_free_slots_multi_window(&[], &windows);
+    assert_eq!(slots.len(), 1);
+    assert_eq!((slots[0].start, slots[0].end), (at(9, 0), at(14, 0)));
+}
+
+#[test]
+fn extreme_window_bounds_do_not_overflow_duration_minutes() {
+    let min = DateTime::<Utc>::MIN_UTC;
+    let max = DateTime::<Utc>::MAX_UTC;
+    let busy = event(2026, 2, 17, 9, 0, 10, 0);
+
+    let slots = find_free_slots(std::slice::from_ref(&busy), min, max);
+    assert_eq!(slots.len(), 2);
+    assert_eq!(slots[0].start, min);
+    assert_eq!(slots[0].end, busy.start);
+    assert_eq!(slots[0].duration_minutes, (busy.start - min).num_minutes());
+    assert_eq!(slots[1].end, max);
+    assert!(slots.iter().all(|s| s.duration_minutes > 0));
+}
+
+#[test]
+fn busy_chain_tolerates_gaps_within_threshold() {
+    // 09:00-10:00, 10:05-11:00, 11:05-12:00 with 5-minute gaps, threshold 10.
+    let events = vec![
+        event(2026, 3, 1, 10, 5, 11, 0),
+        event(2026, 3, 1, 9, 0, 10, 0),
+        event(2026, 3, 1, 11, 5, 12, 0),
+    ];
+
+    let chains = find_busy_chains(&events, 10);
+
+    assert_eq!(chains.len(), 1);
+    assert_eq!(chains[0].start, at(9, 0));
+    assert_eq!(chains[0].end, at(12, 0));
+    assert_eq!(chains[0].event_count, 3);
+    assert_eq!(chains[0].total_minutes, 170);
+}
+
+#[test]
+fn busy_chain_breaks_on_gap_over_threshold() {
+    // 09:00-10:00 and 10:30-11:30: the 30-minute gap exceeds the threshold.
+    let events = vec![
+        event(2026, 3, 1, 9, 0, 10, 0),
+        event(2026, 3, 1, 10, 30, 11, 30),
+    ];
+
+    let chains = find_busy_chains(&events, 10);
+
+    let spans: Vec<_> = chains
+        .iter()
+        .map(|c| (c.start, c.end, c.event_count))
+        .collect();
+    assert_eq!(
+        spans,
+        vec![(at(9, 0), at(10, 0), 1), (at(10, 30), at(11, 30), 1)]
+    );
+}
+
+#[test]
+fn busy_chain_counts_overlap_once_and_skips_zero_duration() {
+    // 09:00-10:00 overlaps 09:30-10:30, and 10:30-11:00 touches; the zero-length
+    // reminder at 12:00 is ignored.
+    let events = vec![
+        event(2026, 3, 1, 9, 0, 10, 0),
+        event(2026, 3, 1, 9, 30, 10, 30),
+        event(2026, 3, 1, 10, 30, 11, 0),
+        event(2026, 3, 1, 12, 0, 12, 0),
+    ];
+
+    let chains = find_busy_chains(&events, 0);
+
+    assert_eq!(chains.len(), 1);
+    assert_eq!(chains[0].event_count, 3);
+    assert_eq!(chains[0].total_minutes, 120);
+    assert!(find_busy_chains(&[], 10).is_empty());
+}
+
+#[test]
+fn rrule_free_busy_weekly_meeting_over_two_weeks() {
+    // Mondays and Wednesdays 10:00-11:00 UTC from 2026-03-02 (a Monday).
+    let spec = RRuleSpec {
+        rrule: "FREQ=WEEKLY;BYDAY=MO,WE".to_string(),
+        dtstart: "2026-03-02T10:00:00".to_string(),
+        duration_minutes: 60,
+        timezone: "UTC".to_string(),
+    };
+    // Two weeks starting 2026-03-09, mid-series.
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 9, 0, 0, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 23, 0, 0, 0).unwrap();
+
+    let (busy, free) = rrule_free_busy(&spec, window_start, window_end).unwrap();
+
+    assert_eq!(
+        busy,
+        vec![
+            event(2026, 3, 9, 10, 0, 11, 0),
+            event(2026, 3, 11, 10, 0, 11, 0),
+            event(2026, 3, 16, 10, 0, 11, 0),
+            event(2026, 3, 18, 10, 0, 11, 0),
+        ]
+    );
+    let gaps: Vec<_> = free.iter().map(|s| (s.start, s.end)).collect();
+    let at = |d, h| Utc.with_ymd_and_hms(2026, 3, d, h, 0, 0).unwrap();
+    assert_eq!(
+        gaps,
+        vec![
+            (window_start, at(9, 10)),
+            (at(9, 11), at(11, 10)),
+            (at(11, 11), at(16, 10)),
+            (at(16, 11), at(18, 10)),
+            (at(18, 11), window_end),
+        ]
+    );
+    assert_eq!(free, find_free_slots(&busy, window_start, window_end));
+}
+
+#[test]
+fn rrule_free_busy_window_far_after_dtstart() {
+    // Hourly since 2018: the window lies beyond the first u16::MAX instances.
+    let spec = RRuleSpec {
+        rrule: "FREQ=HOURLY".to_string(),
+        dtstart: "2018-01-01T00:00:00".to_string(),
+        duration_minutes: 30,
+        timezone: "UTC".to_string(),
+    };
+    let window_start = Utc.with_ymd_and_hms(2026, 3, 16, 9, 15, 0).unwrap();
+    let window_end = Utc.with_ymd_and_hms(2026, 3, 16, 11, 0, 0).unwrap();
+
+    let (busy, free) = rrule_free_busy(&spec, window_start, window_end).unwrap();
+
+    // The 09:00 instance overlaps the window start and is reported unclipped.
+    assert_eq!(
+        busy,
+        vec![
+            event(2026, 3, 16, 9, 0, 9, 30),
+            event(2026, 3, 16, 10, 0, 10, 30),
+        ]
+    );
+    assert_eq!(free, find_free_slots(&busy, window_start, window_end));
+}
+
+#[test]
+fn rrule_free_busy_reports_invalid_rule_and_empty_window() {
+    let spec = RRuleSpec {
+        rrule: "FREQ=WEEKLY".to_string(),
+        dtstart: "2026-03-02T10:00:00".to_string(),
+        duration_minutes: 60,
+        timezone: "UTC".to_string(),
+    };
+    let day = Utc.with_ymd_and_hms(2026, 3, 3, 0, 0, 0).unwrap();
+    let (busy, free) = rrule_free_busy(&spec, day, day + chrono::Duration::days(1)).unwrap();
+    assert!(busy.is_empty());
+    assert_eq!(free.len(), 1);
+    assert_eq!(free[0].duration_minutes, 24 * 60);
+
+    let invalid = RRuleSpec {
+        rrule: "FREQ=SOMETIMES".to_string(),
+        ..spec
+    };
+    assert!(rrule_free_busy(&invalid, day, day + chrono::Duration::days(1)).is_err());
+}
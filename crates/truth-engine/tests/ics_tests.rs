@@ -0,0 +1,201 @@
+//! Tests for iCalendar parsing and expansion of the parsed VEVENTs.
+
+use chrono::{TimeZone, Utc};
+use truth_engine::{parse_ics, TruthError};
+
+const TWO_EVENTS: &str = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//Example//Test//EN\r
+BEGIN:VEVENT\r
+UID:standup@example.com\r
+SUMMARY:Team standup\\, weekly\r
+DTSTART;TZID=America/New_York:20260303T090000\r
+DTEND;TZID=America/New_York:20260303T093000\r
+RRULE:FREQ=WEEKLY;BYDAY=TU;COUNT=4\r
+EXDATE;TZID=America/New_York:20260317T090000\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:review@example.com\r
+SUMMARY:Design review\r
+DTSTART:20260305T180000Z\r
+DURATION:PT1H30M\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+// ---------------------------------------------------------------------------
+// Parsing
+// ---------------------------------------------------------------------------
+
+#[test]
+fn parses_both_vevents() {
+    let events = parse_ics(TWO_EVENTS).unwrap();
+    assert_eq!(events.len(), 2);
+
+    let standup = &events[0];
+    assert_eq!(standup.summary.as_deref(), Some("Team standup, weekly"));
+    assert_eq!(standup.dtstart, "2026-03-03T09:00:00");
+    assert_eq!(standup.timezone, "America/New_York");
+    assert_eq!(standup.duration_minutes, 30);
+    assert_eq!(
+        standup.rrule.as_deref(),
+        Some("FREQ=WEEKLY;BYDAY=TU;COUNT=4")
+    );
+    assert_eq!(standup.exdates, vec!["2026-03-17T09:00:00"]);
+
+    let review = &events[1];
+    assert_eq!(review.summary.as_deref(), Some("Design review"));
+    assert_eq!(review.dtstart, "2026-03-05T18:00:00");
+    assert_eq!(review.timezone, "UTC");
+    assert_eq!(review.duration_minutes, 90);
+    assert_eq!(review.rrule, None);
+    assert!(review.exdates.is_empty());
+}
+
+#[test]
+fn folded_lines_are_unfolded() {
+    let ics = "BEGIN:VEVENT\nSUMMARY:Quarterly plan\n ning session\nDTSTART:20260401T100000Z\nEND:VEVENT\n";
+    let events = parse_ics(ics).unwrap();
+    assert_eq!(
+        events[0].summary.as_deref(),
+        Some("Quarterly planning session")
+    );
+}
+
+#[test]
+fn all_day_event_starts_at_midnight() {
+    let ics = "BEGIN:VEVENT\nDTSTART;VALUE=DATE:20260401\nDTEND;VALUE=DATE:20260402\nEND:VEVENT\n";
+    let events = parse_ics(ics).unwrap();
+    assert_eq!(events[0].dtstart, "2026-04-01T00:00:00");
+    assert_eq!(events[0].duration_minutes, 24 * 60);
+}
+
+#[test]
+fn exdate_in_utc_is_converted_to_event_timezone() {
+    let ics = "BEGIN:VEVENT\n\
+               DTSTART;TZID=Europe/Berlin:20260105T100000\n\
+               RRULE:FREQ=DAILY;COUNT=3\n\
+               EXDATE:20260106T090000Z\n\
+               END:VEVENT\n";
+    let events = parse_ics(ics).unwrap();
+    assert_eq!(events[0].exdates, vec!["2026-01-06T10:00:00"]);
+}
+
+#[test]
+fn nested_valarm_properties_do_not_override_the_event() {
+    let ics = "BEGIN:VEVENT\n\
+               SUMMARY:Dentist\n\
+               DTSTART:20260401T100000Z\n\
+               DURATION:PT45M\n\
+               BEGIN:VALARM\n\
+               ACTION:DISPLAY\n\
+               SUMMARY:Reminder\n\
+               DURATION:PT15M\n\
+               END:VALARM\n\
+               END:VEVENT\n";
+    let events = parse_ics(ics).unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].summary.as_deref(), Some("Dentist"));
+    assert_eq!(events[0].duration_minutes, 45);
+}
+
+// ---------------------------------------------------------------------------
+// Expansion
+// ---------------------------------------------------------------------------
+
+#[test]
+fn expands_recurring_and_single_events() {
+    let events = parse_ics(TWO_EVENTS).unwrap();
+
+    // Weekly standup: COUNT=4 (Mar 3, 10, 17, 24) minus the Mar 17 EXDATE.
+    // DST starts Mar 8, so 09:00 local moves from 14:00Z to 13:00Z.
+    let standup = events[0].expand(None, None).unwrap();
+    let starts: Vec<_> = standup.iter().map(|e| e.start).collect();
+    assert_eq!(
+        starts,
+        vec![
+            Utc.with_ymd_and_hms(2026, 3, 3, 14, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 3, 10, 13, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 3, 24, 13, 0, 0).unwrap(),
+        ]
+    );
+    for instance in &standup {
+        assert_eq!((instance.end - instance.start).num_minutes(), 30);
+    }
+
+    // Design review: a single instance.
+    let review = events[1].expand(None, None).unwrap();
+    assert_eq!(review.len(), 1);
+    assert_eq!(
+        review[0].start,
+        Utc.with_ymd_and_hms(2026, 3, 5, 18, 0, 0).unwrap()
+    );
+    assert_eq!(
+        review[0].end,
+        Utc.with_ymd_and_hms(2026, 3, 5, 19, 30, 0).unwrap()
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+#[test]
+fn unterminated_vevent_is_rejected() {
+    let ics = "BEGIN:VEVENT\nDTSTART:20260401T100000Z\n";
+    assert!(matches!(parse_ics(ics), Err(TruthError::InvalidIcs(_))));
+}
+
+#[test]
+fn vevent_without_dtstart_is_rejected() {
+    let ics = "BEGIN:VEVENT\nSUMMARY:No start\nEND:VEVENT\n";
+    assert!(matches!(parse_ics(ics), Err(TruthError::InvalidIcs(_))));
+}
+
+#[test]
+fn unknown_tzid_is_rejected() {
+    let ics = "BEGIN:VEVENT\nDTSTART;TZID=Mars/Olympus:20260401T100000\nEND:VEVENT\n";
+    assert!(matches!(
+        parse_ics(ics),
+        Err(TruthError::InvalidTimezone(_))
+    ));
+}
+
+#[test]
+fn overflowing_duration_is_rejected() {
+    let ics = "BEGIN:VEVENT\nDTSTART:20260401T100000Z\nDURATION:P9999999999999999W\nEND:VEVENT\n";
+    assert!(matches!(
+        parse_ics(ics),
+        Err(TruthError::InvalidDuration(_))
+    ));
+}
+
+#[test]
+fn dtend_before_dtstart_is_rejected() {
+    let ics = "BEGIN:VEVENT\nDTSTART:20260401T100000Z\nDTEND:20260401T090000Z\nEND:VEVENT\n";
+    assert!(matches!(parse_ics(ics), Err(TruthError::InvalidIcs(_))));
+}
+
+#[test]
+fn dtend_span_too_long_for_minutes_is_rejected() {
+    let ics = "BEGIN:VEVENT\nDTSTART:00010101T000000Z\nDTEND:99991231T000000Z\nEND:VEVENT\n";
+    assert!(matches!(parse_ics(ics), Err(TruthError::InvalidIcs(_))));
+}
+
+#[test]
+fn dtstart_in_dst_gap_uses_the_pre_gap_offset() {
+    // 02:30 does not exist on 2026-03-08 in New York; it reads as 02:30 EST,
+    // i.e. 03:30 EDT, and DTEND at 04:00 EDT leaves 30 minutes.
+    let ics = "BEGIN:VEVENT\n\
+DTSTART;TZID=America/New_York:20260308T023000\n\
+DTEND;TZID=America/New_York:20260308T040000\n\
+END:VEVENT\n";
+    let events = parse_ics(ics).unwrap();
+    assert_eq!(events[0].dtstart, "2026-03-08T03:30:00");
+    assert_eq!(events[0].duration_minutes, 30);
+    let instances = events[0].expand(None, None).unwrap();
+    assert_eq!(
+        instances[0].start,
+        Utc.with_ymd_and_hms(2026, 3, 8, 7, 30, 0).unwrap()
+    );
+}
@@ -294,3 +294,54 @@ fn second_tuesday_of_jan_and_jun() {
     assert_eq!(d[2], (2027, 1, 12), "2nd Tue of Jan 2027");
     assert_eq!(d[3], (2027, 6, 8), "2nd Tue of Jun 2027");
 }
+
+// ===========================================================================
+// 9. COUNT in the RRULE and an external max count — the tighter bound wins
+// ===========================================================================
+
+#[test]
+fn external_count_below_rrule_count_wins() {
+    let result = expand_rrule(
+        "FREQ=DAILY;COUNT=10",
+        "2026-06-01T08:00:00",
+        30,
+        "America/New_York",
+        None,
+        Some(5),
+    )
+    .expect("should expand with both counts");
+
+    assert_eq!(result.len(), 5, "external count 5 is tighter than COUNT=10");
+    assert_eq!(dates(&result)[4], (2026, 6, 5));
+}
+
+#[test]
+fn rrule_count_below_external_count_wins() {
+    let result = expand_rrule(
+        "FREQ=DAILY;COUNT=3",
+        "2026-06-01T08:00:00",
+        30,
+        "America/New_York",
+        None,
+        Some(5),
+    )
+    .expect("should expand with both counts");
+
+    assert_eq!(result.len(), 3, "COUNT=3 is tighter than external count 5");
+}
+
+#[test]
+fn external_count_above_u16_range_does_not_wrap() {
+    // 65_541 would wrap to 5 if narrowed to u16; COUNT=10 must still govern.
+    let result = expand_rrule(
+        "FREQ=DAILY;COUNT=10",
+        "2026-06-01T08:00:00",
+        30,
+        "UTC",
+        None,
+        Some(65_541),
+    )
+    .expect("should expand with a large external count");
+
+    assert_eq!(result.len(), 10);
+}
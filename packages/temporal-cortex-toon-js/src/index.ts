@@ -14,6 +14,8 @@ const require = createRequire(import.meta.url);
 const wasm = require("../wasm/toon_wasm.cjs") as {
   encode: (json: string) => string;
   decode: (toon: string) => string;
+  roundtrip: (json: string) => boolean;
+  validateToon: (toon: string) => boolean;
 };
 
 /**
@@ -33,3 +35,22 @@ export function encode(json: string): string {
 export function decode(toon: string): string {
   return wasm.decode(toon);
 }
+
+/**
+ * Check whether a JSON string survives a TOON encode → decode round trip.
+ * Numbers are compared by value, so `1.0` and `1` match.
+ * @param json - A valid JSON string
+ * @returns true if the decoded value equals the input
+ */
+export function roundtrip(json: string): boolean {
+  return wasm.roundtrip(json);
+}
+
+/**
+ * Check that a TOON string decodes.
+ * @param toon - The TOON string to check
+ * @returns true; throws with the parse error if the input is not valid TOON
+ */
+export function validateToon(toon: string): boolean {
+  return wasm.validateToon(toon);
+}
@@ -1,5 +1,5 @@
 import { describe, it, expect } from "vitest";
-import { encode, decode } from "../src/index.js";
+import { encode, decode, roundtrip, validateToon } from "../src/index.js";
 
 describe("encode", () => {
   it("encodes a flat object", () => {
@@ -119,3 +119,30 @@ describe("roundtrip", () => {
     });
   }
 });
+
+describe("roundtrip (WASM)", () => {
+  it("returns true for a clean round trip", () => {
+    expect(
+      roundtrip('{"users":[{"id":1,"name":"Alice"}],"score":1.0}'),
+    ).toBe(true);
+  });
+
+  it("returns false for input TOON cannot preserve", () => {
+    // An array holding an empty object decodes as an empty array.
+    expect(roundtrip('{"a":[{}]}')).toBe(false);
+  });
+
+  it("throws on invalid JSON", () => {
+    expect(() => roundtrip("not json")).toThrow();
+  });
+});
+
+describe("validateToon", () => {
+  it("returns true for valid TOON", () => {
+    expect(validateToon("name: Alice\ntags[2]: a,b")).toBe(true);
+  });
+
+  it("throws with the parse error for malformed TOON", () => {
+    expect(() => validateToon('tags[2]: "a,b')).toThrow(/Unterminated/);
+  });
+});
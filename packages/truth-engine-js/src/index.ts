@@ -21,16 +21,63 @@ const wasm = require("../wasm/truth_engine_wasm.cjs") as {
     timezone: string,
     until?: string,
     max_count?: number,
+    duration_mode?: string,
   ) => string;
+  expandRRuleDiagnostics: (
+    rrule: string,
+    dtstart: string,
+    duration_minutes: number,
+    timezone: string,
+    until?: string,
+    max_count?: number,
+  ) => string;
+  validateRRule: (rrule: string) => void;
   findConflicts: (events_a_json: string, events_b_json: string) => string;
+  conflictsWithRecurrence: (
+    proposed_json: string,
+    rrule: string,
+    dtstart: string,
+    duration_minutes: number,
+    timezone: string,
+  ) => string;
   findFreeSlots: (events_json: string, window_start: string, window_end: string) => string;
-  mergeAvailability: (streams_json: string, window_start: string, window_end: string, opaque: boolean) => string;
+  rruleFreeBusy: (
+    rrule: string,
+    dtstart: string,
+    duration_minutes: number,
+    timezone: string,
+    window_start: string,
+    window_end: string,
+  ) => string;
+  shiftEvents: (events_json: string, by_minutes: number) => string;
+  mergeAvailability: (
+    streams_json: string,
+    window_start: string,
+    window_end: string,
+    opaque: boolean,
+    privacy?: string,
+  ) => string;
   findFirstFreeAcross: (streams_json: string, window_start: string, window_end: string, min_duration_minutes: number) => string;
+  findAllFreeAcross: (streams_json: string, window_start: string, window_end: string, min_duration_minutes: number) => string;
+  nextFreeSlots: (streams_json: string, after: string, window_end: string, min_duration_minutes: number, n: number) => string;
+  findCommonFree: (participants_json: string, min_duration_minutes: number) => string;
+  findSlotAcrossTimezones: (attendees_json: string, duration_minutes: number, window_start: string, window_end: string) => string;
+  utilization: (
+    streams_json: string,
+    timezone: string,
+    work_start_hour: number,
+    work_end_hour: number,
+    window_start: string,
+    window_end: string,
+  ) => number;
+  bucketByDay: (availability_json: string, timezone: string) => string;
+  parseIcs: (ics: string) => string;
   convertTimezone: (datetime: string, target_timezone: string) => string;
   computeDuration: (start: string, end: string) => string;
   adjustTimestamp: (datetime: string, adjustment: string, timezone: string) => string;
   resolveRelative: (anchor: string, expression: string, timezone: string) => string;
   resolveRelativeWithOptions: (anchor: string, expression: string, timezone: string, options_json: string) => string;
+  parseRelativeWindow: (spec: string, now: string, timezone: string) => string;
 };
 
 // ---------------------------------------------------------------------------
@@ -45,7 +92,25 @@ export interface TimeRange {
 export interface Conflict {
   event_a: TimeRange;
   event_b: TimeRange;
+  /** Start of the overlapping window (RFC 3339). */
+  overlap_start: string;
+  /** End of the overlapping window (RFC 3339). */
+  overlap_end: string;
   overlap_minutes: number;
+  /** How the events relate: "touch", "partial", "contained", or "identical". */
+  severity: "touch" | "partial" | "contained" | "identical";
+}
+
+export interface Diagnostic {
+  /** Stable machine-readable code: "instance_cap_reached", "skipped_months", or "dtstart_not_in_rule". */
+  code: string;
+  /** Human-readable explanation, suitable for showing as a note in a UI. */
+  message: string;
+}
+
+export interface ExpansionDiagnostics {
+  events: TimeRange[];
+  diagnostics: Diagnostic[];
 }
 
 export interface FreeSlot {
@@ -54,6 +119,13 @@ export interface FreeSlot {
   duration_minutes: number;
 }
 
+export interface RRuleFreeBusy {
+  /** Instances overlapping the window, unclipped. */
+  busy: TimeRange[];
+  /** Gaps between the instances within the window. */
+  free: FreeSlot[];
+}
+
 // ---------------------------------------------------------------------------
 // Public API
 // ---------------------------------------------------------------------------
@@ -66,7 +138,9 @@ export interface FreeSlot {
  * @param durationMinutes - Duration of each instance in minutes
  * @param timezone - IANA timezone (e.g., "America/Los_Angeles")
  * @param until - Optional end boundary (local datetime string)
- * @param maxCount - Optional maximum number of instances to generate
+ * @param maxCount - Optional maximum number of instances to generate; if the RRULE also has COUNT, the smaller wins
+ * @param durationMode - `"fixed"` (default) adds the duration in absolute time;
+ *   `"wallclock"` adds it in local time, so ends keep their local time across DST
  * @returns Array of {start, end} objects with RFC 3339 datetime strings
  */
 export function expandRRule(
@@ -76,6 +150,7 @@ export function expandRRule(
   timezone: string,
   until?: string,
   maxCount?: number,
+  durationMode?: DurationMode,
 ): TimeRange[] {
   const json = wasm.expandRRule(
     rrule,
@@ -84,6 +159,50 @@ export function expandRRule(
     timezone,
     until ?? undefined,
     maxCount ?? undefined,
+    durationMode ?? undefined,
+  );
+  return JSON.parse(json);
+}
+
+/**
+ * Check that an RFC 5545 RRULE is valid without expanding it. Needs no DTSTART
+ * or timezone, so it suits validating a rule as the user types it.
+ *
+ * @param rrule - RFC 5545 recurrence rule (e.g., "FREQ=WEEKLY;BYDAY=TU,TH")
+ * @throws If the rule is invalid, with a message naming the problem
+ */
+export function validateRRule(rrule: string): void {
+  wasm.validateRRule(rrule);
+}
+
+/**
+ * Expand an RFC 5545 RRULE and report notable conditions found along the way,
+ * such as months skipped by `BYMONTHDAY=31` or an expansion cut off at the
+ * instance cap.
+ *
+ * @param rrule - RFC 5545 recurrence rule (e.g., "FREQ=MONTHLY;BYMONTHDAY=31")
+ * @param dtstart - Local datetime for the first occurrence (e.g., "2026-01-31T09:00:00")
+ * @param durationMinutes - Duration of each instance in minutes
+ * @param timezone - IANA timezone (e.g., "America/Los_Angeles")
+ * @param until - Optional end boundary (local datetime string)
+ * @param maxCount - Optional maximum number of instances to generate
+ * @returns The instances `expandRRule` would return, plus diagnostics
+ */
+export function expandRRuleDiagnostics(
+  rrule: string,
+  dtstart: string,
+  durationMinutes: number,
+  timezone: string,
+  until?: string,
+  maxCount?: number,
+): ExpansionDiagnostics {
+  const json = wasm.expandRRuleDiagnostics(
+    rrule,
+    dtstart,
+    durationMinutes,
+    timezone,
+    until ?? undefined,
+    maxCount ?? undefined,
   );
   return JSON.parse(json);
 }
@@ -103,6 +222,30 @@ export function findConflicts(
   return JSON.parse(json);
 }
 
+/**
+ * Find the occurrences of a recurring event that overlap one proposed event.
+ *
+ * Only occurrences around the proposed time are expanded, so this is much
+ * cheaper than expanding the series and calling `findConflicts`.
+ *
+ * @param proposed - The proposed event
+ * @param rrule - RFC 5545 RRULE string (e.g., "FREQ=WEEKLY;BYDAY=MO")
+ * @param dtstart - Local datetime of the series start (e.g., "2026-03-02T09:00:00")
+ * @param durationMinutes - Duration of each occurrence in minutes
+ * @param timezone - IANA timezone of the series (e.g., "America/Los_Angeles")
+ * @returns Conflicts with the proposed event as event_a and the occurrence as event_b
+ */
+export function conflictsWithRecurrence(
+  proposed: TimeRange,
+  rrule: string,
+  dtstart: string,
+  durationMinutes: number,
+  timezone: string,
+): Conflict[] {
+  const json = wasm.conflictsWithRecurrence(JSON.stringify(proposed), rrule, dtstart, durationMinutes, timezone);
+  return JSON.parse(json);
+}
+
 /**
  * Find free time slots within a given window, given a list of busy events.
  *
@@ -120,6 +263,44 @@ export function findFreeSlots(
   return JSON.parse(json);
 }
 
+/**
+ * Expand a recurring event over a window and compute the free time around it,
+ * in one call instead of `expandRRule` followed by `findFreeSlots`.
+ *
+ * @param rrule - RFC 5545 RRULE string (e.g., "FREQ=WEEKLY;BYDAY=TU,TH")
+ * @param dtstart - Local datetime string (e.g., "2026-02-17T14:00:00")
+ * @param durationMinutes - Duration of each instance in minutes
+ * @param timezone - IANA timezone (e.g., "America/Los_Angeles")
+ * @param windowStart - Start of the window (ISO 8601 datetime)
+ * @param windowEnd - End of the window (ISO 8601 datetime)
+ * @returns The instances overlapping the window and the free slots between them
+ */
+export function rruleFreeBusy(
+  rrule: string,
+  dtstart: string,
+  durationMinutes: number,
+  timezone: string,
+  windowStart: string,
+  windowEnd: string,
+): RRuleFreeBusy {
+  const json = wasm.rruleFreeBusy(rrule, dtstart, durationMinutes, timezone, windowStart, windowEnd);
+  return JSON.parse(json);
+}
+
+/**
+ * Move every event by a number of minutes, keeping each event's duration.
+ *
+ * The shift is in absolute time, so instances keep their spacing across DST.
+ *
+ * @param events - Events to move
+ * @param byMinutes - Minutes to shift by (negative moves events earlier)
+ * @returns The shifted events, in input order
+ */
+export function shiftEvents(events: TimeRange[], byMinutes: number): TimeRange[] {
+  const json = wasm.shiftEvents(JSON.stringify(events), byMinutes);
+  return JSON.parse(json);
+}
+
 // ---------------------------------------------------------------------------
 // Multi-stream availability types
 // ---------------------------------------------------------------------------
@@ -127,14 +308,30 @@ export function findFreeSlots(
 export interface EventStream {
   stream_id: string;
   events: TimeRange[];
+  /** Calendar name, echoed in busy-block `sources` under "full" privacy. */
+  display_name?: string | null;
+  /** Calendar color, echoed like `display_name`. */
+  color?: string | null;
+}
+
+export interface BusySource {
+  stream_id: string;
+  display_name: string | null;
+  color: string | null;
 }
 
 export interface BusyBlock {
   start: string;
   end: string;
   source_count: number;
+  /** Contributing streams; empty unless privacy is "full". */
+  sources: BusySource[];
 }
 
+export type DurationMode = "fixed" | "wallclock";
+
+export type PrivacyLevel = "full" | "opaque" | "free_only";
+
 export interface UnifiedAvailability {
   busy: BusyBlock[];
   free: FreeSlot[];
@@ -143,6 +340,55 @@ export interface UnifiedAvailability {
   privacy: string;
 }
 
+export interface Participant {
+  /** The participant's calendars. */
+  streams: EventStream[];
+  /** Start of the participant's availability window (ISO 8601 datetime). */
+  window_start: string;
+  /** End of the participant's availability window (ISO 8601 datetime). */
+  window_end: string;
+}
+
+export interface TzParticipant {
+  /** The attendee's event streams (one per calendar). */
+  streams: EventStream[];
+  /** IANA timezone the working hours are local to (e.g., "Europe/London"). */
+  timezone: string;
+  /** Local hour the working day starts (0-23). */
+  work_start_hour: number;
+  /** Local hour the working day ends (1-24). */
+  work_end_hour: number;
+}
+
+export interface WorkingHours {
+  /** IANA timezone the working hours are local to (e.g., "America/New_York"). */
+  timezone: string;
+  /** Local hour the working day starts (0-23). */
+  start_hour: number;
+  /** Local hour the working day ends (1-24). */
+  end_hour: number;
+}
+
+export interface DayAvailability {
+  /** Local calendar date (YYYY-MM-DD) in the bucketing timezone. */
+  date: string;
+  busy: BusyBlock[];
+  free: FreeSlot[];
+}
+
+export interface CalendarEvent {
+  summary: string | null;
+  /** DTSTART as a local datetime in `timezone` (e.g., "2026-03-03T09:00:00"). */
+  dtstart: string;
+  /** IANA timezone from the DTSTART TZID, or "UTC" for UTC and floating times. */
+  timezone: string;
+  duration_minutes: number;
+  /** RRULE value without the "RRULE:" prefix, or null for a single event. */
+  rrule: string | null;
+  /** EXDATE values as local datetimes in `timezone`. */
+  exdates: string[];
+}
+
 // ---------------------------------------------------------------------------
 // One-time hint for multi-calendar users
 // ---------------------------------------------------------------------------
@@ -181,6 +427,8 @@ export function _resetHint(): void {
  * @param windowStart - Start of the analysis window (ISO 8601 datetime)
  * @param windowEnd - End of the analysis window (ISO 8601 datetime)
  * @param opaque - If true, hide source counts in busy blocks (privacy mode). Default: true.
+ * @param privacy - Optional privacy level overriding `opaque`: `"full"`, `"opaque"`,
+ *   or `"free_only"` (return free slots only, with `busy` left empty)
  * @returns Unified availability with busy blocks and free slots
  */
 export function mergeAvailability(
@@ -188,9 +436,16 @@ export function mergeAvailability(
   windowStart: string,
   windowEnd: string,
   opaque: boolean = true,
+  privacy?: PrivacyLevel,
 ): UnifiedAvailability {
   _maybeShowHint(streams.length);
-  const json = wasm.mergeAvailability(JSON.stringify(streams), windowStart, windowEnd, opaque);
+  const json = wasm.mergeAvailability(
+    JSON.stringify(streams),
+    windowStart,
+    windowEnd,
+    opaque,
+    privacy ?? undefined,
+  );
   return JSON.parse(json);
 }
 
@@ -214,6 +469,145 @@ export function findFirstFreeAcross(
   return JSON.parse(json);
 }
 
+/**
+ * Find every free slot of at least `minDurationMinutes` across N merged
+ * event streams.
+ *
+ * @param streams - Array of event streams
+ * @param windowStart - Start of the search window (ISO 8601 datetime)
+ * @param windowEnd - End of the search window (ISO 8601 datetime)
+ * @param minDurationMinutes - Minimum slot duration in minutes
+ * @returns Array of qualifying free slots, sorted by start
+ */
+export function findAllFreeAcross(
+  streams: EventStream[],
+  windowStart: string,
+  windowEnd: string,
+  minDurationMinutes: number,
+): FreeSlot[] {
+  const json = wasm.findAllFreeAcross(JSON.stringify(streams), windowStart, windowEnd, minDurationMinutes);
+  return JSON.parse(json);
+}
+
+/**
+ * Find the next `n` free slots of at least `minDurationMinutes` across N merged
+ * event streams, starting at or after `after` — e.g. the openings to suggest
+ * in a "pick a time" dropdown.
+ *
+ * @param streams - Array of event streams
+ * @param after - Reference time; a slot under way then counts from it (ISO 8601 datetime)
+ * @param windowEnd - End of the search window (ISO 8601 datetime)
+ * @param minDurationMinutes - Minimum slot duration in minutes
+ * @param n - Maximum number of slots to return
+ * @returns Up to `n` qualifying free slots, sorted by start
+ */
+export function nextFreeSlots(
+  streams: EventStream[],
+  after: string,
+  windowEnd: string,
+  minDurationMinutes: number,
+  n: number,
+): FreeSlot[] {
+  const json = wasm.nextFreeSlots(JSON.stringify(streams), after, windowEnd, minDurationMinutes, n);
+  return JSON.parse(json);
+}
+
+/**
+ * Find time slots free for every participant, each with their own window.
+ *
+ * @param participants - Array of participants with their streams and windows
+ * @param minDurationMinutes - Minimum slot duration in minutes
+ * @returns Array of free slot objects shared by all participants
+ */
+export function findCommonFree(
+  participants: Participant[],
+  minDurationMinutes: number,
+): FreeSlot[] {
+  const json = wasm.findCommonFree(JSON.stringify(participants), minDurationMinutes);
+  return JSON.parse(json);
+}
+
+/**
+ * Find the earliest meeting that fits inside every attendee's local working
+ * hours and overlaps none of their events.
+ *
+ * @param attendees - Attendees with their streams, timezone, and working hours
+ * @param durationMinutes - Meeting length in minutes
+ * @param windowStart - Start of the search window (ISO 8601 datetime)
+ * @param windowEnd - End of the search window (ISO 8601 datetime)
+ * @returns The meeting's {start, end}, or null if no slot fits
+ */
+export function findSlotAcrossTimezones(
+  attendees: TzParticipant[],
+  durationMinutes: number,
+  windowStart: string,
+  windowEnd: string,
+): TimeRange | null {
+  const json = wasm.findSlotAcrossTimezones(JSON.stringify(attendees), durationMinutes, windowStart, windowEnd);
+  return JSON.parse(json);
+}
+
+/**
+ * Compute the fraction of working time within a window that is busy.
+ *
+ * Overlapping events count once, and busy time outside the working hours is
+ * ignored, so the result stays between 0 and 1.
+ *
+ * @param streams - Array of event streams to merge
+ * @param workingHours - Local working hours applied to every date
+ * @param windowStart - Start of the window (ISO 8601 datetime)
+ * @param windowEnd - End of the window (ISO 8601 datetime)
+ * @returns Busy working time divided by total working time (0 if there is none)
+ */
+export function utilization(
+  streams: EventStream[],
+  workingHours: WorkingHours,
+  windowStart: string,
+  windowEnd: string,
+): number {
+  return wasm.utilization(
+    JSON.stringify(streams),
+    workingHours.timezone,
+    workingHours.start_hour,
+    workingHours.end_hour,
+    windowStart,
+    windowEnd,
+  );
+}
+
+/**
+ * Split a merged availability into per-day buckets in a local timezone.
+ *
+ * Blocks that cross local midnight are split and clipped to each day; DST
+ * days keep their true 23- or 25-hour length.
+ *
+ * @param availability - Result of `mergeAvailability`
+ * @param timezone - IANA timezone defining local day boundaries (e.g., "America/New_York")
+ * @returns Array of {date, busy, free} objects, one per local date in the window
+ */
+export function bucketByDay(
+  availability: UnifiedAvailability,
+  timezone: string,
+): DayAvailability[] {
+  const json = wasm.bucketByDay(JSON.stringify(availability), timezone);
+  return JSON.parse(json);
+}
+
+/**
+ * Parse the VEVENTs of an iCalendar (.ics) document.
+ *
+ * Covers DTSTART, DTEND/DURATION, RRULE, EXDATE, SUMMARY, and TZID. Pass
+ * `rrule`, `dtstart`, `duration_minutes`, and `timezone` to `expandRRule`
+ * to get concrete instances of a recurring event.
+ *
+ * @param ics - iCalendar text
+ * @returns Array of parsed events in document order
+ */
+export function parseIcs(ics: string): CalendarEvent[] {
+  const json = wasm.parseIcs(ics);
+  return JSON.parse(json);
+}
+
 // ---------------------------------------------------------------------------
 // Temporal computation types
 // ---------------------------------------------------------------------------
@@ -340,3 +734,23 @@ export function resolveRelativeWithOptions(
   const json = wasm.resolveRelativeWithOptions(anchor, expression, timezone, JSON.stringify(options));
   return JSON.parse(json);
 }
+
+/**
+ * Resolve a named window spec to a `{start, end}` range.
+ *
+ * Day, week (Monday start) and month boundaries are local midnights in the
+ * given timezone, so "today" is the local calendar day.
+ *
+ * @param spec - "today", "tomorrow", "this_week", "next_week", "this_month", "next_month", or "next_N_days" (e.g., "next_7_days")
+ * @param now - RFC 3339 datetime string (the "now" reference point)
+ * @param timezone - IANA timezone whose local calendar defines the window
+ * @returns Window start and exclusive end as RFC 3339 UTC strings
+ */
+export function parseRelativeWindow(
+  spec: string,
+  now: string,
+  timezone: string,
+): TimeRange {
+  const json = wasm.parseRelativeWindow(spec, now, timezone);
+  return JSON.parse(json);
+}
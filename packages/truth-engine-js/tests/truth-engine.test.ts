@@ -1,7 +1,7 @@
 import { describe, it, expect, vi, beforeEach } from "vitest";
 import {
-  expandRRule, findConflicts, findFreeSlots, mergeAvailability, _resetHint,
-  convertTimezone, computeDuration, adjustTimestamp, resolveRelative,
+  expandRRule, expandRRuleDiagnostics, validateRRule, findConflicts, conflictsWithRecurrence, findFreeSlots, rruleFreeBusy, shiftEvents, mergeAvailability, findCommonFree, findAllFreeAcross, nextFreeSlots, findSlotAcrossTimezones, utilization, bucketByDay, parseIcs, _resetHint,
+  convertTimezone, computeDuration, adjustTimestamp, resolveRelative, parseRelativeWindow,
 } from "../src/index.js";
 
 describe("expandRRule", () => {
@@ -48,11 +48,62 @@ describe("expandRRule", () => {
     expect(() => expandRRule("", "2026-02-17T14:00:00", 60, "UTC")).toThrow();
   });
 
+  it("rejects a blank RRULE with a clear error", () => {
+    for (const rule of ["", "   "]) {
+      expect(() => expandRRule(rule, "2026-02-17T14:00:00", 60, "UTC")).toThrow("Invalid RRULE: empty RRULE string");
+    }
+  });
+
   it("throws on invalid timezone", () => {
     expect(() => expandRRule("FREQ=DAILY;COUNT=1", "2026-02-17T14:00:00", 60, "Not/Real")).toThrow();
   });
 });
 
+describe("expandRRule durationMode", () => {
+  // 2026-03-08 is the US spring-forward day: 23 hours long in New York.
+  const springForward = (mode?: "fixed" | "wallclock") =>
+    expandRRule("FREQ=DAILY;COUNT=1", "2026-03-08T00:00:00", 1440, "America/New_York", undefined, undefined, mode)[0];
+
+  it("fixed keeps the absolute duration (default)", () => {
+    expect(springForward()).toEqual(springForward("fixed"));
+    expect(springForward("fixed").end).toBe("2026-03-09T05:00:00+00:00");
+  });
+
+  it("wallclock ends at the same local time", () => {
+    expect(springForward("wallclock").end).toBe("2026-03-09T04:00:00+00:00");
+  });
+});
+
+describe("expandRRuleDiagnostics", () => {
+  it("reports months skipped by BYMONTHDAY=31", () => {
+    const result = expandRRuleDiagnostics(
+      "FREQ=MONTHLY;BYMONTHDAY=31", "2026-01-31T09:00:00", 60, "UTC", "2026-12-31T23:59:59",
+    );
+    expect(result.events).toHaveLength(7);
+    expect(result.diagnostics[0].code).toBe("skipped_months");
+  });
+
+  it("returns no diagnostics for a plain rule", () => {
+    const result = expandRRuleDiagnostics("FREQ=DAILY;COUNT=3", "2026-02-17T14:00:00", 60, "UTC");
+    expect(result.events).toHaveLength(3);
+    expect(result.diagnostics).toEqual([]);
+  });
+});
+
+describe("validateRRule", () => {
+  it("accepts a valid rule", () => {
+    expect(() => validateRRule("FREQ=WEEKLY;BYDAY=TU,TH")).not.toThrow();
+  });
+
+  it("rejects a rule without FREQ", () => {
+    expect(() => validateRRule("BYDAY=TU;COUNT=3")).toThrow(/FREQ/);
+  });
+
+  it("names an unknown weekday", () => {
+    expect(() => validateRRule("FREQ=WEEKLY;BYDAY=XX")).toThrow(/`XX` is not a valid weekday/);
+  });
+});
+
 describe("findConflicts", () => {
   it("detects overlapping events", () => {
     const a = [{ start: "2026-02-17T14:00:00+00:00", end: "2026-02-17T15:00:00+00:00" }];
@@ -60,6 +111,15 @@ describe("findConflicts", () => {
     const conflicts = findConflicts(a, b);
     expect(conflicts).toHaveLength(1);
     expect(conflicts[0].overlap_minutes).toBe(30);
+    expect(conflicts[0].overlap_start).toBe("2026-02-17T14:30:00+00:00");
+    expect(conflicts[0].overlap_end).toBe("2026-02-17T15:00:00+00:00");
+    expect(conflicts[0].severity).toBe("partial");
+  });
+
+  it("classifies a meeting inside another as contained", () => {
+    const a = [{ start: "2026-02-17T14:00:00+00:00", end: "2026-02-17T16:00:00+00:00" }];
+    const b = [{ start: "2026-02-17T14:30:00+00:00", end: "2026-02-17T15:00:00+00:00" }];
+    expect(findConflicts(a, b)[0].severity).toBe("contained");
   });
 
   it("returns empty for non-overlapping", () => {
@@ -75,6 +135,38 @@ describe("findConflicts", () => {
   });
 });
 
+describe("conflictsWithRecurrence", () => {
+  it("reports only the weekly occurrence the proposed meeting overlaps", () => {
+    const proposed = { start: "2026-03-16T09:15:00+00:00", end: "2026-03-16T10:00:00+00:00" };
+    const conflicts = conflictsWithRecurrence(proposed, "FREQ=WEEKLY;BYDAY=MO", "2026-03-02T09:00:00", 30, "UTC");
+    expect(conflicts).toHaveLength(1);
+    expect(conflicts[0].event_b.start).toBe("2026-03-16T09:00:00+00:00");
+    expect(conflicts[0].overlap_minutes).toBe(15);
+  });
+
+  it("returns empty when the proposed meeting misses every occurrence", () => {
+    const proposed = { start: "2026-03-17T09:00:00+00:00", end: "2026-03-17T10:00:00+00:00" };
+    expect(conflictsWithRecurrence(proposed, "FREQ=WEEKLY;BYDAY=MO", "2026-03-02T09:00:00", 30, "UTC")).toHaveLength(0);
+  });
+});
+
+describe("reversed events", () => {
+  const reversed = { start: "2026-02-17T15:00:00+00:00", end: "2026-02-17T14:00:00+00:00" };
+
+  it("findConflicts rejects an event whose end is before its start", () => {
+    expect(() => findConflicts([reversed], [])).toThrow(
+      "end '2026-02-17T14:00:00+00:00' is before start '2026-02-17T15:00:00+00:00'",
+    );
+  });
+
+  it("mergeAvailability rejects a reversed event inside a stream", () => {
+    const streams = [{ stream_id: "a", events: [reversed] }];
+    expect(() =>
+      mergeAvailability(streams, "2026-02-17T08:00:00+00:00", "2026-02-17T18:00:00+00:00"),
+    ).toThrow("is before start");
+  });
+});
+
 describe("findFreeSlots", () => {
   it("finds gaps between events", () => {
     const events = [
@@ -96,6 +188,58 @@ describe("findFreeSlots", () => {
   });
 });
 
+describe("rruleFreeBusy", () => {
+  it("returns weekly instances and the gaps between them over two weeks", () => {
+    const result = rruleFreeBusy(
+      "FREQ=WEEKLY;BYDAY=MO,WE",
+      "2026-03-02T10:00:00",
+      60,
+      "UTC",
+      "2026-03-09T00:00:00",
+      "2026-03-23T00:00:00",
+    );
+    expect(result.busy.map((e) => e.start)).toEqual([
+      "2026-03-09T10:00:00+00:00",
+      "2026-03-11T10:00:00+00:00",
+      "2026-03-16T10:00:00+00:00",
+      "2026-03-18T10:00:00+00:00",
+    ]);
+    expect(result.free).toHaveLength(5);
+    expect(result.free[0]).toEqual({
+      start: "2026-03-09T00:00:00+00:00",
+      end: "2026-03-09T10:00:00+00:00",
+      duration_minutes: 600,
+    });
+    expect(result.free[1].start).toBe("2026-03-09T11:00:00+00:00");
+    expect(result.free[1].end).toBe("2026-03-11T10:00:00+00:00");
+  });
+
+  it("throws on an invalid rule", () => {
+    expect(() =>
+      rruleFreeBusy("FREQ=SOMETIMES", "2026-03-02T10:00:00", 60, "UTC", "2026-03-09T00:00:00", "2026-03-23T00:00:00"),
+    ).toThrow();
+  });
+});
+
+describe("shiftEvents", () => {
+  it("moves a series 30 minutes later and keeps durations", () => {
+    const events = [
+      { start: "2026-02-17T14:00:00+00:00", end: "2026-02-17T15:00:00+00:00" },
+      { start: "2026-02-19T14:00:00+00:00", end: "2026-02-19T15:00:00+00:00" },
+    ];
+    const shifted = shiftEvents(events, 30);
+    expect(shifted).toEqual([
+      { start: "2026-02-17T14:30:00+00:00", end: "2026-02-17T15:30:00+00:00" },
+      { start: "2026-02-19T14:30:00+00:00", end: "2026-02-19T15:30:00+00:00" },
+    ]);
+  });
+
+  it("moves events earlier with a negative shift", () => {
+    const events = [{ start: "2026-02-17T14:00:00+00:00", end: "2026-02-17T15:00:00+00:00" }];
+    expect(shiftEvents(events, -90)[0].start).toBe("2026-02-17T12:30:00+00:00");
+  });
+});
+
 describe("mergeAvailability hint", () => {
   beforeEach(() => {
     _resetHint();
@@ -138,6 +282,174 @@ describe("mergeAvailability hint", () => {
   });
 });
 
+describe("mergeAvailability empty input", () => {
+  it("leaves the whole window free with no streams", () => {
+    const result = mergeAvailability([], "2026-03-16T08:00:00Z", "2026-03-16T17:00:00Z");
+    expect(result.busy).toHaveLength(0);
+    expect(result.free).toEqual([
+      { start: "2026-03-16T08:00:00+00:00", end: "2026-03-16T17:00:00+00:00", duration_minutes: 540 },
+    ]);
+  });
+});
+
+describe("mergeAvailability privacy", () => {
+  it("free_only returns free slots without busy blocks", () => {
+    const streams = [
+      { stream_id: "work", events: [{ start: "2026-02-17T09:00:00+00:00", end: "2026-02-17T10:00:00+00:00" }] },
+    ];
+    const full = mergeAvailability(streams, "2026-02-17T08:00:00+00:00", "2026-02-17T12:00:00+00:00", false);
+    const freeOnly = mergeAvailability(streams, "2026-02-17T08:00:00+00:00", "2026-02-17T12:00:00+00:00", true, "free_only");
+    expect(freeOnly.busy).toHaveLength(0);
+    expect(freeOnly.free).toEqual(full.free);
+    expect(freeOnly.privacy).toBe("free_only");
+  });
+});
+
+describe("findAllFreeAcross", () => {
+  it("returns every qualifying slot in order", () => {
+    const streams = [
+      { stream_id: "work", events: [
+        { start: "2026-03-16T09:00:00Z", end: "2026-03-16T10:00:00Z" },
+        { start: "2026-03-16T12:00:00Z", end: "2026-03-16T13:00:00Z" },
+      ] },
+      { stream_id: "personal", events: [{ start: "2026-03-16T13:15:00Z", end: "2026-03-16T15:00:00Z" }] },
+    ];
+    const slots = findAllFreeAcross(streams, "2026-03-16T08:00:00Z", "2026-03-16T17:00:00Z", 30);
+    expect(slots.map((s) => s.duration_minutes)).toEqual([60, 120, 120]);
+    expect(slots[1].start).toContain("2026-03-16T10:00:00");
+  });
+});
+
+describe("nextFreeSlots", () => {
+  it("returns only the requested number of upcoming slots", () => {
+    const streams = [
+      { stream_id: "work", events: [
+        { start: "2026-03-16T09:00:00Z", end: "2026-03-16T10:00:00Z" },
+        { start: "2026-03-16T11:00:00Z", end: "2026-03-16T12:00:00Z" },
+        { start: "2026-03-16T13:00:00Z", end: "2026-03-16T14:00:00Z" },
+        { start: "2026-03-16T15:00:00Z", end: "2026-03-16T16:00:00Z" },
+      ] },
+    ];
+    const slots = nextFreeSlots(streams, "2026-03-16T08:00:00Z", "2026-03-16T18:00:00Z", 30, 3);
+    expect(slots.map((s) => s.start)).toEqual([
+      "2026-03-16T08:00:00+00:00",
+      "2026-03-16T10:00:00+00:00",
+      "2026-03-16T12:00:00+00:00",
+    ]);
+  });
+});
+
+describe("findCommonFree", () => {
+  it("returns only time free for both participants", () => {
+    const alice = {
+      streams: [{ stream_id: "alice", events: [{ start: "2026-03-16T12:00:00+00:00", end: "2026-03-16T13:00:00+00:00" }] }],
+      window_start: "2026-03-16T09:00:00+00:00",
+      window_end: "2026-03-16T17:00:00+00:00",
+    };
+    const bob = { streams: [], window_start: "2026-03-16T11:00:00+00:00", window_end: "2026-03-16T19:00:00+00:00" };
+    const slots = findCommonFree([alice, bob], 30);
+    expect(slots.map((s) => s.duration_minutes)).toEqual([60, 240]);
+    expect(slots[0].start).toContain("11:00:00");
+  });
+});
+
+describe("findSlotAcrossTimezones", () => {
+  const attendees = [
+    { streams: [], timezone: "America/Los_Angeles", work_start_hour: 8, work_end_hour: 17 },
+    {
+      streams: [{ stream_id: "london", events: [{ start: "2026-03-02T16:00:00Z", end: "2026-03-02T16:30:00Z" }] }],
+      timezone: "Europe/London",
+      work_start_hour: 9,
+      work_end_hour: 18,
+    },
+  ];
+
+  it("finds a slot in the working-hours overlap", () => {
+    const slot = findSlotAcrossTimezones(attendees, 60, "2026-03-02T00:00:00Z", "2026-03-03T00:00:00Z");
+    expect(slot?.start).toContain("2026-03-02T16:30:00");
+    expect(slot?.end).toContain("2026-03-02T17:30:00");
+  });
+
+  it("returns null when the overlap is too short", () => {
+    expect(findSlotAcrossTimezones(attendees, 150, "2026-03-02T00:00:00Z", "2026-03-03T00:00:00Z")).toBeNull();
+  });
+});
+
+describe("utilization", () => {
+  // 09:00-17:00 New York (EDT, UTC-4) on Monday 2026-03-16
+  const hours = { timezone: "America/New_York", start_hour: 9, end_hour: 17 };
+  const windowStart = "2026-03-16T04:00:00Z";
+  const windowEnd = "2026-03-17T04:00:00Z";
+
+  it("returns half for a half-booked day", () => {
+    const streams = [{ stream_id: "work", events: [{ start: "2026-03-16T13:00:00Z", end: "2026-03-16T17:00:00Z" }] }];
+    expect(utilization(streams, hours, windowStart, windowEnd)).toBeCloseTo(0.5);
+  });
+
+  it("ignores busy time before the working day", () => {
+    const streams = [{ stream_id: "work", events: [{ start: "2026-03-16T11:00:00Z", end: "2026-03-16T13:00:00Z" }] }];
+    expect(utilization(streams, hours, windowStart, windowEnd)).toBe(0);
+  });
+});
+
+describe("bucketByDay", () => {
+  it("splits a block crossing local midnight across both days", () => {
+    // 22:00-02:00 New York time (EST, UTC-5)
+    const streams = [
+      { stream_id: "a", events: [{ start: "2026-02-18T03:00:00+00:00", end: "2026-02-18T07:00:00+00:00" }] },
+    ];
+    const merged = mergeAvailability(streams, "2026-02-17T05:00:00+00:00", "2026-02-19T05:00:00+00:00");
+    const days = bucketByDay(merged, "America/New_York");
+    expect(days.map((d) => d.date)).toEqual(["2026-02-17", "2026-02-18"]);
+    expect(days[0].busy[0].end).toContain("2026-02-18T05:00:00");
+    expect(days[1].busy[0].start).toContain("2026-02-18T05:00:00");
+  });
+
+  it("throws on invalid timezone", () => {
+    const merged = mergeAvailability([], "2026-02-17T00:00:00+00:00", "2026-02-18T00:00:00+00:00");
+    expect(() => bucketByDay(merged, "Invalid/Zone")).toThrow();
+  });
+});
+
+describe("parseIcs", () => {
+  const ics = [
+    "BEGIN:VCALENDAR",
+    "BEGIN:VEVENT",
+    "SUMMARY:Standup",
+    "DTSTART;TZID=America/New_York:20260303T090000",
+    "DTEND;TZID=America/New_York:20260303T093000",
+    "RRULE:FREQ=WEEKLY;BYDAY=TU;COUNT=3",
+    "END:VEVENT",
+    "BEGIN:VEVENT",
+    "SUMMARY:Review",
+    "DTSTART:20260305T180000Z",
+    "DURATION:PT1H",
+    "END:VEVENT",
+    "END:VCALENDAR",
+  ].join("\r\n");
+
+  it("parses each VEVENT", () => {
+    const events = parseIcs(ics);
+    expect(events).toHaveLength(2);
+    expect(events[0].timezone).toBe("America/New_York");
+    expect(events[0].duration_minutes).toBe(30);
+    expect(events[1].rrule).toBeNull();
+    expect(events[1].duration_minutes).toBe(60);
+  });
+
+  it("feeds the recurring event into expandRRule", () => {
+    const [standup] = parseIcs(ics);
+    const instances = expandRRule(standup.rrule!, standup.dtstart, standup.duration_minutes, standup.timezone);
+    expect(instances).toHaveLength(3);
+    expect(instances[0].start).toContain("2026-03-03T14:00:00");
+    expect(instances[1].start).toContain("2026-03-10T13:00:00");
+  });
+
+  it("throws on an unterminated VEVENT", () => {
+    expect(() => parseIcs("BEGIN:VEVENT\nDTSTART:20260305T180000Z\n")).toThrow();
+  });
+});
+
 // ---------------------------------------------------------------------------
 // Temporal computation
 // ---------------------------------------------------------------------------
@@ -199,3 +511,16 @@ describe("resolveRelative", () => {
     expect(() => resolveRelative(anchor, "gobbledygook", "UTC")).toThrow();
   });
 });
+
+describe("parseRelativeWindow", () => {
+  it("resolves 'today' to the local day", () => {
+    // 03:00 UTC on Feb 19 is still Feb 18 in New York
+    const window = parseRelativeWindow("today", "2026-02-19T03:00:00+00:00", "America/New_York");
+    expect(window.start).toBe("2026-02-18T05:00:00+00:00");
+    expect(window.end).toBe("2026-02-19T05:00:00+00:00");
+  });
+
+  it("throws on an unknown spec", () => {
+    expect(() => parseRelativeWindow("someday", "2026-02-18T14:30:00+00:00", "UTC")).toThrow();
+  });
+});